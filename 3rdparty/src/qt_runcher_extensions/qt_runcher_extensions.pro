@@ -48,6 +48,7 @@ HEADERS += \
     src/pack_list_model.h \
     src/pack_list_tree_view.h \
     src/mod_list_model.h \
+    src/mod_list_roles.h \
     src/mod_list_tree_view.h \
     src/flags_item_delegate.h \
     src/html_item_delegate.h \
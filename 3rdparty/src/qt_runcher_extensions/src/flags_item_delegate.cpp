@@ -1,4 +1,5 @@
 #include "flags_item_delegate.h"
+#include "mod_list_roles.h"
 #include <QAbstractItemView>
 #include <QAbstractTextDocumentLayout>
 #include <QDebug>
@@ -7,11 +8,6 @@
 #include <QTextDocument>
 #include <QTreeView>
 
-const int FLAG_MOD_IS_OUTDATED = 31;
-const int FLAG_MOD_DATA_IS_OLDER_THAN_SECONDARY = 32;
-const int FLAG_MOD_DATA_IS_OLDER_THAN_CONTENT = 33;
-const int FLAG_MOD_SECONDARY_IS_OLDER_THAN_CONTENT = 34;
-
 extern "C" void flags_item_delegate(QObject *parent, const int column) {
     FlagsItemDelegate* delegate = new FlagsItemDelegate(parent);
     dynamic_cast<QAbstractItemView*>(parent)->setItemDelegateForColumn(column, delegate);
@@ -39,11 +35,21 @@ void FlagsItemDelegate::paint(QPainter *painter, const QStyleOptionViewItem &opt
     const int margin = (option.rect.height() - iconWidth) / 2;
     painter->translate(option.rect.topLeft());
 
+    if (index.data(FLAG_MOD_FAILED_TO_LOAD).toBool()) {
+        paintIcon(painter, option, index, "load_error.png", iconWidth, pos_x, margin);
+        pos_x += 3;
+    }
+
     if (index.data(FLAG_MOD_IS_OUTDATED).toBool()) {
         paintIcon(painter, option, index, "outdated.png", iconWidth, pos_x, margin);
         pos_x += 3;
     }
 
+    if (index.data(FLAG_MOD_HAS_ADVISORY).toBool()) {
+        paintIcon(painter, option, index, "advisory.png", iconWidth, pos_x, margin);
+        pos_x += 3;
+    }
+
     if (index.data(FLAG_MOD_DATA_IS_OLDER_THAN_SECONDARY).toBool()) {
         paintIcon(painter, option, index, "data_older_than_secondary.png", iconWidth, pos_x, margin);
         pos_x += 3;
@@ -1,4 +1,5 @@
 #include "html_item_delegate.h"
+#include "mod_list_roles.h"
 #include <QAbstractItemView>
 #include <QAbstractTextDocumentLayout>
 #include <QPainter>
@@ -20,7 +21,7 @@ void HtmlItemDelegate::paint(QPainter *painter, const QStyleOptionViewItem &opti
     QStyleOptionViewItem opt = option;
 
     // Remove indentation for category items.
-    if (index.column() == 0 && index.data(40).toBool()) {
+    if (index.column() == 0 && index.data(VALUE_IS_CATEGORY).toBool()) {
         opt.rect.adjust(-5, 0, 0, 0);
         QStyledItemDelegate::paint(painter, opt, index);
     } else {
@@ -1,6 +1,8 @@
 #include "kicontheme.h"
 #include "launcher_window.h"
+#include <QMimeData>
 #include <QSettings>
+#include <QUrl>
 
 // Fuction to be able to create a custom QMainWindow.
 extern "C" QMainWindow* launcher_window(bool use_dark_theme) {
@@ -8,6 +10,10 @@ extern "C" QMainWindow* launcher_window(bool use_dark_theme) {
 }
 
 LauncherWindow::LauncherWindow(QWidget *parent, bool use_dark_theme) : QMainWindow(parent) {
+
+    // Needed to be able to drop files from outside into the window.
+    setAcceptDrops(true);
+
     #ifdef _WIN32
 
         // Initialize the icon theme. Holy shit this took way too much research to find how it works.
@@ -63,3 +69,36 @@ void LauncherWindow::closeEvent(QCloseEvent *event) {
 
     event->accept();
 }
+
+// Only accept local files dropped from outside. Drops within the window are handled by the views themselves.
+void LauncherWindow::dragEnterEvent(QDragEnterEvent *event) {
+    if (event->mimeData()->hasUrls()) {
+        event->acceptProposedAction();
+    } else {
+        event->ignore();
+    }
+}
+
+void LauncherWindow::dragMoveEvent(QDragMoveEvent *event) {
+    if (event->mimeData()->hasUrls()) {
+        event->acceptProposedAction();
+    } else {
+        event->ignore();
+    }
+}
+
+// NOTE: We don't filter the files here. We pass all of them to rust, so it can tell the user what got rejected.
+void LauncherWindow::dropEvent(QDropEvent *event) {
+    QStringList paths;
+    for (const QUrl &url: event->mimeData()->urls()) {
+        if (url.isLocalFile()) {
+            paths.append(url.toLocalFile());
+        }
+    }
+
+    event->acceptProposedAction();
+
+    if (!paths.isEmpty()) {
+        emit filesDropped(paths);
+    }
+}
@@ -4,6 +4,8 @@
 #include <QApplication>
 #include <QCloseEvent>
 #include <QDebug>
+#include <QDragEnterEvent>
+#include <QDropEvent>
 #include <QFileInfo>
 #include <QIcon>
 #include <QResource>
@@ -13,9 +15,16 @@ extern "C" QMainWindow* launcher_window(bool use_dark_theme);
 
 class LauncherWindow: public QMainWindow {
     Q_OBJECT
+signals:
+    void filesDropped(QStringList const &);
 public:
     explicit LauncherWindow(QWidget *parent = nullptr, bool use_dark_theme = false);
     void closeEvent(QCloseEvent *event) override;
+
+protected:
+    void dragEnterEvent(QDragEnterEvent *event) override;
+    void dragMoveEvent(QDragMoveEvent *event) override;
+    void dropEvent(QDropEvent *event) override;
 };
 
 #endif // LAUNCHERWINDOW_H
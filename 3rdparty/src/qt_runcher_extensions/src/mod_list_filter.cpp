@@ -1,4 +1,5 @@
 #include "mod_list_filter.h"
+#include "mod_list_roles.h"
 #include <QItemSelection>
 #include <QRegExp>
 #include <QSortFilterProxyModel>
@@ -20,7 +21,7 @@ ModListFilter::ModListFilter(QObject *parent): QSortFilterProxyModel(parent) {}
 bool ModListFilter::filterAcceptsRow(int source_row, const QModelIndex &source_parent) const {
     bool result = QSortFilterProxyModel::filterAcceptsRow(source_row, source_parent);
     QModelIndex currntIndex = sourceModel()->index(source_row, 0, source_parent);
-    bool isCategory = currntIndex.data(40).toBool();
+    bool isCategory = currntIndex.data(VALUE_IS_CATEGORY).toBool();
 
     // Always show categories.
     if (isCategory) {
@@ -1,9 +1,74 @@
 #include "mod_list_model.h"
+#include "mod_list_roles.h"
+
+#include <QApplication>
+#include <QPalette>
+
+// Opacity of the accent painted behind enabled mods.
+const int ENABLED_MOD_ACCENT_ALPHA = 40;
 
 extern "C" QStandardItemModel* new_mod_list_model() {
     return dynamic_cast<QStandardItemModel*>(new ModListModel());
 }
 
+extern "C" void mod_list_model_set_color_enabled(QStandardItemModel* model, bool enabled) {
+    ModListModel* modListModel = dynamic_cast<ModListModel*>(model);
+    if (modListModel != nullptr) {
+        modListModel->setColorEnabled(enabled);
+    }
+}
+
+ModListModel::ModListModel(QObject *parent): QStandardItemModel(parent) {
+
+    // The background of a row depends on the check state of its first column,
+    // so when that changes we need to repaint the entire row, not just that cell.
+    connect(this, &QStandardItemModel::itemChanged, this, [this](QStandardItem* item) {
+        if (item->column() == 0 && item->columnCount() == 0) {
+            QModelIndex parent = item->index().parent();
+            int lastColumn = columnCount(parent) - 1;
+            if (lastColumn > 0) {
+                emit dataChanged(index(item->row(), 1, parent), index(item->row(), lastColumn, parent), {Qt::BackgroundRole});
+            }
+        }
+    });
+}
+
+void ModListModel::setColorEnabled(bool enabled) {
+    if (colorEnabled != enabled) {
+        colorEnabled = enabled;
+
+        // Only the background of the mods changes, so there's no need to relayout. Mods are always under a category.
+        int lastColumn = columnCount() - 1;
+        for (int row = 0; row < rowCount(); ++row) {
+            QModelIndex category = index(row, 0);
+            int lastRow = rowCount(category) - 1;
+            if (lastRow >= 0 && lastColumn >= 0) {
+                emit dataChanged(index(0, 0, category), index(lastRow, lastColumn, category), {Qt::BackgroundRole});
+            }
+        }
+    }
+}
+
+// Function to paint a subtle accent behind enabled mods. It uses the highlight color of the current palette,
+// so it follows the theme in use.
+QVariant ModListModel::data(const QModelIndex &index, int role) const {
+    if (role == Qt::BackgroundRole && colorEnabled && index.isValid() && index.parent().isValid()) {
+        QModelIndex first = index.siblingAtColumn(0);
+        if (!first.data(VALUE_IS_CATEGORY).toBool() && first.data(Qt::CheckStateRole).toInt() == Qt::Checked) {
+            QVariant background = QStandardItemModel::data(index, role);
+            if (background.isValid()) {
+                return background;
+            }
+
+            QColor color = QApplication::palette().color(QPalette::Highlight);
+            color.setAlpha(ENABLED_MOD_ACCENT_ALPHA);
+            return QBrush(color);
+        }
+    }
+
+    return QStandardItemModel::data(index, role);
+}
+
 // Function to check if an item can be drag or drop into.
 Qt::ItemFlags ModListModel::flags(const QModelIndex &index) const {
     Qt::ItemFlags defaultFlags = QStandardItemModel::flags(index);
@@ -15,7 +80,7 @@ Qt::ItemFlags ModListModel::flags(const QModelIndex &index) const {
     // - Drop for cats only.
     if (index.isValid()) {
         QStandardItem* item = itemFromIndex(index);
-        bool isCat = item->data(40).toBool();
+        bool isCat = item->data(VALUE_IS_CATEGORY).toBool();
         if (isCat) {
             return Qt::ItemIsDragEnabled | Qt::ItemIsDropEnabled | defaultFlags;
         }
@@ -8,11 +8,18 @@
 #include <QMimeData>
 
 extern "C" QStandardItemModel* new_mod_list_model();
+extern "C" void mod_list_model_set_color_enabled(QStandardItemModel* model, bool enabled);
 
 class ModListModel : public QStandardItemModel {
     Q_OBJECT
 public:
+    explicit ModListModel(QObject *parent = nullptr);
     Qt::ItemFlags flags(const QModelIndex &index) const;
+    QVariant data(const QModelIndex &index, int role = Qt::DisplayRole) const;
+    void setColorEnabled(bool enabled);
+
+private:
+    bool colorEnabled = true;
 };
 
 #endif // MOD_LIST_MODEL_H
@@ -0,0 +1,14 @@
+#ifndef MOD_LIST_ROLES_H
+#define MOD_LIST_ROLES_H
+
+// Data roles of the mod list items. They must match the ones in the Rust side (mod_list_ui).
+const int VALUE_IS_CATEGORY = 40;
+
+const int FLAG_MOD_IS_OUTDATED = 31;
+const int FLAG_MOD_DATA_IS_OLDER_THAN_SECONDARY = 32;
+const int FLAG_MOD_DATA_IS_OLDER_THAN_CONTENT = 33;
+const int FLAG_MOD_SECONDARY_IS_OLDER_THAN_CONTENT = 34;
+const int FLAG_MOD_FAILED_TO_LOAD = 35;
+const int FLAG_MOD_HAS_ADVISORY = 36;
+
+#endif // MOD_LIST_ROLES_H
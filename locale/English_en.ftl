@@ -5,6 +5,7 @@ open_game_root_folder = Open Game Root Folder
 open_game_data_folder = Open Game Data Folder
 open_game_content_folder = Open Game Content Folder
 open_game_secondary_folder = Open Game Secondary Folder
+open_game_secondary_extra_folders = Open Additional Secondary Folder
 open_game_config_folder = Open Game Config Folder
 open_runcher_config_folder = Open Runcher Config Folder
 open_runcher_error_folder = Open Runcher Error Folder
@@ -129,6 +130,29 @@ mod_secondary_older_than_content = <li>
         <p/>
     </li>
 
+mod_load_error_description = <li>
+        <p>
+            <img src="data:image/png;base64,{"{"}{"}"}"/>
+            <b>Failed to Load</b>: The pack of this mod could not be read, so it's probably broken or incomplete. It's kept disabled until it loads again, as it would likely crash the game.
+        <p/>
+        <p><i>{"{"}{"}"}</i><p/>
+    </li>
+mod_load_error_generic = The error found when reading the pack is shown here.
+mod_load_error_redownload = <p>This mod is from the workshop. You can re-download it with <b>Re-download Broken Mods</b> in the context menu.<p/>
+
+mod_advisory_description = <li>
+        <p>
+            <img src="data:image/png;base64,{"{"}{"}"}"/>
+            <b>{"{"}{"}"}</b>: {"{"}{"}"}
+        <p/>
+        {"{"}{"}"}
+    </li>
+mod_advisory_status_outdated = Flagged as Outdated
+mod_advisory_status_incompatible = Flagged as Incompatible
+mod_advisory_status_broken = Flagged as Broken
+mod_advisory_status_generic = Flagged by the Community
+mod_advisory_generic = This mod has been reported as outdated, incompatible or broken in the source of mod advisories set in the settings. The reason, and a link with more details if there's one, are shown here. This is only a warning: the mod is not disabled.
+
 unit_multiplier = Unit Multiplier
 
 update_schema_checker = Update Schema Checker
@@ -142,6 +166,7 @@ check_sql_scripts_updates_on_start = Check SQL Scripts Updates
 steam_user_id = Steam User Id
 merge_all_mods = Merge All Mods
 enable_translations = Enable Translations
+enable_translations_filter = Type to filter
 
 github_link = Open Runcher's Github Page
 discord_link = Open "The Modding Den" discord channel
@@ -209,7 +234,7 @@ profile_delete = Delete Profile
 profile_shortcut_new = Create Shortcut
 profile_manager_title = Profile Manager
 
-are_you_sure_delete_profile = Are you sure you want to delete the currently selected profile?
+are_you_sure_delete_profile = Are you sure you want to delete the currently selected profiles?
 
 profile_shortcut = New Shortcut
 profile_shortcut_name = Shortcut Name
@@ -237,6 +262,7 @@ reload_data_view = Reload Data View
 
 expand_all = Expand All
 collapse_all = Collapse All
+mod_list_reset_layout = Reset Layout
 open_workshop_link_in_steam = Open Workshop Links on Steam
 
 import_string_modlist_mode = Modlist Mode
@@ -244,6 +270,9 @@ import_string_runcher_mode = Runcher Mode
 
 settings_secondary_mods_folder = Secondary Mods Folder
 settings_secondary_mods_folder_ph = This is an alternative folder to place mods, so /data doesn't get too crowded.
+settings_secondary_mods_extra_folders = Additional Secondary Mods Folders
+settings_secondary_mods_extra_folders_ph = Extra folders to load mods from, one per line. If a mod is in more than one secondary folder, the one in the folder higher in this list is used, with the main one above all of them.
+settings_secondary_mods_extra_folders_add = Add a folder to the list.
 
 upload_to_workshop_title = Upload/Update To Workshop
 upload_to_workshop = Upload/Update To Workshop
@@ -260,6 +289,7 @@ upload_workshop_visibility_unlisted = Unlisted
 
 copy_to_secondary = Copy to Secondary folder
 move_to_secondary = Move to Secondary folder
+move_to_data = Move to Data folder
 
 copy_to_secondary_failed = <p>The following files failed to be copied:</p>
     <ul>
@@ -273,6 +303,12 @@ move_to_secondary_failed = <p>The following files failed to be moved:</p>
     </ul>
     <p>This can happen either because of an IO error, or because the file you're trying to move is not /data. To copy files from /content to /secondary use the copy feature.</p>
 
+move_to_data_failed = <p>The following files failed to be moved. Files are never moved over a file with the same name already in /data:</p>
+    <ul>
+    {"{"}{"}"}
+    </ul>
+    <p>This can happen either because of an IO error, or because the file you're trying to move is not in /secondary.</p>
+
 download_subscribed_mods = Download Subscribed Mods
 mods_downloaded = Mods downloaded. Please, MAKE SURE TO CHECK THE LOG.
 
@@ -281,6 +317,12 @@ mods_downloaded = Mods downloaded. Please, MAKE SURE TO CHECK THE LOG.
     The log contains the links to them.
 
 download_from_workshop = Download Selected Mods
+repair_from_workshop = Repair Mod (Re-download)
+repair_from_workshop_confirm = This will delete the local copy of the mod {"{"}{"}"} and download it again from the workshop. Are you sure?
+repair_from_workshop_not_workshop = The mod {"{"}{"}"} is not from the workshop, so it cannot be re-downloaded.
+repair_from_workshop_remove_failed = The local copy of the mod {"{"}{"}"} could not be removed, so it was not re-downloaded. Make sure the game is not running and the mod is in the content folder.
+redownload_broken_mods = Re-download Broken Mods
+redownload_broken_mods_none = There are no broken mods from the workshop to re-download.
 
 log_anaylis_title = Log Analysis
 log_anaylis_explanation_title = Explanation
@@ -298,3 +340,467 @@ updates_locked = Updates Locked
 universal_rebalancer = Universal Rebalancer
 enable_dev_only_ui = Enable Dev-Only UI
 preset = Preset
+
+duplicated_packs_title = Duplicated Packs
+duplicated_packs_explanation_title = Explanation
+duplicated_packs_explanation = The following Pack files are found more than once in the enabled mods, either because more than one mod resolves to them, or because they're installed in more than one folder (like a workshop copy and a manual copy in /data). The game only loads one of them, and which one is not predictable. Unchecking a copy disables the mod it belongs to.
+
+    Uncheck the mods you want to disable before launching the game.
+
+reload_progress_profiles = Reloading profiles...
+reload_progress_saves = Reloading saves...
+reload_progress_mods = Reloading mods...
+reload_progress_scripts = Reloading scripts...
+
+game_log_title = Game Log
+game_log_follow = Follow the game logs. Uncheck to pause the view.
+game_log_placeholder = The logs of the game will show here after launching it, if "Follow Game Logs After Launch" is enabled in the settings. Make sure the "Enable Logging" launch option is checked.
+tail_game_log = Follow Game Logs After Launch
+
+custom_launch_options_manager = Custom Launch Options...
+custom_launch_options_title = Custom Launch Options
+custom_launch_options_explanation_title = Explanation
+custom_launch_options_explanation = Here you can define launch options not supported by Runcher, as long as you know the flag the game expects for them. They'll show up at the end of the launch options menu.
+
+    - Options without value pass their flag to the game when checked.
+    - Options with value pass their flag, followed by the value, when the value is different from the default one.
+
+    Keep in mind these flags are passed as-is to the game. If the game doesn't support them, it may not start.
+custom_launch_options_add = Add Option
+custom_launch_options_remove = Remove Selected Options
+custom_launch_options_name = Name
+custom_launch_options_flag = Flag
+custom_launch_options_has_value = Has Value
+custom_launch_options_default_value = Default Value
+custom_launch_options_duplicated_name = There is more than one option named "{"{"}{"}"}". Option names must be unique.
+custom_launch_option = Custom Launch Option
+
+toggle_matching = Enable/Disable Matching...
+toggle_matching_title = Enable/Disable Matching Mods
+toggle_matching_pattern = Pattern:
+toggle_matching_pattern_placeholder = Mod name or pack name, like "sfo_*"
+toggle_matching_use_regex = Use Regex:
+toggle_matching_action = Action:
+toggle_matching_enable = Enable Matching Mods
+toggle_matching_disable = Disable Matching Mods
+toggle_matching_no_pattern = Write a pattern to see which mods match it.
+toggle_matching_count = {"{"}{"}"} mods match the pattern.
+toggle_matching_invalid_pattern = Invalid pattern: {"{"}{"}"}
+random_subset = Keep Random Half Enabled...
+random_subset_title = Keep Random Half Enabled
+random_subset_seed = Seed:
+random_subset_new_seed = Pick a new random seed.
+random_subset_count = These {"{"}{"}"} of the {"{"}{"}"} enabled mods will be kept enabled. The rest will be disabled. The same seed always picks the same mods, as long as the same mods are enabled.
+random_subset_invalid_seed = The seed must be a positive number.
+random_subset_not_enough_mods = You need at least two mods enabled to pick a random half of them.
+bisect = Find Crashing Mod...
+bisect_title = Find Crashing Mod
+bisect_not_enough_mods = You need at least two mods enabled to search for the one crashing the game.
+bisect_start_confirm = <p>This will help you find which of the {"{"}{"}"} enabled mods makes the game crash, by enabling only some of them on each test, and asking you if the game crashed with them. It'll take {"{"}{"}"} tests at most.</p><p>Your enabled mods will be restored once the search ends. You can stop it at any time from this same menu.</p><p>Do you want to start the search?</p>
+bisect_test = <p>Test {"{"}{"}"}: {"{"}{"}"} mods are now enabled, with {"{"}{"}"} mods still suspected.</p><p>Launch the game and check if it crashes. Then come back to <b>Find Crashing Mod...</b> to report the result. You can close the launcher between tests.</p>
+bisect_status = <p>Test {"{"}{"}"} in progress: {"{"}{"}"} mods enabled, {"{"}{"}"} mods still suspected, {"{"}{"}"} tests left at most.</p><p>Did the game crash with the currently enabled mods?</p><ul>{"{"}{"}"}</ul>
+bisect_result_crashed = Test {"{"}{"}"}: crashed with {"{"}{"}"} suspects enabled.
+bisect_result_worked = Test {"{"}{"}"}: worked with {"{"}{"}"} suspects enabled.
+bisect_crashed = It Crashed
+bisect_worked = It Worked
+bisect_stop = Stop Search
+bisect_found = <p>The mod crashing the game is <b>{"{"}{"}"}</b>, found after {"{"}{"}"} tests.</p><p>Your enabled mods have been restored.</p>
+bisect_stopped = Search stopped. Your enabled mods have been restored.
+
+unit_multiplier_max = Unit Multiplier Limit
+unit_multiplier_tooltip = Multiplies the size of the units. Limited to {"{"}{"}"}. Values known to be safe go up to ~50. Higher values may crash the game on load.
+unit_multiplier_warning = Unit Multiplier values this close to the configured limit may crash the game on load. Use them at your own risk.
+unit_multiplier_preset = Unit Multiplier Preset
+unit_multiplier_preset_tooltip = Named unit multiplier values saved for this game. Selecting one applies its value to the Unit Multiplier.
+unit_multiplier_preset_save = Save Unit Multiplier Preset
+unit_multiplier_preset_delete = Delete Unit Multiplier Preset
+unit_multiplier_preset_name = Preset Name
+
+profile_duplicate = Duplicate Profiles
+profile_export = Export Profiles
+profile_export_folder = Select the folder to export the profiles to
+profile_delete_export_first = Export and Delete
+profile_delete_without_export = Delete Without Exporting
+profile_filter = Filter profiles...
+
+script_expand = Show/Hide the options of this script.
+script_show_params = Switch between the presets and the individual parameters of this script.
+
+copy_load_order_markdown = Copy Load Order as Markdown Table
+copy_load_order_markdown_names_only = Markdown Table: Names Only
+copy_load_order_markdown_copied = Load Order copied to the clipboard as a Markdown table.
+copy_workshop_ids = Copy Workshop IDs of Enabled Mods
+copy_workshop_ids_tooltip = Copies the workshop ids of the enabled mods to the clipboard, one per line, in load order. Mods not from the workshop are skipped.
+copy_workshop_ids_copied = Copied the workshop ids of {"{"}{"}"} enabled mods to the clipboard.
+copy_workshop_ids_copied_skipped = Copied the workshop ids of {"{"}{"}"} enabled mods to the clipboard. Skipped {"{"}{"}"} mods not from the workshop.
+
+launch_wrapper_command = Launch Wrapper Command
+launch_wrapper_command_ph = Command to launch the game through, both when launching from Runcher and in exported launch scripts and commands. Use quotes for paths with spaces. For example: "C:\Tools\My Wrapper.exe" --fps
+launch_environment_variables = Launch Environment Variables
+launch_environment_variables_ph = Environment variables for the game, both when launching from Runcher and in exported launch scripts and commands, in KEY=VALUE;KEY2=VALUE2 format. Names can only contain letters, numbers and underscores, and values cannot contain double quotes.
+launch_extra_args = Extra Launch Arguments
+launch_extra_args_ph = Extra arguments to pass to the game, separated by spaces. Use quotes for arguments with spaces. Profiles with their own arguments use those instead.
+
+script_modified = This script has a preset selected or params with non-default values. Click to reset them to their defaults.
+
+global_search = Search mods, profiles and scripts (Ctrl+K)
+global_search_title = Search
+global_search_placeholder = Type to search mods, profiles and scripts...
+global_search_mods = Mods
+global_search_profiles = Profiles
+global_search_scripts = Scripts
+
+launch_options_reorder = Reorder Launch Options
+launch_options_reorder_info = Drag the launch options to change the order in which they show up in the launch menu.
+launch_options_scripts = Scripts
+
+script_sandbox = Test Script in Sandbox
+script_sandbox_info = Executes the selected script alone, with its current params, over the current load order or the vanilla game, and shows the rows it adds (+), changes (~) or removes (-). The result is never loaded by the game.
+script_sandbox_run = Run
+script_sandbox_no_scripts = There are no scripts available for the game selected.
+script_sandbox_no_changes = The script didn't add, change or remove any row.
+script_sandbox_vanilla = Compare Against Vanilla
+script_sandbox_vanilla_tooltip = Executes the script without mods, and compares its result against the tables of the game alone, so you see what the script itself changes.
+script_sandbox_copy = Copy Results
+
+profile_default = Set as Default for this Game. The default profile is loaded automatically when selecting the game, unless a profile is passed through the command line.
+
+download_max_concurrent = Max Concurrent Downloads
+download_max_concurrent_tt = Max amount of mods to download at the same time when downloading specific mods from the mod list. Mods over the limit wait until the previous ones finish downloading.
+download_max_speed = Max Download Speed
+download_max_speed_suffix =  KB/s
+download_max_speed_tt = Max average speed when downloading specific mods from the mod list. Steam cannot slow down a mod while it's downloading, so the limit is kept by waiting before starting the next mods. Big mods may still go over it while they download. To limit the speed of each download, use the download throttling settings of the Steam client.
+download_max_concurrent_unlimited = Unlimited
+
+mod_updates_title = Recent Mod Updates
+mod_updates_downloaded_title = Mods Downloaded
+mod_updates_info = Mods updated in previous downloads, from newest to oldest. Steam doesn't provide the change notes of each update, so check them in the workshop.
+mod_updates_empty = No mod updates detected yet.
+mod_updates_change_notes = Change Notes
+
+game_pin_to_top = Pin to Top
+game_unpin = Unpin
+game_reset_to_defaults = Reset to Defaults
+game_reset_confirm = <p>This resets all the settings of <b>{"{"}{"}"}</b> to their defaults: launch options, scripts and their params, unit multiplier, custom launch options and mod sources.</p><p>The path of the game, other games and global settings are not touched. Do you want to back up the profiles and launch settings first?</p>
+game_reset_backup = Back Up and Reset
+game_reset_no_backup = Reset without Backup
+game_reset_done = The settings of the game have been reset to their defaults. If you made a backup, you can restore it from the Profile Manager.
+
+open_game_crash_dumps_folder = Open Game Crash Dumps Folder
+create_crash_report = Create Crash Report
+crash_report_created = <p>Crash report created in:</p><p>{"{"}{"}"}</p><p>It contains the latest crash dump of the game (if any), its load order, mod list and launch options, with your user name and home folder removed from the text files. Attach it to your bug report.</p>
+
+effective_settings = Effective Launch Settings
+effective_settings_info = Settings that are going to be used when launching the game selected, alongside their defaults and where their values come from. This is read-only.
+export_launch_script = Export Launch Script
+launch_script_filter = Launch Scripts (*.bat *.cmd *.sh)
+launch_script_exported = <p>Launch script exported to <i>{"{"}{"}"}</i>.</p><p>It launches the executable directly, with the mod list and launch options prepared just now. Export it again if you change them.</p>
+effective_settings_filter = Filter settings...
+effective_settings_copy = Copy to Clipboard
+effective_settings_setting = Setting
+effective_settings_value = Value
+effective_settings_default = Default
+effective_settings_source = Source
+effective_settings_source_default = Default
+effective_settings_source_override = Override
+effective_settings_source_preset = Preset: {"{"}{"}"}
+effective_settings_source_profile = Profile: {"{"}{"}"}
+effective_settings_source_unavailable = Not available for this game
+effective_settings_launch_options = Launch Options
+effective_settings_scripts = Scripts
+effective_settings_custom_launch_options = Custom Launch Options
+
+preset_group = Preset Group
+preset_group_tooltip = Selects the preset with this name in all the scripts that have it.
+
+script_destructive = This script modifies game files irreversibly. The files it affects are backed up before launching the game.
+destructive_scripts_confirm = <p>The following enabled scripts modify game files irreversibly:</p><p><b>{"{"}{"}"}</b></p><p>The files they affect will be backed up before launching the game, and can be restored from the Play menu. Are you sure you want to continue?</p>
+restore_script_backup = Restore Script Backup
+restore_script_backup_confirm = This will restore the files modified by destructive scripts to how they were before any script modified them, overwriting the current ones. Are you sure?
+script_backup_restored = Script backup restored from: {"{"}{"}"}
+script_backup_none = There are no script backups to restore for this game.
+
+launch_options_visible = Launch Options Shown in the Play Menu
+launch_options_visible_tt = Unchecked launch options are hidden from the play menu. They keep their current values, so they still apply when launching the game.
+
+merge_categories = Categories to Merge
+merge_categories_tooltip = Categories whose enabled mods get merged when "Merge all mods" is enabled. If none are selected, all enabled mods are merged.
+merge_categories_all = All
+merge_categories_count = {"{"}{"}"} categories
+
+reapply_scripts = Reapply Scripts Without Launching
+scripts_reapplied = Enabled scripts and launch options applied. The game was not launched.
+
+missing_dependencies_title = Missing Dependencies
+missing_dependencies_not_installed = <p>The following mods are required by enabled mods, but they're not installed:</p><ul>{"{"}{"}"}</ul>
+missing_dependencies_disabled = <p>The following mods are required by enabled mods, but they're disabled:</p><ul>{"{"}{"}"}</ul>
+missing_dependencies_enable = Enable Dependencies and Launch
+missing_dependencies_launch_anyway = Launch Anyway
+enable_dependencies_title = Mod Dependencies
+enable_dependencies = <p><b>{"{"}{"}"}</b> requires other mods to work.</p>
+enable_dependencies_disabled = <p>The following mods it requires are installed, but disabled:</p><ul>{"{"}{"}"}</ul>
+enable_dependencies_not_installed = <p>The following mods it requires are not installed. Subscribe to them, or the game may crash:</p><ul>{"{"}{"}"}</ul>
+enable_dependencies_missing = <p>The mod you just enabled requires mods that are not installed. Subscribe to them, or the game may crash:</p><ul>{"{"}{"}"}</ul>
+enable_dependencies_enable = Enable Dependencies
+enable_dependencies_skip = Don't Enable
+enable_dependencies_always = Don't ask again, always enable them
+enable_dependencies_enabled = Enabled {"{"}{"}"} dependencies of the mod.
+
+recent_profiles_empty = No recently loaded profiles.
+
+import_load_order_file = Import Load Order from File
+import_load_order_file_filter = Load Order Files (*.dat *.txt *.lst);;All Files (*)
+
+load_mods_from = Load Mods From
+load_mods_from_data = Data Folder
+load_mods_from_secondary = Secondary Folder
+load_mods_from_content = Content (Workshop) Folder
+load_mods_from_none = At least one folder must be selected to load mods from.
+
+launch_hook_on_launch = On Launch Hook
+launch_hook_on_launch_ph = Command to run when the game is launched. The output is written to the log.
+launch_hook_on_exit = On Exit Hook
+launch_hook_on_exit_ph = Command to run when the game exits. It only runs if Runcher could follow the game until it closed. The output is written to the log.
+
+unsaved_profile_changes_title = Unsaved Profile Changes
+unsaved_profile_changes = <p>The current load order has changes not saved to the profile <b>{"{"}{"}"}</b>, which will be lost if you continue.</p><p>Check the details to see what changed.</p>
+unsaved_profile_changes_reordered = The order of the mods has changed.
+unsaved_profile_changes_save = Save Profile and Continue
+unsaved_profile_changes_discard = Discard Changes and Continue
+
+script_preset_partial = This preset was made before the script got some of its params, so these use their default values: {"{"}{"}"}.
+script_preset_resave = Re-save the preset, so it includes the params it's missing with their default values.
+
+play_menu_pinned = Keep Menu Open
+
+save_manager = Manage Saves
+save_manager_info = Select the saves to back up or delete. Use Ctrl or Shift to select more than one. Double-click a save to launch the game into it. Saves are sorted from newest to oldest.
+save_manager_selected = {"{"}{"}"} of {"{"}{"}"} saves selected.
+save_manager_backup = Back Up Selected
+save_manager_delete = Delete Selected
+save_manager_delete_confirm = <p>Are you sure you want to delete <b>{"{"}{"}"}</b> saves?</p><p>This cannot be undone. Back them up first if you may want them later.</p>
+save_manager_backed_up = {"{"}{"}"} saves backed up to <i>{"{"}{"}"}</i>.
+save_manager_no_saves = There are no saves for the game selected.
+launch_with_save_missing = The save is no longer available. Reload the saves and try again.
+
+settings_generated_packs_folder = Generated Packs Folder
+settings_generated_packs_folder_ph = Folder where the packs generated on launch by scripts and mod merging are written. Leave it empty to use the default locations.
+settings_generated_packs_folder_not_writable = The Generated Packs Folder ({"{"}{"}"}) cannot be written to. Choose another folder, or leave it empty to use the default locations.
+
+create_diagnostics_bundle = Create Diagnostics Bundle
+diagnostics_bundle_created = <p>Diagnostics bundle created in:</p><p>{"{"}{"}"}</p><p>It contains the latest logs of the game and Runcher, the launch options, the load order and some basic system info. Paths to your user folder and your user name have been redacted. Attach it to your help request.</p>
+export_config_bundle = Export Configuration
+import_config_bundle = Import Configuration
+config_bundle_filter = Configuration Bundles (*.zip)
+config_bundle_import_confirm = <p>This configuration was exported with Runcher {"{"}{"}"}. It contains {"{"}{"}"} files and {"{"}{"}"} settings.</p><p>Profiles, game configs, local scripts and settings with the same names as the ones in the bundle will be overwritten. Everything else will be kept. A backup of your current profiles will be made first.</p><p>Are you sure you want to import it?</p>
+config_bundle_exported = <p>Configuration exported to:</p><p>{"{"}{"}"}</p><p>It contains your settings, profiles, game configs and local scripts. Downloaded scripts, schemas and backups are not included, and neither are your Steam API key, launch hooks and launch wrapper command.</p>
+config_bundle_imported = Configuration imported.
+config_bundle_imported_skipped = <p>Configuration imported, but some parts of it were skipped:</p><ul>{"{"}{"}"}</ul>
+
+scripts_filter = Filter Scripts
+scripts_filter_placeholder = Filter by name or key. Supports wildcards, like unit_*
+scripts_filter_tooltip = Shows only the scripts whose name or key contain this text. If it contains wildcards (*, ? or [abc]), the whole name or key must match the pattern instead.
+
+profile_copy_scripts = Copy Scripts from another Profile, or one Script from the Current Settings
+profile_copy_scripts_empty = No other profiles with scripts stored
+profile_copy_script = Copy one Script from the Current Settings
+profile_copy_script_no_scripts = The profile "{"{"}{"}"}" doesn't store scripts, so copying only one script into it would disable the rest when loading it. Save the profile with its scripts first.
+profile_copy_script_confirm = <p>Copying the script into the profile "{"{"}{"}"}" will overwrite these values:</p><ul>{"{"}{"}"}</ul><p>Are you sure?</p>
+profile_copy_script_removed = (removed)
+
+automatic_scripts = Automatic Scripts
+automatic_scripts_count = {"{"}{"}"} will run
+automatic_scripts_tooltip = Scripts that always run when launching the game, as they're required by other features. They're not shown in the scripts list below. Click to see which ones will run, or uncheck one to disable it if it breaks.
+automatic_scripts_count_disabled = {"{"}{"}"} will run, {"{"}{"}"} disabled
+automatic_scripts_disabled_tooltip = Some automatic scripts have been disabled, so the features depending on them may not work. Click to see which ones, and check them again to re-enable them.
+automatic_scripts_count_dormant = {"{"}{"}"} will run, {"{"}{"}"} inactive
+automatic_scripts_dormant_tooltip = Some automatic scripts only run when the mods they're meant for are enabled, and those mods are not enabled right now. Click to see which ones, and which mods they need.
+automatic_script_dormant = {"{"}{"}"} (needs {"{"}{"}"})
+automatic_script_disable_confirm = <p>The script <b>{"{"}{"}"}</b> always runs, as other features depend on it. Only disable it if it's broken with your version of the game.</p><p>It will stay disabled until you enable it again in this list. Are you sure you want to disable it?</p>
+
+game_executable = Executable
+game_executable_tooltip = Executable of the game to launch. Some games ship more than one, like DX11/DX12 variants or benchmark ones. The first one is the standard one.
+multiplayer_safe = Multiplayer-Safe Mode
+multiplayer_safe_tooltip = Turns off the launch options that change the game for you only, so your game matches the one of the other players. Disabling it restores the options as they were before enabling it.
+multiplayer_safe_options = Allowed in Multiplayer
+multiplayer_safe_options_tooltip = Launch options Multiplayer-Safe Mode leaves as they are. It can only be changed while the mode is disabled.
+multiplayer_safe_options_count = {"{"}{"}"} allowed
+multiplayer_safe_options_none = None allowed
+multiplayer_safe_indicator = MP-Safe
+multiplayer_safe_indicator_tooltip = Launch the game. Multiplayer-Safe Mode is enabled, so the launch options not allowed in multiplayer are turned off.
+
+rebuild_translations = Rebuild Translations Without Launching
+rebuild_translations_no_language = No translation selected. Select one in the "Enable Translations" launch option first.
+translations_rebuilt = Translation {"{"}{"}"} rebuilt. The generated pack contains {"{"}{"}"} loc files. The game was not launched.
+merge_summary_show = Show Last Merge Summary
+merge_summary_none = The mods of the selected game haven't been merged yet. Enable "Merge All Mods" and launch the game to merge them.
+merge_summary_status = Merged {"{"}{"}"} mods into {"{"}{"}"} files, with {"{"}{"}"} conflicts resolved. Check "Show Last Merge Summary" in the launch options for details.
+merge_summary_build_unknown = Unknown
+merge_summary_outdated = <p><b>The game has been updated to build {"{"}{"}"} since this merge. The mods will be merged again on the next launch, but the ones not updated for the new build may still crash the game.</b></p>
+merge_outdated_confirm = The game has been updated since the mods were last merged (build {"{"}{"}"} to build {"{"}{"}"}). The merged pack will be generated again for the new build, but mods not updated for it may still crash the game. Do you want to continue?
+merge_summary = <p>Last merge: <b>{"{"}{"}"}</b></p><ul><li>Pack: <i>{"{"}{"}"}</i></li><li>Game build: {"{"}{"}"}</li><li>Files: {"{"}{"}"}</li><li>Size: {"{"}{"}"}</li><li>Conflicts resolved: {"{"}{"}"} (files present in more than one mod, where the one higher in the load order won)</li></ul><p>Mods merged ({"{"}{"}"}), in load order:</p><ul>{"{"}{"}"}</ul>
+
+post_launch_action = After Launching the Game
+post_launch_action_tt = What to do with the launcher once the game is launched. Closing it disables checking and following the game logs, as there's nothing left to show them.
+post_launch_action_stay_open = Stay Open
+post_launch_action_minimize_to_tray = Minimize to Tray
+post_launch_action_close = Close
+
+tray_show_hide = Show/Hide The Runcher
+tray_recent_profiles = Recent Profiles
+
+drop_packs_no_packs = None of the dropped files are packs, so nothing was installed: {"{"}{"}"}.
+drop_packs_confirm = Do you want to install the following packs into the data folder of the game? {"{"}{"}"}<br/><br/>Data folder: {"{"}{"}"}
+drop_packs_overwrite = The following packs are already there and will be replaced: {"{"}{"}"}
+drop_packs_installed = Packs installed: {"{"}{"}"}.
+drop_packs_rejected = The following files were ignored, as they're not packs: {"{"}{"}"}.
+
+scripts_sort = Sort Scripts By
+scripts_sort_name = Name
+scripts_sort_key = Key
+scripts_sort_tooltip = Order of the scripts list. Scripts can declare their category through the "category" key of their metadata. Scripts without a category go last.
+
+last_launch_failed = The last launch of the game failed to start, with code {"{"}{"}"}.
+last_launch_failed_logs = Show logs
+
+script_copy_arg = Copy the argument this script is passed to the patcher with, with the current values of its params, so it can be used from a terminal.
+
+rebalancer_excluded_categories = Categories to Skip when Rebalancing
+rebalancer_excluded_categories_tooltip = Unit categories for the universal rebalancer to leave untouched, saved per game. The categories are read from the pack selected for rebalancing. The patcher doesn't skip them yet.
+rebalancer_excluded_categories_none = None
+rebalancer_excluded_categories_count = {"{"}{"}"} categories
+
+launch_options_shortcuts = Shortcuts to toggle launch options from the main window:
+launch_option_shortcut_tooltip = Shortcut: {"{"}{"}"}
+
+profile_lock = Lock the selected profile. Locked profiles cannot be overwritten, renamed or deleted until unlocked.
+profile_locked_error = The following profiles are locked, so they cannot be changed: {"{"}{"}"}. Unlock them in the Profile Manager first.
+profile_locked_save_as_new = The profile "{"{"}{"}"}" is locked. Do you want to save the current load order as a new profile called "{"{"}{"}"}" instead?
+profile_launch_args = Edit the extra launch arguments of the selected profile. Profiles without their own use the global ones from the settings.
+profile_launch_args_ph = Leave empty to use the global extra launch arguments.
+profile_tags = Edit the tags of the selected profile, to group it with other profiles.
+profile_tags_label = Tags
+profile_tags_ph = Comma-separated tags, like "Campaign, Multiplayer"
+profile_copy_launch_command = Copy the command that would launch the game with the selected profile, without loading it.
+profile_launch_command_copied = <p>Launch command of the profile "{"{"}{"}"}" copied to the clipboard.</p><p>It uses the load order and extra launch arguments of the profile, with its own mod list file.</p>
+profile_launch_command_generated_packs = Launching with this profile needs packs Runcher generates on launch (for launch options, scripts or merged mods), which a launch command cannot include. Turn them off, or load the profile and launch from Runcher instead.
+profile_launch_command_unsupported = This game always loads the mods from its user script, so launch commands for profiles are not supported for it.
+profile_tag_filter = Show only the profiles with the selected tag.
+profile_tag_filter_all = All Tags
+
+download_disk_space_warning = The mods to download need around {"{"}{"}"}, but the drive of {"{"}{"}"} only has {"{"}{"}"} free. If the drive runs out of space mid-download, the mods may end up broken. Do you want to download them anyway?
+
+open_mod_folder_menu = Open Mod Folder
+open_mod_folder_data = Data
+open_mod_folder_secondary = Secondary
+open_mod_folder_content = Workshop
+open_mod_folder_other = Other
+
+validate_launch = Validate Launch
+validate_launch_passed = All the checks passed. The game should launch without problems.
+validate_launch_failed = Some checks failed. Fix the problems below before launching the game.
+validate_launch_pass = Pass
+validate_launch_fail = Fail
+validate_launch_game = Game installation
+validate_launch_executable_missing = The game executable {"{"}{"}"} doesn't exist.
+validate_launch_executable_missing_path = The game executable cannot be found. Check the game path in the settings.
+validate_launch_duplicated_packs = Duplicated packs
+validate_launch_dependencies = Dependencies
+validate_launch_dependency_missing = <b>{"{"}{"}"}</b> is not installed, but it's required by: {"{"}{"}"}
+validate_launch_dependency_disabled = <b>{"{"}{"}"}</b> is disabled, but it's required by: {"{"}{"}"}
+validate_launch_scripts = Scripts
+validate_launch_launch_flags = Launch flags
+validate_launch_launch_flag_unsupported = <code>{"{"}{"}"}</code> ({"{"}{"}"}) was not found in the game executable. This build of the game may not support it.
+validate_launch_generation = Merge, launch options and scripts
+validate_launch_generation_skipped_errors = Skipped, as other checks failed.
+validate_launch_generation_skipped_destructive = Skipped, as the following scripts modify the game files: {"{"}{"}"}. Launch the game to run them.
+
+test_launch_options = Test Launch Options
+test_launch_options_none = There are no launch flags to test. Only custom launch options and extra launch arguments starting with - or / are passed to the game as flags.
+test_launch_options_passed = All the launch flags were found in the game executable.
+test_launch_options_failed = Some launch flags were not found in the game executable. This build of the game may not support them, and may fail to start with them.
+test_launch_options_supported = Found
+test_launch_options_unsupported = Not found
+test_launch_options_info = The flags are looked for in the game executable, as the game needs their names to recognize them. A flag found may still be ignored by the game, but a flag not found is very likely not supported. Results are kept until the game gets updated.
+launch_flag_unsupported_tooltip = The flag <code>{"{"}{"}"}</code> was not found in the game executable. This build of the game may not support it, and may fail to start with it.
+
+modification_summary = Modification Summary
+modification_summary_info = This is everything that changes the game from vanilla with the current setup.
+modification_summary_info_vanilla = Nothing changes the game from vanilla with the current setup.
+modification_summary_vanilla = Vanilla
+modification_summary_mods = Mods
+modification_summary_mods_enabled = {"{"}{"}"} mods enabled, out of {"{"}{"}"} installed, plus {"{"}{"}"} movie packs.
+modification_summary_merge = Merged Changes
+modification_summary_merge_last = All mods get merged into a single pack. On the last merge, {"{"}{"}"} mods got merged into {"{"}{"}"} files, with {"{"}{"}"} conflicts resolved.
+modification_summary_merge_pending = All mods get merged into a single pack. They haven't been merged yet, so there are no numbers to show until the game is launched.
+modification_summary_launch_options = Launch Options
+modification_summary_scripts = Scripts
+modification_summary_rebalancing = Rebalancing
+modification_summary_rebalancer = Universal Rebalancer, using {"{"}{"}"} as base.
+
+launch_history = Launch History
+launch_history_info = These are the latest launches of the game selected, from newest to oldest. Select one and hit "Re-apply" to restore its load order, launch options and scripts.
+launch_history_empty = The game selected hasn't been launched from here yet, so there's no launch history to show.
+launch_history_reapply = Re-apply
+launch_history_date = Date
+launch_history_profile = Profile
+launch_history_mods = Mods
+launch_history_options = Changed Launch Options
+launch_history_outcome = Outcome
+launch_history_outcome_unknown = Unknown
+launch_history_outcome_failed = Failed to Start
+launch_history_outcome_closed = Closed
+launch_history_max_entries = Launch History Size
+launch_history_max_entries_tt = How many launches are kept in the launch history of each game. Older launches are removed when a new one is recorded.
+
+check_translation_font_coverage = Check Translation Font Coverage
+check_translation_font_coverage_tt = Before launching with a translation enabled, checks that the fonts of the game can render all the characters in the translation, and warns you if some of them would show up as boxes. Only games using TrueType or OpenType fonts can be checked. Makes launching with a translation slower, as the fonts need to be read from the game files.
+translation_font_coverage_confirm = The fonts of the game cannot render {"{"}{"}"} characters used in the "{"{"}{"}"}" translation. They will probably show up as boxes ingame:<br/><br/>{"{"}{"}"}<br/><br/>Do you want to continue?
+
+mod_list_color_enabled = Highlight Enabled Mods
+mod_list_color_enabled_tt = Paints a subtle accent, based on the highlight color of the current theme, behind the mods that are enabled in the mod list.
+
+scripts_category_uncategorized = Uncategorized
+
+scripts_panel_title = Scripts
+scripts_panel_script = Script
+scripts_panel_category = Category
+
+mod_folders_watcher = Watch Mod Folders
+mod_folders_watcher_tt = Watch the data, secondary and workshop folders of the selected game for changes in their packs, like when you rebuild a pack with an external tool. Watching folders with lots of packs has some overhead, so it's disabled by default.
+mod_folders_watcher_disabled = Disabled
+mod_folders_watcher_ask = Ask Before Reloading
+mod_folders_watcher_reload = Reload Automatically
+mod_folders_changed = <p>Some packs in the mod folders changed on disk.</p><p>Do you want to reload the mod list?</p>
+
+auto_enable_dependencies = Always Enable Dependencies
+auto_enable_dependencies_tt = When enabling a mod, enable the installed mods it requires without asking. If it's disabled, you'll be asked what to do when enabling a mod with disabled dependencies.
+
+mod_advisories_source = Mod Advisories Source
+mod_advisories_source_tt = <p>Where to get the list of mods known to be outdated, incompatible or broken. Flagged mods get a warning in the mod list, but they're never disabled.</p>
+    <p>It can be the url of a git repository with a <i>mod_advisories.json</i> file, optionally followed by <i>#branch</i>, which is downloaded again once a day, or the path of a local json file. Leave it empty to not check mods.</p>
+mod_advisories_source_ph = https://github.com/user/mod_advisories.git or /path/to/mod_advisories.json
+
+export_script_bundle = Export Script Bundle
+import_script_bundle = Import Script Bundle
+script_bundle_filter = Script Bundles (*.zip)
+script_bundle_export_no_scripts = There are no scripts enabled to export. Enable the scripts you want to export first.
+script_bundle_exported = Scripts exported to <i>{"{"}{"}"}</i>.
+script_bundle_imported = {"{"}{"}"} scripts imported.
+script_bundle_import_wrong_game = This bundle is for <b>{"{"}{"}"}</b>, but the selected game is <b>{"{"}{"}"}</b>. Select the correct game before importing it.
+script_bundle_import_collision_script = Script: {"{"}{"}"}
+script_bundle_import_collision_preset = Preset: {"{"}{"}"}
+script_bundle_import_collisions = <p>The following scripts and presets are already installed and will be overwritten:</p><ul>{"{"}{"}"}</ul><p>Are you sure you want to continue?</p>
+
+game_already_running = The game is already running. Close it before launching it again, as two instances of the game can corrupt your saves.
+
+profile_backups_interval = Profile Backups Interval
+profile_backups_interval_tt = Every how many hours the profiles and launch settings of all games are backed up automatically. Backups are checked on start, and periodically while the launcher is open.
+profile_backups_interval_suffix =  hours
+profile_backups_interval_disabled = Disabled
+profile_backups_retention = Profile Backups To Keep
+profile_backups_retention_tt = How many backups of the profiles and launch settings are kept. Older backups are deleted when a new one is made.
+profile_backups_restore = Restore Profiles Backup
+profile_backups_restore_info = Select the backup to restore. This replaces the profiles and launch settings of ALL games with the ones in the backup. A backup of the current ones is made before restoring it.
+profile_backups_restore_confirm = <p>This will replace the profiles and launch settings of all games with the ones in the backup.</p><p>Are you sure you want to continue?</p>
+profile_backups_none = There are no profile backups to restore yet.
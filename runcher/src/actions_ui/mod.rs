@@ -11,40 +11,152 @@
 use qt_core::SlotOfDouble;
 use qt_widgets::QAction;
 use qt_widgets::QCheckBox;
-use qt_widgets::QComboBox;
+use qt_widgets::{QComboBox, q_combo_box::InsertPolicy};
+use qt_widgets::{QCompleter, q_completer::CompletionMode};
 use qt_widgets::QDoubleSpinBox;
 use qt_widgets::QGridLayout;
 use qt_widgets::QLabel;
+use qt_widgets::QLineEdit;
 use qt_widgets::QMenu;
+use qt_widgets::q_frame::Shape;
+use qt_widgets::{QMessageBox, q_message_box};
+use qt_widgets::QScrollArea;
+use qt_widgets::QShortcut;
 use qt_widgets::QSpinBox;
 use qt_widgets::{QToolButton, q_tool_button::ToolButtonPopupMode};
 use qt_widgets::QWidget;
 use qt_widgets::QWidgetAction;
 
+use qt_gui::QGuiApplication;
 use qt_gui::QIcon;
+use qt_gui::QKeySequence;
 use qt_gui::QStandardItemModel;
 
+use qt_core::ArrowType;
+use qt_core::CaseSensitivity;
+use qt_core::ContextMenuPolicy;
+use qt_core::ItemDataRole;
+use qt_core::MatchFlag;
 use qt_core::QBox;
 use qt_core::QPtr;
+use qt_core::QRegExp;
+use qt_core::QSortFilterProxyModel;
 use qt_core::QString;
+use qt_core::QTimer;
+use qt_core::QVariant;
+use qt_core::SlotNoArgs;
 use qt_core::SlotOfBool;
 use qt_core::SlotOfInt;
+use qt_core::SlotOfQString;
+use qt_core::ToolButtonStyle;
+
+use cpp_core::Ptr;
 
 use anyhow::Result;
 use getset::*;
+use itertools::Itertools;
+use regex::{Regex, RegexBuilder};
 
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::PathBuf;
 use std::rc::Rc;
 use std::sync::{Arc, RwLock};
 
 use common_utils::sql::{ParamType, Preset, SQLScript};
 
-use rpfm_ui_common::locale::qtr;
+use rpfm_lib::games::GameInfo;
+
+use rpfm_ui_common::clone;
+use rpfm_ui_common::locale::{qtr, qtre, tre};
 use rpfm_ui_common::settings::*;
 use rpfm_ui_common::utils::*;
 
+use crate::games::{complete_preset, game_executable_path, sql_script_arg, ScriptExtraMetadata, VisibleWhen};
+use crate::mod_manager::custom_launch_options::{CustomLaunchOption, CustomLaunchOptionKind, CustomLaunchOptions};
+use crate::mod_manager::launch_flags::LaunchFlagsCheck;
+use crate::mod_manager::MOD_SOURCES;
+use crate::SUPPORTED_GAMES;
+
 const VIEW_DEBUG: &str = "ui_templates/actions_groupbox.ui";
 const VIEW_RELEASE: &str = "ui/actions_groupbox.ui";
 
+/// Default max value allowed for the unit multiplier. Values too high are known to crash the game on load.
+pub const UNIT_MULTIPLIER_MAX_DEFAULT: i32 = 50;
+
+/// Fraction of the max value allowed for the unit multiplier above which we warn the user that it's getting close to the ones known to crash the game.
+const UNIT_MULTIPLIER_WARNING_RATIO: f64 = 0.8;
+
+const UNIT_MULTIPLIER_MIN: f64 = 0.01;
+
+/// Max height of the params of a script in the play menu. Scripts with more params than fit in it get a scrollbar.
+const SCRIPT_PARAMS_MAX_HEIGHT: i32 = 300;
+
+/// Key of the action containing the scripts in the play menu.
+pub const SCRIPTS_ACTION_KEY: &str = "scripts";
+
+/// Prefix of the settings that disable an automatic script for a game. It's not a script setting, so profiles don't store it.
+const AUTOMATIC_SCRIPT_DISABLED_PREFIX: &str = "automatic_script_disabled_";
+
+/// Possible orders of the scripts list. They're stored by index, so the order of the sort combobox must match these.
+pub const SCRIPTS_SORT_NAME: i32 = 0;
+pub const SCRIPTS_SORT_KEY: i32 = 1;
+
+/// Launch options in the play menu the user can reorder, in their default order, with their icons.
+pub const LAUNCH_OPTIONS: [(&str, &str); 20] = [
+    ("game_executable", "application-x-executable"),
+    ("multiplayer_safe", "network-connect"),
+    ("multiplayer_safe_options", "configure"),
+    ("enable_logging", "verb"),
+    ("enable_skip_intro", "kdenlive-hide-video"),
+    ("remove_trait_limit", "folder-unlocked-symbolic"),
+    ("remove_siege_attacker", "folder-unlocked-symbolic"),
+    ("enable_translations", "language-chooser"),
+    ("merge_all_mods", "merge"),
+    ("merge_categories", "tag"),
+    ("unit_multiplier", "view-time-schedule-calculus"),
+    ("unit_multiplier_preset", "bookmarks"),
+    ("universal_rebalancer", "autocorrection"),
+    ("rebalancer_excluded_categories", "list-remove"),
+    ("enable_dev_only_ui", "verb"),
+    ("preset_group", "games-config-options"),
+    ("automatic_scripts", "dialog-information"),
+    ("scripts_sort", "view-sort"),
+    ("scripts_filter", "search"),
+    (SCRIPTS_ACTION_KEY, ""),
+];
+
+/// Launch options the user can toggle through a keyboard shortcut. Only the ones with a checkbox can be toggled.
+///
+/// The shortcuts are created from this list, so every option in it needs its checkbox mapped when creating them.
+pub const SHORTCUT_LAUNCH_OPTIONS: [&str; 7] = [
+    "multiplayer_safe",
+    "enable_logging",
+    "enable_skip_intro",
+    "remove_trait_limit",
+    "remove_siege_attacker",
+    "merge_all_mods",
+    "enable_dev_only_ui",
+];
+
+/// Launch options that change the game, with the setting storing their value (followed by the game key) and the value that turns them off.
+///
+/// Multiplayer-safe mode turns off the ones not allowed for the game. It also turns off the scripts, but those are handled apart.
+pub const MULTIPLAYER_SAFE_OPTIONS: [(&str, &str, &str); 9] = [
+    ("enable_logging", "enable_logging", "false"),
+    ("enable_skip_intro", "enable_skip_intro", "false"),
+    ("remove_trait_limit", "remove_trait_limit", "false"),
+    ("remove_siege_attacker", "remove_siege_attacker", "false"),
+    ("enable_translations", "enable_translations", ""),
+    ("merge_all_mods", "merge_all_mods", "false"),
+    ("unit_multiplier", "unit_multiplier", "1"),
+    ("universal_rebalancer", "universal_rebalancer", ""),
+    ("enable_dev_only_ui", "enable_dev_only_ui", "false"),
+];
+
+/// Options allowed in multiplayer-safe mode for games the user didn't configure. They only change the game on the local side.
+const MULTIPLAYER_SAFE_ALLOWED_DEFAULT: [&str; 3] = ["enable_logging", "enable_skip_intro", "enable_translations"];
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -53,17 +165,55 @@ const VIEW_RELEASE: &str = "ui/actions_groupbox.ui";
 #[getset(get = "pub")]
 pub struct ActionsUI {
     play_button: QPtr<QToolButton>,
+    game_executable_combobox: QBox<QComboBox>,
+    multiplayer_safe_checkbox: QBox<QCheckBox>,
+    multiplayer_safe_options_button: QBox<QToolButton>,
+    multiplayer_safe_options_menu: QBox<QMenu>,
     enable_logging_checkbox: QBox<QCheckBox>,
     enable_skip_intro_checkbox: QBox<QCheckBox>,
     remove_trait_limit_checkbox: QBox<QCheckBox>,
     remove_siege_attacker_checkbox: QBox<QCheckBox>,
     enable_translations_combobox: QBox<QComboBox>,
     merge_all_mods_checkbox: QBox<QCheckBox>,
+    merge_categories_button: QBox<QToolButton>,
+    merge_categories_menu: QBox<QMenu>,
     unit_multiplier_spinbox: QBox<QDoubleSpinBox>,
+    unit_multiplier_preset_combobox: QBox<QComboBox>,
+    unit_multiplier_preset_save_button: QBox<QToolButton>,
+    unit_multiplier_preset_delete_button: QBox<QToolButton>,
     universal_rebalancer_combobox: QBox<QComboBox>,
+    rebalancer_excluded_categories_button: QBox<QToolButton>,
+    rebalancer_excluded_categories_menu: QBox<QMenu>,
     enable_dev_only_ui_checkbox: QBox<QCheckBox>,
+    preset_group_combobox: QBox<QComboBox>,
+    automatic_scripts_button: QBox<QToolButton>,
+    automatic_scripts_menu: QBox<QMenu>,
+    scripts_sort_combobox: QBox<QComboBox>,
+    scripts_filter_line_edit: QBox<QLineEdit>,
     scripts_container: QBox<QWidget>,
+    launch_option_shortcuts: Vec<(String, QBox<QShortcut>, QPtr<QCheckBox>)>,
     scripts_to_execute: Arc<RwLock<Vec<(SQLScript, QBox<QCheckBox>)>>>,
+    scripts_extra_metadata: Arc<RwLock<HashMap<String, ScriptExtraMetadata>>>,
+    scripts_categories: Arc<RwLock<Vec<(Option<String>, QBox<QToolButton>, QBox<QWidget>)>>>,
+    icon_overrides: Arc<RwLock<HashMap<String, String>>>,
+    custom_launch_options_manager: QPtr<QAction>,
+    launch_options_reorder: QPtr<QAction>,
+    script_sandbox: QPtr<QAction>,
+    effective_settings: QPtr<QAction>,
+    export_launch_script: QPtr<QAction>,
+    restore_script_backup: QPtr<QAction>,
+    export_script_bundle: QPtr<QAction>,
+    import_script_bundle: QPtr<QAction>,
+    reapply_scripts: QPtr<QAction>,
+    rebuild_translations: QPtr<QAction>,
+    merge_summary: QPtr<QAction>,
+    validate_launch: QPtr<QAction>,
+    test_launch_options: QPtr<QAction>,
+    modification_summary: QPtr<QAction>,
+    launch_history: QPtr<QAction>,
+    play_menu_pinned_checkbox: QBox<QCheckBox>,
+    play_menu_reopen_timer: QBox<QTimer>,
+    custom_launch_options: Arc<RwLock<Vec<(CustomLaunchOption, QPtr<QAction>, QPtr<QWidget>)>>>,
 
     settings_button: QPtr<QToolButton>,
     folders_button: QPtr<QToolButton>,
@@ -71,16 +221,29 @@ pub struct ActionsUI {
     open_game_data_folder: QPtr<QAction>,
     open_game_content_folder: QPtr<QAction>,
     open_game_secondary_folder: QPtr<QAction>,
+    open_game_secondary_extra_folders_menu: QPtr<QMenu>,
     open_game_config_folder: QPtr<QAction>,
+    open_game_crash_dumps_folder: QPtr<QAction>,
     open_runcher_config_folder: QPtr<QAction>,
     open_runcher_error_folder: QPtr<QAction>,
+    create_crash_report: QPtr<QAction>,
+    create_diagnostics_bundle: QPtr<QAction>,
+    export_config_bundle: QPtr<QAction>,
+    import_config_bundle: QPtr<QAction>,
+    mod_sources_actions: Vec<(&'static str, QPtr<QAction>)>,
 
     copy_load_order_button: QPtr<QToolButton>,
+    copy_load_order_markdown: QPtr<QAction>,
+    copy_load_order_markdown_names_only: QPtr<QAction>,
+    copy_workshop_ids: QPtr<QAction>,
     paste_load_order_button: QPtr<QToolButton>,
+    import_load_order_file: QPtr<QAction>,
     reload_button: QPtr<QToolButton>,
     download_subscribed_mods_button: QPtr<QToolButton>,
+    show_mod_updates: QPtr<QAction>,
 
     profile_load_button: QPtr<QToolButton>,
+    profile_recent_menu: QBox<QMenu>,
     profile_save_button: QPtr<QToolButton>,
     profile_manager_button: QPtr<QToolButton>,
     profile_combobox: QPtr<QComboBox>,
@@ -88,6 +251,15 @@ pub struct ActionsUI {
 
     save_combobox: QPtr<QComboBox>,
     save_model: QBox<QStandardItemModel>,
+    manage_saves: QPtr<QAction>,
+}
+
+/// Widget of a script param, with its default value.
+#[derive(Clone, Copy)]
+enum ScriptParamWidget {
+    Bool(Ptr<QCheckBox>, bool),
+    Integer(Ptr<QSpinBox>, i32),
+    Float(Ptr<QDoubleSpinBox>, f64),
 }
 
 //-------------------------------------------------------------------------------//
@@ -96,7 +268,7 @@ pub struct ActionsUI {
 
 impl ActionsUI {
 
-    pub unsafe fn new_launch_script_option(&self, game_key: &str, icon_key: &str, script: &SQLScript, presets: &[Preset]) -> QBox<QCheckBox> {
+    pub unsafe fn new_launch_script_option(&self, game_key: &str, icon_key: &str, script: &SQLScript, presets: &[Preset], extra_metadata: &ScriptExtraMetadata, requirements_met: bool) -> QBox<QCheckBox> {
         let container = QWidget::new_1a(self.scripts_container());
         let presets_container = QWidget::new_1a(&container);
         let params_container = QWidget::new_1a(&container);
@@ -114,6 +286,10 @@ impl ActionsUI {
         let settings = settings();
         let mut there_are_presets = false;
         let mut is_preset_selected = false;
+        let mut preset_combo_ptr = None;
+        let mut param_widgets = vec![];
+        let mut param_labels = vec![];
+        let mut param_keys = vec![];
 
         // If we have params, add a small combo widget for selecting possible prefabs.
         if !script_params.is_empty() {
@@ -121,6 +297,7 @@ impl ActionsUI {
             let preset_combo = QComboBox::new_1a(&presets_container);
             preset_combo.set_object_name(&QString::from_std_str(format!("{script_key}_preset_combo")));
             preset_combo.add_item_q_string(&QString::from_std_str("No Preset"));
+            preset_combo_ptr = Some(preset_combo.as_ptr());
 
             presets_layout.add_widget_5a(&label_text, 0, 0, 1, 1);
             presets_layout.add_widget_5a(&preset_combo, 0, 1, 1, 1);
@@ -137,16 +314,77 @@ impl ActionsUI {
                     preset_combo.add_item_q_string(&QString::from_std_str(preset.name()));
                 }
 
-                let setting_key = format!("script_to_execute_{}_{}_preset", game_key, script_key);
-                let setting = settings.value_1a(&QString::from_std_str(&setting_key));
-                if setting.is_valid() {
-                    let string = setting.to_string();
-                    if !string.is_empty() {
-                        preset_combo.set_current_text(&setting.to_string());
+                // Presets made before the script got new params are partial: the new params use their default values.
+                // Mark them, and allow re-saving them so they include the new params.
+                let missing_params = presets.iter()
+                    .map(|preset| script_params.iter()
+                        .filter(|param| !preset.params().contains_key(param.key()))
+                        .map(|param| (param.key().to_owned(), param.name().to_owned(), param.default_value().to_owned()))
+                        .collect::<Vec<_>>()
+                    )
+                    .collect::<Vec<_>>();
+
+                let partial_presets = Rc::new(RefCell::new(HashSet::new()));
+                for (index, missing_params) in missing_params.iter().enumerate() {
+                    if !missing_params.is_empty() {
+                        let combo_index = index as i32 + 1;
+                        let tool_tip = qtre("script_preset_partial", &[&missing_params.iter().map(|(_, name, _)| name).join(", ")]);
+                        preset_combo.set_item_icon(combo_index, &QIcon::from_theme_1a(&QString::from_std_str("dialog-warning")));
+                        preset_combo.set_item_data_3a(combo_index, &QVariant::from_q_string(&tool_tip), ItemDataRole::ToolTipRole.to_int());
+                        partial_presets.borrow_mut().insert(combo_index);
                     }
                 }
 
-                if preset_combo.current_index() != 0 {
+                let resave_button = QToolButton::new_1a(&presets_container);
+                resave_button.set_auto_raise(true);
+                resave_button.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("document-save")));
+                resave_button.set_tool_tip(&qtr("script_preset_resave"));
+                presets_layout.add_widget_5a(&resave_button, 0, 2, 1, 1);
+
+                let resave_button_ptr = resave_button.as_ptr();
+                let preset_combo_ptr = preset_combo.as_ptr();
+                let update_partial = Rc::new(clone!(partial_presets => move || {
+                    let index = preset_combo_ptr.current_index();
+                    let partial = partial_presets.borrow().contains(&index);
+                    resave_button_ptr.set_visible(partial);
+
+                    if partial {
+                        preset_combo_ptr.set_tool_tip(&preset_combo_ptr.item_data_2a(index, ItemDataRole::ToolTipRole.to_int()).to_string());
+                    } else {
+                        preset_combo_ptr.set_tool_tip(&QString::new());
+                    }
+                }));
+
+                update_partial();
+                preset_combo.current_index_changed().connect(&SlotOfInt::new(&preset_combo, clone!(update_partial => move |_| update_partial())));
+
+                let presets_container_ptr = presets_container.as_ptr();
+                let preset_keys = presets.iter().map(|preset| preset.key().to_owned()).collect::<Vec<_>>();
+                resave_button.released().connect(&SlotNoArgs::new(&resave_button, move || {
+                    let index = preset_combo_ptr.current_index();
+                    if index > 0 {
+                        let params = missing_params[index as usize - 1].iter()
+                            .map(|(key, _, default_value)| (key.to_owned(), default_value.to_owned()))
+                            .collect::<Vec<_>>();
+
+                        match complete_preset(&preset_keys[index as usize - 1], &params) {
+                            Ok(_) => {
+                                partial_presets.borrow_mut().remove(&index);
+                                preset_combo_ptr.set_item_icon(index, &QIcon::new());
+                                preset_combo_ptr.set_item_data_3a(index, &QVariant::new(), ItemDataRole::ToolTipRole.to_int());
+                                update_partial();
+                            }
+                            Err(error) => show_dialog(presets_container_ptr, error, false),
+                        }
+                    }
+                }));
+
+                // An empty or missing setting means "No Preset". Same if the saved preset no longer exists.
+                let setting_key = format!("script_to_execute_{}_{}_preset", game_key, script_key);
+                let preset_index = Self::preset_index(presets.iter().map(|preset| &preset.name()[..]), &setting_string(&setting_key));
+                preset_combo.set_current_index(preset_index as i32);
+
+                if preset_index != 0 {
                     is_preset_selected = true;
                 } else {
                     set_setting_string(&setting_key, "");
@@ -173,9 +411,11 @@ impl ActionsUI {
 
             let label_text = QLabel::from_q_string_q_widget(&QString::from_std_str(param_name), &params_container);
             param_layout.add_widget_5a(&label_text, index as i32 + 1, 0, 1, 1);
+            param_labels.push(label_text.as_ptr());
 
             let setting = format!("script_to_execute_{}_{}_{}", game_key, script_key, param_key);
             let use_default = !settings.value_1a(&QString::from_std_str(&setting)).is_valid();
+            param_keys.push((param_key.to_owned(), setting.clone()));
 
             match param_type {
                 ParamType::Bool => {
@@ -190,6 +430,7 @@ impl ActionsUI {
                     }
 
                     param_layout.add_widget_5a(&widget, index as i32 + 1, 1, 1, 1);
+                    param_widgets.push(ScriptParamWidget::Bool(widget.as_ptr(), default_value));
                     widget.toggled().connect(&SlotOfBool::new(&params_container, move |state| {
                         set_setting_bool(&setting, state);
                     }));
@@ -206,6 +447,7 @@ impl ActionsUI {
                     }
 
                     param_layout.add_widget_5a(&widget, index as i32 + 1, 1, 1, 1);
+                    param_widgets.push(ScriptParamWidget::Integer(widget.as_ptr(), default_value));
                     widget.value_changed().connect(&SlotOfInt::new(&params_container, move |value| {
                         set_setting_int(&setting, value);
                     }));
@@ -222,6 +464,7 @@ impl ActionsUI {
                     }
 
                     param_layout.add_widget_5a(&widget, index as i32 + 1, 1, 1, 1);
+                    param_widgets.push(ScriptParamWidget::Float(widget.as_ptr(), default_value as f64));
                     widget.value_changed().connect(&SlotOfDouble::new(&params_container, move |value| {
                         set_setting_f32(&setting, value as f32);
                     }));
@@ -229,6 +472,71 @@ impl ActionsUI {
             }
         }
 
+        // Show the values of the selected preset in the params, and go back to the manual values when returning to "No Preset".
+        if let Some(preset_combo) = preset_combo_ptr {
+            let presets = presets.to_vec();
+            let param_widgets = param_widgets.clone();
+            let load_param_values = move |index: i32| {
+                let preset = if index > 0 { presets.get(index as usize - 1) } else { None };
+                Self::load_param_values(&param_widgets, &param_keys, preset);
+            };
+
+            load_param_values(preset_combo.current_index());
+            preset_combo.current_index_changed().connect(&SlotOfInt::new(&params_container, load_param_values));
+        }
+
+        // Show or hide the params with visibility conditions, depending on the values of the params controlling them.
+        let conditions = script_params.iter()
+            .enumerate()
+            .filter_map(|(index, param)| {
+                let condition = extra_metadata.param_conditions().get(param.key())?;
+                let controller = script_params.iter().position(|param| param.key() == condition.param())?;
+                Some((index, controller, condition.clone()))
+            })
+            .collect::<Vec<_>>();
+
+        if !conditions.is_empty() {
+            let controllers = conditions.iter().map(|(_, controller, _)| *controller).unique().collect::<Vec<_>>();
+            let param_widgets = param_widgets.clone();
+            let update_visibility = Rc::new(move || {
+
+                // Conditions can be chained, so keep updating them until nothing changes.
+                for _ in 0..=conditions.len() {
+                    let mut changed = false;
+                    for (index, controller, condition) in &conditions {
+                        let controller_widget = Self::param_widget(&param_widgets[*controller]);
+                        let visible = controller_widget.is_visible_to(&controller_widget.parent_widget()) && Self::is_condition_met(condition, &param_widgets[*controller]);
+
+                        let widget = Self::param_widget(&param_widgets[*index]);
+                        if widget.is_visible_to(&widget.parent_widget()) != visible {
+                            widget.set_visible(visible);
+                            param_labels[*index].set_visible(visible);
+                            changed = true;
+                        }
+                    }
+
+                    if !changed {
+                        break;
+                    }
+                }
+            });
+
+            for controller in controllers {
+                match param_widgets[controller] {
+                    ScriptParamWidget::Bool(widget, _) => widget.toggled().connect(&SlotOfBool::new(&params_container, clone!(update_visibility => move |_| update_visibility()))),
+                    ScriptParamWidget::Integer(widget, _) => widget.value_changed().connect(&SlotOfInt::new(&params_container, clone!(update_visibility => move |_| update_visibility()))),
+                    ScriptParamWidget::Float(widget, _) => widget.value_changed().connect(&SlotOfDouble::new(&params_container, clone!(update_visibility => move |_| update_visibility()))),
+                };
+            }
+
+            // Preset values are loaded with the signals blocked, so we need to update the visibility manually when changing presets.
+            if let Some(preset_combo) = preset_combo_ptr {
+                preset_combo.current_index_changed().connect(&SlotOfInt::new(&params_container, clone!(update_visibility => move |_| update_visibility())));
+            }
+
+            update_visibility();
+        }
+
 
         let checkbox = QCheckBox::from_q_widget(&container);
         let icon = QIcon::from_theme_1a(&QString::from_std_str(icon_key));
@@ -239,15 +547,63 @@ impl ActionsUI {
         let label_text = QLabel::from_q_string_q_widget(&QString::from_std_str(script_pretty_name.trim()), &container);
         label_text.set_fixed_height(26);
 
+        // Destructive scripts get a warning icon instead of the normal one, so they're easy to spot.
+        if *extra_metadata.destructive() {
+            let warning_icon = QIcon::from_theme_1a(&QString::from_std_str("dialog-warning"));
+            label_icon.set_pixmap(&warning_icon.pixmap_2_int(22, 22));
+            label_icon.set_tool_tip(&qtr("script_destructive"));
+            label_text.set_tool_tip(&qtr("script_destructive"));
+        }
+
         let label_fill = QLabel::from_q_widget(&container);
+
+        // Buttons to collapse the script options, and to switch between the presets and the params.
+        let view_button = QToolButton::new_1a(&container);
+        view_button.set_checkable(true);
+        view_button.set_auto_raise(true);
+        view_button.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("view-list-details")));
+        view_button.set_tool_tip(&qtr("script_show_params"));
+        view_button.set_visible(there_are_presets);
+
+        // Indicator for scripts with non-default values. Clicking it resets them.
+        let reset_button = QToolButton::new_1a(&container);
+        reset_button.set_auto_raise(true);
+        reset_button.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("edit-undo")));
+        reset_button.set_tool_tip(&qtr("script_modified"));
+        reset_button.set_visible(false);
+
+        // Copies the argument the script is going to be passed to the patcher with, for debugging.
+        let copy_arg_button = QToolButton::new_1a(&container);
+        copy_arg_button.set_auto_raise(true);
+        copy_arg_button.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("edit-copy")));
+        copy_arg_button.set_tool_tip(&qtr("script_copy_arg"));
+
+        let expand_button = QToolButton::new_1a(&container);
+        expand_button.set_checkable(true);
+        expand_button.set_auto_raise(true);
+        expand_button.set_tool_tip(&qtr("script_expand"));
+        expand_button.set_visible(!script_params.is_empty());
+
         let layout = create_grid_layout(container.static_upcast());
 
         layout.add_widget_5a(&label_icon, 0, 0, 1, 1);
         layout.add_widget_5a(&label_text, 0, 1, 1, 1);
         layout.add_widget_5a(&label_fill, 0, 2, 1, 1);
-        layout.add_widget_5a(&checkbox, 0, 3, 1, 1);
-        layout.add_widget_5a(&presets_container, 1, 1, 1, 3);
-        layout.add_widget_5a(&params_container, 2, 1, 1, 3);
+        layout.add_widget_5a(&reset_button, 0, 3, 1, 1);
+        layout.add_widget_5a(&copy_arg_button, 0, 4, 1, 1);
+        layout.add_widget_5a(&view_button, 0, 5, 1, 1);
+        layout.add_widget_5a(&expand_button, 0, 6, 1, 1);
+        layout.add_widget_5a(&checkbox, 0, 7, 1, 1);
+        layout.add_widget_5a(&presets_container, 1, 1, 1, 7);
+        // Params go in a scroll area, so scripts with a lot of them don't push the rest of the menu off-screen.
+        // The layout of the params must be set before this, or the scroll area doesn't show them.
+        let params_scroll_area = QScrollArea::new_1a(&container);
+        params_scroll_area.set_widget_resizable(true);
+        params_scroll_area.set_frame_shape(Shape::NoFrame);
+        params_scroll_area.set_maximum_height(SCRIPT_PARAMS_MAX_HEIGHT);
+        params_scroll_area.set_widget(&params_container);
+
+        layout.add_widget_5a(&params_scroll_area, 2, 1, 1, 7);
         layout.set_column_stretch(2, 10);
 
         // If the script is marked as automatic, hide the UI and automatically check the checkbox.
@@ -255,16 +611,123 @@ impl ActionsUI {
             label_icon.set_visible(false);
             label_text.set_visible(false);
             label_fill.set_visible(false);
+            view_button.set_visible(false);
+            copy_arg_button.set_visible(false);
+            expand_button.set_visible(false);
             checkbox.set_visible(false);
             presets_container.set_visible(false);
-            params_container.set_visible(false);
+            params_scroll_area.set_visible(false);
+        }
+
+        // Restore how the user left the options of the script: expanded or not, and showing the presets or the params.
+        // If we don't have the params view saved, show whatever is in use right now.
+        let expanded_setting = format!("script_to_execute_{}_{}_expanded", game_key, script_key);
+        let show_params_setting = format!("script_to_execute_{}_{}_show_params", game_key, script_key);
+        let expanded = !settings.value_1a(&QString::from_std_str(&expanded_setting)).is_valid() || setting_bool(&expanded_setting);
+        let show_params = if settings.value_1a(&QString::from_std_str(&show_params_setting)).is_valid() {
+            setting_bool(&show_params_setting)
+        } else {
+            !is_preset_selected
+        };
+
+        let presets_container_ptr = presets_container.as_ptr();
+        let params_scroll_area_ptr = params_scroll_area.as_ptr();
+        let expand_button_ptr = expand_button.as_ptr();
+        let view_button_ptr = view_button.as_ptr();
+        let update_views = move || {
+            let expanded = expand_button_ptr.is_checked();
+            let show_params = view_button_ptr.is_checked();
+
+            expand_button_ptr.set_arrow_type(if expanded { ArrowType::DownArrow } else { ArrowType::RightArrow });
+            presets_container_ptr.set_visible(expanded && there_are_presets && !show_params);
+            params_scroll_area_ptr.set_visible(expanded && (!there_are_presets || show_params));
+        };
+
+        expand_button.set_checked(expanded);
+        view_button.set_checked(show_params);
+
+        if !*script.metadata().automatic() {
+            update_views();
+        }
+
+        expand_button.toggled().connect(&SlotOfBool::new(&expand_button, move |state| {
+            update_views();
+            set_setting_bool(&expanded_setting, state);
+        }));
+
+        view_button.toggled().connect(&SlotOfBool::new(&view_button, move |state| {
+            update_views();
+            set_setting_bool(&show_params_setting, state);
+        }));
+
+        // Keep the modified indicator up to date with the values of the params and presets.
+        if !*script.metadata().automatic() {
+            let reset_button_ptr = reset_button.as_ptr();
+            let param_widgets = Rc::new(param_widgets);
+            let update_modified = Rc::new(clone!(param_widgets => move || {
+                let preset_selected = preset_combo_ptr.map(|combo| combo.current_index() != 0).unwrap_or_default();
+                reset_button_ptr.set_visible(preset_selected || param_widgets.iter().any(|widget| {
+                    let param_widget = Self::param_widget(widget);
+                    param_widget.is_visible_to(&param_widget.parent_widget()) && Self::is_param_modified(widget)
+                }));
+            }));
+
+            if let Some(preset_combo) = preset_combo_ptr {
+                preset_combo.current_index_changed().connect(&SlotOfInt::new(&reset_button, clone!(update_modified => move |_| update_modified())));
+            }
+
+            for widget in param_widgets.iter() {
+                match widget {
+                    ScriptParamWidget::Bool(widget, _) => widget.toggled().connect(&SlotOfBool::new(&reset_button, clone!(update_modified => move |_| update_modified()))),
+                    ScriptParamWidget::Integer(widget, _) => widget.value_changed().connect(&SlotOfInt::new(&reset_button, clone!(update_modified => move |_| update_modified()))),
+                    ScriptParamWidget::Float(widget, _) => widget.value_changed().connect(&SlotOfDouble::new(&reset_button, clone!(update_modified => move |_| update_modified()))),
+                };
+            }
+
+            // Resetting the widgets triggers their slots, so the settings get reset too.
+            reset_button.released().connect(&SlotNoArgs::new(&reset_button, clone!(param_widgets => move || {
+                if let Some(preset_combo) = preset_combo_ptr {
+                    preset_combo.set_current_index(0);
+                }
+
+                for widget in param_widgets.iter() {
+                    match widget {
+                        ScriptParamWidget::Bool(widget, default) => widget.set_checked(*default),
+                        ScriptParamWidget::Integer(widget, default) => widget.set_value(*default),
+                        ScriptParamWidget::Float(widget, default) => widget.set_value(*default),
+                    }
+                }
+            })));
+
+            update_modified();
         }
 
         let layout = self.scripts_container().layout().static_downcast::<QGridLayout>();
         layout.add_widget(&container);
 
+        // New scripts need to respect the current filter, or reloading the game would show all of them.
+        let filter = self.scripts_filter_line_edit().text().to_std_string();
+        container.set_visible(Self::script_matches_filter(script, &filter));
+
         let setting = format!("script_to_execute_{}_{}", game_key, script_key);
-        let is_enabled = setting_bool(&setting);
+        let is_enabled = if *script.metadata().automatic() {
+            !Self::automatic_script_disabled(&game_key, script_key) && requirements_met
+        } else {
+            setting_bool(&setting)
+        };
+
+        // The argument is built on click, so it reflects the values of the params at that moment.
+        let copy_arg_game_key = game_key.to_owned();
+        let copy_arg_script = script.clone();
+        let checkbox_ptr = checkbox.as_ptr();
+        copy_arg_button.released().connect(&SlotNoArgs::new(&copy_arg_button, move || {
+            if let Some(game) = SUPPORTED_GAMES.game(&copy_arg_game_key) {
+                match sql_script_arg(game, &copy_arg_script, checkbox_ptr) {
+                    Ok(arg) => QGuiApplication::clipboard().set_text_1a(&QString::from_std_str(format!("--sql-script \"{arg}\""))),
+                    Err(error) => show_dialog(checkbox_ptr, error, false),
+                }
+            }
+        }));
 
         let script_key = script_key.to_owned();
         checkbox.toggled().connect(&SlotOfBool::new(&checkbox, move |state| {
@@ -279,12 +742,500 @@ impl ActionsUI {
             set_setting_bool(&setting, state);
         }));
 
-        checkbox.set_checked(is_enabled || *script.metadata().automatic());
+        checkbox.set_checked(is_enabled);
         checkbox
     }
 
+    /// This function hides the scripts not matching the text in the scripts filter, and the headers of the categories left empty.
+    pub unsafe fn filter_scripts(&self) {
+        let filter = self.scripts_filter_line_edit().text().to_std_string();
+        let extra_metadata = self.scripts_extra_metadata().read().unwrap();
+        let mut visible_categories = HashSet::new();
+        for (script, checkbox) in self.scripts_to_execute().read().unwrap().iter() {
+            let visible = Self::script_matches_filter(script, &filter);
+            checkbox.parent_widget().set_visible(visible);
+
+            if visible && !*script.metadata().automatic() {
+                visible_categories.insert(Self::script_category(&extra_metadata, script));
+            }
+        }
+
+        // If no script has a category, there's only the default group and its header is not shown.
+        let categories = self.scripts_categories().read().unwrap();
+        let show_headers = categories.iter().any(|(category, _, _)| category.is_some());
+        for (category, header, _) in categories.iter() {
+            header.set_visible(show_headers && visible_categories.contains(category));
+        }
+    }
+
+    /// This function reorders the scripts list following the sort selected, grouping the scripts under collapsible headers by category.
+    ///
+    /// Scripts without a category go in a default group after the rest. Automatic scripts are not shown, so they're left at the end, outside the groups.
+    pub unsafe fn sort_scripts(&self) {
+        let scripts = self.scripts_to_execute().read().unwrap();
+        let extra_metadata = self.scripts_extra_metadata().read().unwrap();
+        let sort = self.scripts_sort_combobox().current_index();
+
+        let (automatic, mut sorted): (Vec<_>, Vec<_>) = scripts.iter().partition(|(script, _)| *script.metadata().automatic());
+        sorted.sort_by_cached_key(|(script, _)| {
+            let category = Self::script_category(&extra_metadata, script);
+            let sort_key = match sort {
+                SCRIPTS_SORT_KEY => script.metadata().key().to_lowercase(),
+                _ => script.metadata().name().trim().to_lowercase(),
+            };
+
+            (category.is_none(), category.unwrap_or_default().to_lowercase(), sort_key)
+        });
+
+        // Take the old groups out of the list before adding the new ones. They're deleted once their scripts are moved to the new groups.
+        let layout = self.scripts_container().layout().static_downcast::<QGridLayout>();
+        let old_categories = std::mem::take(&mut *self.scripts_categories().write().unwrap());
+        for (_, header, body) in &old_categories {
+            if !header.is_null() {
+                layout.remove_widget(header);
+                header.set_visible(false);
+            }
+
+            if !body.is_null() {
+                layout.remove_widget(body);
+                body.set_visible(false);
+            }
+        }
+
+        let show_headers = sorted.iter().any(|(script, _)| Self::script_category(&extra_metadata, script).is_some());
+        let mut categories: Vec<(Option<String>, QBox<QToolButton>, QBox<QWidget>)> = vec![];
+        for (script, checkbox) in sorted {
+            let category = Self::script_category(&extra_metadata, script);
+            if categories.last().map(|(last, _, _)| *last != category).unwrap_or(true) {
+                let (header, body) = self.new_scripts_category(&category);
+                let row = categories.len() as i32 * 2;
+                layout.add_widget_3a(&header, row, 0);
+                layout.add_widget_3a(&body, row + 1, 0);
+
+                // Without headers there's no way to expand a group, so never leave it collapsed.
+                if !show_headers {
+                    header.set_visible(false);
+                    body.set_visible(true);
+                }
+
+                categories.push((category, header, body));
+            }
+
+            let container = checkbox.parent_widget();
+            Self::remove_from_parent_layout(&container);
+
+            if let Some((_, _, body)) = categories.last() {
+                let body_layout = body.layout().static_downcast::<QGridLayout>();
+                body_layout.add_widget_3a(&container, body_layout.count(), 0);
+            }
+        }
+
+        let mut row = categories.len() as i32 * 2;
+        for (_, checkbox) in automatic {
+            let container = checkbox.parent_widget();
+            Self::remove_from_parent_layout(&container);
+            layout.add_widget_3a(&container, row, 0);
+            row += 1;
+        }
+
+        for (_, header, body) in old_categories {
+            if !header.is_null() {
+                header.delete_later();
+            }
+
+            if !body.is_null() {
+                body.delete_later();
+            }
+        }
+
+        *self.scripts_categories().write().unwrap() = categories;
+
+        // Headers depend on the filter, so re-apply it.
+        drop(scripts);
+        drop(extra_metadata);
+        self.filter_scripts();
+    }
+
+    /// This function creates the collapsible header and the container of a category of the scripts list.
+    ///
+    /// The collapsed state of each category is remembered between sessions.
+    unsafe fn new_scripts_category(&self, category: &Option<String>) -> (QBox<QToolButton>, QBox<QWidget>) {
+        let header = QToolButton::new_1a(self.scripts_container());
+        let body = QWidget::new_1a(self.scripts_container());
+        create_grid_layout(body.static_upcast());
+
+        let (text, setting) = match category {
+            Some(category) => (QString::from_std_str(category), format!("scripts_category_{category}_collapsed")),
+            None => (qtr("scripts_category_uncategorized"), "scripts_uncategorized_collapsed".to_owned()),
+        };
+
+        header.set_text(&text);
+        header.set_tool_button_style(ToolButtonStyle::ToolButtonTextBesideIcon);
+        header.set_auto_raise(true);
+        header.set_checkable(true);
+
+        let header_ptr = header.as_ptr();
+        let body_ptr = body.as_ptr();
+        let update_collapsed = move |collapsed: bool| {
+            header_ptr.set_arrow_type(if collapsed { ArrowType::RightArrow } else { ArrowType::DownArrow });
+            body_ptr.set_visible(!collapsed);
+        };
+
+        let collapsed = setting_bool(&setting);
+        header.set_checked(collapsed);
+        update_collapsed(collapsed);
+
+        header.toggled().connect(&SlotOfBool::new(&header, move |collapsed| {
+            update_collapsed(collapsed);
+            set_setting_bool(&setting, collapsed);
+        }));
+
+        (header, body)
+    }
+
+    /// This function returns the category of the provided script, if it has one.
+    pub fn script_category(extra_metadata: &HashMap<String, ScriptExtraMetadata>, script: &SQLScript) -> Option<String> {
+        extra_metadata.get(script.metadata().key())
+            .and_then(|extra| extra.category().as_ref())
+            .map(|category| category.trim().to_owned())
+    }
+
+    /// This function removes the provided widget from the layout of its parent, if it's in one.
+    unsafe fn remove_from_parent_layout(widget: &QPtr<QWidget>) {
+        let parent = widget.parent_widget();
+        if !parent.is_null() && !parent.layout().is_null() {
+            parent.layout().remove_widget(widget);
+        }
+    }
+
+    /// This function returns if the provided script matches the filter, by name or by key.
+    ///
+    /// Filters with glob wildcards (`*`, `?` or `[]`) need to match the entire name or key. Otherwise, any part of them can match.
+    /// Either way, matching is case-insensitive.
+    fn script_matches_filter(script: &SQLScript, filter: &str) -> bool {
+        let filter = filter.trim();
+        if filter.is_empty() {
+            return true;
+        }
+
+        let name = script.metadata().name().trim();
+        let key = script.metadata().key();
+
+        if filter.contains(['*', '?', '[']) {
+
+            // Invalid patterns match nothing.
+            match Self::glob_to_regex(filter) {
+                Some(regex) => regex.is_match(name) || regex.is_match(key),
+                None => false,
+            }
+        } else {
+            let filter = filter.to_lowercase();
+            name.to_lowercase().contains(&filter) || key.to_lowercase().contains(&filter)
+        }
+    }
+
+    /// This function turns a glob pattern into a case-insensitive regex. Returns None if the pattern is not valid.
+    fn glob_to_regex(pattern: &str) -> Option<Regex> {
+        let mut regex = String::from("^");
+        let mut chars = pattern.chars();
+        while let Some(char) = chars.next() {
+            match char {
+                '*' => regex.push_str(".*"),
+                '?' => regex.push('.'),
+                '[' => {
+                    regex.push('[');
+
+                    let mut class = String::new();
+                    let mut closed = false;
+                    for char in chars.by_ref() {
+                        if char == ']' {
+                            closed = true;
+                            break;
+                        }
+
+                        class.push(char);
+                    }
+
+                    if let Some(negated) = class.strip_prefix('!') {
+                        regex.push('^');
+                        class = negated.to_owned();
+                    }
+
+                    // Unclosed or empty classes are invalid.
+                    if !closed || class.is_empty() {
+                        return None;
+                    }
+
+                    regex.push_str(&class.replace('\\', "\\\\").replace('[', "\\["));
+                    regex.push(']');
+                }
+                _ => regex.push_str(&regex::escape(&char.to_string())),
+            }
+        }
+
+        regex.push('$');
+        RegexBuilder::new(&regex).case_insensitive(true).build().ok()
+    }
+
+    /// This function returns if the value of a script param is different from its default.
+    unsafe fn is_param_modified(widget: &ScriptParamWidget) -> bool {
+        match widget {
+            ScriptParamWidget::Bool(widget, default) => widget.is_checked() != *default,
+            ScriptParamWidget::Integer(widget, default) => widget.value() != *default,
+
+            // Floats are rounded by the spinbox, so compare them with the precision of the spinbox.
+            ScriptParamWidget::Float(widget, default) => (widget.value() - *default).abs() >= 0.5 / 10f64.powi(widget.decimals()),
+        }
+    }
+
+    /// This function loads the preset groups available for the game into the play menu, restoring the one the user had selected.
+    ///
+    /// A preset group is a preset name shared by multiple scripts.
+    pub unsafe fn load_preset_groups(&self, game_key: &str, preset_groups: &[String]) {
+        let combobox = self.preset_group_combobox();
+        combobox.block_signals(true);
+        combobox.clear();
+        combobox.add_item_q_string(&QString::from_std_str("--"));
+
+        for preset_group in preset_groups {
+            combobox.add_item_q_string(&QString::from_std_str(preset_group));
+        }
+
+        // Only restore it if it's still valid.
+        let preset_group = setting_string(&format!("preset_group_{}", game_key));
+        if combobox.find_text_1a(&QString::from_std_str(&preset_group)) != -1 {
+            combobox.set_current_text(&QString::from_std_str(&preset_group));
+        }
+
+        combobox.block_signals(false);
+    }
+
+    /// This function updates the count of automatic scripts, and the list of them shown when clicking it.
+    ///
+    /// Automatic scripts are hidden in the scripts list, so this is the only place where the user can see they're going to run.
+    ///
+    /// Each script can be disabled by unchecking it, as an escape hatch for when one breaks. That's asked first, as other features may depend on it.
+    /// Scripts come with the packs they require that are not enabled. Those scripts are dormant: they're listed, but they don't run.
+    pub unsafe fn load_automatic_scripts(&self, game_key: &str, scripts: &[(String, String, Vec<String>)]) {
+        let button = self.automatic_scripts_button();
+        button.set_enabled(!scripts.is_empty());
+        button.set_tool_button_style(ToolButtonStyle::ToolButtonTextBesideIcon);
+
+        let menu = self.automatic_scripts_menu();
+        menu.clear();
+        for (script_key, script_name, missing_mods) in scripts {
+            let dormant = !missing_mods.is_empty();
+            let action = if dormant {
+                menu.add_action_q_string(&qtre("automatic_script_dormant", &[script_name, &missing_mods.join(", ")]))
+            } else {
+                menu.add_action_q_string(&QString::from_std_str(script_name))
+            };
+
+            action.set_checkable(true);
+            action.set_checked(!Self::automatic_script_disabled(game_key, script_key));
+            action.set_data(&QVariant::from_bool(dormant));
+
+            let game_key = game_key.to_owned();
+            let script_key = script_key.to_owned();
+            let action_ptr = action.as_ptr();
+            let menu_ptr = menu.as_ptr();
+            let button_ptr = button.as_ptr();
+            let scripts_to_execute = self.scripts_to_execute().clone();
+            action.toggled().connect(&SlotOfBool::new(&action, move |state| {
+                if !state {
+                    let confirmed = QMessageBox::from_2_q_string_icon3_int_q_widget(
+                        &qtr("are_you_sure_title"),
+                        &qtre("automatic_script_disable_confirm", &[&action_ptr.text().to_std_string()]),
+                        q_message_box::Icon::Warning,
+                        65536, // No
+                        16384, // Yes
+                        1, // By default, select yes.
+                        &button_ptr.window(),
+                    ).exec() == 3;
+
+                    if !confirmed {
+                        action_ptr.block_signals(true);
+                        action_ptr.set_checked(true);
+                        action_ptr.block_signals(false);
+                        return;
+                    }
+                }
+
+                set_setting_bool(&format!("{AUTOMATIC_SCRIPT_DISABLED_PREFIX}{game_key}_{script_key}"), !state);
+                if let Some((_, checkbox)) = scripts_to_execute.read().unwrap().iter().find(|(script, _)| script.metadata().key() == script_key) {
+                    checkbox.set_checked(state && !dormant);
+                }
+
+                Self::update_automatic_scripts_button(&button_ptr, &menu_ptr);
+            }));
+        }
+
+        Self::update_automatic_scripts_button(&button.as_ptr(), &menu.as_ptr());
+    }
+
+    /// This function returns if the provided automatic script has been disabled by the user for the provided game.
+    pub fn automatic_script_disabled(game_key: &str, script_key: &str) -> bool {
+        setting_bool(&format!("{AUTOMATIC_SCRIPT_DISABLED_PREFIX}{game_key}_{script_key}"))
+    }
+
+    /// This function updates the automatic scripts button to reflect how many of them will run, with a warning if any of them is disabled.
+    ///
+    /// Dormant scripts are counted apart, as they're not disabled: they'll run once the mods they require are enabled.
+    unsafe fn update_automatic_scripts_button(button: &Ptr<QToolButton>, menu: &Ptr<QMenu>) {
+        let actions = menu.actions();
+        let total = actions.count_0a();
+        let mut running = 0;
+        let mut disabled = 0;
+        let mut dormant = 0;
+        for index in 0..total {
+            let action = actions.value_1a(index);
+            if !action.is_checked() {
+                disabled += 1;
+                action.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("dialog-warning")));
+            } else if action.data().to_bool() {
+                dormant += 1;
+                action.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("media-playback-pause")));
+            } else {
+                running += 1;
+                action.set_icon(&QIcon::new());
+            }
+        }
+
+        if disabled > 0 {
+            button.set_text(&qtre("automatic_scripts_count_disabled", &[&running.to_string(), &disabled.to_string()]));
+            button.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("dialog-warning")));
+            button.set_tool_tip(&qtr("automatic_scripts_disabled_tooltip"));
+        } else if dormant > 0 {
+            button.set_text(&qtre("automatic_scripts_count_dormant", &[&running.to_string(), &dormant.to_string()]));
+            button.set_icon(&QIcon::new());
+            button.set_tool_tip(&qtr("automatic_scripts_dormant_tooltip"));
+        } else {
+            button.set_text(&qtre("automatic_scripts_count", &[&running.to_string()]));
+            button.set_icon(&QIcon::new());
+            button.set_tool_tip(&qtr("automatic_scripts_tooltip"));
+        }
+    }
+
+    /// This function selects the preset with the provided name in all the scripts that have it.
+    ///
+    /// Selecting a preset through the combo triggers its slots, so the preset of each script gets saved as if it were selected manually.
+    pub unsafe fn apply_preset_group(&self, preset_group: &str) {
+        for (script, _) in self.scripts_to_execute().read().unwrap().iter() {
+            let preset_combo_name = format!("{}_preset_combo", script.metadata().key());
+            if let Ok(preset_combo) = self.scripts_container().find_child::<QComboBox>(&preset_combo_name) {
+                let index = preset_combo.find_text_1a(&QString::from_std_str(preset_group));
+                if index != -1 {
+                    preset_combo.set_current_index(index);
+                }
+            }
+        }
+    }
+
+    /// This function returns the name and affected files of the enabled scripts marked as destructive.
+    pub unsafe fn destructive_scripts_enabled(&self) -> Vec<(String, Vec<String>)> {
+        let scripts_extra_metadata = self.scripts_extra_metadata().read().unwrap();
+        self.scripts_to_execute().read().unwrap().iter()
+            .filter(|(_, item)| item.is_checked())
+            .filter_map(|(script, _)| {
+                let extra_metadata = scripts_extra_metadata.get(script.metadata().key())?;
+                if *extra_metadata.destructive() {
+                    Some((script.metadata().name().trim().to_owned(), extra_metadata.affected_files().to_vec()))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// This function returns the widget of a script param as a generic widget.
+    unsafe fn param_widget(widget: &ScriptParamWidget) -> Ptr<QWidget> {
+        match widget {
+            ScriptParamWidget::Bool(widget, _) => widget.static_upcast(),
+            ScriptParamWidget::Integer(widget, _) => widget.static_upcast(),
+            ScriptParamWidget::Float(widget, _) => widget.static_upcast(),
+        }
+    }
+
+    /// This function returns if the value of a script param matches any of the values of a visibility condition.
+    unsafe fn is_condition_met(condition: &VisibleWhen, widget: &ScriptParamWidget) -> bool {
+        condition.values().iter().any(|value| match widget {
+            ScriptParamWidget::Bool(widget, _) => value.parse::<bool>().map(|value| value == widget.is_checked()).unwrap_or_default(),
+            ScriptParamWidget::Integer(widget, _) => value.parse::<i32>().map(|value| value == widget.value()).unwrap_or_default(),
+
+            // Same as when checking if they're modified, compare floats with the precision of the spinbox.
+            ScriptParamWidget::Float(widget, _) => value.parse::<f64>().map(|value| (widget.value() - value).abs() < 0.5 / 10f64.powi(widget.decimals())).unwrap_or_default(),
+        })
+    }
+
+    /// This function loads the values of a preset into the param widgets of a script, or their manual values if no preset is provided.
+    ///
+    /// Signals are blocked while doing it, so the manual values saved in the settings are not overwritten by the preset ones.
+    unsafe fn load_param_values(param_widgets: &[ScriptParamWidget], param_keys: &[(String, String)], preset: Option<&Preset>) {
+        let settings = settings();
+        for (widget, (param_key, setting)) in param_widgets.iter().zip(param_keys.iter()) {
+
+            // Params missing in a preset use their default value, same as when they're passed to the script.
+            let preset_value = preset.map(|preset| preset.params().get(param_key).map(|value| value.to_string()));
+            let use_default = match preset_value {
+                Some(ref value) => value.is_none(),
+                None => !settings.value_1a(&QString::from_std_str(setting)).is_valid(),
+            };
+
+            match widget {
+                ScriptParamWidget::Bool(widget, default) => {
+                    let value = match preset_value {
+                        _ if use_default => *default,
+                        Some(Some(value)) => value.parse::<bool>().unwrap_or(*default),
+                        _ => setting_bool(setting),
+                    };
+
+                    widget.block_signals(true);
+                    widget.set_checked(value);
+                    widget.block_signals(false);
+                },
+                ScriptParamWidget::Integer(widget, default) => {
+                    let value = match preset_value {
+                        _ if use_default => *default,
+                        Some(Some(value)) => value.parse::<i32>().unwrap_or(*default),
+                        _ => setting_int(setting),
+                    };
+
+                    widget.block_signals(true);
+                    widget.set_value(value);
+                    widget.block_signals(false);
+                },
+                ScriptParamWidget::Float(widget, default) => {
+                    let value = match preset_value {
+                        _ if use_default => *default,
+                        Some(Some(value)) => value.parse::<f64>().unwrap_or(*default),
+                        _ => setting_f32(setting) as f64,
+                    };
+
+                    widget.block_signals(true);
+                    widget.set_value(value);
+                    widget.block_signals(false);
+                },
+            }
+        }
+    }
+
+    /// This function returns the index in the preset combo of the provided preset name, or 0 ("No Preset") if there's no preset with that name.
+    ///
+    /// The names must be in the same order as the presets in the combo.
+    fn preset_index<'a>(preset_names: impl IntoIterator<Item = &'a str>, preset_name: &str) -> usize {
+        if preset_name.is_empty() {
+            return 0;
+        }
+
+        preset_names.into_iter()
+            .position(|name| name == preset_name)
+            .map(|index| index + 1)
+            .unwrap_or_default()
+    }
+
     pub unsafe fn new_launch_option(menu: &QBox<QMenu>, text_key: &str, icon_key: &str, base_widget: &QBox<QWidget>, option_widget: &QPtr<QWidget>) {
         let action = QWidgetAction::new(menu);
+        action.set_object_name(&QString::from_std_str(text_key));
         let icon = QIcon::from_theme_1a(&QString::from_std_str(icon_key));
         let label_icon = QLabel::from_q_widget(base_widget);
         label_icon.set_pixmap(&icon.pixmap_2_int(22, 22));
@@ -302,43 +1253,116 @@ impl ActionsUI {
         layout.add_widget_5a(option_widget, 0, 3, 1, 1);
         layout.set_column_stretch(2, 10);
         action.set_default_widget(base_widget);
+        action.set_visible(!Self::hidden_launch_options().iter().any(|key| key == text_key));
         menu.add_action(&action);
     }
 
-    pub unsafe fn update_icons(&self) {
-        let enable_logging_icon = QIcon::from_theme_1a(&QString::from_std_str("verb"));
-        let enable_skip_intro_icon = QIcon::from_theme_1a(&QString::from_std_str("kdenlive-hide-video"));
-        let remove_trait_limit_icon = QIcon::from_theme_1a(&QString::from_std_str("folder-unlocked-symbolic"));
-        let remove_siege_attacker_icon = QIcon::from_theme_1a(&QString::from_std_str("folder-unlocked-symbolic"));
-        let enable_translations_icon = QIcon::from_theme_1a(&QString::from_std_str("language-chooser"));
-        let merge_all_mods_icon = QIcon::from_theme_1a(&QString::from_std_str("merge"));
-        let unit_multiplier_icon = QIcon::from_theme_1a(&QString::from_std_str("view-time-schedule-calculus"));
-        let universal_rebalancer_icon = QIcon::from_theme_1a(&QString::from_std_str("autocorrection"));
-        let enable_dev_only_ui_icon = QIcon::from_theme_1a(&QString::from_std_str("verb"));
+    /// This function returns the theme icon key to use for the provided launch option or script, taking into account the overrides of the game.
+    pub fn icon_key(&self, key: &str, default_icon_key: &str) -> String {
+        self.icon_overrides().read().unwrap()
+            .get(key)
+            .cloned()
+            .unwrap_or_else(|| default_icon_key.to_owned())
+    }
 
+    /// This function replaces the icon overrides with the ones of a new game, and updates the icons to use them.
+    pub unsafe fn set_icon_overrides(&self, icon_overrides: &HashMap<String, String>) {
+        *self.icon_overrides().write().unwrap() = icon_overrides.clone();
+        self.update_icons();
+    }
+
+    pub unsafe fn update_icons(&self) {
         let menu = self.play_button().menu();
+        let hidden_launch_options = Self::hidden_launch_options();
         for index in 0..menu.actions().count_0a() {
+            let action = menu.actions().value_1a(index);
+            let key = action.object_name().to_std_string();
+            if hidden_launch_options.contains(&key) {
+                continue;
+            }
 
-            if index < 8 {
-                let action = menu.actions().value_1a(index);
+            // Find the icon by the key of the action, so it doesn't matter in what order they are.
+            if let Some((_, icon_key)) = LAUNCH_OPTIONS.iter().find(|(option_key, icon_key)| *option_key == key && !icon_key.is_empty()) {
+                let icon = QIcon::from_theme_1a(&QString::from_std_str(self.icon_key(&key, icon_key)));
                 let widget_action = action.static_downcast::<QWidgetAction>();
                 let widget = widget_action.default_widget();
                 let layout = widget.layout().static_downcast::<QGridLayout>();
                 let child = layout.item_at_position(0, 0).widget();
                 let label = child.static_downcast::<QLabel>();
+                label.set_pixmap(&icon.pixmap_2_int(22, 22));
+            }
+        }
+    }
 
-                match index {
-                    0 => label.set_pixmap(&enable_logging_icon.pixmap_2_int(22, 22)),
-                    1 => label.set_pixmap(&enable_skip_intro_icon.pixmap_2_int(22, 22)),
-                    2 => label.set_pixmap(&remove_trait_limit_icon.pixmap_2_int(22, 22)),
-                    3 => label.set_pixmap(&remove_siege_attacker_icon.pixmap_2_int(22, 22)),
-                    4 => label.set_pixmap(&enable_translations_icon.pixmap_2_int(22, 22)),
-                    5 => label.set_pixmap(&merge_all_mods_icon.pixmap_2_int(22, 22)),
-                    6 => label.set_pixmap(&unit_multiplier_icon.pixmap_2_int(22, 22)),
-                    7 => label.set_pixmap(&universal_rebalancer_icon.pixmap_2_int(22, 22)),
-                    8 => label.set_pixmap(&enable_dev_only_ui_icon.pixmap_2_int(22, 22)),
-                    _ => {}
-                }
+    /// This function returns the keys of the launch options the user hid from the play menu.
+    pub fn hidden_launch_options() -> Vec<String> {
+        setting_string("hidden_launch_options")
+            .split(',')
+            .filter(|key| LAUNCH_OPTIONS.iter().any(|(option_key, _)| option_key == key))
+            .map(|key| key.to_owned())
+            .collect()
+    }
+
+    /// This function shows or hides the launch options in the play menu, following the settings.
+    ///
+    /// Hidden options keep their current values, so they still apply when launching the game.
+    pub unsafe fn update_launch_options_visibility(&self) {
+        let menu = self.play_button().menu();
+        let hidden_launch_options = Self::hidden_launch_options();
+        for index in 0..menu.actions().count_0a() {
+            let action = menu.actions().value_1a(index);
+            let key = action.object_name().to_std_string();
+            if LAUNCH_OPTIONS.iter().any(|(option_key, _)| *option_key == key) {
+                action.set_visible(!hidden_launch_options.contains(&key));
+            }
+        }
+
+        // Icons are not updated while hidden, so make sure the ones we just showed are up to date.
+        self.update_icons();
+    }
+
+    /// This function returns the keys of the reorderable launch options, in the order the user set them.
+    ///
+    /// Unknown keys are ignored, and options missing from the saved order go at the end, in their default order.
+    pub fn launch_options_order() -> Vec<String> {
+        let mut order = setting_string("launch_options_order")
+            .split(',')
+            .filter(|key| LAUNCH_OPTIONS.iter().any(|(option_key, _)| option_key == key))
+            .map(|key| key.to_owned())
+            .unique()
+            .collect::<Vec<_>>();
+
+        for (key, _) in LAUNCH_OPTIONS {
+            if !order.iter().any(|option_key| option_key == key) {
+                order.push(key.to_owned());
+            }
+        }
+
+        order
+    }
+
+    /// This function reorders the launch options in the play menu, following the order saved in the settings.
+    pub unsafe fn reorder_launch_options(&self) {
+        let menu = self.play_button().menu();
+        let actions = menu.actions();
+        let mut reorderable = vec![];
+        let mut first_fixed = Ptr::null();
+
+        for index in 0..actions.count_0a() {
+            let action = actions.value_1a(index);
+            let key = action.object_name().to_std_string();
+            if LAUNCH_OPTIONS.iter().any(|(option_key, _)| *option_key == key) {
+                reorderable.push((key, action));
+            } else if first_fixed.is_null() {
+                first_fixed = action;
+            }
+        }
+
+        // Re-insert them in order before the first action we cannot reorder.
+        for key in Self::launch_options_order() {
+            if let Some((_, action)) = reorderable.iter().find(|(option_key, _)| *option_key == key) {
+                menu.remove_action(*action);
+                menu.insert_action(first_fixed, *action);
             }
         }
     }
@@ -364,6 +1388,509 @@ impl ActionsUI {
         combobox
     }
 
+    /// This function makes the provided combobox editable, so typing in it shows a popup with only the items containing the typed text.
+    ///
+    /// The first item is the "none" item of the combobox, so it's always shown. The typed text is never inserted as a new item:
+    /// if it doesn't match an item when the user is done editing, the text of the selected item is restored.
+    pub unsafe fn make_combobox_filterable(combobox: &QBox<QComboBox>, placeholder: &QString) {
+        combobox.set_editable(true);
+        combobox.set_insert_policy(InsertPolicy::NoInsert);
+
+        let line_edit = combobox.line_edit();
+        line_edit.set_placeholder_text(placeholder);
+
+        let filter = QSortFilterProxyModel::new_1a(combobox);
+        filter.set_source_model(combobox.model());
+
+        // We do the filtering, so the completer just shows what passes the filter.
+        let completer = QCompleter::new_1a(combobox);
+        completer.set_model(&filter);
+        completer.set_completion_mode(CompletionMode::UnfilteredPopupCompletion);
+        line_edit.set_completer(&completer);
+
+        let combobox_ptr = combobox.as_ptr();
+        let filter_ptr = filter.as_ptr();
+        let completer_ptr = completer.as_ptr();
+        line_edit.text_edited().connect(&SlotOfQString::new(combobox, move |text| {
+            let pinned = QRegExp::escape(&combobox_ptr.item_text(0)).to_std_string();
+            let text = QRegExp::escape(text).to_std_string();
+            let pattern = QRegExp::new_1a(&QString::from_std_str(format!("^({pinned}|.*{text}.*)$")));
+            pattern.set_case_sensitivity(CaseSensitivity::CaseInsensitive);
+            filter_ptr.set_filter_reg_exp_q_reg_exp(&pattern);
+            completer_ptr.complete_0a();
+        }));
+
+        completer.activated().connect(&SlotOfQString::new(combobox, move |text| {
+            let index = combobox_ptr.find_text_1a(text);
+            if index != -1 {
+                combobox_ptr.set_current_index(index);
+            }
+        }));
+
+        line_edit.editing_finished().connect(&SlotNoArgs::new(combobox, move || {
+            let index = combobox_ptr.find_text_2a(&combobox_ptr.current_text(), MatchFlag::MatchFixedString.into());
+            if index != -1 {
+                combobox_ptr.set_current_index(index);
+            }
+
+            combobox_ptr.set_edit_text(&combobox_ptr.item_text(combobox_ptr.current_index()));
+        }));
+    }
+
+    pub unsafe fn new_launch_option_line_edit(menu: &QBox<QMenu>, text_key: &str, icon_key: &str) -> QBox<QLineEdit> {
+        let widget = QWidget::new_1a(menu);
+        let line_edit = QLineEdit::from_q_widget(&widget);
+        Self::new_launch_option(menu, text_key, icon_key, &widget, &line_edit.static_upcast());
+        line_edit
+    }
+
+    pub unsafe fn new_launch_option_toolbutton(menu: &QBox<QMenu>, text_key: &str, icon_key: &str) -> QBox<QToolButton> {
+        let widget = QWidget::new_1a(menu);
+        let button = QToolButton::new_1a(&widget);
+        Self::new_launch_option(menu, text_key, icon_key, &widget, &button.static_upcast());
+        button
+    }
+
+    /// This function returns the key of the setting storing the shortcut of the provided launch option.
+    pub fn launch_option_shortcut_setting(key: &str) -> String {
+        format!("launch_option_shortcut_{}", key)
+    }
+
+    /// This function applies the shortcuts saved in the settings to the launch options, and shows them in their tooltips.
+    pub unsafe fn update_launch_option_shortcuts(&self) {
+        for (key, shortcut, checkbox) in self.launch_option_shortcuts() {
+            let sequence = setting_string(&Self::launch_option_shortcut_setting(key));
+            shortcut.set_key(&QKeySequence::from_q_string(&QString::from_std_str(&sequence)));
+
+            if sequence.is_empty() {
+                checkbox.set_tool_tip(&QString::new());
+            } else {
+                checkbox.set_tool_tip(&qtre("launch_option_shortcut_tooltip", &[&sequence]));
+            }
+        }
+    }
+
+    /// This function returns the categories selected for merging for the provided game.
+    ///
+    /// An empty list means all the enabled mods get merged, regardless of their category.
+    pub fn merge_categories(game_key: &str) -> Vec<String> {
+        setting_string(&format!("merge_all_mods_categories_{}", game_key))
+            .lines()
+            .filter(|category| !category.is_empty())
+            .map(|category| category.to_owned())
+            .collect()
+    }
+
+    /// This function loads the provided categories into the merge categories menu, checking the ones selected for the provided game.
+    pub unsafe fn load_merge_categories(&self, game_key: &str, categories: &[String]) {
+        let menu = self.merge_categories_menu();
+        menu.clear();
+
+        let selected = Self::merge_categories(game_key);
+        for category in categories {
+            let action = menu.add_action_q_string(&QString::from_std_str(category));
+            action.set_checkable(true);
+            action.set_checked(selected.contains(category));
+
+            let game_key = game_key.to_owned();
+            let menu_ptr = menu.as_ptr();
+            let button_ptr = self.merge_categories_button().as_ptr();
+            action.toggled().connect(&SlotOfBool::new(&action, move |_| {
+                let actions = menu_ptr.actions();
+                let selected = (0..actions.count_0a())
+                    .map(|index| actions.value_1a(index))
+                    .filter(|action| action.is_checked())
+                    .map(|action| action.text().to_std_string())
+                    .join("\n");
+
+                set_setting_string(&format!("merge_all_mods_categories_{}", game_key), &selected);
+                Self::update_merge_categories_text(&button_ptr, &game_key);
+            }));
+        }
+
+        Self::update_merge_categories_text(&self.merge_categories_button().as_ptr(), game_key);
+    }
+
+    /// This function loads the additional secondary mods folders of the current game into the folders menu, one action per folder.
+    ///
+    /// The submenu is hidden if there are none.
+    pub unsafe fn load_secondary_extra_folders(&self, folders: &[PathBuf]) {
+        let menu = self.open_game_secondary_extra_folders_menu();
+        menu.clear();
+        menu.menu_action().set_visible(!folders.is_empty());
+
+        for folder in folders {
+            let action = menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("folder")), &QString::from_std_str(folder.to_string_lossy()));
+            let folder = folder.to_path_buf();
+            action.triggered().connect(&SlotNoArgs::new(&action, move || {
+                let _ = open::that(&folder);
+            }));
+        }
+    }
+
+    /// This function updates the text of the merge categories button to reflect the categories selected for the provided game.
+    pub unsafe fn update_merge_categories_text(button: &Ptr<QToolButton>, game_key: &str) {
+        let selected = Self::merge_categories(game_key);
+        let text = match selected.len() {
+            0 => qtr("merge_categories_all"),
+            1 => QString::from_std_str(&selected[0]),
+            count => qtre("merge_categories_count", &[&count.to_string()]),
+        };
+
+        button.set_text(&text);
+    }
+
+    /// This function returns the unit categories the universal rebalancer has to skip for the provided game.
+    pub fn rebalancer_excluded_categories(game_key: &str) -> Vec<String> {
+        setting_string(&format!("universal_rebalancer_excluded_categories_{}", game_key))
+            .lines()
+            .filter(|category| !category.is_empty())
+            .map(|category| category.to_owned())
+            .collect()
+    }
+
+    /// This function loads the provided unit categories into the rebalancer exclusions menu, checking the ones excluded for the provided game.
+    ///
+    /// Excluded categories no longer present in the rebalanced pack are kept, so they apply again if the user goes back to a pack using them.
+    pub unsafe fn load_rebalancer_excluded_categories(&self, game_key: &str, categories: &[String]) {
+        let menu = self.rebalancer_excluded_categories_menu();
+        menu.clear();
+
+        let excluded = Self::rebalancer_excluded_categories(game_key);
+        let categories = categories.iter().chain(excluded.iter()).sorted().dedup().collect::<Vec<_>>();
+        for category in categories {
+            let action = menu.add_action_q_string(&QString::from_std_str(category));
+            action.set_checkable(true);
+            action.set_checked(excluded.contains(category));
+
+            let game_key = game_key.to_owned();
+            let menu_ptr = menu.as_ptr();
+            let button_ptr = self.rebalancer_excluded_categories_button().as_ptr();
+            action.toggled().connect(&SlotOfBool::new(&action, move |_| {
+                let actions = menu_ptr.actions();
+                let excluded = (0..actions.count_0a())
+                    .map(|index| actions.value_1a(index))
+                    .filter(|action| action.is_checked())
+                    .map(|action| action.text().to_std_string())
+                    .join("\n");
+
+                set_setting_string(&format!("universal_rebalancer_excluded_categories_{}", game_key), &excluded);
+                Self::update_rebalancer_excluded_categories_text(&button_ptr, &game_key);
+            }));
+        }
+
+        Self::update_rebalancer_excluded_categories_text(&self.rebalancer_excluded_categories_button().as_ptr(), game_key);
+    }
+
+    /// This function updates the text of the rebalancer exclusions button to reflect the categories excluded for the provided game.
+    pub unsafe fn update_rebalancer_excluded_categories_text(button: &Ptr<QToolButton>, game_key: &str) {
+        let excluded = Self::rebalancer_excluded_categories(game_key);
+        let text = match excluded.len() {
+            0 => qtr("rebalancer_excluded_categories_none"),
+            1 => QString::from_std_str(&excluded[0]),
+            count => qtre("rebalancer_excluded_categories_count", &[&count.to_string()]),
+        };
+
+        button.set_text(&text);
+    }
+
+    /// This function returns if multiplayer-safe mode is enabled for the provided game.
+    pub fn multiplayer_safe_enabled(game_key: &str) -> bool {
+        setting_bool(&format!("multiplayer_safe_{}", game_key))
+    }
+
+    /// This function returns the options allowed in multiplayer-safe mode for the provided game, by launch option key.
+    pub unsafe fn multiplayer_safe_allowed(game_key: &str) -> Vec<String> {
+        let setting = format!("multiplayer_safe_options_{}", game_key);
+        if settings().value_1a(&QString::from_std_str(&setting)).is_valid() {
+            setting_string(&setting)
+                .split(',')
+                .filter(|key| !key.is_empty())
+                .map(|key| key.to_owned())
+                .collect()
+        } else {
+            MULTIPLAYER_SAFE_ALLOWED_DEFAULT.iter().map(|key| key.to_string()).collect()
+        }
+    }
+
+    /// This function loads the options that can be allowed in multiplayer-safe mode into its menu, checking the ones allowed for the provided game.
+    pub unsafe fn load_multiplayer_safe_options(&self, game_key: &str) {
+        let menu = self.multiplayer_safe_options_menu();
+        menu.clear();
+
+        let allowed = Self::multiplayer_safe_allowed(game_key);
+        let options = MULTIPLAYER_SAFE_OPTIONS.iter()
+            .map(|(key, _, _)| (*key, qtr(key)))
+            .chain([(SCRIPTS_ACTION_KEY, qtr("launch_options_scripts"))]);
+
+        for (key, text) in options {
+            let action = menu.add_action_q_string(&text);
+            action.set_checkable(true);
+            action.set_checked(allowed.iter().any(|allowed| allowed == key));
+            action.set_object_name(&QString::from_std_str(key));
+
+            let game_key = game_key.to_owned();
+            let menu_ptr = menu.as_ptr();
+            let button_ptr = self.multiplayer_safe_options_button().as_ptr();
+            action.toggled().connect(&SlotOfBool::new(&action, move |_| {
+                let actions = menu_ptr.actions();
+                let allowed = (0..actions.count_0a())
+                    .map(|index| actions.value_1a(index))
+                    .filter(|action| action.is_checked())
+                    .map(|action| action.object_name().to_std_string())
+                    .join(",");
+
+                set_setting_string(&format!("multiplayer_safe_options_{}", game_key), &allowed);
+                Self::update_multiplayer_safe_options_text(&button_ptr, &game_key);
+            }));
+        }
+
+        Self::update_multiplayer_safe_options_text(&self.multiplayer_safe_options_button().as_ptr(), game_key);
+    }
+
+    /// This function updates the text of the multiplayer-safe options button to reflect the options allowed for the provided game.
+    pub unsafe fn update_multiplayer_safe_options_text(button: &Ptr<QToolButton>, game_key: &str) {
+        let allowed = Self::multiplayer_safe_allowed(game_key);
+        let text = match allowed.len() {
+            0 => qtr("multiplayer_safe_options_none"),
+            count => qtre("multiplayer_safe_options_count", &[&count.to_string()]),
+        };
+
+        button.set_text(&text);
+    }
+
+    /// This function locks the options not allowed in multiplayer-safe mode if it's enabled for the provided game, and shows if it's enabled in the play button.
+    ///
+    /// It doesn't change the values of the options, except for the scripts, which are rebuilt from the settings every time and need to be turned off again.
+    pub unsafe fn update_multiplayer_safe(&self, game_key: &str) {
+        let enabled = Self::multiplayer_safe_enabled(game_key);
+        self.multiplayer_safe_checkbox().block_signals(true);
+        self.multiplayer_safe_checkbox().set_checked(enabled);
+        self.multiplayer_safe_checkbox().block_signals(false);
+
+        // The allowed options cannot be changed while the mode is enabled, as the values to restore depend on them.
+        self.multiplayer_safe_options_button().set_enabled(!enabled);
+        Self::update_multiplayer_safe_options_text(&self.multiplayer_safe_options_button().as_ptr(), game_key);
+
+        if enabled {
+            let allowed = Self::multiplayer_safe_allowed(game_key);
+            for (key, _, _) in MULTIPLAYER_SAFE_OPTIONS {
+                if !allowed.iter().any(|allowed| allowed == key) {
+                    if let Some(widget) = self.launch_option_widget(key) {
+                        widget.parent_widget().set_enabled(false);
+                    }
+                }
+            }
+
+            if !allowed.iter().any(|allowed| allowed == SCRIPTS_ACTION_KEY) {
+                for (_, checkbox) in self.scripts_to_execute().read().unwrap().iter() {
+                    checkbox.set_checked(false);
+                }
+
+                self.scripts_container().set_enabled(false);
+                self.preset_group_combobox().parent_widget().set_enabled(false);
+                self.automatic_scripts_button().parent_widget().set_enabled(false);
+            }
+
+            self.play_button().set_text(&qtr("multiplayer_safe_indicator"));
+            self.play_button().set_tool_button_style(ToolButtonStyle::ToolButtonTextBesideIcon);
+            self.play_button().set_tool_tip(&qtr("multiplayer_safe_indicator_tooltip"));
+        } else {
+            self.scripts_container().set_enabled(true);
+            self.automatic_scripts_button().parent_widget().set_enabled(true);
+            self.play_button().set_text(&QString::new());
+            self.play_button().set_tool_button_style(ToolButtonStyle::ToolButtonIconOnly);
+            self.play_button().set_tool_tip(&qtr("launch_game"));
+        }
+
+        // The presets row follows the multiplier row, which may have been locked above.
+        self.unit_multiplier_preset_combobox().parent_widget().set_enabled(self.unit_multiplier_spinbox().parent_widget().is_enabled());
+    }
+
+    /// This function returns the widget of the provided launch option, if it's one of the options multiplayer-safe mode can turn off.
+    unsafe fn launch_option_widget(&self, key: &str) -> Option<QPtr<QWidget>> {
+        match key {
+            "enable_logging" => Some(self.enable_logging_checkbox().static_upcast()),
+            "enable_skip_intro" => Some(self.enable_skip_intro_checkbox().static_upcast()),
+            "remove_trait_limit" => Some(self.remove_trait_limit_checkbox().static_upcast()),
+            "remove_siege_attacker" => Some(self.remove_siege_attacker_checkbox().static_upcast()),
+            "enable_translations" => Some(self.enable_translations_combobox().static_upcast()),
+            "merge_all_mods" => Some(self.merge_all_mods_checkbox().static_upcast()),
+            "unit_multiplier" => Some(self.unit_multiplier_spinbox().static_upcast()),
+            "universal_rebalancer" => Some(self.universal_rebalancer_combobox().static_upcast()),
+            "enable_dev_only_ui" => Some(self.enable_dev_only_ui_checkbox().static_upcast()),
+            _ => None,
+        }
+    }
+
+    /// This function loads the custom launch options of the provided game into the play menu, replacing the ones already there.
+    pub unsafe fn load_custom_launch_options(&self, game: &GameInfo) -> Result<()> {
+        let menu = QBox::new(self.play_button().menu().as_ptr());
+
+        let mut custom_launch_options = self.custom_launch_options().write().unwrap();
+        for (_, action, _) in custom_launch_options.drain(..) {
+            menu.remove_action(&action);
+            action.delete_later();
+        }
+
+        let options = CustomLaunchOptions::load(game, true)?;
+
+        // Check which flags the current build of the game accepts, to warn about the ones it doesn't. This only reads
+        // the executable the first time a flag is checked for a build. If the check fails, we just don't warn.
+        let flags = options.options().iter().map(|option| option.flag().to_owned()).collect::<Vec<_>>();
+        let flags_check = game_executable_path(game, &setting_path(game.key()))
+            .filter(|path| path.is_file() && !flags.is_empty())
+            .and_then(|path| LaunchFlagsCheck::check(game, &path, &flags).ok());
+
+        for option in options.options() {
+            let setting = option.setting_key(game.key());
+            let widget: QPtr<QWidget> = match option.kind() {
+                CustomLaunchOptionKind::Bool => {
+                    let checkbox = Self::new_launch_option_checkbox(&menu, "custom_launch_option", "run-build");
+                    checkbox.set_checked(setting_bool(&setting));
+                    checkbox.toggled().connect(&SlotOfBool::new(&checkbox, move |state| {
+                        set_setting_bool(&setting, state);
+                    }));
+
+                    checkbox.static_upcast()
+                },
+                CustomLaunchOptionKind::Value => {
+                    let spinbox = Self::new_launch_option_doublespinbox(&menu, "custom_launch_option", "run-build");
+                    spinbox.set_range(-1_000_000.0, 1_000_000.0);
+
+                    // Like with the unit multiplier, we use the default if we don't have a value yet.
+                    if settings().value_1a(&QString::from_std_str(&setting)).is_valid() {
+                        spinbox.set_value(setting_f32(&setting) as f64);
+                    } else {
+                        spinbox.set_value(*option.default_value());
+                    }
+
+                    spinbox.value_changed().connect(&SlotOfDouble::new(&spinbox, move |value| {
+                        set_setting_f32(&setting, value as f32);
+                    }));
+
+                    spinbox.static_upcast()
+                },
+            };
+
+            // The helpers use translated names, so we need to manually replace them with the user-defined ones.
+            let action = QPtr::new(menu.actions().value_1a(menu.actions().count_0a() - 1));
+            let layout = widget.parent_widget().layout().static_downcast::<QGridLayout>();
+            let label = layout.item_at_position(0, 1).widget().static_downcast::<QLabel>();
+            label.set_text(&QString::from_std_str(option.name()));
+            label.set_tool_tip(&QString::from_std_str(option.flag()));
+
+            if flags_check.as_ref().and_then(|check| check.supported(option.flag())) == Some(false) {
+                let label_icon = layout.item_at_position(0, 0).widget().static_downcast::<QLabel>();
+                label_icon.set_pixmap(&QIcon::from_theme_1a(&QString::from_std_str("dialog-warning")).pixmap_2_int(22, 22));
+                label.set_tool_tip(&qtre("launch_flag_unsupported_tooltip", &[option.flag()]));
+            }
+
+            custom_launch_options.push((option.clone(), action, widget));
+        }
+
+        Ok(())
+    }
+
+    /// This function returns the language selected in the translations combobox.
+    ///
+    /// The combobox is editable, so this returns the selected item, not whatever the user may be typing in it.
+    pub unsafe fn selected_translation(&self) -> String {
+        let combobox = self.enable_translations_combobox();
+        combobox.item_text(combobox.current_index()).to_std_string()
+    }
+
+    /// This function returns the arguments to pass to the game for the enabled custom launch options.
+    pub unsafe fn custom_launch_options_args(&self) -> Vec<String> {
+        let mut args = vec![];
+        for (option, _, widget) in self.custom_launch_options().read().unwrap().iter() {
+            if !widget.is_enabled() {
+                continue;
+            }
+
+            match option.kind() {
+                CustomLaunchOptionKind::Bool => {
+                    if widget.static_downcast::<QCheckBox>().is_checked() {
+                        args.push(option.flag().to_owned());
+                    }
+                },
+                CustomLaunchOptionKind::Value => {
+                    let value = widget.static_downcast::<QDoubleSpinBox>().value();
+                    if value != *option.default_value() {
+                        args.push(option.flag().to_owned());
+                        args.push(value.to_string());
+                    }
+                },
+            }
+        }
+
+        args
+    }
+
+    /// This function returns the max value allowed for the unit multiplier, as configured in the settings.
+    pub fn unit_multiplier_max() -> i32 {
+        match setting_int("unit_multiplier_max") {
+            0 => UNIT_MULTIPLIER_MAX_DEFAULT,
+            max => max,
+        }
+    }
+
+    /// This function returns the value of the unit multiplier from which we warn the user, derived from its max value.
+    pub fn unit_multiplier_warning_threshold() -> f64 {
+        Self::unit_multiplier_max() as f64 * UNIT_MULTIPLIER_WARNING_RATIO
+    }
+
+    /// This function clamps the unit multiplier to the range configured in the settings.
+    pub unsafe fn update_unit_multiplier_range(&self) {
+        let max = Self::unit_multiplier_max();
+        self.unit_multiplier_spinbox().set_range(UNIT_MULTIPLIER_MIN, max as f64);
+        self.unit_multiplier_spinbox().set_tool_tip(&QString::from_std_str(tre("unit_multiplier_tooltip", &[&max.to_string()])));
+    }
+
+    /// This function returns the unit multiplier presets of the provided game, by name.
+    pub fn unit_multiplier_presets(game_key: &str) -> BTreeMap<String, f64> {
+        serde_json::from_str(&setting_string(&format!("unit_multiplier_presets_{}", game_key))).unwrap_or_default()
+    }
+
+    pub fn set_unit_multiplier_presets(game_key: &str, presets: &BTreeMap<String, f64>) {
+        if let Ok(presets) = serde_json::to_string(presets) {
+            set_setting_string(&format!("unit_multiplier_presets_{}", game_key), &presets);
+        }
+    }
+
+    /// This function loads the unit multiplier presets of the provided game into their combobox, and selects the one matching the current multiplier.
+    pub unsafe fn load_unit_multiplier_presets(&self, game_key: &str) {
+        let combobox = self.unit_multiplier_preset_combobox();
+        combobox.block_signals(true);
+        combobox.clear();
+        combobox.add_item_q_string(&QString::from_std_str("--"));
+
+        for (name, value) in Self::unit_multiplier_presets(game_key) {
+            combobox.add_item_q_string_q_variant(&QString::from_std_str(&name), &QVariant::from_double(value));
+        }
+
+        combobox.block_signals(false);
+        self.select_unit_multiplier_preset();
+    }
+
+    /// This function selects the preset matching the current unit multiplier, or none if no preset matches it.
+    ///
+    /// If more than one preset has the same value, the selected one is kept.
+    pub unsafe fn select_unit_multiplier_preset(&self) {
+        let combobox = self.unit_multiplier_preset_combobox();
+        let value = self.unit_multiplier_spinbox().value();
+        let matches = |index: i32| index > 0 && (combobox.item_data_1a(index).to_double_0a() - value).abs() < f64::EPSILON;
+
+        if !matches(combobox.current_index()) {
+            let index = (1..combobox.count()).find(|index| matches(*index)).unwrap_or(0);
+            combobox.block_signals(true);
+            combobox.set_current_index(index);
+            combobox.block_signals(false);
+        }
+
+        self.unit_multiplier_preset_delete_button().set_enabled(combobox.current_index() > 0);
+    }
+
     pub unsafe fn new(parent: &QBox<QWidget>) -> Result<Rc<Self>> {
         let layout: QPtr<QGridLayout> = parent.layout().static_downcast();
 
@@ -373,26 +1900,134 @@ impl ActionsUI {
 
         let play_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "play_button")?;
         let play_menu = QMenu::from_q_widget(&play_button);
+        let game_executable_combobox = Self::new_launch_option_combobox(&play_menu, "game_executable", "application-x-executable");
+        game_executable_combobox.set_tool_tip(&qtr("game_executable_tooltip"));
+        let multiplayer_safe_checkbox = Self::new_launch_option_checkbox(&play_menu, "multiplayer_safe", "network-connect");
+        multiplayer_safe_checkbox.set_tool_tip(&qtr("multiplayer_safe_tooltip"));
+        let multiplayer_safe_options_button = Self::new_launch_option_toolbutton(&play_menu, "multiplayer_safe_options", "configure");
+        let multiplayer_safe_options_menu = QMenu::from_q_widget(&multiplayer_safe_options_button);
+        multiplayer_safe_options_button.set_menu(&multiplayer_safe_options_menu);
+        multiplayer_safe_options_button.set_popup_mode(ToolButtonPopupMode::InstantPopup);
+        multiplayer_safe_options_button.set_tool_tip(&qtr("multiplayer_safe_options_tooltip"));
         let enable_logging_checkbox = Self::new_launch_option_checkbox(&play_menu, "enable_logging", "verb");
         let enable_skip_intro_checkbox = Self::new_launch_option_checkbox(&play_menu, "enable_skip_intro", "kdenlive-hide-video");
         let remove_trait_limit_checkbox = Self::new_launch_option_checkbox(&play_menu, "remove_trait_limit", "folder-unlocked-symbolic");
         let remove_siege_attacker_checkbox = Self::new_launch_option_checkbox(&play_menu, "remove_siege_attacker", "folder-unlocked-symbolic");
         let enable_translations_combobox = Self::new_launch_option_combobox(&play_menu, "enable_translations", "language-chooser");
+        Self::make_combobox_filterable(&enable_translations_combobox, &qtr("enable_translations_filter"));
         let merge_all_mods_checkbox = Self::new_launch_option_checkbox(&play_menu, "merge_all_mods", "merge");
+        let merge_categories_button = Self::new_launch_option_toolbutton(&play_menu, "merge_categories", "tag");
+        let merge_categories_menu = QMenu::from_q_widget(&merge_categories_button);
+        merge_categories_button.set_menu(&merge_categories_menu);
+        merge_categories_button.set_popup_mode(ToolButtonPopupMode::InstantPopup);
+        merge_categories_button.set_tool_tip(&qtr("merge_categories_tooltip"));
         let unit_multiplier_spinbox = Self::new_launch_option_doublespinbox(&play_menu, "unit_multiplier", "view-time-schedule-calculus");
-        let universal_rebalancer_combobox = Self::new_launch_option_combobox(&play_menu, "universal_rebalancer", "view-time-schedule-calculus");
+        let unit_multiplier_preset_combobox = Self::new_launch_option_combobox(&play_menu, "unit_multiplier_preset", "bookmarks");
+        unit_multiplier_preset_combobox.set_tool_tip(&qtr("unit_multiplier_preset_tooltip"));
+
+        // The buttons to manage the presets go in the same row as them.
+        let unit_multiplier_preset_layout: QPtr<QGridLayout> = unit_multiplier_preset_combobox.parent_widget().layout().static_downcast();
+        let unit_multiplier_preset_save_button = QToolButton::new_1a(unit_multiplier_preset_combobox.parent_widget());
+        unit_multiplier_preset_save_button.set_auto_raise(true);
+        unit_multiplier_preset_save_button.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("document-save")));
+        unit_multiplier_preset_save_button.set_tool_tip(&qtr("unit_multiplier_preset_save"));
+        unit_multiplier_preset_layout.add_widget_5a(&unit_multiplier_preset_save_button, 0, 4, 1, 1);
+        let unit_multiplier_preset_delete_button = QToolButton::new_1a(unit_multiplier_preset_combobox.parent_widget());
+        unit_multiplier_preset_delete_button.set_auto_raise(true);
+        unit_multiplier_preset_delete_button.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("edit-delete")));
+        unit_multiplier_preset_delete_button.set_tool_tip(&qtr("unit_multiplier_preset_delete"));
+        unit_multiplier_preset_layout.add_widget_5a(&unit_multiplier_preset_delete_button, 0, 5, 1, 1);
+        let universal_rebalancer_combobox = Self::new_launch_option_combobox(&play_menu, "universal_rebalancer", "autocorrection");
+        let rebalancer_excluded_categories_button = Self::new_launch_option_toolbutton(&play_menu, "rebalancer_excluded_categories", "list-remove");
+        let rebalancer_excluded_categories_menu = QMenu::from_q_widget(&rebalancer_excluded_categories_button);
+        rebalancer_excluded_categories_button.set_menu(&rebalancer_excluded_categories_menu);
+        rebalancer_excluded_categories_button.set_popup_mode(ToolButtonPopupMode::InstantPopup);
+        rebalancer_excluded_categories_button.set_tool_tip(&qtr("rebalancer_excluded_categories_tooltip"));
         let enable_dev_only_ui_checkbox = Self::new_launch_option_checkbox(&play_menu, "enable_dev_only_ui", "verb");
+        let preset_group_combobox = Self::new_launch_option_combobox(&play_menu, "preset_group", "games-config-options");
+        preset_group_combobox.set_tool_tip(&qtr("preset_group_tooltip"));
+        let automatic_scripts_button = Self::new_launch_option_toolbutton(&play_menu, "automatic_scripts", "dialog-information");
+        let automatic_scripts_menu = QMenu::from_q_widget(&automatic_scripts_button);
+        automatic_scripts_button.set_menu(&automatic_scripts_menu);
+        automatic_scripts_button.set_popup_mode(ToolButtonPopupMode::InstantPopup);
+        automatic_scripts_button.set_tool_tip(&qtr("automatic_scripts_tooltip"));
+        let scripts_sort_combobox = Self::new_launch_option_combobox(&play_menu, "scripts_sort", "view-sort");
+        scripts_sort_combobox.add_item_q_string(&qtr("scripts_sort_name"));
+        scripts_sort_combobox.add_item_q_string(&qtr("scripts_sort_key"));
+
+        // Sorting by category was replaced by the category groups, so fall back to the default sort if it was selected.
+        let scripts_sort = setting_int("scripts_sort");
+        scripts_sort_combobox.set_current_index(if scripts_sort < scripts_sort_combobox.count() { scripts_sort } else { SCRIPTS_SORT_NAME });
+        scripts_sort_combobox.set_tool_tip(&qtr("scripts_sort_tooltip"));
+        let scripts_filter_line_edit = Self::new_launch_option_line_edit(&play_menu, "scripts_filter", "search");
+        scripts_filter_line_edit.set_placeholder_text(&qtr("scripts_filter_placeholder"));
+        scripts_filter_line_edit.set_tool_tip(&qtr("scripts_filter_tooltip"));
+        scripts_filter_line_edit.set_clear_button_enabled(true);
         enable_translations_combobox.set_current_index(0);
         unit_multiplier_spinbox.set_value(1.00);
+        unit_multiplier_spinbox.set_single_step(0.1);
         universal_rebalancer_combobox.set_current_index(0);
 
+        // Shortcuts toggle the checkbox, so its own slot takes care of saving the new state.
+        let mut launch_option_shortcuts = vec![];
+        for key in SHORTCUT_LAUNCH_OPTIONS {
+            let checkbox = match key {
+                "multiplayer_safe" => &multiplayer_safe_checkbox,
+                "enable_logging" => &enable_logging_checkbox,
+                "enable_skip_intro" => &enable_skip_intro_checkbox,
+                "remove_trait_limit" => &remove_trait_limit_checkbox,
+                "remove_siege_attacker" => &remove_siege_attacker_checkbox,
+                "merge_all_mods" => &merge_all_mods_checkbox,
+                "enable_dev_only_ui" => &enable_dev_only_ui_checkbox,
+                _ => unreachable!("launch option without checkbox {}", key),
+            };
+
+            let shortcut = QShortcut::new_1a(parent);
+            let checkbox_ptr = checkbox.as_ptr();
+            shortcut.activated().connect(&SlotNoArgs::new(&shortcut, move || {
+                if checkbox_ptr.is_enabled() {
+                    checkbox_ptr.toggle();
+                }
+            }));
+
+            launch_option_shortcuts.push((key.to_owned(), shortcut, QPtr::new(checkbox.as_ptr())));
+        }
+
         let scripts_action = QWidgetAction::new(&play_menu);
+        scripts_action.set_object_name(&QString::from_std_str(SCRIPTS_ACTION_KEY));
         let scripts_container = QWidget::new_1a(&play_menu);
         create_grid_layout(scripts_container.static_upcast());
         scripts_action.set_default_widget(&scripts_container);
+        scripts_action.set_visible(!Self::hidden_launch_options().iter().any(|key| key == SCRIPTS_ACTION_KEY));
 
         play_menu.add_action(&scripts_action);
 
+        // Custom launch options go after the manager action, as they're loaded dynamically.
+        play_menu.add_separator();
+        let custom_launch_options_manager = play_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("configure")), &qtr("custom_launch_options_manager"));
+        let launch_options_reorder = play_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("view-sort")), &qtr("launch_options_reorder"));
+        let script_sandbox = play_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("debug-run")), &qtr("script_sandbox"));
+        let effective_settings = play_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("document-properties")), &qtr("effective_settings"));
+        let export_launch_script = play_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("application-x-shellscript")), &qtr("export_launch_script"));
+        let restore_script_backup = play_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("document-revert")), &qtr("restore_script_backup"));
+        let export_script_bundle = play_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("document-export")), &qtr("export_script_bundle"));
+        let import_script_bundle = play_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("document-import")), &qtr("import_script_bundle"));
+        let reapply_scripts = play_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("run-build")), &qtr("reapply_scripts"));
+        let rebuild_translations = play_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("language-chooser")), &qtr("rebuild_translations"));
+        let merge_summary = play_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("documentinfo")), &qtr("merge_summary_show"));
+        let validate_launch = play_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("checkmark")), &qtr("validate_launch"));
+        let test_launch_options = play_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("run-build-configure")), &qtr("test_launch_options"));
+        let modification_summary = play_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("view-statistics")), &qtr("modification_summary"));
+        let launch_history = play_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("view-history")), &qtr("launch_history"));
+
+        // Pinning the menu reopens it when it gets closed by clicking outside, so multiple options can be configured in one go.
+        // It's not saved to the settings, so it only lasts for the current session.
+        play_menu.add_separator();
+        let play_menu_pinned_checkbox = Self::new_launch_option_checkbox(&play_menu, "play_menu_pinned", "window-pin");
+        let play_menu_reopen_timer = QTimer::new_1a(&play_menu);
+        play_menu_reopen_timer.set_single_shot(true);
+        play_menu_reopen_timer.set_interval(0);
+
         play_button.set_menu(play_menu.into_raw_ptr());
         play_button.set_popup_mode(ToolButtonPopupMode::MenuButtonPopup);
 
@@ -407,9 +2042,28 @@ impl ActionsUI {
         let open_game_data_folder = folders_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("folder")), &qtr("open_game_data_folder"));
         let open_game_content_folder = folders_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("folder")), &qtr("open_game_content_folder"));
         let open_game_secondary_folder = folders_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("folder")), &qtr("open_game_secondary_folder"));
+        let open_game_secondary_extra_folders_menu = folders_menu.add_menu_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("folder")), &qtr("open_game_secondary_extra_folders"));
         let open_game_config_folder = folders_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("folder")), &qtr("open_game_config_folder"));
+        let open_game_crash_dumps_folder = folders_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("folder")), &qtr("open_game_crash_dumps_folder"));
         let open_runcher_config_folder = folders_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("folder")), &qtr("open_runcher_config_folder"));
         let open_runcher_error_folder = folders_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("folder")), &qtr("open_runcher_error_folder"));
+        folders_menu.add_separator();
+
+        let mod_sources_menu = folders_menu.add_menu_q_string(&qtr("load_mods_from"));
+        let mod_sources_actions = MOD_SOURCES.iter()
+            .map(|source| {
+                let action = mod_sources_menu.add_action_q_string(&qtr(&format!("load_mods_from_{source}")));
+                action.set_checkable(true);
+                (*source, action)
+            })
+            .collect::<Vec<_>>();
+
+        folders_menu.add_separator();
+        let create_crash_report = folders_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("tools-report-bug")), &qtr("create_crash_report"));
+        let create_diagnostics_bundle = folders_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("document-export")), &qtr("create_diagnostics_bundle"));
+        folders_menu.add_separator();
+        let export_config_bundle = folders_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("document-export")), &qtr("export_config_bundle"));
+        let import_config_bundle = folders_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("document-import")), &qtr("import_config_bundle"));
         folders_button.set_menu(folders_menu.into_raw_ptr());
         folders_button.set_popup_mode(ToolButtonPopupMode::MenuButtonPopup);
 
@@ -419,9 +2073,30 @@ impl ActionsUI {
         let download_subscribed_mods_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "download_subscribed_mods_button")?;
         copy_load_order_button.set_tool_tip(&qtr("copy_load_order"));
         paste_load_order_button.set_tool_tip(&qtr("paste_load_order"));
+
+        let copy_load_order_menu = QMenu::from_q_widget(&copy_load_order_button);
+        let copy_load_order_markdown = copy_load_order_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("text-x-markdown")), &qtr("copy_load_order_markdown"));
+        let copy_load_order_markdown_names_only = copy_load_order_menu.add_action_q_string(&qtr("copy_load_order_markdown_names_only"));
+        copy_load_order_markdown_names_only.set_checkable(true);
+        copy_load_order_markdown_names_only.set_checked(setting_bool("copy_load_order_markdown_names_only"));
+        let copy_workshop_ids = copy_load_order_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("edit-copy")), &qtr("copy_workshop_ids"));
+        copy_workshop_ids.set_tool_tip(&qtr("copy_workshop_ids_tooltip"));
+        copy_load_order_button.set_menu(copy_load_order_menu.into_raw_ptr());
+        copy_load_order_button.set_popup_mode(ToolButtonPopupMode::MenuButtonPopup);
+
+        let paste_load_order_menu = QMenu::from_q_widget(&paste_load_order_button);
+        let import_load_order_file = paste_load_order_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("document-import")), &qtr("import_load_order_file"));
+        paste_load_order_button.set_menu(paste_load_order_menu.into_raw_ptr());
+        paste_load_order_button.set_popup_mode(ToolButtonPopupMode::MenuButtonPopup);
+
         reload_button.set_tool_tip(&qtr("reload"));
         download_subscribed_mods_button.set_tool_tip(&qtr("download_subscribed_mods"));
 
+        let download_subscribed_mods_menu = QMenu::from_q_widget(&download_subscribed_mods_button);
+        let show_mod_updates = download_subscribed_mods_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("view-history")), &qtr("mod_updates_title"));
+        download_subscribed_mods_button.set_menu(download_subscribed_mods_menu.into_raw_ptr());
+        download_subscribed_mods_button.set_popup_mode(ToolButtonPopupMode::MenuButtonPopup);
+
         let profile_load_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "profile_load_button")?;
         let profile_save_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "profile_save_button")?;
         let profile_manager_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "profile_manager_button")?;
@@ -430,6 +2105,11 @@ impl ActionsUI {
         profile_combobox.set_model(&profile_model);
         profile_combobox.line_edit().set_placeholder_text(&qtr("profile_name"));
         profile_load_button.set_tool_tip(&qtr("load_profile"));
+
+        // The recent profiles are loaded when opening the menu, as they change every time we load a profile.
+        let profile_recent_menu = QMenu::from_q_widget(&profile_load_button);
+        profile_load_button.set_menu(&profile_recent_menu);
+        profile_load_button.set_popup_mode(ToolButtonPopupMode::MenuButtonPopup);
         profile_save_button.set_tool_tip(&qtr("save_profile"));
         profile_manager_button.set_tool_tip(&qtr("profile_manager"));
 
@@ -437,22 +2117,65 @@ impl ActionsUI {
         let save_model: QBox<QStandardItemModel> = QStandardItemModel::new_1a(&save_combobox);
         save_combobox.set_model(&save_model);
 
+        // Bulk backup and removal of saves, for people with a lot of autosaves to clean up.
+        let manage_saves = QAction::from_q_icon_q_string_q_object(&QIcon::from_theme_1a(&QString::from_std_str("document-multiple")), &qtr("save_manager"), &save_combobox).into_q_ptr();
+        save_combobox.add_action(&manage_saves);
+        save_combobox.set_context_menu_policy(ContextMenuPolicy::ActionsContextMenu);
+
         layout.add_widget_5a(&main_widget, 0, 0, 1, 1);
 
         let ui = Rc::new(Self {
             play_button,
+            game_executable_combobox,
+            multiplayer_safe_checkbox,
+            multiplayer_safe_options_button,
+            multiplayer_safe_options_menu,
             enable_logging_checkbox,
             enable_skip_intro_checkbox,
             remove_trait_limit_checkbox,
             remove_siege_attacker_checkbox,
             enable_translations_combobox,
             merge_all_mods_checkbox,
+            merge_categories_button,
+            merge_categories_menu,
             unit_multiplier_spinbox,
+            unit_multiplier_preset_combobox,
+            unit_multiplier_preset_save_button,
+            unit_multiplier_preset_delete_button,
             universal_rebalancer_combobox,
+            rebalancer_excluded_categories_button,
+            rebalancer_excluded_categories_menu,
             //universal_balancer_ignored: QToolButton::new_0a();
             enable_dev_only_ui_checkbox,
+            preset_group_combobox,
+            automatic_scripts_button,
+            automatic_scripts_menu,
+            scripts_sort_combobox,
+            scripts_filter_line_edit,
             scripts_container,
+            launch_option_shortcuts,
             scripts_to_execute: Arc::new(RwLock::new(vec![])),
+            scripts_extra_metadata: Arc::new(RwLock::new(HashMap::new())),
+            scripts_categories: Arc::new(RwLock::new(vec![])),
+            icon_overrides: Arc::new(RwLock::new(HashMap::new())),
+            custom_launch_options_manager,
+            launch_options_reorder,
+            script_sandbox,
+            effective_settings,
+            export_launch_script,
+            restore_script_backup,
+            export_script_bundle,
+            import_script_bundle,
+            reapply_scripts,
+            rebuild_translations,
+            merge_summary,
+            validate_launch,
+            test_launch_options,
+            modification_summary,
+            launch_history,
+            play_menu_pinned_checkbox,
+            play_menu_reopen_timer,
+            custom_launch_options: Arc::new(RwLock::new(vec![])),
 
             settings_button,
             folders_button,
@@ -460,25 +2183,64 @@ impl ActionsUI {
             open_game_data_folder,
             open_game_content_folder,
             open_game_secondary_folder,
+            open_game_secondary_extra_folders_menu,
             open_game_config_folder,
+            open_game_crash_dumps_folder,
             open_runcher_config_folder,
             open_runcher_error_folder,
+            create_crash_report,
+            create_diagnostics_bundle,
+            export_config_bundle,
+            import_config_bundle,
+            mod_sources_actions,
 
             copy_load_order_button,
+            copy_load_order_markdown,
+            copy_load_order_markdown_names_only,
+            copy_workshop_ids,
             paste_load_order_button,
+            import_load_order_file,
             reload_button,
             download_subscribed_mods_button,
+            show_mod_updates,
 
             profile_load_button,
+            profile_recent_menu,
             profile_save_button,
             profile_manager_button,
             profile_combobox,
             profile_model,
 
             save_combobox,
-            save_model
+            save_model,
+            manage_saves,
         });
 
+        ui.update_unit_multiplier_range();
+        ui.reorder_launch_options();
+        ui.update_launch_option_shortcuts();
+
         Ok(ui)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preset_index_skips_the_no_preset_entry() {
+        let names = ["Balanced", "Hardcore", "Casual"];
+        assert_eq!(ActionsUI::preset_index(names, "Balanced"), 1);
+        assert_eq!(ActionsUI::preset_index(names, "Casual"), 3);
+    }
+
+    #[test]
+    fn preset_index_falls_back_to_no_preset() {
+        let names = ["Balanced", "Hardcore"];
+        assert_eq!(ActionsUI::preset_index(names, ""), 0);
+        assert_eq!(ActionsUI::preset_index(names, "Removed"), 0);
+        assert_eq!(ActionsUI::preset_index(names, "balanced"), 0);
+        assert_eq!(ActionsUI::preset_index([] as [&str; 0], "Balanced"), 0);
+    }
+}
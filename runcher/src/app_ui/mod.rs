@@ -12,44 +12,68 @@ use qt_widgets::QAction;
 use qt_widgets::QActionGroup;
 use qt_widgets::QApplication;
 use qt_widgets::QButtonGroup;
+use qt_widgets::QCheckBox;
 use qt_widgets::QComboBox;
 use qt_widgets::QGroupBox;
+use qt_widgets::q_header_view::ResizeMode;
 use qt_widgets::QLineEdit;
 use qt_widgets::QRadioButton;
 use qt_widgets::QTabWidget;
 use qt_widgets::QToolBar;
-use qt_widgets::{QDialog, QDialogButtonBox, q_dialog_button_box::StandardButton};
+use qt_widgets::QToolButton;
+use qt_widgets::{QDialog, QDialogButtonBox, q_dialog_button_box::{ButtonRole, StandardButton}};
+use qt_widgets::QDoubleSpinBox;
+use qt_widgets::{QFileDialog, q_file_dialog::{AcceptMode, FileMode}};
 use qt_widgets::QLabel;
+use qt_widgets::QListView;
 use qt_widgets::QMainWindow;
+use qt_widgets::QMenu;
 use qt_widgets::QMessageBox;
 use qt_widgets::q_message_box;
+use qt_widgets::QPlainTextEdit;
+use qt_widgets::QProgressBar;
 use qt_widgets::QPushButton;
 use qt_widgets::QSplitter;
+use qt_widgets::{QSystemTrayIcon, q_system_tray_icon::ActivationReason};
 use qt_widgets::QTableView;
+use qt_widgets::QTextBrowser;
 use qt_widgets::QTextEdit;
 use qt_widgets::QTreeView;
 use qt_widgets::QWidget;
 
+use qt_gui::QCursor;
 use qt_gui::QFont;
+use qt_gui::QGuiApplication;
 use qt_gui::QIcon;
+use qt_gui::QKeySequence;
 use qt_gui::QListOfQStandardItem;
 use qt_gui::QStandardItem;
 use qt_gui::QStandardItemModel;
 
+use qt_core::CaseSensitivity;
 use qt_core::CheckState;
+use qt_core::ContextMenuPolicy;
 use qt_core::Orientation;
+use qt_core::TextFormat;
 use qt_core::QBox;
 use qt_core::QCoreApplication;
+use qt_core::QFileSystemWatcher;
 use qt_core::QModelIndex;
 use qt_core::QObject;
+use qt_core::QPoint;
 use qt_core::QPtr;
 use qt_core::QSize;
 use qt_core::QSortFilterProxyModel;
 use qt_core::QString;
+use qt_core::QStringList;
+use qt_core::QTimer;
 use qt_core::QVariant;
 use qt_core::SlotNoArgs;
+use qt_core::SlotOfQModelIndex;
+use qt_core::SlotOfQString;
 
 use cpp_core::CppBox;
+use cpp_core::Ptr;
 use cpp_core::Ref;
 
 use anyhow::{anyhow, Result};
@@ -58,17 +82,22 @@ use crossbeam::channel::Receiver;
 use flate2::read::ZlibDecoder;
 use getset::Getters;
 use itertools::Itertools;
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
 use rayon::prelude::*;
+use regex::Regex;
 use sha256::try_digest;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs::{DirBuilder, File};
 use std::io::{BufReader, BufWriter, Cursor, Read, Write};
 use std::path::{Path, PathBuf};
 use std::process::exit;
 use std::rc::Rc;
 use std::sync::{Arc, RwLock};
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use time::OffsetDateTime;
+use zip::{CompressionMethod, ZipWriter, write::SimpleFileOptions};
 
 use rpfm_lib::binary::{ReadBytes, WriteBytes};
 use rpfm_lib::files::{Container, db::DB, EncodeableExtraData, FileType, loc::Loc, pack::Pack, RFile, RFileDecoded, table::DecodedData};
@@ -84,7 +113,7 @@ use rpfm_ui_common::settings::*;
 use rpfm_ui_common::tools::*;
 use rpfm_ui_common::utils::*;
 
-use crate::actions_ui::ActionsUI;
+use crate::actions_ui::{ActionsUI, LAUNCH_OPTIONS, MULTIPLAYER_SAFE_OPTIONS, SCRIPTS_ACTION_KEY};
 use crate::CENTRAL_COMMAND;
 use crate::cli::Cli;
 use crate::communications::*;
@@ -93,11 +122,14 @@ use crate::data_ui::DataListUI;
 use crate::data_ui::pack_tree::PackTree;
 use crate::ffi::*;
 use crate::games::*;
-use crate::mod_manager::{*, game_config::{GameConfig, DEFAULT_CATEGORY}, integrations::*, load_order::{ImportedLoadOrderMode, LoadOrder}, mods::{Mod, ShareableMod}, profiles::Profile, saves::Save};
+use crate::mod_manager::{*, bisect::BisectSession, config_bundle::ConfigBundle, custom_launch_options::{CustomLaunchOptionKind, CustomLaunchOptions}, font_coverage::CHECK_TRANSLATION_FONT_COVERAGE, game_config::{GameConfig, DEFAULT_CATEGORY}, integrations::*, launch_flags::LaunchFlagsCheck, launch_history::{LaunchHistory, LaunchHistoryEntry, LaunchOutcome}, launch_script::{LaunchScript, LaunchScriptKind, quote_command_arg}, load_order::{ImportedLoadOrderMode, LoadOrder}, merge_summary::MergeSummary, mod_advisories::{ModAdvisories, MOD_ADVISORIES_LAST_UPDATE, MOD_ADVISORIES_SOURCE, MOD_ADVISORIES_UPDATE_INTERVAL}, mod_updates::{ModUpdate, ModUpdates}, mods::{Mod, ShareableMod}, profiles::Profile, saves::{self, Save}, script_bundles::ScriptBundle};
 use crate::LIGHT_PALETTE;
 use crate::LIGHT_STYLE_SHEET;
+use crate::log_ui::LogUI;
 use crate::mod_list_ui::*;
 use crate::pack_list_ui::PackListUI;
+use crate::profiles_ui::ProfilesUI;
+use crate::scripts_ui::ScriptsUI;
 use crate::{
     REGEX_MAP_INFO_DISPLAY_NAME,
     REGEX_MAP_INFO_DESCRIPTION,
@@ -125,8 +157,85 @@ const WORKSHOP_UPLOAD_VIEW_RELEASE: &str = "ui/workshop_upload_dialog.ui";
 const LOG_ANALYSIS_VIEW_DEBUG: &str = "ui_templates/log_analysis_dialog.ui";
 const LOG_ANALYSIS_VIEW_RELEASE: &str = "ui/log_analysis_dialog.ui";
 
+const DUPLICATED_PACKS_VIEW_DEBUG: &str = "ui_templates/duplicated_packs_dialog.ui";
+const DUPLICATED_PACKS_VIEW_RELEASE: &str = "ui/duplicated_packs_dialog.ui";
+
+const TOGGLE_MATCHING_VIEW_DEBUG: &str = "ui_templates/toggle_matching_dialog.ui";
+const TOGGLE_MATCHING_VIEW_RELEASE: &str = "ui/toggle_matching_dialog.ui";
+
+const RANDOM_SUBSET_VIEW_DEBUG: &str = "ui_templates/random_subset_dialog.ui";
+const RANDOM_SUBSET_VIEW_RELEASE: &str = "ui/random_subset_dialog.ui";
+
+const GLOBAL_SEARCH_VIEW_DEBUG: &str = "ui_templates/global_search_dialog.ui";
+const GLOBAL_SEARCH_VIEW_RELEASE: &str = "ui/global_search_dialog.ui";
+
+/// Roles where the global search stores the kind of each result, and the key to find it.
+const GLOBAL_SEARCH_KIND: i32 = 21;
+const GLOBAL_SEARCH_KEY: i32 = 22;
+
+const MOD_UPDATES_VIEW_DEBUG: &str = "ui_templates/mod_updates_dialog.ui";
+const MOD_UPDATES_VIEW_RELEASE: &str = "ui/mod_updates_dialog.ui";
+
+const SCRIPT_SANDBOX_VIEW_DEBUG: &str = "ui_templates/script_sandbox_dialog.ui";
+const SCRIPT_SANDBOX_VIEW_RELEASE: &str = "ui/script_sandbox_dialog.ui";
+
+const LAUNCH_OPTIONS_ORDER_VIEW_DEBUG: &str = "ui_templates/launch_options_order_dialog.ui";
+const LAUNCH_OPTIONS_ORDER_VIEW_RELEASE: &str = "ui/launch_options_order_dialog.ui";
+
+const EFFECTIVE_SETTINGS_VIEW_DEBUG: &str = "ui_templates/effective_settings_dialog.ui";
+const EFFECTIVE_SETTINGS_VIEW_RELEASE: &str = "ui/effective_settings_dialog.ui";
+
+const LAUNCH_HISTORY_VIEW_DEBUG: &str = "ui_templates/launch_history_dialog.ui";
+const LAUNCH_HISTORY_VIEW_RELEASE: &str = "ui/launch_history_dialog.ui";
+
+const SAVE_MANAGER_VIEW_DEBUG: &str = "ui_templates/save_manager_dialog.ui";
+const SAVE_MANAGER_VIEW_RELEASE: &str = "ui/save_manager_dialog.ui";
+
+const UNIT_MULTIPLIER_PRESET_VIEW_DEBUG: &str = "ui_templates/profile_rename_dialog.ui";
+const UNIT_MULTIPLIER_PRESET_VIEW_RELEASE: &str = "ui/profile_rename_dialog.ui";
+
+/// Role where the launch options order dialog stores the key of each option.
+const LAUNCH_OPTION_KEY: i32 = 21;
+
+/// Results the save manager dialog is closed with when one of its action buttons is pressed.
+const SAVE_MANAGER_BACKUP: i32 = 2;
+const SAVE_MANAGER_DELETE: i32 = 3;
+
+/// Result the save manager dialog is closed with when a save is double-clicked, to launch the game with it.
+const SAVE_MANAGER_LAUNCH: i32 = 4;
+
+/// Amount of steps reported by the progress bar when reloading a game: profiles, saves, mods and scripts.
+const RELOAD_STEPS: i32 = 4;
+
+/// Amount of profiles shown in the recent profiles menu.
+const MAX_RECENT_PROFILES: usize = 5;
+
 const MERGE_ALL_PACKS_PACK_NAME: &str = "merge_me_sideways_honey";
 
+/// Prefix of the settings of the scripts. Followed by the game key, and the script key.
+const SCRIPT_SETTINGS_PREFIX: &str = "script_to_execute_";
+
+/// Suffixes of the script settings that only affect the UI, so they're not stored in profiles.
+const SCRIPT_SETTINGS_UI_ONLY_SUFFIXES: [&str; 2] = ["_expanded", "_show_params"];
+
+/// Amount of game and Runcher logs included in the diagnostics bundle.
+const DIAGNOSTICS_MAX_LOGS: usize = 5;
+
+/// Interval, in ms, between checks of the exit status of the last launch.
+const LAST_LAUNCH_STATUS_INTERVAL: i32 = 5000;
+
+/// Interval, in ms, between checks of the game while waiting for it to finish.
+const WAIT_FOR_FINISH_INTERVAL: u64 = 50;
+
+/// Interval, in ms, between checks of whether a backup of the profiles is due.
+const PROFILE_BACKUPS_CHECK_INTERVAL: i32 = 10 * 60 * 1000;
+
+/// Time, in ms, to wait after the last change in the mod folders before reloading, so big or multiple writes only trigger one reload.
+const MOD_FOLDERS_WATCHER_DELAY: i32 = 2000;
+
+/// Amount of characters the fonts cannot render listed when warning about the coverage of a translation.
+const TRANSLATION_FONT_COVERAGE_MAX_CHARACTERS: usize = 30;
+
 #[allow(dead_code)] const VANILLA_MOD_LIST_FILE_NAME: &str = "used_mods.txt";
 #[allow(dead_code)] pub const CUSTOM_MOD_LIST_FILE_NAME: &str = "mod_list.txt";
 #[allow(dead_code)] const USER_SCRIPT_FILE_NAME: &str = "user.script.txt";
@@ -148,16 +257,29 @@ pub struct AppUI {
     //-------------------------------------------------------------------------------//
     main_window: QBox<QMainWindow>,
     right_tabbar: QBox<QTabWidget>,
+    tray_icon: QBox<QSystemTrayIcon>,
+    tray_menu: QBox<QMenu>,
+    tray_show_hide: QPtr<QAction>,
+    tray_launch_game: QPtr<QAction>,
+    tray_recent_profiles_menu: QBox<QMenu>,
 
+    global_search_button: QBox<QPushButton>,
     github_button: QBox<QPushButton>,
     discord_button: QBox<QPushButton>,
     patreon_button: QBox<QPushButton>,
     about_runcher_button: QBox<QPushButton>,
     check_updates_button: QBox<QPushButton>,
+    reload_progress_bar: QBox<QProgressBar>,
+    last_launch_status_label: QBox<QLabel>,
+    last_launch_status_timer: QBox<QTimer>,
+    mod_folders_watcher: QBox<QFileSystemWatcher>,
+    mod_folders_watcher_timer: QBox<QTimer>,
+    profile_backups_timer: QBox<QTimer>,
 
     //-------------------------------------------------------------------------------//
     // `Game Selected` menu.
     //-------------------------------------------------------------------------------//
+    game_selected_bar: QPtr<QToolBar>,
     game_selected_pharaoh_dynasties: QPtr<QAction>,
     game_selected_pharaoh: QPtr<QAction>,
     game_selected_warhammer_3: QPtr<QAction>,
@@ -194,12 +316,23 @@ pub struct AppUI {
     //-------------------------------------------------------------------------------//
     pack_list_ui: Rc<PackListUI>,
 
+    //-------------------------------------------------------------------------------//
+    // `Game Log` section.
+    //-------------------------------------------------------------------------------//
+    log_ui: Rc<LogUI>,
+
+    //-------------------------------------------------------------------------------//
+    // `Scripts` section.
+    //-------------------------------------------------------------------------------//
+    scripts_ui: Rc<ScriptsUI>,
+
     //-------------------------------------------------------------------------------//
     // Extra stuff
     //-------------------------------------------------------------------------------//
     slots: Rc<RwLock<Option<AppUISlots>>>,
     focused_widget: Rc<RwLock<Option<QPtr<QWidget>>>>,
     disabled_counter: Rc<RwLock<u32>>,
+    reloading: Rc<RwLock<bool>>,
 
     tools: Arc<RwLock<Tools>>,
     game_config: Arc<RwLock<Option<GameConfig>>>,
@@ -220,6 +353,18 @@ pub struct ScriptBreak {
     full_log: String,
 }
 
+/// Settings that need parts of the UI reloaded when they change, as they were the last time they got applied.
+#[derive(Clone, Debug, Default)]
+pub struct AppliedSettings {
+    game_key: String,
+    game_path: PathBuf,
+    secondary_mods_paths: Vec<PathBuf>,
+    dark_theme: bool,
+    font_name: String,
+    font_size: i32,
+    mod_advisories_source: String,
+}
+
 //-------------------------------------------------------------------------------//
 //                             Implementations
 //-------------------------------------------------------------------------------//
@@ -252,10 +397,33 @@ impl AppUI {
 
         central_layout.add_widget_5a(splitter.into_raw_ptr(), 0, 1, 1, 1);
 
+        // Tray icon, so the launcher can be used while minimized to the tray.
+        let tray_icon = QSystemTrayIcon::from_q_icon_q_object(&QApplication::window_icon(), &main_window);
+        tray_icon.set_tool_tip(&QString::from_std_str("The Runcher"));
+
+        let tray_menu = QMenu::from_q_widget(&main_window);
+        let tray_show_hide = tray_menu.add_action_q_string(&qtr("tray_show_hide"));
+        tray_menu.add_separator();
+        let tray_launch_game = tray_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("media-playback-start")), &qtr("launch_game"));
+        let tray_recent_profiles_menu = QMenu::from_q_string_q_widget(&qtr("tray_recent_profiles"), &tray_menu);
+        tray_menu.add_menu_q_menu(&tray_recent_profiles_menu);
+        tray_icon.set_context_menu(&tray_menu);
+
+        if QSystemTrayIcon::is_system_tray_available() {
+            tray_icon.show();
+        }
+
         // Get the Status bar.
         let status_bar = main_window.status_bar();
         status_bar.set_size_grip_enabled(false);
 
+        let global_search_button = QPushButton::from_q_widget(&status_bar);
+        global_search_button.set_flat(true);
+        global_search_button.set_tool_tip(&qtr("global_search"));
+        global_search_button.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("search")));
+        global_search_button.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str("Ctrl+K")));
+        status_bar.add_permanent_widget_1a(&global_search_button);
+
         let github_button = QPushButton::from_q_widget(&status_bar);
         github_button.set_flat(true);
         github_button.set_tool_tip(&qtr("github_link"));
@@ -286,6 +454,35 @@ impl AppUI {
         check_updates_button.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("svn-update")));
         status_bar.add_permanent_widget_1a(&check_updates_button);
 
+        // Progress bar for reloads. Only visible while reloading.
+        let reload_progress_bar = QProgressBar::new_1a(&status_bar);
+        reload_progress_bar.set_range(0, RELOAD_STEPS);
+        reload_progress_bar.set_maximum_width(300);
+        reload_progress_bar.set_visible(false);
+        status_bar.add_widget_1a(&reload_progress_bar);
+
+        // Status of the last launch. Only visible if the game failed to start.
+        //
+        // The game is followed from another thread until it closes, so we need to check how the launch ended periodically.
+        // The same timer is used to check if the game is still running. It only runs while there's a launch to follow.
+        let last_launch_status_label = QLabel::from_q_widget(&status_bar);
+        last_launch_status_label.set_text_format(TextFormat::RichText);
+        last_launch_status_label.set_visible(false);
+        status_bar.add_widget_1a(&last_launch_status_label);
+
+        let last_launch_status_timer = QTimer::new_1a(&main_window);
+        last_launch_status_timer.set_interval(LAST_LAUNCH_STATUS_INTERVAL);
+
+        let mod_folders_watcher = QFileSystemWatcher::new_1a(&main_window);
+        let mod_folders_watcher_timer = QTimer::new_1a(&main_window);
+        mod_folders_watcher_timer.set_interval(MOD_FOLDERS_WATCHER_DELAY);
+        mod_folders_watcher_timer.set_single_shot(true);
+
+        // Backups are checked periodically, so sessions left open for days also get them.
+        let profile_backups_timer = QTimer::new_1a(&main_window);
+        profile_backups_timer.set_interval(PROFILE_BACKUPS_CHECK_INTERVAL);
+        profile_backups_timer.start_0a();
+
         //-----------------------------------------------//
         // `Game Selected` Menu.
         //-----------------------------------------------//
@@ -296,6 +493,7 @@ impl AppUI {
         game_selected_bar.set_orientation(Orientation::Vertical);
         game_selected_bar.set_icon_size(&QSize::new_2a(64, 64));
         game_selected_bar.set_fixed_width(64);
+        game_selected_bar.set_context_menu_policy(ContextMenuPolicy::CustomContextMenu);
 
         let icon_folder = format!("{}/icons/", ASSETS_PATH.to_string_lossy());
         let game_selected_pharaoh_dynasties = game_selected_bar.add_action_2a(&QIcon::from_q_string(&QString::from_std_str(icon_folder.clone() + SUPPORTED_GAMES.game(KEY_PHARAOH_DYNASTIES).unwrap().icon_small())), &QString::from_std_str(DISPLAY_NAME_PHARAOH_DYNASTIES));
@@ -342,7 +540,8 @@ impl AppUI {
         game_selected_napoleon.set_checkable(true);
         game_selected_empire.set_checkable(true);
 
-        central_layout.add_widget_5a(game_selected_bar.into_raw_ptr(), 0, 0, 1, 1);
+        let game_selected_bar = game_selected_bar.into_q_ptr();
+        central_layout.add_widget_5a(&game_selected_bar, 0, 0, 1, 1);
 
         //-------------------------------------------------------------------------------//
         // `Actions` section.
@@ -364,6 +563,16 @@ impl AppUI {
         //-------------------------------------------------------------------------------//
         let pack_list_ui = PackListUI::new(&right_tabbar)?;
 
+        //-------------------------------------------------------------------------------//
+        // `Game Log` section.
+        //-------------------------------------------------------------------------------//
+        let log_ui = LogUI::new(&right_tabbar)?;
+
+        //-------------------------------------------------------------------------------//
+        // `Scripts` section.
+        //-------------------------------------------------------------------------------//
+        let scripts_ui = ScriptsUI::new(&right_tabbar, &actions_ui)?;
+
         let app_ui = Rc::new(Self {
 
             //-------------------------------------------------------------------------------//
@@ -371,16 +580,29 @@ impl AppUI {
             //-------------------------------------------------------------------------------//
             main_window,
             right_tabbar,
+            tray_icon,
+            tray_menu,
+            tray_show_hide,
+            tray_launch_game,
+            tray_recent_profiles_menu,
 
             github_button,
             discord_button,
             patreon_button,
+            global_search_button,
             about_runcher_button,
             check_updates_button,
+            reload_progress_bar,
+            last_launch_status_label,
+            last_launch_status_timer,
+            mod_folders_watcher,
+            mod_folders_watcher_timer,
+            profile_backups_timer,
 
             //-------------------------------------------------------------------------------//
             // "Game Selected" menu.
             //-------------------------------------------------------------------------------//
+            game_selected_bar,
             game_selected_pharaoh_dynasties,
             game_selected_pharaoh,
             game_selected_warhammer_3,
@@ -417,12 +639,23 @@ impl AppUI {
             //-------------------------------------------------------------------------------//
             pack_list_ui,
 
+            //-------------------------------------------------------------------------------//
+            // `Game Log` section.
+            //-------------------------------------------------------------------------------//
+            log_ui,
+
+            //-------------------------------------------------------------------------------//
+            // `Scripts` section.
+            //-------------------------------------------------------------------------------//
+            scripts_ui,
+
             //-------------------------------------------------------------------------------//
             // "Extra stuff" menu.
             //-------------------------------------------------------------------------------//
             slots: Rc::new(RwLock::new(None)),
             focused_widget: Rc::new(RwLock::new(None)),
             disabled_counter: Rc::new(RwLock::new(0)),
+            reloading: Rc::new(RwLock::new(false)),
 
             tools: Arc::new(RwLock::new(Tools::load(&None).unwrap_or_else(|_| Tools::default()))),
             game_config: Arc::new(RwLock::new(None)),
@@ -501,6 +734,9 @@ impl AppUI {
             }
         }
 
+        // Put the pinned and most used games at the top of the bar.
+        app_ui.sort_game_selected_bar();
+
         // Load the correct theme.
         app_ui.reload_theme();
 
@@ -535,6 +771,12 @@ impl AppUI {
             Err(error) => show_dialog(app_ui.main_window(), error, false),
         }
 
+        // Backup the profiles before anything can touch them.
+        Self::backup_profiles_if_due();
+
+        // Advisories are only informative, so they're updated after everything else is loaded.
+        app_ui.update_mod_advisories_if_due();
+
         // Check for updates.
         UpdaterUI::new_with_precheck(&app_ui)?;
 
@@ -543,16 +785,37 @@ impl AppUI {
 
     pub unsafe fn set_connections(&self, slots: &AppUISlots) {
         self.actions_ui().play_button().released().connect(slots.launch_game());
+        self.tray_icon().activated().connect(slots.tray_activated());
+        self.last_launch_status_timer().timeout().connect(slots.check_launch_status());
+        self.last_launch_status_label().link_activated().connect(slots.show_last_launch_logs());
+        self.mod_folders_watcher().directory_changed().connect(slots.mod_folders_changed());
+        self.mod_folders_watcher().file_changed().connect(slots.mod_folders_changed());
+        self.mod_folders_watcher_timer().timeout().connect(slots.mod_folders_reload());
+        self.profile_backups_timer().timeout().connect(slots.profile_backups_check());
+        self.tray_show_hide().triggered().connect(slots.tray_show_hide());
+        self.tray_launch_game().triggered().connect(slots.launch_game());
+        self.tray_recent_profiles_menu().about_to_show().connect(slots.tray_recent_profiles_menu_open());
         self.actions_ui().enable_logging_checkbox().toggled().connect(slots.toggle_logging());
         self.actions_ui().enable_skip_intro_checkbox().toggled().connect(slots.toggle_skip_intros());
         self.actions_ui().remove_trait_limit_checkbox().toggled().connect(slots.toggle_remove_trait_limit());
         self.actions_ui().remove_siege_attacker_checkbox().toggled().connect(slots.toggle_remove_siege_attacker());
         self.actions_ui().merge_all_mods_checkbox().toggled().connect(slots.toggle_merge_all_mods());
-        self.actions_ui().enable_translations_combobox().current_text_changed().connect(slots.toggle_enable_translations());
+        self.actions_ui().merge_categories_menu().about_to_show().connect(slots.merge_categories_menu_open());
+        self.actions_ui().rebalancer_excluded_categories_menu().about_to_show().connect(slots.rebalancer_excluded_categories_menu_open());
+        self.actions_ui().game_executable_combobox().current_index_changed().connect(slots.change_game_executable());
+        self.actions_ui().multiplayer_safe_checkbox().toggled().connect(slots.toggle_multiplayer_safe());
+        self.actions_ui().multiplayer_safe_options_menu().about_to_show().connect(slots.multiplayer_safe_options_menu_open());
+        self.actions_ui().enable_translations_combobox().current_index_changed().connect(slots.toggle_enable_translations());
         self.actions_ui().unit_multiplier_spinbox().value_changed().connect(slots.change_unit_multiplier());
+        self.actions_ui().unit_multiplier_preset_combobox().current_index_changed().connect(slots.change_unit_multiplier_preset());
+        self.actions_ui().unit_multiplier_preset_save_button().released().connect(slots.save_unit_multiplier_preset());
+        self.actions_ui().unit_multiplier_preset_delete_button().released().connect(slots.delete_unit_multiplier_preset());
         self.actions_ui().settings_button().released().connect(slots.open_settings());
         self.actions_ui().universal_rebalancer_combobox().current_text_changed().connect(slots.toggle_universal_rebalancer());
         self.actions_ui().enable_dev_only_ui_checkbox().toggled().connect(slots.toggle_dev_only_ui());
+        self.actions_ui().preset_group_combobox().current_text_changed().connect(slots.change_preset_group());
+        self.actions_ui().scripts_sort_combobox().current_index_changed().connect(slots.change_scripts_sort());
+        self.actions_ui().scripts_filter_line_edit().text_changed().connect(slots.filter_scripts());
         self.actions_ui().folders_button().released().connect(slots.open_folders_submenu());
         self.actions_ui().open_game_root_folder().triggered().connect(slots.open_game_root_folder());
         self.actions_ui().open_game_data_folder().triggered().connect(slots.open_game_data_folder());
@@ -560,14 +823,46 @@ impl AppUI {
         self.actions_ui().open_game_secondary_folder().triggered().connect(slots.open_game_secondary_folder());
         self.actions_ui().open_game_config_folder().triggered().connect(slots.open_game_config_folder());
         self.actions_ui().open_runcher_config_folder().triggered().connect(slots.open_runcher_config_folder());
+        self.actions_ui().open_game_crash_dumps_folder().triggered().connect(slots.open_game_crash_dumps_folder());
         self.actions_ui().open_runcher_error_folder().triggered().connect(slots.open_runcher_error_folder());
+        self.actions_ui().create_crash_report().triggered().connect(slots.create_crash_report());
+        self.actions_ui().create_diagnostics_bundle().triggered().connect(slots.create_diagnostics_bundle());
+        self.actions_ui().export_config_bundle().triggered().connect(slots.export_config_bundle());
+        self.actions_ui().import_config_bundle().triggered().connect(slots.import_config_bundle());
+        for (_, action) in self.actions_ui().mod_sources_actions() {
+            action.triggered().connect(slots.toggle_mod_sources());
+        }
         self.actions_ui().copy_load_order_button().released().connect(slots.copy_load_order());
+        self.actions_ui().copy_load_order_markdown().triggered().connect(slots.copy_load_order_markdown());
+        self.actions_ui().copy_load_order_markdown_names_only().toggled().connect(slots.copy_load_order_markdown_names_only());
+        self.actions_ui().copy_workshop_ids().triggered().connect(slots.copy_workshop_ids());
         self.actions_ui().paste_load_order_button().released().connect(slots.paste_load_order());
+        self.actions_ui().import_load_order_file().triggered().connect(slots.import_load_order_file());
+        self.actions_ui().manage_saves().triggered().connect(slots.manage_saves());
         self.actions_ui().reload_button().released().connect(slots.reload());
         self.actions_ui().download_subscribed_mods_button().released().connect(slots.download_subscribed_mods());
         self.actions_ui().profile_load_button().released().connect(slots.load_profile());
+        self.actions_ui().profile_recent_menu().about_to_show().connect(slots.recent_profiles_menu_open());
         self.actions_ui().profile_save_button().released().connect(slots.save_profile());
         self.actions_ui().profile_manager_button().released().connect(slots.open_profile_manager());
+        self.actions_ui().custom_launch_options_manager().triggered().connect(slots.open_custom_launch_options_manager());
+        self.actions_ui().launch_options_reorder().triggered().connect(slots.reorder_launch_options());
+        self.actions_ui().script_sandbox().triggered().connect(slots.script_sandbox());
+        self.actions_ui().effective_settings().triggered().connect(slots.effective_settings());
+        self.actions_ui().export_launch_script().triggered().connect(slots.export_launch_script());
+        self.actions_ui().restore_script_backup().triggered().connect(slots.restore_script_backup());
+        self.actions_ui().reapply_scripts().triggered().connect(slots.reapply_scripts());
+        self.actions_ui().rebuild_translations().triggered().connect(slots.rebuild_translations());
+        self.actions_ui().merge_summary().triggered().connect(slots.merge_summary());
+        self.actions_ui().validate_launch().triggered().connect(slots.validate_launch());
+        self.actions_ui().test_launch_options().triggered().connect(slots.test_launch_options());
+        self.actions_ui().modification_summary().triggered().connect(slots.modification_summary());
+        self.actions_ui().launch_history().triggered().connect(slots.launch_history());
+        self.actions_ui().export_script_bundle().triggered().connect(slots.export_script_bundle());
+        self.actions_ui().import_script_bundle().triggered().connect(slots.import_script_bundle());
+        self.actions_ui().play_button().menu().about_to_hide().connect(slots.play_menu_about_to_hide());
+        self.actions_ui().play_button().menu().triggered().connect(slots.play_menu_triggered());
+        self.actions_ui().play_menu_reopen_timer().timeout().connect(slots.reopen_play_menu());
 
         self.game_selected_pharaoh_dynasties().triggered().connect(slots.change_game_selected());
         self.game_selected_pharaoh().triggered().connect(slots.change_game_selected());
@@ -582,7 +877,9 @@ impl AppUI {
         self.game_selected_shogun_2().triggered().connect(slots.change_game_selected());
         self.game_selected_napoleon().triggered().connect(slots.change_game_selected());
         self.game_selected_empire().triggered().connect(slots.change_game_selected());
+        self.game_selected_bar().custom_context_menu_requested().connect(slots.game_selected_context_menu());
 
+        self.global_search_button().released().connect(slots.global_search());
         self.about_runcher_button().released().connect(slots.about_runcher());
         self.check_updates_button().released().connect(slots.check_updates());
 
@@ -593,9 +890,15 @@ impl AppUI {
         self.mod_list_ui().model().item_changed().connect(slots.update_pack_list());
         self.mod_list_ui().upload_to_workshop().triggered().connect(slots.upload_to_workshop());
         self.mod_list_ui().download_from_workshop().triggered().connect(slots.download_from_workshop());
+        self.mod_list_ui().repair_from_workshop().triggered().connect(slots.repair_from_workshop());
+        self.mod_list_ui().redownload_broken_mods().triggered().connect(slots.redownload_broken_mods());
+        self.actions_ui().show_mod_updates().triggered().connect(slots.show_mod_updates());
         self.mod_list_ui().context_menu().about_to_show().connect(slots.mod_list_context_menu_open());
         self.mod_list_ui().enable_selected().triggered().connect(slots.enable_selected());
         self.mod_list_ui().disable_selected().triggered().connect(slots.disable_selected());
+        self.mod_list_ui().toggle_matching().triggered().connect(slots.toggle_matching());
+        self.mod_list_ui().enable_random_subset().triggered().connect(slots.enable_random_subset());
+        self.mod_list_ui().find_crashing_mod().triggered().connect(slots.find_crashing_mod());
         self.mod_list_ui().category_new().triggered().connect(slots.category_create());
         self.mod_list_ui().category_delete().triggered().connect(slots.category_delete());
         self.mod_list_ui().category_rename().triggered().connect(slots.category_rename());
@@ -604,14 +907,176 @@ impl AppUI {
 
         self.mod_list_ui().copy_to_secondary().triggered().connect(slots.copy_to_secondary());
         self.mod_list_ui().move_to_secondary().triggered().connect(slots.move_to_secondary());
+        self.mod_list_ui().move_to_data().triggered().connect(slots.move_to_data());
 
         self.pack_list_ui().automatic_order_button().toggled().connect(slots.pack_toggle_auto_sorting());
         draggable_tree_view_drop_signal(self.pack_list_ui().tree_view().static_upcast()).connect(slots.pack_move());
+        launcher_window_files_dropped_signal(self.main_window().static_upcast()).connect(slots.install_dropped_packs());
 
         self.data_list_ui().reload_button().released().connect(slots.data_view_reload());
         self.data_list_ui().tree_view().double_clicked().connect(slots.open_file_with_rpfm());
     }
 
+    /// This function records the launches that finished since the last check, and updates the status bar and play button with them.
+    ///
+    /// Once there's no launch left to follow, the timer calling this is stopped until the next launch.
+    pub unsafe fn check_launch_status(&self) {
+        record_finished_launches();
+        self.update_last_launch_status();
+        self.update_game_running_status();
+
+        if !is_launch_pending() {
+            self.last_launch_status_timer().stop();
+        }
+    }
+
+    /// This function shows the exit code of the last launch of the game selected in the status bar, if the game failed to start.
+    pub unsafe fn update_last_launch_status(&self) {
+        let game_key = self.game_selected().read().unwrap().key().to_owned();
+        let exit_code = setting_string(&format!("{LAST_LAUNCH_EXIT_CODE}{game_key}"));
+        if exit_code.is_empty() {
+            self.last_launch_status_label().set_visible(false);
+            return;
+        }
+
+        // Crash codes on windows are way more readable in hex.
+        let exit_code = match exit_code.parse::<i32>() {
+            Ok(code) if code < 0 => format!("{:#X}", code as u32),
+            _ => exit_code,
+        };
+
+        let text = format!("{} <a href=\"logs\">{}</a>", tre("last_launch_failed", &[&exit_code]), tr("last_launch_failed_logs"));
+        self.last_launch_status_label().set_text(&QString::from_std_str(text));
+        self.last_launch_status_label().set_visible(true);
+    }
+
+    /// This function disables the play button while the game selected is running, so we don't end up with two instances of it writing the same saves.
+    ///
+    /// The play button gets enabled again once the game exits, unless the game path is not valid. Only launches done from here are tracked,
+    /// as the game process is followed from a background thread. Games started from somewhere else are checked when pressing play.
+    pub unsafe fn update_game_running_status(&self) {
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+        let path_is_valid = game_path.is_dir() && !game_path.to_string_lossy().is_empty();
+        let running = is_game_launched(&game);
+
+        let tool_tip = if running { qtr("game_already_running") } else { qtr("launch_game") };
+        self.actions_ui().play_button().set_enabled(path_is_valid && !running);
+        self.actions_ui().play_button().set_tool_tip(&tool_tip);
+        self.tray_launch_game().set_enabled(!running);
+    }
+
+    /// This function returns the summary of the last merged pack generated for the game selected, formatted for a dialog.
+    pub unsafe fn merge_summary_message(&self) -> Result<String> {
+        let game = self.game_selected().read().unwrap().clone();
+        let summary = MergeSummary::load(&game)?.ok_or_else(|| anyhow!(tr("merge_summary_none")))?;
+
+        let date_format_str = format!("{} [hour]:[minute]:[second]", setting_string("date_format"));
+        let date = time::format_description::parse(&date_format_str).ok()
+            .and_then(|date_format| OffsetDateTime::from_unix_timestamp(*summary.date() as i64).ok()?.format(&date_format).ok())
+            .unwrap_or_default();
+
+        let current_build = game_build(&game, &setting_path(game.key()));
+        let outdated = if summary.is_outdated(current_build.as_deref()) {
+            tre("merge_summary_outdated", &[current_build.as_deref().unwrap_or_default()])
+        } else {
+            String::new()
+        };
+
+        let mods = summary.mods().iter().map(|mod_id| format!("<li>{}</li>", mod_id)).join("");
+        Ok(outdated + &tre("merge_summary", &[
+            &date,
+            &summary.path().to_string_lossy(),
+            &summary.game_build().clone().unwrap_or_else(|| tr("merge_summary_build_unknown")),
+            &summary.files().to_string(),
+            &format!("{:.2} MB", *summary.size() as f64 / 1024.0 / 1024.0),
+            &summary.conflicts().to_string(),
+            &summary.mods().len().to_string(),
+            &mods
+        ]))
+    }
+
+    /// This function makes a backup of the profiles and launch settings, if one is due.
+    ///
+    /// This runs in the background, so errors are only logged.
+    pub unsafe fn backup_profiles_if_due() {
+        match profile_backups::backup_if_due() {
+            Ok(Some(path)) => info!("Profiles backed up to {}.", path.to_string_lossy()),
+            Ok(None) => {},
+            Err(error) => error!("Error when backing up the profiles: {}", error),
+        }
+    }
+
+    /// This function updates the mod advisories if their source is remote and they haven't been updated in a day.
+    pub unsafe fn update_mod_advisories_if_due(&self) {
+        let source = setting_string(MOD_ADVISORIES_SOURCE);
+        let last_update = setting_string(MOD_ADVISORIES_LAST_UPDATE).parse::<u64>().unwrap_or_default();
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|date| date.as_secs()).unwrap_or_default();
+
+        if ModAdvisories::is_remote(&source) && now.saturating_sub(last_update) >= MOD_ADVISORIES_UPDATE_INTERVAL {
+            self.update_mod_advisories(&source);
+        }
+    }
+
+    /// This function downloads the mod advisories of the provided remote source, and flags the mods of the mod list with them.
+    ///
+    /// Advisories are only informative, so errors are logged instead of reported. The last downloaded ones are kept if it fails.
+    pub unsafe fn update_mod_advisories(&self, source: &str) {
+        let receiver = CENTRAL_COMMAND.send_network(Command::UpdateModAdvisories(source.to_owned()));
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        match response {
+            Response::Success => {
+                let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|date| date.as_secs()).unwrap_or_default();
+                set_setting_string(MOD_ADVISORIES_LAST_UPDATE, &now.to_string());
+                self.reload_mod_advisories();
+            }
+            Response::Error(error) => error!("Error when updating the mod advisories from {}: {}", source, error),
+            _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+        }
+    }
+
+    /// This function refreshes the flags of the mods of the mod list, so they reflect the current mod advisories.
+    pub unsafe fn reload_mod_advisories(&self) {
+        let game = self.game_selected().read().unwrap().clone();
+        if let Some(ref game_config) = *self.game_config().read().unwrap() {
+            if let Err(error) = self.mod_list_ui().update(&game, game_config.mods(), &[]) {
+                error!("Error when refreshing the mod list with the mod advisories: {}", error);
+            }
+        }
+    }
+
+    /// This function shows in the log view the logs written by the game selected since its last launch.
+    pub unsafe fn show_last_launch_logs(&self) {
+        let game_key = self.game_selected().read().unwrap().key().to_owned();
+        let start_date = setting_string(&format!("{LAST_LAUNCH_START_DATE}{game_key}")).parse::<u64>().unwrap_or_default();
+
+        self.log_ui().start(&setting_path(&game_key), UNIX_EPOCH + Duration::from_secs(start_date));
+        self.log_ui().update();
+        self.right_tabbar().set_current_widget(self.log_ui().main_widget());
+    }
+
+    /// This function brings back the main window after being minimized to the tray.
+    pub unsafe fn restore_from_tray(&self) {
+        self.main_window().show_normal();
+        self.main_window().activate_window();
+    }
+
+    /// This function shows or hides the main window from the tray, depending on its current state.
+    pub unsafe fn toggle_from_tray(&self) {
+        if self.main_window().is_visible() && !self.main_window().is_minimized() {
+            self.main_window().hide();
+        } else {
+            self.restore_from_tray();
+        }
+    }
+
+    /// This function returns if the tray activation reason is one that should show or hide the main window.
+    ///
+    /// Right clicks also trigger an activation, but they're for the tray menu.
+    pub fn is_tray_toggle(reason: ActivationReason) -> bool {
+        reason == ActivationReason::Trigger || reason == ActivationReason::DoubleClick
+    }
+
     /// Function to toggle the main window on and off, while keeping the stupid focus from breaking.
     pub unsafe fn toggle_main_window(&self, enable: bool) {
         if enable {
@@ -649,7 +1114,7 @@ impl AppUI {
         }
     }
 
-    pub unsafe fn change_game_selected(&self, reload_same_game: bool, skip_network_update: bool) -> Result<Option<Receiver<Response>>> {
+    pub unsafe fn change_game_selected(&self, reload_same_game: bool, skip_network_update: bool, load_default_profile: bool) -> Result<Option<Receiver<Response>>> {
 
         // Get the new `Game Selected` and clean his name up, so it ends up like "x_y".
         let mut new_game_selected = self.game_selected_group.checked_action().text().to_std_string();
@@ -659,15 +1124,52 @@ impl AppUI {
         // If the game changed or we're initializing the program, change the game selected.
         //
         // This works because by default, the initially stored game selected is arena, and that one can never set manually.
-        if reload_same_game || new_game_selected != self.game_selected().read().unwrap().key() {
+        let game_changed = new_game_selected != self.game_selected().read().unwrap().key();
+        if reload_same_game || game_changed {
+
+            // Do not allow reloads to stack. The mod list is reloaded in the background, so the event loop keeps running while we reload.
+            if *self.reloading().read().unwrap() {
+                return Ok(None);
+            }
+
+            *self.reloading().write().unwrap() = true;
+            self.actions_ui().reload_button().set_enabled(false);
             self.toggle_main_window(false);
 
+            self.reload_progress_bar().set_value(0);
+            self.reload_progress_bar().set_visible(true);
+
             let event_loop = qt_core::QEventLoop::new_0a();
             event_loop.process_events_0a();
 
             let result = self.load_data(&new_game_selected, skip_network_update);
 
+            // Watch the folders of the game we just loaded. Changes detected while reloading are already loaded, so drop them.
+            self.update_mod_folders_watcher();
+            self.mod_folders_watcher_timer().stop();
+
+            // Only load the default profile when switching games. Reloading the same game should keep the current mods.
+            if result.is_ok() && game_changed && load_default_profile {
+                if let Err(error) = self.load_default_profile() {
+                    show_dialog(self.main_window(), error, false);
+                }
+            }
+
+            // Keep track of how many times each game is used, so the most used ones can go first in the game bar.
+            if result.is_ok() && game_changed {
+                let setting_key = format!("game_usage_count_{new_game_selected}");
+                set_setting_int(&setting_key, setting_int(&setting_key).saturating_add(1));
+            }
+
+            self.reload_progress_bar().set_visible(false);
+            self.main_window().status_bar().clear_message();
+            self.update_last_launch_status();
+            self.update_game_running_status();
+
             self.toggle_main_window(true);
+            self.actions_ui().reload_button().set_enabled(true);
+            *self.reloading().write().unwrap() = false;
+
             result
         } else {
             Ok(None)
@@ -696,17 +1198,16 @@ impl AppUI {
                 let _ = Profile::update(&self.game_config().read().unwrap().clone().unwrap(), game);
 
                 // Load the profile's list.
+                self.update_reload_progress(0, "reload_progress_profiles");
                 match Profile::profiles_for_game(game) {
                     Ok(profiles) => *self.game_profiles().write().unwrap() = profiles,
                     Err(error) => show_dialog(self.main_window(), format!("Error loading profiles: {}", error), false),
                 }
 
-                self.actions_ui().profile_model().clear();
-                for profile in self.game_profiles().read().unwrap().keys().sorted() {
-                    self.actions_ui().profile_combobox().add_item_q_string(&QString::from_std_str(profile));
-                }
+                self.load_profiles_to_combobox();
 
                 // Load the saves list for the selected game.
+                self.update_reload_progress(1, "reload_progress_saves");
                 let game_path_str = setting_string(game.key());
                 let game_path = PathBuf::from(&game_path_str);
                 if let Err(error) = self.load_saves_to_ui(game, &game_path) {
@@ -714,10 +1215,12 @@ impl AppUI {
                 }
 
                 // Load the mods to the UI. This does an early return, just in case you add something after this.
+                self.update_reload_progress(2, "reload_progress_mods");
                 match self.load_mods_to_ui(game, &game_path, skip_network_update) {
                     Ok(network_receiver) => {
 
                         // Load the launch options for the game selected, as some of them may depend on mods we just loaded.
+                        self.update_reload_progress(3, "reload_progress_scripts");
                         let _ = setup_actions(self, game, self.game_config().read().unwrap().as_ref().unwrap(), &game_path, &self.game_load_order().read().unwrap());
 
                         return Ok(network_receiver)
@@ -731,6 +1234,77 @@ impl AppUI {
         }
     }
 
+    /// This function updates the folders and packs watched for changes, following the mod folders of the selected game.
+    ///
+    /// If the watcher is disabled in the settings, nothing is watched.
+    pub unsafe fn update_mod_folders_watcher(&self) {
+        let watcher = self.mod_folders_watcher();
+        let watched = watcher.files();
+        watched.append_q_string_list(&watcher.directories());
+        if !watched.is_empty() {
+            watcher.remove_paths(&watched);
+        }
+
+        if setting_int(MOD_FOLDERS_WATCHER) == MOD_FOLDERS_WATCHER_DISABLED {
+            return;
+        }
+
+        // Workshop mods are in a subfolder each, so that one needs to be read recursively.
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+        let folders = [(game.data_path(&game_path).ok(), false)].into_iter()
+            .chain(secondary_mods_paths(game.key()).into_iter().map(|path| (Some(path), false)))
+            .chain([(game.content_path(&game_path).ok(), true)])
+            .collect::<Vec<_>>();
+
+        let mut folders_watched = BTreeSet::new();
+        let mut packs_watched = BTreeSet::new();
+        for (folder, recursive) in folders {
+            if let Some(folder) = folder.filter(|folder| folder.is_dir()) {
+
+                // Folders only notify about packs being added or removed. To know when a pack is overwritten, we need to watch the pack itself.
+                for path in files_from_subdir(&folder, recursive).unwrap_or_default() {
+                    if path.extension().map_or(false, |extension| extension == "pack") && !Self::is_reserved_pack(&path) {
+                        if let Some(parent) = path.parent() {
+                            folders_watched.insert(parent.to_path_buf());
+                        }
+
+                        packs_watched.insert(path);
+                    }
+                }
+
+                folders_watched.insert(folder);
+            }
+        }
+
+        let paths = QStringList::new();
+        for path in folders_watched.iter().chain(packs_watched.iter()) {
+            paths.append_q_string(&QString::from_std_str(path.to_string_lossy()));
+        }
+
+        if !paths.is_empty() {
+            watcher.add_paths(&paths);
+        }
+    }
+
+    /// This function schedules a reload of the mod list after a change in one of the watched paths.
+    ///
+    /// The reload is delayed until there are no more changes for a bit, so writing big packs or many of them only triggers one reload.
+    pub unsafe fn mod_folders_changed(&self, path: &str) {
+
+        // The reserved pack is regenerated on each launch, and reloads already pick up their own changes.
+        if Self::is_reserved_pack(Path::new(path)) || *self.reloading().read().unwrap() {
+            return;
+        }
+
+        self.mod_folders_watcher_timer().start_0a();
+    }
+
+    /// This function returns if the provided path is one of the reserved packs Runcher generates on launch.
+    fn is_reserved_pack(path: &Path) -> bool {
+        path.file_name().map_or(false, |name| name == RESERVED_PACK_NAME || name == RESERVED_PACK_NAME_ALTERNATIVE)
+    }
+
     pub unsafe fn load_saves_to_ui(&self, game: &GameInfo, game_path: &Path) -> Result<()> {
         self.actions_ui().save_model().clear();
         let item = QStandardItem::from_q_string(&QString::from_std_str("No saves"));
@@ -808,200 +1382,685 @@ impl AppUI {
     }
 
     pub unsafe fn load_mods_to_ui(&self, game: &GameInfo, game_path: &Path, skip_network_update: bool) -> Result<Option<Receiver<Response>>> {
-        let mut mods = self.game_config().write().unwrap();
-        if let Some(ref mut mods) = *mods {
-            let mut load_order = self.game_load_order().write().unwrap();
-            let network_receiver = mods.update_mod_list(game, game_path, &mut load_order, skip_network_update)?;
+        let game_config = self.game_config().read().unwrap().clone();
+        if let Some(game_config) = game_config {
+            let load_order = self.game_load_order().read().unwrap().clone();
+
+            // Updating the mod list means reading every pack, which can take a while on big installs. Do it in the background to keep the UI responsive.
+            let receiver = CENTRAL_COMMAND.send_background(Command::UpdateModList(Box::new(game.clone()), game_path.to_path_buf(), game_config, load_order, skip_network_update));
+            let response = CENTRAL_COMMAND.recv_try(&receiver);
+            match response {
+                Response::GameConfigLoadOrderReceiver(game_config, load_order, network_receiver) => {
+                    self.mod_list_ui().load(game, &game_config)?;
+                    self.pack_list_ui().load(&game_config, game, game_path, &load_order)?;
 
-            self.mod_list_ui().load(game, mods)?;
-            self.pack_list_ui().load(mods, game, game_path, &load_order)?;
+                    *self.game_config().write().unwrap() = Some(game_config);
+                    *self.game_load_order().write().unwrap() = load_order;
 
-            Ok(network_receiver)
+                    Ok(network_receiver)
+                }
+                Response::Error(error) => Err(error),
+                _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+            }
         } else {
             Ok(None)
         }
     }
 
-    pub unsafe fn open_settings(&self) {
-        let game_key = self.game_selected().read().unwrap().key().to_owned();
-        let game_path_old = setting_path(&game_key);
-        let dark_theme_old = setting_bool("dark_mode");
-        let font_name_old = setting_string("font_name");
-        let font_size_old = setting_int("font_size");
-
-        match SettingsUI::new(self.main_window()) {
-            Ok(saved) => {
-                if saved {
-                    let game_path_new = setting_path(&game_key);
-
-                    // If we have changed the path of any of the games, and that game is the current `GameSelected`,
-                    // re-select the current `GameSelected` to force it to reload the game's files.
-                    if game_path_old != game_path_new {
-                        QAction::trigger(&self.game_selected_group.checked_action());
-                    }
-
-                    // Reload the tools, just in case they changed.
-                    *self.tools().write().unwrap() = Tools::load(&None).unwrap_or_else(|_| Tools::default());
-
-                    // Disable the games we don't have a path for (uninstalled).
-                    for game in SUPPORTED_GAMES.games_sorted().iter() {
-                        let has_exe = game.executable_path(&setting_path(game.key())).filter(|path| path.is_file()).is_some();
-                        match game.key() {
-                            KEY_PHARAOH_DYNASTIES => self.game_selected_pharaoh_dynasties().set_enabled(has_exe),
-                            KEY_PHARAOH => self.game_selected_pharaoh().set_enabled(has_exe),
-                            KEY_WARHAMMER_3 => self.game_selected_warhammer_3().set_enabled(has_exe),
-                            KEY_TROY => self.game_selected_troy().set_enabled(has_exe),
-                            KEY_THREE_KINGDOMS => self.game_selected_three_kingdoms().set_enabled(has_exe),
-                            KEY_WARHAMMER_2 => self.game_selected_warhammer_2().set_enabled(has_exe),
-                            KEY_WARHAMMER => self.game_selected_warhammer().set_enabled(has_exe),
-                            KEY_THRONES_OF_BRITANNIA => self.game_selected_thrones_of_britannia().set_enabled(has_exe),
-                            KEY_ATTILA => self.game_selected_attila().set_enabled(has_exe),
-                            KEY_ROME_2 => self.game_selected_rome_2().set_enabled(has_exe),
-                            KEY_SHOGUN_2 => self.game_selected_shogun_2().set_enabled(has_exe),
-                            KEY_NAPOLEON => self.game_selected_napoleon().set_enabled(has_exe),
-                            KEY_EMPIRE => self.game_selected_empire().set_enabled(has_exe),
-                            _ => {},
-                        }
-                    }
-
-                    // If we detect a change in theme, reload it.
-                    let dark_theme_new = setting_bool("dark_mode");
-                    if dark_theme_old != dark_theme_new {
-                        self.reload_theme();
-                    }
+    /// This function updates the reload progress bar with the provided step, and shows what we're reloading in the status bar.
+    unsafe fn update_reload_progress(&self, step: i32, message_key: &str) {
+        self.reload_progress_bar().set_value(step);
+        self.main_window().status_bar().show_message_1a(&qtr(message_key));
 
-                    // If we detect a change in the saved font, trigger a font change.
-                    let font_name = setting_string("font_name");
-                    let font_size = setting_int("font_size");
-                    if font_name_old != font_name || font_size_old != font_size {
-                        let font = QFont::from_q_string_int(&QString::from_std_str(&font_name), font_size);
-                        QApplication::set_font_1a(&font);
-                    }
+        let event_loop = qt_core::QEventLoop::new_0a();
+        event_loop.process_events_0a();
+    }
 
-                    // If we detect a factory reset, reset the window's geometry and state.
-                    let factory_reset = setting_bool("factoryReset");
-                    if factory_reset {
-                        self.main_window().restore_geometry(&setting_byte_array("originalGeometry"));
-                        self.main_window().restore_state_1a(&setting_byte_array("originalWindowState"));
-                    }
-                }
-            }
-            Err(error) => show_dialog(&self.main_window, error, false),
+    pub unsafe fn open_settings(app_ui: &Rc<Self>) {
+        if let Err(error) = SettingsUI::new(app_ui) {
+            show_dialog(app_ui.main_window(), error, false);
         }
 
         // Make sure we don't drag the factory reset setting, no matter if the user saved or not.
         set_setting_bool("factoryReset", false);
     }
 
-    pub unsafe fn launch_game(&self) -> Result<()> {
-        let mut folder_list = String::new();
-        let mut pack_list = String::new();
-        let game = self.game_selected().read().unwrap();
-        let game_path = setting_path(game.key());
-        let data_path = game.data_path(&game_path)?;
+    /// This function returns the current value of the settings that need parts of the UI reloaded when they change.
+    pub unsafe fn applied_settings(&self) -> AppliedSettings {
+        let game_key = self.game_selected().read().unwrap().key().to_owned();
+        AppliedSettings {
+            game_path: setting_path(&game_key),
+            secondary_mods_paths: secondary_mods_paths(&game_key),
+            game_key,
+            dark_theme: setting_bool("dark_mode"),
+            font_name: setting_string("font_name"),
+            font_size: setting_int("font_size"),
+            mod_advisories_source: setting_string(MOD_ADVISORIES_SOURCE),
+        }
+    }
 
-        // If we have "merge all mods" checked, we need to load the entire load order into a single pack, and load that pack instead of the entire load order.
-        //
-        // TODO: Review this before re-enabling merged mods. This pretty sure breaks on older games.
-        if self.actions_ui().merge_all_mods_checkbox().is_enabled() && self.actions_ui().merge_all_mods_checkbox().is_checked() {
-            let temp_path_file_name = format!("{}_{}.pack", MERGE_ALL_PACKS_PACK_NAME, self.game_selected().read().unwrap().key());
-            let temp_path = data_path.join(&temp_path_file_name);
-            pack_list.push_str(&format!("mod \"{}\";", temp_path_file_name));
+    /// This function applies the saved settings to the UI, reloading whatever changed since the provided applied settings.
+    ///
+    /// The applied settings are updated to the new values afterwards.
+    pub unsafe fn apply_settings(&self, applied: &mut AppliedSettings) {
+        let game_path_new = setting_path(&applied.game_key);
 
-            // Generate the merged pack.
-            let load_order = self.game_load_order().read().unwrap();
-            if let Some(ref game_config) = *self.game_config().read().unwrap() {
+        // If we have changed the path of any of the games, and that game is the current `GameSelected`,
+        // re-select the current `GameSelected` to force it to reload the game's files. Same if the secondary folders changed.
+        if applied.game_path != game_path_new || applied.secondary_mods_paths != secondary_mods_paths(&applied.game_key) {
+            QAction::trigger(&self.game_selected_group.checked_action());
+        }
 
-                let pack_paths = load_order.mods().iter()
-                    .filter_map(|mod_id| {
-                        let modd = game_config.mods().get(mod_id)?;
-                        std::fs::canonicalize(modd.paths().first()?).ok()
-                    })
-                .collect::<Vec<_>>();
+        // Reload the tools, just in case they changed.
+        *self.tools().write().unwrap() = Tools::load(&None).unwrap_or_else(|_| Tools::default());
 
-                if !pack_paths.is_empty() {
-                    let mut reserved_pack = Pack::read_and_merge(&pack_paths, true, false, true)?;
-                    let pack_version = game.pfh_version_by_file_type(PFHFileType::Mod);
-                    reserved_pack.set_pfh_version(pack_version);
+        // Same with the launch options hidden from the play menu.
+        self.actions_ui().update_launch_options_visibility();
+        self.actions_ui().update_launch_option_shortcuts();
 
-                    let mut encode_data = EncodeableExtraData::default();
-                    encode_data.set_nullify_dates(true);
+        // Disable the games we don't have a path for (uninstalled).
+        for game in SUPPORTED_GAMES.games_sorted().iter() {
+            let has_exe = game.executable_path(&setting_path(game.key())).filter(|path| path.is_file()).is_some();
+            match game.key() {
+                KEY_PHARAOH_DYNASTIES => self.game_selected_pharaoh_dynasties().set_enabled(has_exe),
+                KEY_PHARAOH => self.game_selected_pharaoh().set_enabled(has_exe),
+                KEY_WARHAMMER_3 => self.game_selected_warhammer_3().set_enabled(has_exe),
+                KEY_TROY => self.game_selected_troy().set_enabled(has_exe),
+                KEY_THREE_KINGDOMS => self.game_selected_three_kingdoms().set_enabled(has_exe),
+                KEY_WARHAMMER_2 => self.game_selected_warhammer_2().set_enabled(has_exe),
+                KEY_WARHAMMER => self.game_selected_warhammer().set_enabled(has_exe),
+                KEY_THRONES_OF_BRITANNIA => self.game_selected_thrones_of_britannia().set_enabled(has_exe),
+                KEY_ATTILA => self.game_selected_attila().set_enabled(has_exe),
+                KEY_ROME_2 => self.game_selected_rome_2().set_enabled(has_exe),
+                KEY_SHOGUN_2 => self.game_selected_shogun_2().set_enabled(has_exe),
+                KEY_NAPOLEON => self.game_selected_napoleon().set_enabled(has_exe),
+                KEY_EMPIRE => self.game_selected_empire().set_enabled(has_exe),
+                _ => {},
+            }
+        }
 
-                    reserved_pack.save(Some(&temp_path), &game, &Some(encode_data))?;
-                }
+        // The unit multiplier limit may have changed.
+        self.actions_ui().update_unit_multiplier_range();
+
+        // Same with the coloring of the enabled mods and the watcher of the mod folders.
+        mod_list_model_set_color_enabled_safe(&self.mod_list_ui().model(), setting_bool("mod_list_color_enabled"));
+        self.update_mod_folders_watcher();
+
+        // If we detect a change in theme, reload it.
+        let dark_theme_new = setting_bool("dark_mode");
+        if applied.dark_theme != dark_theme_new {
+            self.reload_theme();
+        }
+
+        // If we detect a change in the saved font, trigger a font change.
+        let font_name = setting_string("font_name");
+        let font_size = setting_int("font_size");
+        if applied.font_name != font_name || applied.font_size != font_size {
+            let font = QFont::from_q_string_int(&QString::from_std_str(&font_name), font_size);
+            QApplication::set_font_1a(&font);
+        }
+
+        // If the source of the mod advisories changed, get the new ones.
+        let mod_advisories_source = setting_string(MOD_ADVISORIES_SOURCE);
+        if applied.mod_advisories_source != mod_advisories_source {
+            if ModAdvisories::is_remote(&mod_advisories_source) {
+                self.update_mod_advisories(&mod_advisories_source);
             } else {
-                return Err(anyhow!(tr("game_config_error")));
+                self.reload_mod_advisories();
             }
         }
 
-        // Otherwise, just add the packs from the load order to the text file.
-        else if let Some(ref game_config) = *self.game_config().read().unwrap() {
-            let load_order = self.game_load_order().read().unwrap();
-            load_order.build_load_order_string(game_config, &game, &data_path, &mut pack_list, &mut folder_list);
+        // If we detect a factory reset, reset the window's geometry and state.
+        let factory_reset = setting_bool("factoryReset");
+        if factory_reset {
+            self.main_window().restore_geometry(&setting_byte_array("originalGeometry"));
+            self.main_window().restore_state_1a(&setting_byte_array("originalWindowState"));
+            set_setting_bool("factoryReset", false);
         }
 
-        // If our folder list contains the secondary folder, we need to make sure we create the masks folder in it,
-        // and mask in there all non-enabled movie files. Note that we only use this in games older than warhammer. Newer games use the exclude_pack_file command.
-        if *game.raw_db_version() <= 1 || (*game.raw_db_version() == 2 && (game.key() == KEY_ROME_2 || game.key() == KEY_ATTILA || game.key() == KEY_THRONES_OF_BRITANNIA)) {
-            let secondary_mods_path = secondary_mods_path(game.key()).unwrap_or_else(|_| PathBuf::new());
-            let secondary_mods_path_str = path_to_absolute_string(&secondary_mods_path);
+        *applied = self.applied_settings();
+    }
 
-            if secondary_mods_path.is_dir() && folder_list.contains(&secondary_mods_path_str) {
-                let masks_path = secondary_mods_path.join(SECONDARY_FOLDER_NAME);
+    pub unsafe fn launch_game(&self) -> Result<()> {
+        let game = self.game_selected().read().unwrap().clone();
+        if is_game_running(&game, &setting_path(game.key())) {
+            return Err(anyhow!(tr("game_already_running")));
+        }
 
-                // Remove all files in it so previous maskings do not interfere.
-                if masks_path.is_dir() {
-                    std::fs::remove_dir_all(&masks_path)?;
-                }
+        let result = self.prepare_launch(true, true).map(|_| ());
+        self.update_game_running_status();
+        result
+    }
 
-                DirBuilder::new().recursive(true).create(&masks_path)?;
+    /// This function launches the game into the provided save of the game selected, with the current mods and launch options.
+    ///
+    /// The save gets selected in the save selector, so it's passed to the game through its startup arguments. Games that cannot
+    /// load a save on startup are launched normally, with the save left selected.
+    pub unsafe fn launch_game_with_save(&self, save_index: usize) -> Result<()> {
+        let game = self.game_selected().read().unwrap().clone();
+        if self.game_saves().read().unwrap().get(save_index).is_none() {
+            return Err(anyhow!(tr("launch_with_save_missing")));
+        }
 
-                let mut mask_pack = Pack::new_with_version(game.pfh_version_by_file_type(PFHFileType::Movie));
-                mask_pack.set_pfh_file_type(PFHFileType::Movie);
+        // The first item of the save selector is the "No saves" one.
+        self.actions_ui().save_combobox().set_current_index(save_index as i32 + 1);
 
-                if let Some(ref game_config) = *self.game_config().read().unwrap() {
-                    for path in std::fs::read_dir(secondary_mods_path)? {
-                        let file_name = path?.file_name().to_string_lossy().to_string();
+        // Empire and Napoleon get launched through their user script, without the startup arguments.
+        if *game.raw_db_version() < 1 {
+            info!("{} cannot load a save on startup. Launching it normally.", game.key());
+        }
 
-                        if let Some(modd) = game_config.mods().get(&file_name) {
-                            if modd.pack_type() == &PFHFileType::Movie && !modd.enabled(&game, &data_path) {
-                                mask_pack.save(Some(&masks_path.join(file_name)), &game, &None)?;
-                            }
-                        }
-                    }
-                }
+        self.launch_game()
+    }
+
+    /// This function opens a dialog to back up or delete several saves of the game selected at once.
+    ///
+    /// The dialog stays open after each action, so the list can be cleaned in more than one go.
+    /// Double-clicking a save closes the dialog and launches the game into it.
+    pub unsafe fn manage_saves(&self) -> Result<()> {
+        let template_path = if cfg!(debug_assertions) { SAVE_MANAGER_VIEW_DEBUG } else { SAVE_MANAGER_VIEW_RELEASE };
+        let main_widget = load_template(self.main_window(), template_path)?;
+        let dialog = main_widget.static_downcast::<QDialog>();
+
+        let info_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "info_label")?;
+        let saves_list_view: QPtr<QListView> = find_widget(&main_widget.static_upcast(), "saves_list_view")?;
+        let selected_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "selected_label")?;
+        let button_box: QPtr<QDialogButtonBox> = find_widget(&main_widget.static_upcast(), "button_box")?;
+
+        dialog.set_window_title(&qtr("save_manager"));
+        info_label.set_text(&qtr("save_manager_info"));
+
+        let saves_model = QStandardItemModel::new_1a(&saves_list_view);
+        saves_list_view.set_model(&saves_model);
+
+        let backup_button = button_box.add_button_q_string_button_role(&qtr("save_manager_backup"), ButtonRole::ActionRole);
+        let delete_button = button_box.add_button_q_string_button_role(&qtr("save_manager_delete"), ButtonRole::ActionRole);
+        backup_button.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("document-save-all")));
+        delete_button.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("edit-delete")));
+
+        let update_selection = Rc::new(clone!(saves_list_view, selected_label, backup_button, delete_button => move || {
+            let selected = saves_list_view.selection_model().selection().indexes().count_0a();
+            selected_label.set_text(&qtre("save_manager_selected", &[&selected.to_string(), &saves_list_view.model().row_count_0a().to_string()]));
+            backup_button.set_enabled(selected > 0);
+            delete_button.set_enabled(selected > 0);
+        }));
+
+        let update_selection_slot = SlotNoArgs::new(&dialog, clone!(update_selection => move || update_selection()));
+        saves_list_view.selection_model().selection_changed().connect(&update_selection_slot);
+
+        let backup_slot = SlotNoArgs::new(&dialog, clone!(dialog => move || dialog.done(SAVE_MANAGER_BACKUP)));
+        let delete_slot = SlotNoArgs::new(&dialog, clone!(dialog => move || dialog.done(SAVE_MANAGER_DELETE)));
+        backup_button.released().connect(&backup_slot);
+        delete_button.released().connect(&delete_slot);
+
+        let launch_slot = SlotOfQModelIndex::new(&dialog, clone!(dialog => move |_| dialog.done(SAVE_MANAGER_LAUNCH)));
+        saves_list_view.double_clicked().connect(&launch_slot);
+
+        let load_saves = || {
+            saves_model.clear();
+            for save in self.game_saves().read().unwrap().iter() {
+                let item = QStandardItem::from_q_string(&QString::from_std_str(save.name()));
+                item.set_tool_tip(&QString::from_std_str(save.path().to_string_lossy()));
+                item.set_editable(false);
+                saves_model.append_row_q_standard_item(item.into_ptr());
             }
-        }
 
-        // Check if we are loading a save. First option is no save load. Any index above that is a save.
-        let mut extra_args = vec![];
-        let save_index = self.actions_ui.save_combobox().current_index();
-        if self.actions_ui.save_combobox().current_index() > 0 {
-            if let Some(save) = self.game_saves.read().unwrap().get(save_index as usize - 1) {
-                extra_args.push("game_startup_mode".to_owned());
-                extra_args.push("campaign_load".to_owned());
-                extra_args.push(save.name().to_owned());
+            update_selection();
+        };
+
+        load_saves();
+        if saves_model.row_count_0a() == 0 {
+            return Err(anyhow!(tr("save_manager_no_saves")));
+        }
+
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+
+        dialog.set_modal(true);
+        loop {
+            let action = dialog.exec();
+            if action == SAVE_MANAGER_LAUNCH {
+                let save_index = usize::try_from(saves_list_view.current_index().row()).map_err(|_| anyhow!(tr("launch_with_save_missing")))?;
+                return self.launch_game_with_save(save_index);
+            }
+
+            if action != SAVE_MANAGER_BACKUP && action != SAVE_MANAGER_DELETE {
+                break;
+            }
+
+            let indexes = saves_list_view.selection_model().selection().indexes();
+            let selected_saves = {
+                let game_saves = self.game_saves().read().unwrap();
+                (0..indexes.count_0a())
+                    .filter_map(|index| usize::try_from(indexes.at(index).row()).ok())
+                    .sorted()
+                    .filter_map(|row| game_saves.get(row).cloned())
+                    .collect::<Vec<_>>()
+            };
+
+            if selected_saves.is_empty() {
+                continue;
+            }
+
+            if action == SAVE_MANAGER_BACKUP {
+                match saves::backup(&game, &selected_saves) {
+                    Ok(path) => show_dialog(&dialog, tre("save_manager_backed_up", &[&selected_saves.len().to_string(), &path.to_string_lossy()]), true),
+                    Err(error) => show_dialog(&dialog, error, false),
+                }
+            } else if self.are_you_sure_text(&tre("save_manager_delete_confirm", &[&selected_saves.len().to_string()])) {
+                let result = saves::delete(&selected_saves);
+
+                // Reload the saves even if some of them failed to be deleted, as the rest are gone.
+                self.load_saves_to_ui(&game, &game_path)?;
+                load_saves();
+
+                if let Err(error) = result {
+                    show_dialog(&dialog, error, false);
+                }
+
+                if saves_model.row_count_0a() == 0 {
+                    break;
+                }
             }
         }
 
-        // NOTE: On Empire and Napoleon we need to use the user_script, not the custom file, as it doesn't seem to work.
-        // Older versions of shogun 2 also used the user_script, but the latest update enabled use of custom mod lists.
-        let file_path = if *game.raw_db_version() >= 1 {
-            game_path.join(CUSTOM_MOD_LIST_FILE_NAME)
-        } else {
+        Ok(())
+    }
 
-            // Games may fail to launch if we don't have this path created, which is done the first time we start the game.
-            let config_path = game.config_path(&game_path).ok_or(anyhow!("Error getting the game's config path."))?;
-            let scripts_path = config_path.join("scripts");
-            DirBuilder::new().recursive(true).create(&scripts_path)?;
+    /// This function runs the enabled scripts and the merge step, like when launching the game, but without launching it.
+    ///
+    /// Returns false if the user cancelled it.
+    pub unsafe fn reapply_scripts(&self) -> Result<bool> {
+        self.prepare_launch(false, true)
+    }
 
-            // Empire has its own user script.
-            if game.key() == KEY_EMPIRE {
-                scripts_path.join(USER_SCRIPT_EMPIRE_FILE_NAME)
+    /// This function regenerates the translation selected in the launch options, without launching the game.
+    ///
+    /// The translation is merged into the same generated pack as the rest of the launch options, so that pack gets fully regenerated.
+    /// Returns the amount of loc files in the regenerated pack, or None if the user cancelled it.
+    pub unsafe fn rebuild_translations(&self) -> Result<Option<usize>> {
+        let translations_combobox = self.actions_ui().enable_translations_combobox();
+        if !translations_combobox.is_enabled() || translations_combobox.current_index() == 0 {
+            return Err(anyhow!(tr("rebuild_translations_no_language")));
+        }
+
+        if !self.prepare_launch(false, true)? {
+            return Ok(None);
+        }
+
+        let game = self.game_selected().read().unwrap().clone();
+        let data_path = game.data_path(&setting_path(game.key()))?;
+        let pack_path = reserved_pack_path(&game, &data_path)?;
+        let pack = Pack::read_and_merge(&[pack_path], true, false, false)?;
+
+        Ok(Some(pack.files_by_type(&[FileType::Loc]).len()))
+    }
+
+    /// This function runs all the checks done before launching the game, and generates the files needed to launch it, without launching it.
+    ///
+    /// Nothing is asked to the user. Returns each check with the problems it found. Checks without problems passed.
+    pub unsafe fn validate_launch(&self) -> Result<Vec<(String, Vec<String>)>> {
+        let mut report = vec![];
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+        let game_data_path = game.data_path(&game_path)?;
+
+        let mut problems = vec![];
+        match game_executable_path(&game, &game_path) {
+            Some(path) if path.is_file() => {},
+            Some(path) => problems.push(tre("validate_launch_executable_missing", &[&path.to_string_lossy()])),
+            None => problems.push(tr("validate_launch_executable_missing_path")),
+        }
+        report.push((tr("validate_launch_game"), problems));
+
+        let mut problems = vec![];
+        if let Some(ref game_config) = *self.game_config().read().unwrap() {
+            for (pack_name, copies) in game_config.duplicated_packs(&game, &game_data_path) {
+                let copies = copies.iter().map(|(mod_id, path)| format!("{} ({})", mod_id, path_to_absolute_string(path))).join(", ");
+                problems.push(format!("<b>{}</b>: {}", pack_name, copies));
+            }
+        }
+        report.push((tr("validate_launch_duplicated_packs"), problems));
+
+        let (disabled, missing) = self.missing_dependencies()?;
+        let problems = missing.iter()
+            .map(|(dependency, required_by)| tre("validate_launch_dependency_missing", &[dependency, &required_by.iter().sorted().join(", ")]))
+            .chain(disabled.iter().map(|(dependency, required_by)| tre("validate_launch_dependency_disabled", &[dependency, &required_by.iter().sorted().join(", ")])))
+            .collect::<Vec<_>>();
+        report.push((tr("validate_launch_dependencies"), problems));
+
+        let problems = self.actions_ui().scripts_to_execute().read().unwrap()
+            .iter()
+            .filter(|(_, item)| item.is_checked())
+            .filter_map(|(script, item)| sql_script_arg(&game, script, item.as_ptr())
+                .err()
+                .map(|error| format!("<b>{}</b>: {}", script.metadata().name().trim(), error)))
+            .collect::<Vec<_>>();
+        report.push((tr("validate_launch_scripts"), problems));
+
+        // If the executable is missing, that's already reported in the game check.
+        let mut problems = vec![];
+        if let Some(executable_path) = game_executable_path(&game, &game_path).filter(|path| path.is_file()) {
+            let flags = self.launch_flags();
+            let check = LaunchFlagsCheck::check(&game, &executable_path, &flags.iter().map(|(flag, _)| flag.to_owned()).collect::<Vec<_>>())?;
+            problems = flags.iter()
+                .filter(|(flag, _)| check.supported(flag) == Some(false))
+                .map(|(flag, source)| tre("validate_launch_launch_flag_unsupported", &[flag, source]))
+                .collect();
+        }
+        report.push((tr("validate_launch_launch_flags"), problems));
+
+        // The generation runs the merge and the scripts for real, so it's only done if there's nothing that'd make it fail or touch the game files.
+        let mut problems = vec![];
+        let destructive_scripts = self.actions_ui().destructive_scripts_enabled();
+        if report.iter().any(|(_, problems)| !problems.is_empty()) {
+            problems.push(tr("validate_launch_generation_skipped_errors"));
+        } else if !destructive_scripts.is_empty() {
+            let names = destructive_scripts.iter().map(|(name, _)| name).join(", ");
+            problems.push(tre("validate_launch_generation_skipped_destructive", &[&names]));
+        } else if let Err(error) = self.prepare_launch(false, false) {
+            problems.push(error.to_string());
+        }
+        report.push((tr("validate_launch_generation"), problems));
+
+        Ok(report)
+    }
+
+    /// This function returns the flags that'd be passed to the game selected on launch, with where each one comes from.
+    ///
+    /// Extra arguments not starting with a dash or a slash are skipped, as they may be values of other flags.
+    pub unsafe fn launch_flags(&self) -> Vec<(String, String)> {
+        let game = self.game_selected().read().unwrap().clone();
+        let mut flags = CustomLaunchOptions::load(&game, true)
+            .map(|options| options.options().iter().map(|option| (option.flag().to_owned(), option.name().to_owned())).collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        let (extra_args, _) = self.launch_extra_args();
+        flags.extend(extra_args.into_iter()
+            .filter(|arg| arg.starts_with(['-', '/']))
+            .map(|arg| (arg, tr("launch_extra_args"))));
+
+        flags
+    }
+
+    /// This function checks if the executable of the game selected accepts its launch flags, and returns a report formatted for a dialog,
+    /// and if all of them passed.
+    pub unsafe fn test_launch_options(&self) -> Result<(String, bool)> {
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+        let executable_path = game_executable_path(&game, &game_path)
+            .filter(|path| path.is_file())
+            .ok_or_else(|| anyhow!(tr("validate_launch_executable_missing_path")))?;
+
+        let flags = self.launch_flags();
+        if flags.is_empty() {
+            return Ok((tr("test_launch_options_none"), true));
+        }
+
+        let check = LaunchFlagsCheck::check(&game, &executable_path, &flags.iter().map(|(flag, _)| flag.to_owned()).collect::<Vec<_>>())?;
+        let passed = flags.iter().all(|(flag, _)| check.supported(flag) != Some(false));
+        let results = flags.iter()
+            .map(|(flag, source)| if check.supported(flag) == Some(false) {
+                format!("<li><code>{}</code> ({}): <b>{}</b></li>", flag, source, tr("test_launch_options_unsupported"))
             } else {
-                scripts_path.join(USER_SCRIPT_FILE_NAME)
+                format!("<li><code>{}</code> ({}): {}</li>", flag, source, tr("test_launch_options_supported"))
+            })
+            .join("");
+
+        let summary = if passed { tr("test_launch_options_passed") } else { tr("test_launch_options_failed") };
+        Ok((format!("<p>{}</p><ul>{}</ul><p>{}</p>", summary, results, tr("test_launch_options_info")), passed))
+    }
+
+    /// This function returns a summary of everything that changes the game selected from vanilla, formatted for a dialog.
+    ///
+    /// It only reports what's configured. Nothing is generated, so the merge data is the one of the last merge.
+    pub unsafe fn modification_summary(&self) -> Result<String> {
+        let game = self.game_selected().read().unwrap().clone();
+        let actions_ui = self.actions_ui();
+        let mut sections = vec![];
+
+        // Mods, by category.
+        let mut mods = vec![];
+        if let Some(ref game_config) = *self.game_config().read().unwrap() {
+            let load_order = self.game_load_order().read().unwrap();
+            if !load_order.mods().is_empty() || !load_order.movies().is_empty() {
+                mods.push(tre("modification_summary_mods_enabled", &[&load_order.mods().len().to_string(), &game_config.mods().len().to_string(), &load_order.movies().len().to_string()]));
+
+                let mods_by_category = load_order.mods().iter().counts_by(|mod_id| game_config.category_for_mod(mod_id));
+                for category in game_config.categories_order() {
+                    if let Some(count) = mods_by_category.get(category) {
+                        mods.push(format!("{}: {}", category, count));
+                    }
+                }
+            }
+        }
+        sections.push((tr("modification_summary_mods"), mods));
+
+        // Merged changes. The counts are from the last merge, as merging again is too slow for a summary.
+        let mut merge = vec![];
+        if actions_ui.merge_all_mods_checkbox().is_enabled() && actions_ui.merge_all_mods_checkbox().is_checked() {
+            match MergeSummary::load(&game)? {
+                Some(summary) => merge.push(tre("modification_summary_merge_last", &[&summary.mods().len().to_string(), &summary.files().to_string(), &summary.conflicts().to_string()])),
+                None => merge.push(tr("modification_summary_merge_pending")),
+            }
+        }
+        sections.push((tr("modification_summary_merge"), merge));
+
+        // Launch options changed from their defaults.
+        let mut launch_options = [
+            ("enable_logging", actions_ui.enable_logging_checkbox()),
+            ("enable_skip_intro", actions_ui.enable_skip_intro_checkbox()),
+            ("remove_trait_limit", actions_ui.remove_trait_limit_checkbox()),
+            ("remove_siege_attacker", actions_ui.remove_siege_attacker_checkbox()),
+            ("enable_dev_only_ui", actions_ui.enable_dev_only_ui_checkbox()),
+        ].iter()
+            .filter(|(_, checkbox)| checkbox.is_enabled() && checkbox.is_checked())
+            .map(|(key, _)| tr(key))
+            .collect::<Vec<_>>();
+
+        let translations = actions_ui.enable_translations_combobox();
+        if translations.is_enabled() && translations.current_index() > 0 {
+            launch_options.push(format!("{}: {}", tr("enable_translations"), actions_ui.selected_translation()));
+        }
+
+        let custom_args = actions_ui.custom_launch_options_args();
+        if !custom_args.is_empty() {
+            launch_options.push(format!("{}: {}", tr("custom_launch_options_manager"), custom_args.join(" ")));
+        }
+
+        let (extra_args, _) = self.launch_extra_args();
+        if !extra_args.is_empty() {
+            launch_options.push(format!("{}: {}", tr("launch_extra_args"), extra_args.join(" ")));
+        }
+        sections.push((tr("modification_summary_launch_options"), launch_options));
+
+        // Scripts.
+        let scripts = actions_ui.scripts_to_execute().read().unwrap().iter()
+            .filter(|(_, item)| item.is_checked())
+            .map(|(script, _)| script.metadata().name().trim().to_owned())
+            .sorted()
+            .collect::<Vec<_>>();
+        sections.push((tr("modification_summary_scripts"), scripts));
+
+        // Rebalancing.
+        let mut rebalancing = vec![];
+        let rebalancer = actions_ui.universal_rebalancer_combobox();
+        if rebalancer.is_enabled() && rebalancer.current_index() > 0 {
+            rebalancing.push(tre("modification_summary_rebalancer", &[&rebalancer.current_text().to_std_string()]));
+        }
+
+        let unit_multiplier = actions_ui.unit_multiplier_spinbox();
+        if unit_multiplier.is_enabled() && unit_multiplier.value() != 1.00 {
+            rebalancing.push(format!("{}: {}", tr("unit_multiplier"), unit_multiplier.value()));
+        }
+        sections.push((tr("modification_summary_rebalancing"), rebalancing));
+
+        let vanilla = sections.iter().all(|(_, items)| items.is_empty());
+        let sections = sections.iter()
+            .map(|(title, items)| if items.is_empty() {
+                format!("<li><b>{}</b>: {}</li>", title, tr("modification_summary_vanilla"))
+            } else {
+                format!("<li><b>{}</b>:<ul>{}</ul></li>", title, items.iter().map(|item| format!("<li>{}</li>", item)).join(""))
+            })
+            .join("");
+
+        let info = if vanilla { tr("modification_summary_info_vanilla") } else { tr("modification_summary_info") };
+        Ok(format!("<p>{}</p><ul>{}</ul>", info, sections))
+    }
+
+    /// This function prepares the load order, launch options and scripts for the selected game, and launches it if `launch` is true.
+    ///
+    /// If `interactive_checks` is false, the checks that ask the user what to do with duplicated packs and missing dependencies are skipped.
+    /// Returns false if the user cancelled it.
+    unsafe fn prepare_launch(&self, launch: bool, interactive_checks: bool) -> Result<bool> {
+
+        // Before anything else, make sure we're not loading the same pack twice from different mods, and that we're not missing any dependency.
+        if interactive_checks && (!self.check_duplicated_packs()? || !self.check_missing_dependencies()?) {
+            return Ok(false);
+        }
+
+        let mut folder_list = String::new();
+        let mut pack_list = String::new();
+        let game = self.game_selected().read().unwrap();
+        let game_path = setting_path(game.key());
+        let data_path = game.data_path(&game_path)?;
+
+        // Destructive scripts need confirmation, and a backup of the files they modify before they run.
+        let destructive_scripts = self.actions_ui().destructive_scripts_enabled();
+        if !destructive_scripts.is_empty() {
+            let names = destructive_scripts.iter().map(|(name, _)| name).join(", ");
+            if !self.are_you_sure_text(&tre("destructive_scripts_confirm", &[&names])) {
+                return Ok(false);
+            }
+
+            let affected_files = destructive_scripts.into_iter()
+                .flat_map(|(_, affected_files)| affected_files)
+                .unique()
+                .collect::<Vec<_>>();
+
+            if let Some(path) = backup_script_files(&game, &game_path, &affected_files)? {
+                info!("Files affected by destructive scripts backed up to {}.", path.to_string_lossy());
+            }
+        }
+
+        // If we have "merge all mods" checked, we need to load the entire load order into a single pack, and load that pack instead of the entire load order.
+        // If the merge is limited to some categories, only the mods in those categories are merged, and the rest are loaded as usual after the merged pack.
+        //
+        // TODO: Review this before re-enabling merged mods. This pretty sure breaks on older games.
+        if self.actions_ui().merge_all_mods_checkbox().is_enabled() && self.actions_ui().merge_all_mods_checkbox().is_checked() {
+            let temp_path_file_name = format!("{}_{}.pack", MERGE_ALL_PACKS_PACK_NAME, self.game_selected().read().unwrap().key());
+            let temp_path = match merged_packs_folder(&game)? {
+                Some(merged_packs_folder) => {
+                    folder_list.push_str(&format!("add_working_directory \"{}\";\n", merged_packs_folder.to_string_lossy()));
+                    merged_packs_folder.join(&temp_path_file_name)
+                }
+                None => data_path.join(&temp_path_file_name),
+            };
+            pack_list.push_str(&format!("mod \"{}\";", temp_path_file_name));
+
+            // If the game got patched since the last merge, warn the user before merging again, as mods made for the old build can still break the new one.
+            if interactive_checks {
+                if let Some(summary) = MergeSummary::load(&game)? {
+                    let current_build = game_build(&game, &game_path);
+                    if summary.is_outdated(current_build.as_deref()) {
+                        let message = tre("merge_outdated_confirm", &[
+                            summary.game_build().as_deref().unwrap_or_default(),
+                            current_build.as_deref().unwrap_or_default(),
+                        ]);
+
+                        if !self.are_you_sure_text(&message) {
+                            return Ok(false);
+                        }
+                    }
+                }
             }
-        };
+
+            // Generate the merged pack.
+            let load_order = self.game_load_order().read().unwrap();
+            if let Some(ref game_config) = *self.game_config().read().unwrap() {
+                let merge_categories = ActionsUI::merge_categories(game.key());
+                let is_merged = |mod_id: &String| merge_categories.is_empty() || merge_categories.contains(&game_config.category_for_mod(mod_id));
+
+                let (merged_mods, pack_paths): (Vec<_>, Vec<_>) = load_order.mods().iter()
+                    .filter(|mod_id| is_merged(*mod_id))
+                    .filter_map(|mod_id| {
+                        let modd = game_config.mods().get(mod_id)?;
+                        Some((mod_id.to_owned(), std::fs::canonicalize(modd.paths().first()?).ok()?))
+                    })
+                .unzip();
+
+                if !pack_paths.is_empty() {
+                    let mut reserved_pack = Pack::read_and_merge(&pack_paths, true, false, true)?;
+                    let pack_version = game.pfh_version_by_file_type(PFHFileType::Mod);
+                    reserved_pack.set_pfh_version(pack_version);
+
+                    let mut encode_data = EncodeableExtraData::default();
+                    encode_data.set_nullify_dates(true);
+
+                    reserved_pack.save(Some(&temp_path), &game, &Some(encode_data))?;
+
+                    // The summary is only informative, so failing to generate it doesn't stop the launch.
+                    match MergeSummary::new(&game, &temp_path, &merged_mods, &pack_paths, &reserved_pack).and_then(|summary| summary.save(&game).map(|_| summary)) {
+                        Ok(summary) => self.main_window().status_bar().show_message_2a(&qtre("merge_summary_status", &[
+                            &summary.mods().len().to_string(),
+                            &summary.files().to_string(),
+                            &summary.conflicts().to_string()
+                        ]), 10000),
+                        Err(error) => error!("Error when generating the summary of the merged pack: {}", error),
+                    }
+                }
+
+                if !merge_categories.is_empty() {
+                    let mut load_order_not_merged = load_order.clone();
+                    load_order_not_merged.mods_mut().retain(|mod_id| !is_merged(mod_id));
+                    load_order_not_merged.build_load_order_string(game_config, &game, &data_path, &mut pack_list, &mut folder_list);
+                }
+            } else {
+                return Err(anyhow!(tr("game_config_error")));
+            }
+        }
+
+        // Otherwise, just add the packs from the load order to the text file.
+        else if let Some(ref game_config) = *self.game_config().read().unwrap() {
+            let load_order = self.game_load_order().read().unwrap();
+            load_order.build_load_order_string(game_config, &game, &data_path, &mut pack_list, &mut folder_list);
+        }
+
+        // If our folder list contains the secondary folder, we need to make sure we create the masks folder in it,
+        // and mask in there all non-enabled movie files. Note that we only use this in games older than warhammer. Newer games use the exclude_pack_file command.
+        if *game.raw_db_version() <= 1 || (*game.raw_db_version() == 2 && (game.key() == KEY_ROME_2 || game.key() == KEY_ATTILA || game.key() == KEY_THRONES_OF_BRITANNIA)) {
+            let secondary_mods_path = secondary_mods_path(game.key()).unwrap_or_else(|_| PathBuf::new());
+            let secondary_mods_path_str = path_to_absolute_string(&secondary_mods_path);
+
+            if secondary_mods_path.is_dir() && folder_list.contains(&secondary_mods_path_str) {
+                let masks_path = secondary_mods_path.join(SECONDARY_FOLDER_NAME);
+
+                // Remove all files in it so previous maskings do not interfere.
+                if masks_path.is_dir() {
+                    std::fs::remove_dir_all(&masks_path)?;
+                }
+
+                DirBuilder::new().recursive(true).create(&masks_path)?;
+
+                let mut mask_pack = Pack::new_with_version(game.pfh_version_by_file_type(PFHFileType::Movie));
+                mask_pack.set_pfh_file_type(PFHFileType::Movie);
+
+                if let Some(ref game_config) = *self.game_config().read().unwrap() {
+                    for path in std::fs::read_dir(secondary_mods_path)? {
+                        let file_name = path?.file_name().to_string_lossy().to_string();
+
+                        if let Some(modd) = game_config.mods().get(&file_name) {
+                            if modd.pack_type() == &PFHFileType::Movie && !modd.enabled(&game, &data_path) {
+                                mask_pack.save(Some(&masks_path.join(file_name)), &game, &None)?;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let extra_args = self.launch_args();
+        let file_path = Self::load_order_file_path(&game, &game_path)?;
 
         // Setup the launch options stuff. This may add a line to the folder list, so we need to resave the load order file after this.
         let folder_list_pre = folder_list.to_owned();
@@ -1012,24 +2071,45 @@ impl AppUI {
             Self::save_load_order_file(&file_path, &game, &folder_list, &pack_list)?;
         }
 
+        // The translation is generated with the rest of the launch options, so it can only be checked after them.
+        if interactive_checks && setting_bool(CHECK_TRANSLATION_FONT_COVERAGE) && !self.check_translation_font_coverage(&game, &game_path, &data_path)? {
+            return Ok(false);
+        }
+
+        if !launch {
+            return Ok(true);
+        }
+
         // Launch is done through workshopper to getup the Steam Api.
         //
         // Here we just build the commands and pass them to workshopper.
-        match game.executable_path(&game_path) {
+        match game_executable_path(&game, &game_path) {
             Some(exec_game) => {
                 if cfg!(target_os = "windows") {
 
+                    // If the user wants to launch the game through a wrapper, the wrapper is what gets started, with the game as its argument.
+                    // Start takes the first quoted argument as the window title, so we need to pass an empty one before the wrapper.
+                    let wrapper = launch_wrapper();
+                    let start = if wrapper.is_empty() {
+                        format!("cmd /C start /W /d \"{}\"", game_path.to_string_lossy().replace('\\', "/"))
+                    } else {
+                        format!("cmd /C start \"\" /W /d \"{}\" {}",
+                            game_path.to_string_lossy().replace('\\', "/"),
+                            wrapper.iter().map(|arg| quote_command_arg(arg)).join(" ")
+                        )
+                    };
+
                     // For post-shogun 2 games, we use the same command to bypass the launcher.
                     let command = if *game.raw_db_version() >= 1 {
-                        let mut command = format!("cmd /C start /W /d \"{}\" \"{}\" {};",
-                            game_path.to_string_lossy().replace('\\', "/"),
+                        let mut command = format!("{} \"{}\" {};",
+                            start,
                             exec_game.file_name().unwrap().to_string_lossy(),
                             CUSTOM_MOD_LIST_FILE_NAME
                         );
 
                         for arg in &extra_args {
                             command.push(' ');
-                            command.push_str(arg);
+                            command.push_str(&quote_command_arg(arg));
                         }
 
                         command
@@ -1037,8 +2117,8 @@ impl AppUI {
 
                     // Empire and Napoleon do not have a launcher. We can make our lives easier calling steam instead of launching the game manually.
                     else {
-                        format!("cmd /C start /W /d \"{}\" \"{}\" \"{}\";",
-                            game_path.to_string_lossy().replace('\\', "/"),
+                        format!("{} \"{}\" \"{}\";",
+                            start,
                             exec_game.file_name().unwrap().to_string_lossy(),
                             file_path.to_string_lossy().replace('\\', "/")
                         )
@@ -1046,23 +2126,75 @@ impl AppUI {
 
                     self.toggle_main_window(false);
 
+                    // If we're going to close the launcher after launching, there's no point on monitoring the game.
+                    let post_launch_action = setting_int(POST_LAUNCH_ACTION);
+                    let close_after_launch = post_launch_action == POST_LAUNCH_CLOSE;
+                    let minimize_to_tray = post_launch_action == POST_LAUNCH_MINIMIZE_TO_TRAY && QSystemTrayIcon::is_system_tray_available();
+                    if minimize_to_tray {
+                        self.main_window().hide();
+                    }
+
                     let event_loop = qt_core::QEventLoop::new_0a();
                     event_loop.process_events_0a();
 
                     let start_date = SystemTime::now();
                     let command = BASE64_STANDARD.encode(command);
 
-                    let wait_for_finish = setting_bool("check_logs");
-                    let result = crate::mod_manager::integrations::launch_game(&game, &command, wait_for_finish);
+                    // Start following the game logs before launching, so we don't miss anything.
+                    if setting_bool("tail_game_log") && !close_after_launch {
+                        self.log_ui().start(&game_path, start_date);
+                        self.right_tabbar().set_current_widget(self.log_ui().main_widget());
+                    }
+
+                    // Keep track of the launch, so it can be checked and repeated later. Its outcome is recorded once the game exits.
+                    let profile = self.actions_ui().profile_combobox().current_text().to_std_string();
+                    let entry = LaunchHistoryEntry::new(
+                        start_date.duration_since(UNIX_EPOCH)?.as_secs(),
+                        Some(profile).filter(|profile| self.game_profiles().read().unwrap().contains_key(profile)),
+                        self.game_load_order().read().unwrap().clone(),
+                        Self::launch_option_settings(&game),
+                        Self::script_settings(&game),
+                    );
+
+                    if let Err(error) = LaunchHistory::add(&game, entry) {
+                        error!("Error when adding the launch to the launch history: {}", error);
+                    }
+
+                    let wait_for_finish = setting_bool("check_logs") && !close_after_launch;
+                    let result = crate::mod_manager::integrations::launch_game(&game, &command);
+                    if result.is_err() {
+                        if let Err(error) = LaunchHistory::set_last_outcome(&game, LaunchOutcome::Failed) {
+                            error!("Error when recording the outcome of the launch in the launch history: {}", error);
+                        }
+                    } else {
+                        self.last_launch_status_timer().start_0a();
+                    }
 
                     // Check the logs post-launch, if there's any log to check.
-                    if setting_bool("check_logs") {
+                    //
+                    // The game is followed from another thread, so we keep processing events while waiting for it to close.
+                    // Otherwise the log view, which updates from a timer, would only catch up after the game closes.
+                    if wait_for_finish {
+                        while is_game_launched(&game) {
+                            event_loop.process_events_0a();
+                            std::thread::sleep(Duration::from_millis(WAIT_FOR_FINISH_INTERVAL));
+                        }
+
                         self.check_logs(&game, &game_path, &start_date)?;
                     }
 
                     self.toggle_main_window(true);
 
-                    result
+                    // If we waited for the game to finish, there's nothing left to wait for in the tray.
+                    if minimize_to_tray && wait_for_finish {
+                        self.restore_from_tray();
+                    }
+
+                    if close_after_launch && result.is_ok() {
+                        self.main_window().close();
+                    }
+
+                    result.map(|_| true)
                 } else if cfg!(target_os = "linux") {
                     Err(anyhow!("Unsupported OS."))
                 } else {
@@ -1084,99 +2216,512 @@ impl AppUI {
             return Err(anyhow!("Profile name is empty."));
         }
 
-        match self.game_profiles().read().unwrap().get(&profile_name) {
+        let profile = self.game_profiles().read().unwrap().get(&profile_name).cloned();
+        match profile {
             Some(profile) => {
+                let game_info = self.game_selected().read().unwrap();
 
-                // First, disable all mods, so we return to a neutral state.
-                self.mod_list_ui().model().block_signals(true);
-
-                for cat in 0..self.mod_list_ui().model().row_count_0a() {
-                    let category = self.mod_list_ui().model().item_1a(cat);
-                    for row in 0..category.row_count() {
-                        let item = category.child_1a(row);
-                        item.set_check_state(CheckState::Unchecked);
-                    }
+                // Keep in mind that if it's an autostart we have to avoid saving these changes to disk.
+                // Scripts are stored in the settings, so they're only restored when not autostarting.
+                //
+                // Scripts need to be restored before setting up the actions, as that's when the script widgets are rebuilt from the settings.
+                if !is_autostart && !profile.scripts().is_empty() {
+                    Self::apply_script_settings(&game_info, profile.scripts());
                 }
 
+                self.apply_load_order(&game_info, profile.load_order(), !is_autostart)?;
 
-                // Then, enable the mods from the profile in the UI.
-                for mod_id in profile.load_order().mods() {
-                    let mod_id = QString::from_std_str(mod_id);
-                    for cat in 0..self.mod_list_ui().model().row_count_0a() {
-                        let category = self.mod_list_ui().model().item_1a(cat);
-                        for row in 0..category.row_count() {
-                            let item = category.child_1a(row);
-                            if !item.is_null() && item.data_1a(VALUE_MOD_ID).to_string().compare_q_string(&mod_id) == 0 {
-                                item.set_check_state(CheckState::Checked);
-                            }
-                        }
-                    }
+                // Keep track of when the profile was last used, so we can show it in the profile manager.
+                if let Some(profile) = self.game_profiles().write().unwrap().get_mut(&profile_name) {
+                    profile.set_last_used(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs());
+                    profile.save(&game_info, &profile_name)?;
                 }
 
-                self.mod_list_ui().model().block_signals(false);
-
-                let game_info = self.game_selected().read().unwrap();
-                let game_path = setting_path(game_info.key());
-                let game_data_path = game_info.data_path(&game_path)?;
-
-                // Then do the same for the backend. Keep in mind that if it's an autostart we have to avoid saving these changes to disk.
-                if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
-                    game_config.mods_mut().values_mut().for_each(|modd| { modd.set_enabled(false); });
+                Ok(())
+            }
+            None => Err(anyhow!("No profile with said name found for the game selected."))
+        }
+    }
 
-                    for mod_id in profile.load_order().mods() {
-                        if let Some(ref mut modd) = game_config.mods_mut().get_mut(mod_id) {
-                            modd.set_enabled(true);
-                        }
-                    }
+    /// This function replaces the current load order with the provided one, enabling only its mods, and updates the UI to reflect it.
+    ///
+    /// If `save` is false, the changes are not saved to disk, and the pack list is not updated.
+    unsafe fn apply_load_order(&self, game_info: &GameInfo, new_load_order: &LoadOrder, save: bool) -> Result<()> {
+        let game_path = setting_path(game_info.key());
+        let game_data_path = game_info.data_path(&game_path)?;
 
-                    // Replace the current load order with the one from the profile, and update it.
-                    *self.game_load_order().write().unwrap() = profile.load_order().clone();
-                    let mut load_order = self.game_load_order().write().unwrap();
-                    load_order.update(game_config, &game_info, &game_data_path);
+        // Enable only the mods from the load order, by id, then mirror that in the UI.
+        // Movie packs are included, so they don't get lost when switching load orders.
+        if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
+            game_config.set_enabled_mods(&new_load_order.enabled_mods());
+            self.mod_list_ui().sync_enabled_state(game_info, game_config)?;
 
-                    setup_actions(&self, &game_info, &game_config, &game_path, &load_order)?;
+            // Replace the current load order with the new one, and update it.
+            *self.game_load_order().write().unwrap() = new_load_order.clone();
+            let mut load_order = self.game_load_order().write().unwrap();
+            load_order.update(game_config, game_info, &game_data_path);
 
-                    // No need to do the expensive stuff on autostart, as it'll never get shown.
-                    if !is_autostart {
-                        load_order.save(&game_info)?;
+            setup_actions(self, game_info, game_config, &game_path, &load_order)?;
 
-                        let game_path = setting_path(game_info.key());
-                        self.pack_list_ui().load(game_config, &game_info, &game_path, &load_order)?;
-                        self.data_list_ui().set_enabled(false);
-                        game_config.save(&game_info)?;
-                    }
-                }
+            // No need to do the expensive stuff if it's not going to be kept.
+            if save {
+                load_order.save(game_info)?;
 
-                Ok(())
+                self.pack_list_ui().load(game_config, game_info, &game_path, &load_order)?;
+                self.data_list_ui().set_enabled(false);
+                game_config.save(game_info)?;
             }
-            None => Err(anyhow!("No profile with said name found for the game selected."))
         }
+
+        Ok(())
     }
 
-    pub unsafe fn save_profile(&self) -> Result<()> {
-        let profile_name = self.actions_ui().profile_combobox().current_text().to_std_string();
-        if profile_name.is_empty() {
-            return Err(anyhow!("Profile name is empty."));
+    /// This function returns the values of the built-in launch options of the provided game, by setting key.
+    pub unsafe fn launch_option_settings(game: &GameInfo) -> BTreeMap<String, String> {
+        launch_option_defaults(game.key())
+            .iter()
+            .map(|(key, _)| {
+                let setting = format!("{}_{}", key, game.key());
+                let value = setting_string(&setting);
+                (setting, value)
+            })
+            .collect()
+    }
+
+    /// This function opens a dialog with the recent launches of the game selected, and re-applies the configuration of the one selected if the user asks for it.
+    pub unsafe fn launch_history_dialog(&self) -> Result<()> {
+        let game = self.game_selected().read().unwrap().clone();
+        let history = LaunchHistory::load(&game, true)?;
+        if history.entries().is_empty() {
+            return Err(anyhow!(tr("launch_history_empty")));
         }
 
-        let mut profile = Profile::default();
-        profile.set_id(profile_name.to_owned());
+        let template_path = if cfg!(debug_assertions) { LAUNCH_HISTORY_VIEW_DEBUG } else { LAUNCH_HISTORY_VIEW_RELEASE };
+        let main_widget = load_template(self.main_window(), template_path)?;
+        let dialog = main_widget.static_downcast::<QDialog>();
+
+        let info_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "info_label")?;
+        let history_tree_view: QPtr<QTreeView> = find_widget(&main_widget.static_upcast(), "history_tree_view")?;
+        let button_box: QPtr<QDialogButtonBox> = find_widget(&main_widget.static_upcast(), "button_box")?;
+
+        dialog.set_window_title(&qtr("launch_history"));
+        info_label.set_text(&qtr("launch_history_info"));
+        button_box.add_button_q_string_button_role(&qtr("launch_history_reapply"), ButtonRole::AcceptRole);
+
+        let history_model = QStandardItemModel::new_1a(&history_tree_view).into_q_ptr();
+        history_tree_view.set_model(&history_model);
+        history_tree_view.set_root_is_decorated(false);
+
+        history_model.set_column_count(5);
+        for (column, title) in ["launch_history_date", "launch_history_profile", "launch_history_mods", "launch_history_options", "launch_history_outcome"].iter().enumerate() {
+            history_model.set_horizontal_header_item(column as i32, QStandardItem::from_q_string(&qtr(title)).into_ptr());
+        }
+
+        let date_format_str = format!("{} [hour]:[minute]:[second]", setting_string("date_format"));
+        let date_format = time::format_description::parse(&date_format_str).ok();
+        let defaults = launch_option_defaults(game.key());
+
+        for entry in history.entries() {
+            let date = date_format.as_ref()
+                .and_then(|date_format| OffsetDateTime::from_unix_timestamp(*entry.date() as i64).ok()?.format(date_format).ok())
+                .unwrap_or_default();
+
+            // Only show the options changed from their defaults, as the rest are the same on every launch.
+            let options = entry.launch_options().iter()
+                .filter_map(|(setting, value)| {
+                    let key = setting.strip_suffix(&format!("_{}", game.key()))?;
+                    let default = defaults.iter().find(|(default_key, _)| *default_key == key).map(|(_, default)| *default)?;
+                    if value != default { Some(format!("{}: {}", key, value)) } else { None }
+                })
+                .join(", ");
+
+            let (outcome, icon) = match entry.outcome() {
+                LaunchOutcome::Unknown => (tr("launch_history_outcome_unknown"), "dialog-question"),
+                LaunchOutcome::Failed => (tr("launch_history_outcome_failed"), "dialog-warning"),
+                LaunchOutcome::Closed => (tr("launch_history_outcome_closed"), "dialog-ok"),
+            };
+
+            let items = [date, entry.profile().clone().unwrap_or_default(), entry.load_order().mods().len().to_string(), options.to_owned(), outcome]
+                .into_iter()
+                .map(|text| {
+                    let item = QStandardItem::from_q_string(&QString::from_std_str(text));
+                    item.set_editable(false);
+                    item
+                })
+                .collect::<Vec<_>>();
+
+            items[3].set_tool_tip(&QString::from_std_str(&options));
+            items[4].set_icon(&QIcon::from_theme_1a(&QString::from_std_str(icon)));
+
+            let row = QListOfQStandardItem::new();
+            for item in items {
+                row.append_q_standard_item(&item.into_ptr().as_mut_raw_ptr());
+            }
+
+            history_model.append_row_q_list_of_q_standard_item(&row);
+        }
+
+        // Launches are sorted from newest to oldest, so the newest one is selected by default.
+        history_tree_view.set_current_index(&history_model.index_2a(0, 0));
+        history_tree_view.header().resize_sections(ResizeMode::ResizeToContents);
+
+        if dialog.exec() != 1 {
+            return Ok(());
+        }
+
+        let entry = match usize::try_from(history_tree_view.current_index().row()).ok().and_then(|row| history.entries().get(row)) {
+            Some(entry) => entry,
+            None => return Ok(()),
+        };
+
+        self.apply_launch_history_entry(&game, entry)
+    }
+
+    /// This function replaces the current load order, launch options and scripts with the ones used in the provided launch.
+    pub unsafe fn apply_launch_history_entry(&self, game: &GameInfo, entry: &LaunchHistoryEntry) -> Result<()> {
+        let q_settings = settings();
+        for (setting, value) in entry.launch_options() {
+            set_setting_string_to_q_setting(&q_settings, &migrated_setting_key(setting), value);
+        }
+        q_settings.sync();
+
+        // Scripts need to be restored before setting up the actions, as that's when the script widgets are rebuilt from the settings.
+        Self::apply_script_settings(game, entry.scripts());
+
+        // The profile is selected so its launch arguments are used, but it's not loaded, as its load order may have changed since then.
+        if let Some(profile) = entry.profile() {
+            if self.game_profiles().read().unwrap().contains_key(profile) {
+                self.actions_ui().profile_combobox().set_current_text(&QString::from_std_str(profile));
+            }
+        }
+
+        self.apply_load_order(game, entry.load_order(), true)
+    }
+
+    /// This function loads the default profile of the game selected, if it has one.
+    pub unsafe fn load_default_profile(&self) -> Result<()> {
+        let default_profile = Profile::default_for_game(&self.game_selected().read().unwrap());
+        if let Some(profile_name) = default_profile {
+
+            // If the profile no longer exists, just ignore it.
+            if self.game_profiles().read().unwrap().contains_key(&profile_name) {
+                self.actions_ui().profile_combobox().set_current_text(&QString::from_std_str(&profile_name));
+                self.load_profile(Some(profile_name), false)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub unsafe fn save_profile(&self) -> Result<()> {
+        let mut profile_name = self.actions_ui().profile_combobox().current_text().to_std_string();
+        if profile_name.is_empty() {
+            return Err(anyhow!("Profile name is empty."));
+        }
+
+        // Locked profiles can only be saved as a copy.
+        if self.game_profiles().read().unwrap().get(&profile_name).map_or(false, |profile| *profile.locked()) {
+            let new_name = ProfilesUI::duplicate_name(&profile_name, &self.game_profiles().read().unwrap().keys().cloned().collect::<Vec<_>>());
+            if !self.are_you_sure_text(&tre("profile_locked_save_as_new", &[&profile_name, &new_name])) {
+                return Ok(());
+            }
+
+            profile_name = new_name;
+        }
+
+        let mut profile = Profile::default();
+        profile.set_id(profile_name.to_owned());
         profile.set_game(self.game_selected().read().unwrap().key().to_string());
         profile.set_load_order(self.game_load_order().read().unwrap().clone());
+        profile.set_scripts(Self::script_settings(&self.game_selected().read().unwrap()));
 
-        self.game_profiles().write().unwrap().insert(profile_name.to_owned(), profile.clone());
-
-        self.actions_ui().profile_model().clear();
-        for profile in self.game_profiles().read().unwrap().keys() {
-            self.actions_ui().profile_combobox().add_item_q_string(&QString::from_std_str(profile));
+        // Launch arguments are edited from the profile manager, so keep the ones the profile already had.
+        if let Some(old_profile) = self.game_profiles().read().unwrap().get(&profile_name) {
+            profile.set_launch_args(old_profile.launch_args().clone());
         }
 
+        self.game_profiles().write().unwrap().insert(profile_name.to_owned(), profile.clone());
+        self.load_profiles_to_combobox();
+
         // Make sure the one we saved stays selected!!!
         self.actions_ui().profile_combobox().set_current_text(&QString::from_std_str(&profile_name));
 
         profile.save(&self.game_selected().read().unwrap(), &profile_name)
     }
 
+    /// This function reloads the profiles of the selected game into the profile combobox, marking the locked ones.
+    pub unsafe fn load_profiles_to_combobox(&self) {
+        self.actions_ui().profile_model().clear();
+
+        let lock_icon = QIcon::from_theme_1a(&QString::from_std_str("object-locked"));
+        for profile in self.game_profiles().read().unwrap().values().sorted_by_key(|profile| profile.id()) {
+            if *profile.locked() {
+                self.actions_ui().profile_combobox().add_item_q_icon_q_string(&lock_icon, &QString::from_std_str(profile.id()));
+            } else {
+                self.actions_ui().profile_combobox().add_item_q_string(&QString::from_std_str(profile.id()));
+            }
+        }
+    }
+
+    /// This function returns the extra arguments to pass to the game, and the name of the profile they come from.
+    ///
+    /// If the profile selected has its own arguments, those are used. Otherwise, the global ones are used.
+    pub unsafe fn launch_extra_args(&self) -> (Vec<String>, Option<String>) {
+        let profile_name = self.actions_ui().profile_combobox().current_text().to_std_string();
+        let profile_args = self.game_profiles().read().unwrap().get(&profile_name).and_then(|profile| profile.launch_args().clone());
+        match profile_args {
+            Some(args) => (split_launch_args(&args), Some(profile_name)),
+            None => (split_launch_args(&setting_string("launch_extra_args")), None),
+        }
+    }
+
+    /// This function returns the arguments to pass to the game after the mod list: the save to load, the custom launch options and the extra arguments.
+    unsafe fn launch_args(&self) -> Vec<String> {
+
+        // Check if we are loading a save. First option is no save load. Any index above that is a save.
+        let mut extra_args = vec![];
+        let save_index = self.actions_ui.save_combobox().current_index();
+        if self.actions_ui.save_combobox().current_index() > 0 {
+            if let Some(save) = self.game_saves.read().unwrap().get(save_index as usize - 1) {
+                extra_args.push("game_startup_mode".to_owned());
+                extra_args.push("campaign_load".to_owned());
+                extra_args.push(save.name().to_owned());
+            }
+        }
+
+        // Custom launch options are passed as-is after the built-in ones, and the extra arguments after them, so they can override any of them.
+        extra_args.extend(self.actions_ui().custom_launch_options_args());
+        extra_args.extend(self.launch_extra_args().0);
+        extra_args
+    }
+
+    /// This function returns the path of the file the load order is written to for the provided game.
+    fn load_order_file_path(game: &GameInfo, game_path: &Path) -> Result<PathBuf> {
+
+        // NOTE: On Empire and Napoleon we need to use the user_script, not the custom file, as it doesn't seem to work.
+        // Older versions of shogun 2 also used the user_script, but the latest update enabled use of custom mod lists.
+        if *game.raw_db_version() >= 1 {
+            Ok(game_path.join(CUSTOM_MOD_LIST_FILE_NAME))
+        } else {
+
+            // Games may fail to launch if we don't have this path created, which is done the first time we start the game.
+            let config_path = game.config_path(game_path).ok_or(anyhow!("Error getting the game's config path."))?;
+            let scripts_path = config_path.join("scripts");
+            DirBuilder::new().recursive(true).create(&scripts_path)?;
+
+            // Empire has its own user script.
+            if game.key() == KEY_EMPIRE {
+                Ok(scripts_path.join(USER_SCRIPT_EMPIRE_FILE_NAME))
+            } else {
+                Ok(scripts_path.join(USER_SCRIPT_FILE_NAME))
+            }
+        }
+    }
+
+    /// This function returns the settings of the scripts of the provided game (enabled state, presets and params), by setting key.
+    ///
+    /// UI-only settings, like if the params of a script are expanded, are not included.
+    pub unsafe fn script_settings(game: &GameInfo) -> BTreeMap<String, String> {
+        let q_settings = settings();
+        let all_keys = q_settings.all_keys();
+        let prefix = format!("{SCRIPT_SETTINGS_PREFIX}{}_", game.key());
+
+        // Some game keys start with other game's key (warhammer and warhammer_2), so we need to skip the settings of those games.
+        let other_prefixes = SUPPORTED_GAMES.games_sorted().iter()
+            .filter(|other| other.key() != game.key() && other.key().starts_with(game.key()))
+            .map(|other| format!("{SCRIPT_SETTINGS_PREFIX}{}_", other.key()))
+            .collect::<Vec<_>>();
+
+        (0..all_keys.size())
+            .map(|index| all_keys.at(index).to_std_string())
+            .filter(|key| key.starts_with(&prefix) && !other_prefixes.iter().any(|other_prefix| key.starts_with(other_prefix)))
+            .filter(|key| !SCRIPT_SETTINGS_UI_ONLY_SUFFIXES.iter().any(|suffix| key.ends_with(suffix)))
+            .map(|key| {
+                let value = q_settings.value_1a(&QString::from_std_str(&key)).to_string().to_std_string();
+                (key, value)
+            })
+            .collect()
+    }
+
+    /// This function replaces the settings of the scripts of the provided game with the provided ones.
+    ///
+    /// Script settings not in the provided list are removed, so scripts not enabled in it end up disabled.
+    pub unsafe fn apply_script_settings(game: &GameInfo, script_settings: &BTreeMap<String, String>) {
+        let q_settings = settings();
+        for key in Self::script_settings(game).keys() {
+            if !script_settings.contains_key(key) {
+                q_settings.remove(&QString::from_std_str(key));
+            }
+        }
+
+        for (key, value) in script_settings {
+            set_setting_string_to_q_setting(&q_settings, key, value);
+        }
+
+        q_settings.sync();
+    }
+
+    /// This function enables or disables multiplayer-safe mode for the game selected.
+    ///
+    /// Enabling it turns off the launch options not allowed in it, keeping their values so disabling it restores them.
+    pub unsafe fn set_multiplayer_safe(&self, enabled: bool) -> Result<()> {
+        let game = self.game_selected().read().unwrap().clone();
+        let snapshot_key = format!("multiplayer_safe_snapshot_{}", game.key());
+        let q_settings = settings();
+
+        if enabled {
+            let allowed = ActionsUI::multiplayer_safe_allowed(game.key());
+            let disallowed = MULTIPLAYER_SAFE_OPTIONS.iter()
+                .filter(|(key, _, _)| !allowed.iter().any(|allowed| allowed == key))
+                .collect::<Vec<_>>();
+
+            let options = disallowed.iter()
+                .map(|(_, prefix, _)| format!("{}_{}", prefix, game.key()))
+                .filter(|setting| q_settings.value_1a(&QString::from_std_str(setting)).is_valid())
+                .map(|setting| {
+                    let value = q_settings.value_1a(&QString::from_std_str(&setting)).to_string().to_std_string();
+                    (setting, value)
+                })
+                .collect::<BTreeMap<_, _>>();
+
+            let scripts = if allowed.iter().any(|allowed| allowed == SCRIPTS_ACTION_KEY) {
+                None
+            } else {
+                Some(Self::script_settings(&game))
+            };
+
+            set_setting_string_to_q_setting(&q_settings, &snapshot_key, &serde_json::to_string(&(options, scripts))?);
+
+            for (_, prefix, value) in &disallowed {
+                set_setting_string_to_q_setting(&q_settings, &format!("{}_{}", prefix, game.key()), value);
+            }
+        } else {
+            let snapshot = setting_string(&snapshot_key);
+            if !snapshot.is_empty() {
+                let (options, scripts): (BTreeMap<String, String>, Option<BTreeMap<String, String>>) = serde_json::from_str(&snapshot)?;
+                for (setting, value) in &options {
+                    set_setting_string_to_q_setting(&q_settings, &migrated_setting_key(setting), value);
+                }
+
+                if let Some(scripts) = scripts {
+                    Self::apply_script_settings(&game, &scripts);
+                }
+            }
+
+            q_settings.remove(&QString::from_std_str(&snapshot_key));
+        }
+
+        set_setting_bool_to_q_setting(&q_settings, &format!("multiplayer_safe_{}", game.key()), enabled);
+        q_settings.sync();
+
+        let game_path = setting_path(game.key());
+        if let Some(ref game_config) = *self.game_config().read().unwrap() {
+            setup_actions(self, &game, game_config, &game_path, &self.game_load_order().read().unwrap())?;
+        }
+
+        Ok(())
+    }
+
+    /// This function saves the current unit multiplier as a preset for the game selected, asking the user for its name.
+    ///
+    /// Saving it with the name of an existing preset replaces that preset.
+    pub unsafe fn save_unit_multiplier_preset(&self) -> Result<()> {
+        let game = self.game_selected().read().unwrap().clone();
+        let current_name = if self.actions_ui().unit_multiplier_preset_combobox().current_index() > 0 {
+            self.actions_ui().unit_multiplier_preset_combobox().current_text().to_std_string()
+        } else {
+            String::new()
+        };
+
+        if let Some(name) = self.unit_multiplier_preset_dialog(&current_name)? {
+            let mut presets = ActionsUI::unit_multiplier_presets(game.key());
+            presets.insert(name.clone(), self.actions_ui().unit_multiplier_spinbox().value());
+            ActionsUI::set_unit_multiplier_presets(game.key(), &presets);
+
+            self.actions_ui().load_unit_multiplier_presets(game.key());
+            self.actions_ui().unit_multiplier_preset_combobox().block_signals(true);
+            self.actions_ui().unit_multiplier_preset_combobox().set_current_text(&QString::from_std_str(&name));
+            self.actions_ui().unit_multiplier_preset_combobox().block_signals(false);
+            self.actions_ui().select_unit_multiplier_preset();
+        }
+
+        Ok(())
+    }
+
+    /// This function removes the selected unit multiplier preset of the game selected. The multiplier itself is not changed.
+    pub unsafe fn delete_unit_multiplier_preset(&self) {
+        let combobox = self.actions_ui().unit_multiplier_preset_combobox();
+        if combobox.current_index() <= 0 {
+            return;
+        }
+
+        let game = self.game_selected().read().unwrap().clone();
+        let mut presets = ActionsUI::unit_multiplier_presets(game.key());
+        presets.remove(&combobox.current_text().to_std_string());
+        ActionsUI::set_unit_multiplier_presets(game.key(), &presets);
+
+        self.actions_ui().load_unit_multiplier_presets(game.key());
+    }
+
+    unsafe fn unit_multiplier_preset_dialog(&self, current_name: &str) -> Result<Option<String>> {
+
+        // Load the UI Template.
+        let template_path = if cfg!(debug_assertions) { UNIT_MULTIPLIER_PRESET_VIEW_DEBUG } else { UNIT_MULTIPLIER_PRESET_VIEW_RELEASE };
+        let main_widget = load_template(self.main_window(), template_path)?;
+
+        let dialog = main_widget.static_downcast::<QDialog>();
+        dialog.set_window_title(&qtr("unit_multiplier_preset_save"));
+
+        let name_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "name_line_edit")?;
+        let name_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "name_label")?;
+        let button_box: QPtr<QDialogButtonBox> = find_widget(&main_widget.static_upcast(), "button_box")?;
+        name_line_edit.set_text(&QString::from_std_str(current_name));
+        name_label.set_text(&qtr("unit_multiplier_preset_name"));
+
+        button_box.button(StandardButton::Ok).released().connect(dialog.slot_accept());
+        button_box.button(StandardButton::Ok).set_enabled(!current_name.trim().is_empty());
+
+        let name_line_edit_ptr = name_line_edit.as_ptr();
+        let ok_button = button_box.button(StandardButton::Ok);
+        name_line_edit.text_changed().connect(&SlotNoArgs::new(&name_line_edit, move || {
+            ok_button.set_enabled(!name_line_edit_ptr.text().to_std_string().trim().is_empty());
+        }));
+
+        if dialog.exec() == 1 {
+            Ok(Some(name_line_edit.text().to_std_string().trim().to_owned()))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// This function returns the workshop ids of the enabled mods of the game selected, in load order.
+    ///
+    /// Mods not from the workshop have no id, so they're skipped. Returns the ids and the amount of mods skipped.
+    pub unsafe fn enabled_workshop_ids(&self) -> Result<(Vec<String>, usize)> {
+        let game = self.game_selected().read().unwrap();
+        let game_path = setting_path(game.key());
+        let game_data_path = game.data_path(&game_path)?;
+
+        let game_config = self.game_config().read().unwrap();
+        let game_config = game_config.as_ref().ok_or_else(|| anyhow!("There is no mod list loaded for the game selected."))?;
+        let load_order = self.game_load_order().read().unwrap();
+
+        let mut ids = vec![];
+        let mut skipped = 0;
+        for modd in load_order.mods()
+            .iter()
+            .filter_map(|mod_id| game_config.mods().get(mod_id))
+            .filter(|modd| modd.enabled(&game, &game_data_path) && !modd.paths().is_empty()) {
+
+            match modd.steam_id() {
+                Some(steam_id) => ids.push(steam_id.to_owned()),
+                None => skipped += 1,
+            }
+        }
+
+        Ok((ids, skipped))
+    }
+
     /// This returns the selection REVERSED!!!
     pub unsafe fn mod_list_selection(&self) -> Vec<CppBox<QModelIndex>> {
         self.mod_list_ui().mod_list_selection()
@@ -1192,13 +2737,58 @@ impl AppUI {
         self.data_list_ui().data_list_selection()
     }
 
+    /// This function installs the provided packs into the data folder of the game selected, after asking the user.
+    ///
+    /// Returns the names of the packs installed and of the files rejected for not being packs, or None if the user cancelled it.
+    pub unsafe fn install_dropped_packs(&self, paths: &[PathBuf]) -> Result<Option<(Vec<String>, Vec<String>)>> {
+        let file_name = |path: &PathBuf| path.file_name().unwrap_or_default().to_string_lossy().to_string();
+        let (packs, rejected): (Vec<&PathBuf>, Vec<&PathBuf>) = paths.iter()
+            .partition(|path| path.is_file() && path.extension().map(|ext| ext.eq_ignore_ascii_case("pack")).unwrap_or_default());
+
+        let rejected = rejected.into_iter().map(file_name).collect::<Vec<_>>();
+        if packs.is_empty() {
+            return Err(anyhow!(tre("drop_packs_no_packs", &[&rejected.join(", ")])));
+        }
+
+        let game = self.game_selected().read().unwrap().clone();
+        let data_path = game.data_path(&setting_path(game.key()))?;
+        let names = packs.iter().map(|path| file_name(path)).collect::<Vec<_>>();
+
+        let mut message = tre("drop_packs_confirm", &[&names.join(", "), &data_path.to_string_lossy()]);
+        let existing = names.iter().filter(|name| data_path.join(name).is_file()).join(", ");
+        if !existing.is_empty() {
+            message.push_str("<br/><br/>");
+            message.push_str(&tre("drop_packs_overwrite", &[&existing]));
+        }
+
+        if !self.are_you_sure_text(&message) {
+            return Ok(None);
+        }
+
+        for (path, name) in packs.iter().zip(&names) {
+            let dest_path = data_path.join(name);
+
+            // Dropping a pack that's already in the data folder would wipe it if we copy it over itself.
+            if path.canonicalize().ok() != dest_path.canonicalize().ok() {
+                std::fs::copy(path, &dest_path)?;
+            }
+        }
+
+        Ok(Some((names, rejected)))
+    }
+
     /// This function pops up a modal asking you if you're sure you want to do an action that may result in loss of data.
     pub unsafe fn are_you_sure(&self, message: &str) -> bool {
+        self.are_you_sure_text(&tr(message))
+    }
+
+    /// This function asks the user for confirmation, using an already translated message.
+    pub unsafe fn are_you_sure_text(&self, message: &str) -> bool {
 
         // Create the dialog and run it (Yes => 3, No => 4).
         QMessageBox::from_2_q_string_icon3_int_q_widget(
             &qtr("are_you_sure_title"),
-            &qtr(message),
+            &QString::from_std_str(message),
             q_message_box::Icon::Warning,
             65536, // No
             16384, // Yes
@@ -1207,6 +2797,194 @@ impl AppUI {
         ).exec() == 3
     }
 
+    /// This function restores the latest backup made before running destructive scripts on the selected game.
+    ///
+    /// Returns the path of the restored backup, or None if there was nothing to restore.
+    pub unsafe fn restore_script_backup(&self) -> Result<Option<PathBuf>> {
+        let game = self.game_selected().read().unwrap();
+        let game_path = setting_path(game.key());
+        crate::games::restore_script_backup(&game, &game_path)
+    }
+
+    /// This function exports the scripts enabled for the selected game, with their presets, to a bundle file chosen by the user.
+    ///
+    /// Returns the path of the bundle, or None if the user cancelled it.
+    pub unsafe fn export_script_bundle(&self) -> Result<Option<PathBuf>> {
+        let game = self.game_selected().read().unwrap().clone();
+        let script_keys = self.actions_ui().scripts_to_execute().read().unwrap()
+            .iter()
+            .filter(|(script, checkbox)| checkbox.is_checked() && !*script.metadata().automatic())
+            .map(|(script, _)| script.metadata().key().to_owned())
+            .collect::<Vec<_>>();
+
+        if script_keys.is_empty() {
+            return Err(anyhow!(tr("script_bundle_export_no_scripts")));
+        }
+
+        let file_dialog = QFileDialog::from_q_widget_q_string(
+            self.main_window(),
+            &qtr("export_script_bundle"),
+        );
+
+        file_dialog.set_accept_mode(AcceptMode::AcceptSave);
+        file_dialog.set_name_filter(&qtr("script_bundle_filter"));
+        file_dialog.set_default_suffix(&QString::from_std_str("zip"));
+        file_dialog.select_file(&QString::from_std_str(format!("scripts_{}.zip", game.key())));
+
+        if file_dialog.exec() == 1 {
+            let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+            ScriptBundle::new(&game, &script_keys)?.save(&path)?;
+            Ok(Some(path))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// This function exports the command used to launch the selected game to a script chosen by the user.
+    ///
+    /// The kind of script depends on the extension of the chosen file: batch for .bat and .cmd, shell for .sh.
+    ///
+    /// The launch is prepared first, like when launching the game, so the mod list and generated packs the script uses are up to date.
+    /// Returns the path of the script, or None if the user cancelled it.
+    pub unsafe fn export_launch_script(&self) -> Result<Option<PathBuf>> {
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+        let executable_path = game_executable_path(&game, &game_path)
+            .filter(|path| path.is_file())
+            .ok_or_else(|| anyhow!(tr("validate_launch_executable_missing_path")))?;
+
+        let file_dialog = QFileDialog::from_q_widget_q_string(
+            self.main_window(),
+            &qtr("export_launch_script"),
+        );
+
+        file_dialog.set_accept_mode(AcceptMode::AcceptSave);
+        file_dialog.set_name_filter(&qtr("launch_script_filter"));
+        let default_kind = LaunchScriptKind::default();
+        file_dialog.set_default_suffix(&QString::from_std_str(default_kind.extension()));
+        file_dialog.select_file(&QString::from_std_str(format!("launch_{}.{}", game.key(), default_kind.extension())));
+
+        if file_dialog.exec() != 1 {
+            return Ok(None);
+        }
+
+        let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+        if !self.prepare_launch(false, true)? {
+            return Ok(None);
+        }
+
+        // Same arguments as the launch: the mod list file plus the extra arguments since Shogun 2, and the user script before it.
+        let args = if *game.raw_db_version() >= 1 {
+            let mut args = vec![CUSTOM_MOD_LIST_FILE_NAME.to_owned()];
+            args.extend(self.launch_args());
+            args
+        } else {
+            vec![Self::load_order_file_path(&game, &game_path)?.to_string_lossy().to_string()]
+        };
+
+        let script = LaunchScript::new(&game_path, &launch_wrapper(), &executable_path, &args, &launch_environment_variables());
+        script.save(&path, LaunchScriptKind::from_path(&path))?;
+        Ok(Some(path))
+    }
+
+    /// This function returns the command that would launch the selected game with the provided profile, without loading it.
+    ///
+    /// The load order of the profile is written to its own mod list file, which the command uses instead of the game's one,
+    /// so neither the current load order nor the current mod list are touched. The command uses the current custom launch options,
+    /// and the extra arguments of the profile or the global ones.
+    ///
+    /// Packs generated on launch (launch options, scripts, merges) cannot be included, as they depend on preparing a launch,
+    /// so this fails if launching with the profile would need any of them.
+    pub unsafe fn profile_launch_command(&self, profile: &Profile) -> Result<String> {
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+        let data_path = game.data_path(&game_path)?;
+        let executable_path = game_executable_path(&game, &game_path)
+            .filter(|path| path.is_file())
+            .ok_or_else(|| anyhow!(tr("validate_launch_executable_missing_path")))?;
+
+        // Empire and Napoleon always read the user script, so they cannot be pointed to another mod list.
+        if *game.raw_db_version() < 1 {
+            return Err(anyhow!(tr("profile_launch_command_unsupported")));
+        }
+
+        // Profiles with scripts replace the current ones when loaded, so if they differ, the current ones tell us nothing.
+        let actions_ui = self.actions_ui();
+        let scripts_differ = !profile.scripts().is_empty() && *profile.scripts() != Self::script_settings(&game);
+        let scripts_enabled = actions_ui.scripts_to_execute().read().unwrap().iter().any(|(_, item)| item.is_checked());
+        let merge_enabled = actions_ui.merge_all_mods_checkbox().is_enabled() && actions_ui.merge_all_mods_checkbox().is_checked();
+        if launch_options_need_patch(actions_ui) || scripts_differ || scripts_enabled || merge_enabled {
+            return Err(anyhow!(tr("profile_launch_command_generated_packs")));
+        }
+
+        // Same as when loading the profile, but over copies, so the current state stays as it is.
+        let mut game_config = self.game_config().read().unwrap().clone().ok_or_else(|| anyhow!(tr("game_config_error")))?;
+        game_config.set_enabled_mods(&profile.load_order().enabled_mods());
+
+        let mut load_order = profile.load_order().clone();
+        load_order.update(&game_config, &game, &data_path);
+
+        let mut folder_list = String::new();
+        let mut pack_list = String::new();
+        load_order.build_load_order_string(&game_config, &game, &data_path, &mut pack_list, &mut folder_list);
+
+        // Profile ids can contain anything, so the file is named after their hash.
+        let mod_list_path = profile_mod_lists_folder(&game)?.join(format!("{}.txt", sha256::digest(profile.id().as_str())));
+        Self::save_load_order_file(&mod_list_path, &game, &folder_list, &pack_list)?;
+
+        let mut args = vec![mod_list_path.to_string_lossy().to_string()];
+        args.extend(self.actions_ui().custom_launch_options_args());
+        match profile.launch_args() {
+            Some(launch_args) => args.extend(split_launch_args(launch_args)),
+            None => args.extend(split_launch_args(&setting_string("launch_extra_args"))),
+        }
+
+        let script = LaunchScript::new(&game_path, &launch_wrapper(), &executable_path, &args, &launch_environment_variables());
+        Ok(script.to_command(LaunchScriptKind::default()))
+    }
+
+    /// This function installs the scripts and presets of a bundle file chosen by the user for the selected game.
+    ///
+    /// If the bundle overwrites any script or preset already installed, the user is asked first.
+    /// Returns the amount of scripts imported, or None if the user cancelled it.
+    pub unsafe fn import_script_bundle(&self) -> Result<Option<usize>> {
+        let file_dialog = QFileDialog::from_q_widget_q_string(
+            self.main_window(),
+            &qtr("import_script_bundle"),
+        );
+
+        file_dialog.set_file_mode(FileMode::ExistingFile);
+        file_dialog.set_name_filter(&qtr("script_bundle_filter"));
+
+        if file_dialog.exec() != 1 {
+            return Ok(None);
+        }
+
+        let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+        let bundle = ScriptBundle::load(&path)?;
+
+        let game = self.game_selected().read().unwrap().clone();
+        if bundle.game_key() != game.key() {
+            return Err(anyhow!(tre("script_bundle_import_wrong_game", &[bundle.game_key(), game.key()])));
+        }
+
+        let (scripts, presets) = bundle.collisions(&game)?;
+        if !scripts.is_empty() || !presets.is_empty() {
+            let collisions = scripts.iter()
+                .map(|key| tre("script_bundle_import_collision_script", &[key]))
+                .chain(presets.iter().map(|key| tre("script_bundle_import_collision_preset", &[key])))
+                .map(|collision| format!("<li>{collision}</li>"))
+                .join("");
+
+            if !self.are_you_sure_text(&tre("script_bundle_import_collisions", &[&collisions])) {
+                return Ok(None);
+            }
+        }
+
+        bundle.install(&game)?;
+        Ok(Some(bundle.scripts().len()))
+    }
+
     /// This function creates the stylesheet used for the dark theme in windows.
     pub fn dark_stylesheet() -> Result<String> {
         let mut file = File::open(ASSETS_PATH.join("dark-theme.qss"))?;
@@ -1236,14 +3014,12 @@ impl AppUI {
                 }
 
                 self.github_button().set_icon(&QIcon::from_q_string(&QString::from_std_str(format!("{}/icons/github.svg", ASSETS_PATH.to_string_lossy()))));
-                self.actions_ui().update_icons();
             } else {
                 QApplication::set_style_q_string(&QString::from_std_str("windowsvista"));
                 QApplication::set_palette_1a(light_palette);
                 qapp.set_style_sheet(light_style_sheet);
 
                 self.github_button().set_icon(&QIcon::from_q_string(&QString::from_std_str(format!("{}/icons/github-dark.svg", ASSETS_PATH.to_string_lossy()))));
-                self.actions_ui().update_icons();
             }
         }
 
@@ -1270,6 +3046,9 @@ impl AppUI {
             qt_widgets::QApplication::set_palette_1a(light_palette);
             qapp.set_style_sheet(light_style_sheet);
         }
+
+        // Icons may depend on the theme, so make sure they're updated on all platforms.
+        self.actions_ui().update_icons();
     }
 
     // String none means paste mode.
@@ -1327,18 +3106,61 @@ impl AppUI {
         }
     }
 
-    pub unsafe fn load_order_from_shareable_mod_list(&self, shareable_mod_list: &[ShareableMod]) -> Result<()> {
-        if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
-
-            // Before we begin, we need to set all mods to disable. Otherwise, new load orders would get mods mixed up.
-            game_config.mods_mut().iter_mut().for_each(|(_, modd)| { modd.set_enabled(false); });
+    /// This function asks the user for a load order file exported by another mod manager, and returns its contents to import them.
+    pub unsafe fn load_order_file_dialog(&self) -> Result<Option<ImportedLoadOrderMode>> {
+        let file_dialog = QFileDialog::from_q_widget_q_string(
+            self.main_window(),
+            &qtr("import_load_order_file"),
+        );
 
-            let mut missing = vec![];
-            let mut wrong_hash = vec![];
-            let mut ids = vec![];
+        file_dialog.set_file_mode(FileMode::ExistingFile);
+        file_dialog.set_name_filter(&qtr("import_load_order_file_filter"));
 
-            for modd in shareable_mod_list {
-                match game_config.mods_mut().get_mut(modd.id()) {
+        if file_dialog.exec() == 1 {
+            let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+            let string = std::fs::read_to_string(path)?;
+            Ok(Some(ImportedLoadOrderMode::External(string)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// This function parses the provided load order in the background thread and applies it, reporting any error.
+    pub unsafe fn import_load_order(&self, mode: ImportedLoadOrderMode) {
+
+        // Importing overwrites the current load order, so make sure we're not losing unsaved profile changes.
+        match self.check_unsaved_profile_changes() {
+            Ok(true) => {},
+            Ok(false) => return,
+            Err(error) => return show_dialog(self.main_window(), error, false),
+        }
+
+        self.toggle_main_window(false);
+
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetLoadOrderFromString(mode));
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        match response {
+            Response::VecShareableMods(response) => {
+                if let Err(error) = self.load_order_from_shareable_mod_list(&response) {
+                    show_dialog(self.main_window(), error, false);
+                }
+            }
+            Response::Error(error) => show_dialog(self.main_window(), error, false),
+            _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+        }
+
+        self.toggle_main_window(true);
+    }
+
+    pub unsafe fn load_order_from_shareable_mod_list(&self, shareable_mod_list: &[ShareableMod]) -> Result<()> {
+        if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
+
+            let mut missing = vec![];
+            let mut wrong_hash = vec![];
+            let mut ids = vec![];
+
+            for modd in shareable_mod_list {
+                match game_config.mods().get(modd.id()) {
                     Some(modd_local) => {
                         if let Some(path) = modd_local.paths().first() {
                             if !modd.hash().is_empty() {
@@ -1348,7 +3170,6 @@ impl AppUI {
                                 }
                             }
 
-                            modd_local.set_enabled(true);
                             ids.push(modd_local.id().to_owned());
                         }
                     },
@@ -1356,6 +3177,9 @@ impl AppUI {
                 }
             }
 
+            // Only the listed mods must end up enabled. Otherwise, new load orders would get mods mixed up.
+            game_config.set_enabled_mods(&ids);
+
             // Once we're done updating the game config, we need to update the load order.
             //
             // We need manual order to respect the provided load order, as it may not be automatic.
@@ -1425,42 +3249,961 @@ impl AppUI {
             }
         }
 
-        // Unlock the signals, then manually trigger a full load order rebuild.
-        self.mod_list_ui().model().block_signals(false);
+        // Unlock the signals, then manually trigger a full load order rebuild.
+        self.mod_list_ui().model().block_signals(false);
+        self.update_enabled_mods_from_mod_list()
+    }
+
+    /// This function updates the enabled status of all mods in the backend to match the mod list, and rebuilds the load order.
+    ///
+    /// Meant to be used after changing the check state of mods with the model signals blocked.
+    pub unsafe fn update_enabled_mods_from_mod_list(&self) -> Result<()> {
+        if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
+            for category in 0..self.mod_list_ui().model().row_count_0a() {
+                let cat_item = self.mod_list_ui().model().item_2a(category, 0);
+                for mod_row in 0..cat_item.row_count() {
+                    let mod_item = cat_item.child_2a(mod_row, 0);
+                    if !mod_item.is_null() && mod_item.is_checkable() {
+                        let mod_id = mod_item.data_1a(VALUE_MOD_ID).to_string().to_std_string();
+                        if let Some(ref mut modd) = game_config.mods_mut().get_mut(&mod_id) {
+                            modd.set_enabled(mod_item.check_state() == CheckState::Checked);
+                        }
+                    }
+                }
+            }
+
+            // Reload the pack view.
+            let game_info = self.game_selected().read().unwrap();
+            let game_path = setting_path(game_info.key());
+            let game_data_path = game_info.data_path(&game_path)?;
+            let mut load_order = self.game_load_order().write().unwrap();
+
+            load_order.update(game_config, &game_info, &game_data_path);
+            load_order.save(&game_info)?;
+
+            setup_actions(&self, &game_info, &game_config, &game_path, &load_order)?;
+
+            self.pack_list_ui().load(game_config, &game_info, &game_path, &load_order)?;
+            self.data_list_ui().set_enabled(false);
+            game_config.save(&game_info)?;
+
+            Ok(())
+        } else {
+            Err(anyhow!("WTF?!!! game config is not writable? This is probably a bug."))
+        }
+    }
+
+    /// This function opens a dialog to enable or disable in bulk all mods matching a wildcard or regex pattern.
+    pub unsafe fn toggle_matching_mods(&self) -> Result<()> {
+        let template_path = if cfg!(debug_assertions) { TOGGLE_MATCHING_VIEW_DEBUG } else { TOGGLE_MATCHING_VIEW_RELEASE };
+        let main_widget = load_template(self.main_window(), template_path)?;
+        let dialog = main_widget.static_downcast::<QDialog>();
+
+        let pattern_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "pattern_label")?;
+        let pattern_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "pattern_line_edit")?;
+        let use_regex_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "use_regex_label")?;
+        let use_regex_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "use_regex_checkbox")?;
+        let action_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "action_label")?;
+        let action_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "action_combobox")?;
+        let count_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "count_label")?;
+        let matches_list_view: QPtr<QListView> = find_widget(&main_widget.static_upcast(), "matches_list_view")?;
+        let button_box: QPtr<QDialogButtonBox> = find_widget(&main_widget.static_upcast(), "button_box")?;
+
+        dialog.set_window_title(&qtr("toggle_matching_title"));
+        pattern_label.set_text(&qtr("toggle_matching_pattern"));
+        pattern_line_edit.set_placeholder_text(&qtr("toggle_matching_pattern_placeholder"));
+        use_regex_label.set_text(&qtr("toggle_matching_use_regex"));
+        action_label.set_text(&qtr("toggle_matching_action"));
+        action_combobox.add_item_q_string(&qtr("toggle_matching_enable"));
+        action_combobox.add_item_q_string(&qtr("toggle_matching_disable"));
+
+        let matches_model = QStandardItemModel::new_1a(&matches_list_view).into_q_ptr();
+        matches_list_view.set_model(&matches_model);
+
+        // Get the list of mods we can toggle, with their ids and names, so we can match against both.
+        let mut mods = vec![];
+        for cat in 0..self.mod_list_ui().model().row_count_0a() {
+            let category = self.mod_list_ui().model().item_1a(cat);
+            for row in 0..category.row_count() {
+                let item = category.child_1a(row);
+                if !item.is_null() && item.is_checkable() {
+                    mods.push((item.data_1a(VALUE_MOD_ID).to_string().to_std_string(), item.text().to_std_string()));
+                }
+            }
+        }
+
+        // Update the preview every time the pattern changes.
+        let matches = Rc::new(RwLock::new(vec![]));
+        let update_matches = Rc::new(clone!(
+            matches,
+            pattern_line_edit,
+            use_regex_checkbox,
+            button_box,
+            matches_model => move || {
+            matches_model.clear();
+            matches.write().unwrap().clear();
+
+            let pattern = pattern_line_edit.text().to_std_string();
+            if pattern.is_empty() {
+                count_label.set_text(&qtr("toggle_matching_no_pattern"));
+                button_box.button(StandardButton::Ok).set_enabled(false);
+                return;
+            }
+
+            match Self::mod_matching_regex(&pattern, use_regex_checkbox.is_checked()) {
+                Ok(regex) => {
+                    let mut matches = matches.write().unwrap();
+                    for (mod_id, mod_name) in &mods {
+                        if regex.is_match(mod_id) || regex.is_match(mod_name) {
+                            let item = QStandardItem::from_q_string(&QString::from_std_str(format!("{} ({})", mod_name, mod_id)));
+                            matches_model.append_row_q_standard_item(item.into_ptr());
+                            matches.push(mod_id.to_owned());
+                        }
+                    }
+
+                    count_label.set_text(&QString::from_std_str(tre("toggle_matching_count", &[&matches.len().to_string()])));
+                    button_box.button(StandardButton::Ok).set_enabled(!matches.is_empty());
+                }
+                Err(error) => {
+                    count_label.set_text(&QString::from_std_str(tre("toggle_matching_invalid_pattern", &[&error.to_string()])));
+                    button_box.button(StandardButton::Ok).set_enabled(false);
+                }
+            }
+        }));
+
+        let update_matches_slot = SlotNoArgs::new(&dialog, clone!(update_matches => move || update_matches()));
+        pattern_line_edit.text_changed().connect(&update_matches_slot);
+        use_regex_checkbox.toggled().connect(&update_matches_slot);
+        update_matches();
+
+        dialog.set_modal(true);
+        if dialog.exec() != 1 {
+            return Ok(());
+        }
+
+        let toggle = action_combobox.current_index() == 0;
+        let matches = matches.read().unwrap();
+        if matches.is_empty() {
+            return Ok(());
+        }
+
+        // Lock the signals for the model, so we avoid repeating full updates of the load order.
+        self.mod_list_ui().model().block_signals(true);
+
+        for cat in 0..self.mod_list_ui().model().row_count_0a() {
+            let category = self.mod_list_ui().model().item_1a(cat);
+            for row in 0..category.row_count() {
+                let item = category.child_1a(row);
+                if !item.is_null() && item.is_checkable() && matches.contains(&item.data_1a(VALUE_MOD_ID).to_string().to_std_string()) {
+                    item.set_check_state(if toggle { CheckState::Checked } else { CheckState::Unchecked });
+                }
+            }
+        }
+
+        self.mod_list_ui().model().block_signals(false);
+        self.update_enabled_mods_from_mod_list()
+    }
+
+    /// This function opens a dialog to keep enabled only a random half of the enabled mods, to help finding conflicts by bisection.
+    ///
+    /// The half is picked using a seed, so the same seed with the same enabled mods always picks the same half.
+    pub unsafe fn enable_random_subset(&self) -> Result<()> {
+        let mut enabled = vec![];
+        for cat in 0..self.mod_list_ui().model().row_count_0a() {
+            let category = self.mod_list_ui().model().item_1a(cat);
+            for row in 0..category.row_count() {
+                let item = category.child_1a(row);
+                if !item.is_null() && item.is_checkable() && item.check_state() == CheckState::Checked {
+                    enabled.push((item.data_1a(VALUE_MOD_ID).to_string().to_std_string(), item.text().to_std_string()));
+                }
+            }
+        }
+
+        if enabled.len() < 2 {
+            return Err(anyhow!(tr("random_subset_not_enough_mods")));
+        }
+
+        // Sort them, so the subset only depends on the seed and the enabled mods, not on their position in the list.
+        enabled.sort();
+        let enabled_ids = enabled.iter().map(|(mod_id, _)| mod_id.to_owned()).collect::<Vec<_>>();
+
+        let template_path = if cfg!(debug_assertions) { RANDOM_SUBSET_VIEW_DEBUG } else { RANDOM_SUBSET_VIEW_RELEASE };
+        let main_widget = load_template(self.main_window(), template_path)?;
+        let dialog = main_widget.static_downcast::<QDialog>();
+
+        let seed_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "seed_label")?;
+        let seed_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "seed_line_edit")?;
+        let new_seed_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "new_seed_button")?;
+        let count_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "count_label")?;
+        let subset_list_view: QPtr<QListView> = find_widget(&main_widget.static_upcast(), "subset_list_view")?;
+        let button_box: QPtr<QDialogButtonBox> = find_widget(&main_widget.static_upcast(), "button_box")?;
+
+        dialog.set_window_title(&qtr("random_subset_title"));
+        seed_label.set_text(&qtr("random_subset_seed"));
+        new_seed_button.set_tool_tip(&qtr("random_subset_new_seed"));
+        seed_line_edit.set_text(&QString::from_std_str(rand::random::<u32>().to_string()));
+
+        let subset_model = QStandardItemModel::new_1a(&subset_list_view).into_q_ptr();
+        subset_list_view.set_model(&subset_model);
+
+        // Update the preview every time the seed changes.
+        let total = enabled.len();
+        let subset = Rc::new(RwLock::new(vec![]));
+        let update_subset = Rc::new(clone!(
+            subset,
+            seed_line_edit,
+            button_box,
+            subset_model => move || {
+            subset_model.clear();
+            subset.write().unwrap().clear();
+
+            match seed_line_edit.text().to_std_string().trim().parse::<u64>() {
+                Ok(seed) => {
+                    let mut subset = subset.write().unwrap();
+                    *subset = Self::random_subset(&enabled, seed);
+                    for (mod_id, mod_name) in subset.iter() {
+                        let item = QStandardItem::from_q_string(&QString::from_std_str(format!("{} ({})", mod_name, mod_id)));
+                        subset_model.append_row_q_standard_item(item.into_ptr());
+                    }
+
+                    count_label.set_text(&QString::from_std_str(tre("random_subset_count", &[&subset.len().to_string(), &total.to_string()])));
+                    button_box.button(StandardButton::Ok).set_enabled(true);
+                }
+                Err(_) => {
+                    count_label.set_text(&qtr("random_subset_invalid_seed"));
+                    button_box.button(StandardButton::Ok).set_enabled(false);
+                }
+            }
+        }));
+
+        let update_subset_slot = SlotNoArgs::new(&dialog, clone!(update_subset => move || update_subset()));
+        let new_seed_slot = SlotNoArgs::new(&dialog, clone!(seed_line_edit => move || {
+            seed_line_edit.set_text(&QString::from_std_str(rand::random::<u32>().to_string()));
+        }));
+
+        seed_line_edit.text_changed().connect(&update_subset_slot);
+        new_seed_button.released().connect(&new_seed_slot);
+        update_subset();
+
+        dialog.set_modal(true);
+        if dialog.exec() != 1 {
+            return Ok(());
+        }
+
+        let subset = subset.read().unwrap();
+        if subset.is_empty() {
+            return Ok(());
+        }
+
+        // Log it, so the subset can be found later even if the seed is lost.
+        let subset = subset.iter().map(|(mod_id, _)| mod_id.to_owned()).collect::<Vec<_>>();
+        info!("Random subset with seed {}: {}", seed_line_edit.text().to_std_string().trim(), subset.join(", "));
+
+        self.set_mods_enabled_in_mod_list(&subset, &enabled_ids)
+    }
+
+    /// This function enables the provided mods and disables the rest of the mods in the provided scope, then updates the load order.
+    ///
+    /// Mods outside the scope are left as they are.
+    unsafe fn set_mods_enabled_in_mod_list(&self, mod_ids: &[String], scope: &[String]) -> Result<()> {
+
+        // Lock the signals for the model, so we avoid repeating full updates of the load order.
+        self.mod_list_ui().model().block_signals(true);
+
+        for cat in 0..self.mod_list_ui().model().row_count_0a() {
+            let category = self.mod_list_ui().model().item_1a(cat);
+            for row in 0..category.row_count() {
+                let item = category.child_1a(row);
+                if !item.is_null() && item.is_checkable() {
+                    let mod_id = item.data_1a(VALUE_MOD_ID).to_string().to_std_string();
+                    if scope.contains(&mod_id) {
+                        item.set_check_state(if mod_ids.contains(&mod_id) { CheckState::Checked } else { CheckState::Unchecked });
+                    }
+                }
+            }
+        }
+
+        self.mod_list_ui().model().block_signals(false);
+        self.update_enabled_mods_from_mod_list()
+    }
+
+    /// This function guides the user through finding which of the enabled mods makes the game crash, by bisection.
+    ///
+    /// The first call starts the search. The next ones ask for the result of the last test, so the search can be resumed after launching the game,
+    /// even after closing the launcher. Once the culprit is found, or the search is stopped, the mods enabled before the search are enabled again.
+    pub unsafe fn find_crashing_mod(&self) -> Result<()> {
+        let game = self.game_selected().read().unwrap().clone();
+        let mut session = match BisectSession::load(&game)? {
+            Some(session) => session,
+            None => {
+                let mods = self.game_load_order().read().unwrap().mods().to_vec();
+                if mods.len() < 2 {
+                    return Err(anyhow!(tr("bisect_not_enough_mods")));
+                }
+
+                let session = BisectSession::new(&game, &mods)?;
+                if !self.are_you_sure_text(&tre("bisect_start_confirm", &[&mods.len().to_string(), &session.steps_left().to_string()])) {
+                    return Ok(());
+                }
+
+                self.set_mods_enabled_in_mod_list(&session.testing(), session.mods())?;
+                session.save(&game)?;
+
+                show_dialog(self.main_window(), Self::bisect_test_message(&session), true);
+                return Ok(());
+            }
+        };
+
+        let results = session.results().iter()
+            .enumerate()
+            .map(|(index, result)| {
+                let key = if *result.crashed() { "bisect_result_crashed" } else { "bisect_result_worked" };
+                format!("<li>{}</li>", tre(key, &[&(index + 1).to_string(), &result.suspects_tested().to_string()]))
+            })
+            .join("");
+
+        let message_box = QMessageBox::from_q_widget(self.main_window());
+        message_box.set_window_title(&qtr("bisect_title"));
+        message_box.set_icon(q_message_box::Icon::Question);
+        message_box.set_text(&QString::from_std_str(tre("bisect_status", &[
+            &(session.results().len() + 1).to_string(),
+            &session.testing().len().to_string(),
+            &session.suspects().len().to_string(),
+            &session.steps_left().to_string(),
+            &results,
+        ])));
+        message_box.set_detailed_text(&QString::from_std_str(session.suspects().join("\n")));
+        message_box.add_button_q_string_button_role(&qtr("bisect_crashed"), q_message_box::ButtonRole::YesRole);
+        message_box.add_button_q_string_button_role(&qtr("bisect_worked"), q_message_box::ButtonRole::NoRole);
+        message_box.add_button_q_string_button_role(&qtr("bisect_stop"), q_message_box::ButtonRole::DestructiveRole);
+        message_box.add_button_standard_button(q_message_box::StandardButton::Close);
+        message_box.exec();
+
+        let role = message_box.button_role(&message_box.clicked_button());
+        match role {
+            q_message_box::ButtonRole::YesRole | q_message_box::ButtonRole::NoRole => {
+                session.report(role == q_message_box::ButtonRole::YesRole);
+
+                match session.culprit().cloned() {
+                    Some(culprit) => {
+                        info!("Crashing mod search finished. Culprit: {}.", culprit);
+                        self.set_mods_enabled_in_mod_list(session.mods(), session.mods())?;
+                        BisectSession::delete(&game)?;
+
+                        show_dialog(self.main_window(), tre("bisect_found", &[&culprit, &session.results().len().to_string()]), true);
+                    }
+                    None => {
+                        self.set_mods_enabled_in_mod_list(&session.testing(), session.mods())?;
+                        session.save(&game)?;
+
+                        show_dialog(self.main_window(), Self::bisect_test_message(&session), true);
+                    }
+                }
+            }
+            q_message_box::ButtonRole::DestructiveRole => {
+                self.set_mods_enabled_in_mod_list(session.mods(), session.mods())?;
+                BisectSession::delete(&game)?;
+
+                show_dialog(self.main_window(), tr("bisect_stopped"), true);
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// This function returns the message telling the user what to test next in a crashing mod search.
+    fn bisect_test_message(session: &BisectSession) -> String {
+        tre("bisect_test", &[
+            &(session.results().len() + 1).to_string(),
+            &session.testing().len().to_string(),
+            &session.suspects().len().to_string(),
+        ])
+    }
+
+    /// This function returns a random half of the provided mods, rounding up, picked using the provided seed. The result is sorted by name.
+    fn random_subset(mods: &[(String, String)], seed: u64) -> Vec<(String, String)> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut subset = mods.to_vec();
+        subset.shuffle(&mut rng);
+        subset.truncate((mods.len() + 1) / 2);
+        subset.sort_by(|(_, a), (_, b)| a.cmp(b));
+        subset
+    }
+
+    /// This function returns the actions of the game selected bar, with the key of their game, in their default order.
+    unsafe fn game_selected_actions(&self) -> Vec<(&'static str, &QPtr<QAction>)> {
+        vec![
+            (KEY_PHARAOH_DYNASTIES, self.game_selected_pharaoh_dynasties()),
+            (KEY_PHARAOH, self.game_selected_pharaoh()),
+            (KEY_WARHAMMER_3, self.game_selected_warhammer_3()),
+            (KEY_TROY, self.game_selected_troy()),
+            (KEY_THREE_KINGDOMS, self.game_selected_three_kingdoms()),
+            (KEY_WARHAMMER_2, self.game_selected_warhammer_2()),
+            (KEY_WARHAMMER, self.game_selected_warhammer()),
+            (KEY_THRONES_OF_BRITANNIA, self.game_selected_thrones_of_britannia()),
+            (KEY_ATTILA, self.game_selected_attila()),
+            (KEY_ROME_2, self.game_selected_rome_2()),
+            (KEY_SHOGUN_2, self.game_selected_shogun_2()),
+            (KEY_NAPOLEON, self.game_selected_napoleon()),
+            (KEY_EMPIRE, self.game_selected_empire()),
+        ]
+    }
+
+    /// This function sorts the game selected bar, putting the pinned games first, then the most used ones.
+    ///
+    /// Games with the same usage keep their default order.
+    pub unsafe fn sort_game_selected_bar(&self) {
+        let mut actions = self.game_selected_actions();
+        actions.sort_by_key(|(key, _)| (
+            !setting_bool(&format!("game_pinned_{key}")),
+            std::cmp::Reverse(setting_int(&format!("game_usage_count_{key}")))
+        ));
+
+        // Re-adding an action moves it to the end of the bar, without affecting its group or its checked state.
+        for (_, action) in &actions {
+            self.game_selected_bar().remove_action(*action);
+            self.game_selected_bar().add_action(*action);
+        }
+    }
+
+    /// This function shows the context menu of the game selected bar, used to pin games to the top of it.
+    pub unsafe fn game_selected_bar_context_menu(&self, pos: Ref<QPoint>) {
+        let action = self.game_selected_bar().action_at_q_point(pos);
+        if action.is_null() {
+            return;
+        }
+
+        let key = match self.game_selected_actions().iter().find(|(_, game_action)| game_action.as_raw_ptr() == action.as_raw_ptr()) {
+            Some((key, _)) => *key,
+            None => return,
+        };
+
+        let setting_key = format!("game_pinned_{key}");
+        let pinned = setting_bool(&setting_key);
+
+        let menu = QMenu::from_q_widget(self.game_selected_bar());
+        let pin = menu.add_action_q_string(&qtr(if pinned { "game_unpin" } else { "game_pin_to_top" }));
+        pin.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("pin")));
+
+        menu.add_separator();
+        let reset = menu.add_action_q_string(&qtr("game_reset_to_defaults"));
+        reset.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("edit-reset")));
+
+        let triggered = menu.exec_1a_mut(&QCursor::pos_0a());
+        if !triggered.is_null() && triggered.as_raw_ptr() == pin.as_raw_ptr() {
+            set_setting_bool(&setting_key, !pinned);
+            self.sort_game_selected_bar();
+        } else if !triggered.is_null() && triggered.as_raw_ptr() == reset.as_raw_ptr() {
+            match self.reset_game_to_defaults(key) {
+                Ok(true) => show_dialog(self.main_window(), tr("game_reset_done"), true),
+                Ok(false) => {},
+                Err(error) => show_dialog(self.main_window(), error, false),
+            }
+        }
+
+        menu.delete_later();
+    }
+
+    /// This function resets the settings of the provided game to their defaults, after asking the user, who can back them up first.
+    ///
+    /// If the game is the one selected, it's reloaded so the launch options reflect the defaults. Returns false if the user cancelled it.
+    pub unsafe fn reset_game_to_defaults(&self, game_key: &str) -> Result<bool> {
+        let game = SUPPORTED_GAMES.game(game_key).ok_or_else(|| anyhow!("Game {} not supported.", game_key))?;
+
+        let message_box = QMessageBox::from_q_widget(self.main_window());
+        message_box.set_window_title(&qtr("are_you_sure_title"));
+        message_box.set_icon(q_message_box::Icon::Warning);
+        message_box.set_text(&qtre("game_reset_confirm", &[game.display_name()]));
+
+        message_box.add_button_q_string_button_role(&qtr("game_reset_backup"), q_message_box::ButtonRole::AcceptRole);
+        message_box.add_button_q_string_button_role(&qtr("game_reset_no_backup"), q_message_box::ButtonRole::DestructiveRole);
+        message_box.add_button_standard_button(q_message_box::StandardButton::Cancel);
+        message_box.exec();
+
+        match message_box.button_role(&message_box.clicked_button()) {
+            q_message_box::ButtonRole::AcceptRole => {
+                let path = profile_backups::backup()?;
+                info!("Profiles and launch settings backed up to {} before resetting {}.", path.to_string_lossy(), game_key);
+            }
+            q_message_box::ButtonRole::DestructiveRole => {},
+            _ => return Ok(false),
+        }
+
+        reset_game_settings(game);
+
+        if self.game_selected().read().unwrap().key() == game_key {
+            self.actions_ui().reload_button().click();
+        }
+
+        Ok(true)
+    }
+
+    /// This function opens a dialog to reorder the launch options of the play menu, and saves the new order.
+    pub unsafe fn reorder_launch_options(&self) -> Result<()> {
+        let template_path = if cfg!(debug_assertions) { LAUNCH_OPTIONS_ORDER_VIEW_DEBUG } else { LAUNCH_OPTIONS_ORDER_VIEW_RELEASE };
+        let main_widget = load_template(self.main_window(), template_path)?;
+        let dialog = main_widget.static_downcast::<QDialog>();
+
+        let info_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "info_label")?;
+        let options_list_view: QPtr<QListView> = find_widget(&main_widget.static_upcast(), "options_list_view")?;
+
+        dialog.set_window_title(&qtr("launch_options_reorder"));
+        info_label.set_text(&qtr("launch_options_reorder_info"));
+
+        let options_model = QStandardItemModel::new_1a(&options_list_view);
+        options_list_view.set_model(&options_model);
+
+        for key in ActionsUI::launch_options_order() {
+            let text = if key == SCRIPTS_ACTION_KEY { qtr("launch_options_scripts") } else { qtr(&key) };
+            let item = QStandardItem::from_q_string(&text);
+            if let Some((_, icon_key)) = LAUNCH_OPTIONS.iter().find(|(option_key, _)| *option_key == key) {
+                item.set_icon(&QIcon::from_theme_1a(&QString::from_std_str(self.actions_ui().icon_key(&key, icon_key))));
+            }
+
+            // Items can be moved around, but not dropped into other items, or they'll overwrite them.
+            item.set_drop_enabled(false);
+            item.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(&key)), LAUNCH_OPTION_KEY);
+            options_model.append_row_q_standard_item(item.into_ptr());
+        }
+
+        dialog.set_modal(true);
+        if dialog.exec() == 1 {
+            let order = (0..options_model.row_count_0a())
+                .map(|row| options_model.item_1a(row).data_1a(LAUNCH_OPTION_KEY).to_string().to_std_string())
+                .join(",");
+
+            set_setting_string("launch_options_order", &order);
+            self.actions_ui().reorder_launch_options();
+        }
+
+        Ok(())
+    }
+
+    /// This function opens a dialog to execute scripts in a sandbox, showing the changes they would make to the current load order,
+    /// or to the vanilla game.
+    ///
+    /// Scripts are executed alone, with their current params, into a pack that's never loaded by the game.
+    pub unsafe fn script_sandbox(&self) -> Result<()> {
+        let template_path = if cfg!(debug_assertions) { SCRIPT_SANDBOX_VIEW_DEBUG } else { SCRIPT_SANDBOX_VIEW_RELEASE };
+        let main_widget = load_template(self.main_window(), template_path)?;
+        let dialog = main_widget.static_downcast::<QDialog>();
+
+        let info_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "info_label")?;
+        let script_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "script_combobox")?;
+        let vanilla_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "vanilla_checkbox")?;
+        let results_text_edit: QPtr<QPlainTextEdit> = find_widget(&main_widget.static_upcast(), "results_text_edit")?;
+        let button_box: QPtr<QDialogButtonBox> = find_widget(&main_widget.static_upcast(), "button_box")?;
+
+        dialog.set_window_title(&qtr("script_sandbox"));
+        info_label.set_text(&qtr("script_sandbox_info"));
+        vanilla_checkbox.set_text(&qtr("script_sandbox_vanilla"));
+        vanilla_checkbox.set_tool_tip(&qtr("script_sandbox_vanilla_tooltip"));
+        button_box.button(StandardButton::Ok).set_text(&qtr("script_sandbox_run"));
+
+        let copy_button = button_box.add_button_q_string_button_role(&qtr("script_sandbox_copy"), ButtonRole::ActionRole);
+        let copy_slot = SlotNoArgs::new(&dialog, clone!(results_text_edit => move || {
+            QGuiApplication::clipboard().set_text_1a(&results_text_edit.to_plain_text());
+        }));
+        copy_button.released().connect(&copy_slot);
+
+        for (script, _) in self.actions_ui().scripts_to_execute().read().unwrap().iter() {
+            if !*script.metadata().automatic() {
+                let key = script.metadata().key();
+                script_combobox.add_item_q_string_q_variant(&QString::from_std_str(script.metadata().name().trim()), &QVariant::from_q_string(&QString::from_std_str(key)));
+            }
+        }
+
+        if script_combobox.count() == 0 {
+            return Err(anyhow!(tr("script_sandbox_no_scripts")));
+        }
+
+        // The dialog is kept open between runs, so different scripts and params can be tested one after another.
+        dialog.set_modal(true);
+        while dialog.exec() == 1 {
+            let script_key = script_combobox.current_data_0a().to_string().to_std_string();
+            match self.script_sandbox_diff(&script_key, vanilla_checkbox.is_checked()) {
+                Ok(diff) => {
+                    if diff.is_empty() {
+                        results_text_edit.set_plain_text(&qtr("script_sandbox_no_changes"));
+                    } else {
+                        results_text_edit.set_plain_text(&QString::from_std_str(diff));
+                    }
+                }
+                Err(error) => show_dialog(&dialog, error, false),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// This function executes the provided script in the sandbox, and returns the changes it made to the tables of the current load order.
+    ///
+    /// If vanilla is true, the script is executed without mods, and compared against the tables of the game alone.
+    unsafe fn script_sandbox_diff(&self, script_key: &str, vanilla: bool) -> Result<String> {
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+        let data_path = game.data_path(&game_path)?;
+
+        let game_config = self.game_config().read().unwrap().clone().ok_or_else(|| anyhow!(tr("game_config_error")))?;
+        let load_order = if vanilla {
+            LoadOrder::default()
+        } else {
+            self.game_load_order().read().unwrap().clone()
+        };
+
+        // The patcher needs a load order file to know what mods to patch over. Use our own, so we don't touch the game's one.
+        let mut folder_list = String::new();
+        let mut pack_list = String::new();
+        load_order.build_load_order_string(&game_config, &game, &data_path, &mut pack_list, &mut folder_list);
+
+        let mod_list_path = script_sandbox_folder(&game)?.join(CUSTOM_MOD_LIST_FILE_NAME);
+        Self::save_load_order_file(&mod_list_path, &game, &folder_list, &pack_list)?;
+
+        self.toggle_main_window(false);
+
+        // The script is executed from the background thread, as the patcher may take a while with big load orders.
+        let result = script_sandbox_arg(self, &game, script_key).and_then(|script_arg| {
+            let receiver = CENTRAL_COMMAND.send_background(Command::GetScriptSandboxDiff(game_config, game.clone(), game_path, load_order, mod_list_path, script_arg));
+            let response = CENTRAL_COMMAND.recv_try(&receiver);
+            match response {
+                Response::String(diff) => Ok(diff),
+                Response::Error(error) => Err(error),
+                _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+            }
+        });
+
+        self.toggle_main_window(true);
+        result
+    }
+
+    /// This function opens a read-only dialog with the launch settings that are going to be used when launching the game selected,
+    /// their default values, and where their values come from.
+    pub unsafe fn effective_settings(&self) -> Result<()> {
+        let template_path = if cfg!(debug_assertions) { EFFECTIVE_SETTINGS_VIEW_DEBUG } else { EFFECTIVE_SETTINGS_VIEW_RELEASE };
+        let main_widget = load_template(self.main_window(), template_path)?;
+        let dialog = main_widget.static_downcast::<QDialog>();
+
+        let info_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "info_label")?;
+        let filter_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "filter_line_edit")?;
+        let settings_tree_view: QPtr<QTreeView> = find_widget(&main_widget.static_upcast(), "settings_tree_view")?;
+        let button_box: QPtr<QDialogButtonBox> = find_widget(&main_widget.static_upcast(), "button_box")?;
+
+        dialog.set_window_title(&qtr("effective_settings"));
+        info_label.set_text(&qtr("effective_settings_info"));
+        filter_line_edit.set_placeholder_text(&qtr("effective_settings_filter"));
+        let copy_button = button_box.add_button_q_string_button_role(&qtr("effective_settings_copy"), ButtonRole::ActionRole);
+
+        let settings_model = QStandardItemModel::new_1a(&settings_tree_view).into_q_ptr();
+        let settings_filter = QSortFilterProxyModel::new_1a(&settings_tree_view).into_q_ptr();
+        settings_filter.set_source_model(&settings_model);
+        settings_filter.set_filter_case_sensitivity(CaseSensitivity::CaseInsensitive);
+        settings_filter.set_filter_key_column(-1);
+        settings_filter.set_recursive_filtering_enabled(true);
+        settings_tree_view.set_model(&settings_filter);
+
+        settings_model.set_column_count(4);
+        for (column, title) in ["effective_settings_setting", "effective_settings_value", "effective_settings_default", "effective_settings_source"].iter().enumerate() {
+            settings_model.set_horizontal_header_item(column as i32, QStandardItem::from_q_string(&qtr(title)).into_ptr());
+        }
+
+        let new_row = |name: String, value: String, default: String, source: String| {
+            let row = QListOfQStandardItem::new();
+            for text in [name, value, default, source] {
+                let item = QStandardItem::from_q_string(&QString::from_std_str(text));
+                item.set_editable(false);
+                row.append_q_standard_item(&item.into_ptr().as_mut_raw_ptr());
+            }
+            row
+        };
+
+        // Settings disabled for the game are not used on launch, no matter their value.
+        let source = |enabled: bool, is_default: bool| if !enabled {
+            tr("effective_settings_source_unavailable")
+        } else if is_default {
+            tr("effective_settings_source_default")
+        } else {
+            tr("effective_settings_source_override")
+        };
+
+        // Built-in launch options. Their values are taken from the widgets, as that's what's used on launch.
+        let actions_ui = self.actions_ui();
+        let launch_options = QStandardItem::from_q_string(&qtr("effective_settings_launch_options"));
+        launch_options.set_editable(false);
+
+        let checkboxes = [
+            ("enable_logging", actions_ui.enable_logging_checkbox()),
+            ("enable_skip_intro", actions_ui.enable_skip_intro_checkbox()),
+            ("remove_trait_limit", actions_ui.remove_trait_limit_checkbox()),
+            ("remove_siege_attacker", actions_ui.remove_siege_attacker_checkbox()),
+            ("merge_all_mods", actions_ui.merge_all_mods_checkbox()),
+            ("enable_dev_only_ui", actions_ui.enable_dev_only_ui_checkbox()),
+        ];
+
+        for (key, checkbox) in checkboxes {
+            let checked = checkbox.is_checked();
+            launch_options.append_row_q_list_of_q_standard_item(&new_row(tr(key), checked.to_string(), false.to_string(), source(checkbox.is_enabled(), !checked)));
+        }
+
+        for (key, combobox) in [("game_executable", actions_ui.game_executable_combobox()), ("enable_translations", actions_ui.enable_translations_combobox()), ("universal_rebalancer", actions_ui.universal_rebalancer_combobox())] {
+            let value = combobox.item_text(combobox.current_index()).to_std_string();
+            let default = combobox.item_text(0).to_std_string();
+            launch_options.append_row_q_list_of_q_standard_item(&new_row(tr(key), value, default, source(combobox.is_enabled(), combobox.current_index() == 0)));
+        }
+
+        let unit_multiplier = actions_ui.unit_multiplier_spinbox();
+        launch_options.append_row_q_list_of_q_standard_item(&new_row(tr("unit_multiplier"), unit_multiplier.value().to_string(), 1.0.to_string(), source(unit_multiplier.is_enabled(), unit_multiplier.value() == 1.00)));
+
+        for key in ["launch_wrapper_command", "launch_environment_variables"] {
+            let value = setting_string(key);
+            let is_default = value.is_empty();
+            launch_options.append_row_q_list_of_q_standard_item(&new_row(tr(key), value, String::new(), source(true, is_default)));
+        }
+
+        let (extra_args, extra_args_profile) = self.launch_extra_args();
+        let extra_args_source = match extra_args_profile {
+            Some(profile_name) => tre("effective_settings_source_profile", &[&profile_name]),
+            None => source(true, extra_args.is_empty()),
+        };
+        launch_options.append_row_q_list_of_q_standard_item(&new_row(tr("launch_extra_args"), extra_args.join(" "), String::new(), extra_args_source));
+
+        settings_model.append_row_q_standard_item(launch_options.into_ptr());
+
+        // Scripts, with the params they're going to be executed with.
+        let scripts = QStandardItem::from_q_string(&qtr("effective_settings_scripts"));
+        scripts.set_editable(false);
+
+        for (script, checkbox) in actions_ui.scripts_to_execute().read().unwrap().iter() {
+            let checked = checkbox.is_checked();
+            let is_default = checked == *script.metadata().automatic();
+            scripts.append_row_q_list_of_q_standard_item(&new_row(script.metadata().name().trim().to_owned(), checked.to_string(), script.metadata().automatic().to_string(), source(true, is_default)));
+
+            let script_item = scripts.child_1a(scripts.row_count() - 1);
+            let (preset_key, params) = sql_script_params(script, checkbox.as_ptr())?;
+            for (param, value) in script.metadata().parameters().iter().zip(params.into_iter()) {
+                let default = param.default_value().to_string();
+                let param_source = if preset_key.is_empty() {
+                    source(true, value == default)
+                } else {
+                    tre("effective_settings_source_preset", &[&preset_key])
+                };
+
+                script_item.append_row_q_list_of_q_standard_item(&new_row(param.name().to_owned(), value, default, param_source));
+            }
+        }
+
+        settings_model.append_row_q_standard_item(scripts.into_ptr());
+
+        // Custom launch options.
+        let custom_launch_options = QStandardItem::from_q_string(&qtr("effective_settings_custom_launch_options"));
+        custom_launch_options.set_editable(false);
+
+        for (option, _, widget) in actions_ui.custom_launch_options().read().unwrap().iter() {
+            let (value, default) = match option.kind() {
+                CustomLaunchOptionKind::Bool => (widget.static_downcast::<QCheckBox>().is_checked().to_string(), false.to_string()),
+                CustomLaunchOptionKind::Value => (widget.static_downcast::<QDoubleSpinBox>().value().to_string(), option.default_value().to_string()),
+            };
+
+            let is_default = value == default;
+            custom_launch_options.append_row_q_list_of_q_standard_item(&new_row(format!("{} ({})", option.name(), option.flag()), value, default, source(widget.is_enabled(), is_default)));
+        }
+
+        settings_model.append_row_q_standard_item(custom_launch_options.into_ptr());
+
+        settings_tree_view.expand_all();
+        settings_tree_view.header().resize_sections(ResizeMode::ResizeToContents);
+
+        let filter_slot = SlotOfQString::new(&dialog, clone!(settings_filter, settings_tree_view => move |text| {
+            settings_filter.set_filter_fixed_string(text);
+            settings_tree_view.expand_all();
+        }));
+        filter_line_edit.text_changed().connect(&filter_slot);
+
+        // Copy the entire tree, not just the filtered one, so filtering doesn't leave things out of bug reports.
+        let copy_slot = SlotNoArgs::new(&dialog, clone!(settings_model => move || {
+            let mut text = format!("{}\n", tr("effective_settings"));
+            let row_text = |item: Ptr<QStandardItem>, row: i32| (0..4)
+                .map(|column| item.child_2a(row, column).text().to_std_string())
+                .join(" | ");
+
+            for group_row in 0..settings_model.row_count_0a() {
+                let group = settings_model.item_1a(group_row);
+                text.push_str(&format!("\n{}:\n", group.text().to_std_string()));
+
+                for row in 0..group.row_count() {
+                    text.push_str(&format!("  {}\n", row_text(group, row)));
+
+                    let child = group.child_1a(row);
+                    for child_row in 0..child.row_count() {
+                        text.push_str(&format!("    {}\n", row_text(child, child_row)));
+                    }
+                }
+            }
+
+            QGuiApplication::clipboard().set_text_1a(&QString::from_std_str(text));
+        }));
+        copy_button.released().connect(&copy_slot);
+
+        dialog.set_modal(true);
+        dialog.exec();
+
+        Ok(())
+    }
+
+    /// This function opens a dialog to search across mods, profiles and scripts, and jumps to the selected result.
+    pub unsafe fn global_search(&self) -> Result<()> {
+        let template_path = if cfg!(debug_assertions) { GLOBAL_SEARCH_VIEW_DEBUG } else { GLOBAL_SEARCH_VIEW_RELEASE };
+        let main_widget = load_template(self.main_window(), template_path)?;
+        let dialog = main_widget.static_downcast::<QDialog>();
+
+        let search_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "search_line_edit")?;
+        let results_tree_view: QPtr<QTreeView> = find_widget(&main_widget.static_upcast(), "results_tree_view")?;
+        let button_box: QPtr<QDialogButtonBox> = find_widget(&main_widget.static_upcast(), "button_box")?;
+
+        dialog.set_window_title(&qtr("global_search_title"));
+        search_line_edit.set_placeholder_text(&qtr("global_search_placeholder"));
+
+        let results_model = QStandardItemModel::new_1a(&results_tree_view).into_q_ptr();
+        results_tree_view.set_model(&results_model);
+
+        // Get everything we can search for, as (kind, key, text to show).
+        let mut entries = vec![];
+        for cat in 0..self.mod_list_ui().model().row_count_0a() {
+            let category = self.mod_list_ui().model().item_1a(cat);
+            for row in 0..category.row_count() {
+                let item = category.child_1a(row);
+                if !item.is_null() && item.is_checkable() {
+                    let mod_id = item.data_1a(VALUE_MOD_ID).to_string().to_std_string();
+                    let text = format!("{} ({})", item.text().to_std_string(), mod_id);
+                    entries.push(("mod", mod_id, text));
+                }
+            }
+        }
+
+        for row in 0..self.actions_ui().profile_model().row_count_0a() {
+            let profile = self.actions_ui().profile_model().item_1a(row).text().to_std_string();
+            entries.push(("profile", profile.to_owned(), profile));
+        }
+
+        for (script, _) in self.actions_ui().scripts_to_execute().read().unwrap().iter() {
+            if !*script.metadata().automatic() {
+                let key = script.metadata().key().to_owned();
+                let text = format!("{} ({})", script.metadata().name().trim(), key);
+                entries.push(("script", key, text));
+            }
+        }
+
+        // Update the results every time the search changes, grouped by kind.
+        let update_results = Rc::new(clone!(
+            search_line_edit,
+            results_tree_view,
+            button_box,
+            results_model => move || {
+            results_model.clear();
+
+            let pattern = search_line_edit.text().to_std_string().to_lowercase();
+            if !pattern.is_empty() {
+                for (kind, title) in [("mod", "global_search_mods"), ("profile", "global_search_profiles"), ("script", "global_search_scripts")] {
+                    let category = QStandardItem::from_q_string(&qtr(title));
+                    category.set_selectable(false);
+
+                    for (_, key, text) in entries.iter().filter(|(entry_kind, _, text)| *entry_kind == kind && text.to_lowercase().contains(&pattern)) {
+                        let item = QStandardItem::from_q_string(&QString::from_std_str(text));
+                        item.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(kind)), GLOBAL_SEARCH_KIND);
+                        item.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(key)), GLOBAL_SEARCH_KEY);
+                        category.append_row_q_standard_item(item.into_ptr());
+                    }
+
+                    if category.row_count() > 0 {
+                        results_model.append_row_q_standard_item(category.into_ptr());
+                    }
+                }
+            }
+
+            results_tree_view.expand_all();
+
+            // Pre-select the first result, so enter jumps straight to it.
+            let first = results_model.index_2a(0, 0);
+            if first.is_valid() {
+                results_tree_view.set_current_index(&results_model.index_3a(0, 0, &first));
+            }
+
+            button_box.button(StandardButton::Ok).set_enabled(results_model.row_count_0a() > 0);
+        }));
+
+        let update_results_slot = SlotNoArgs::new(&dialog, clone!(update_results => move || update_results()));
+        search_line_edit.text_changed().connect(&update_results_slot);
+        results_tree_view.activated().connect(dialog.slot_accept());
+        update_results();
+
+        dialog.set_modal(true);
+        if dialog.exec() != 1 {
+            return Ok(());
+        }
+
+        let index = results_tree_view.current_index();
+        if !index.is_valid() || !index.parent().is_valid() {
+            return Ok(());
+        }
 
-        if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
-            for category in 0..self.mod_list_ui().model().row_count_0a() {
-                let cat_item = self.mod_list_ui().model().item_2a(category, 0);
-                for mod_row in 0..cat_item.row_count() {
-                    let mod_item = cat_item.child_2a(mod_row, 0);
-                    if !mod_item.is_null() && mod_item.is_checkable() {
-                        let mod_id = mod_item.data_1a(VALUE_MOD_ID).to_string().to_std_string();
-                        if let Some(ref mut modd) = game_config.mods_mut().get_mut(&mod_id) {
-                            modd.set_enabled(mod_item.check_state() == CheckState::Checked);
-                        }
-                    }
+        let kind = index.data_1a(GLOBAL_SEARCH_KIND).to_string().to_std_string();
+        let key = index.data_1a(GLOBAL_SEARCH_KEY).to_string().to_std_string();
+        match &*kind {
+            "mod" => self.focus_mod(&key),
+            "profile" => {
+                self.actions_ui().profile_combobox().set_current_text(&QString::from_std_str(&key));
+                self.actions_ui().profile_combobox().set_focus_0a();
+            },
+            "script" => {
+                if let Some((_, checkbox)) = self.actions_ui().scripts_to_execute().read().unwrap().iter().find(|(script, _)| script.metadata().key() == key) {
+                    checkbox.set_focus_0a();
                 }
-            }
 
-            // Reload the pack view.
-            let game_info = self.game_selected().read().unwrap();
-            let game_path = setting_path(game_info.key());
-            let game_data_path = game_info.data_path(&game_path)?;
-            let mut load_order = self.game_load_order().write().unwrap();
+                self.actions_ui().play_button().show_menu();
+            }
+            _ => {},
+        }
 
-            load_order.update(game_config, &game_info, &game_data_path);
-            load_order.save(&game_info)?;
+        Ok(())
+    }
 
-            setup_actions(&self, &game_info, &game_config, &game_path, &load_order)?;
+    /// This function selects the provided mod in the mod list, and scrolls to it.
+    ///
+    /// If the mod is hidden by the current filter, the filter is cleared.
+    pub unsafe fn focus_mod(&self, mod_id: &str) {
+        let mod_id = QString::from_std_str(mod_id);
+        for cat in 0..self.mod_list_ui().model().row_count_0a() {
+            let category = self.mod_list_ui().model().item_1a(cat);
+            for row in 0..category.row_count() {
+                let item = category.child_1a(row);
+                if !item.is_null() && item.data_1a(VALUE_MOD_ID).to_string().compare_q_string(&mod_id) == 0 {
+                    let mut index = self.mod_list_ui().filter().map_from_source(&item.index());
+                    if !index.is_valid() {
+                        self.mod_list_ui().filter_line_edit().clear();
+                        self.mod_list_ui().filter_list();
+                        index = self.mod_list_ui().filter().map_from_source(&item.index());
+                    }
 
-            self.pack_list_ui().load(game_config, &game_info, &game_path, &load_order)?;
-            self.data_list_ui().set_enabled(false);
-            game_config.save(&game_info)?;
+                    self.mod_list_ui().tree_view().scroll_to_1a(&index);
+                    self.mod_list_ui().tree_view().set_current_index(&index);
+                    self.mod_list_ui().tree_view().set_focus_0a();
+                    return;
+                }
+            }
+        }
+    }
 
-            Ok(())
+    /// This function builds the regex used to match mods by their id or name.
+    ///
+    /// If it's not a regex, the pattern is treated as a case-insensitive wildcard, where `*` matches anything and `?` matches a single character.
+    fn mod_matching_regex(pattern: &str, use_regex: bool) -> Result<Regex> {
+        let pattern = if use_regex {
+            pattern.to_owned()
         } else {
-            Err(anyhow!("WTF?!!! game config is not writable? This is probably a bug."))
-        }
+            format!("(?i)^{}$", regex::escape(pattern).replace("\\*", ".*").replace("\\?", "."))
+        };
+
+        Regex::new(&pattern).map_err(From::from)
     }
 
     pub unsafe fn create_category(&self) -> Result<()> {
@@ -1837,6 +4580,39 @@ impl AppUI {
         Ok(())
     }
 
+    /// This function fills the provided recent profiles menu with the last profiles loaded for the game selected, most recent first.
+    pub unsafe fn generate_recent_profiles_menu(app_ui: &Rc<AppUI>, menu: &QBox<QMenu>) {
+        menu.clear();
+
+        let recent_profiles = app_ui.game_profiles().read().unwrap()
+            .iter()
+            .filter(|(_, profile)| *profile.last_used() > 0)
+            .sorted_by(|(_, a), (_, b)| b.last_used().cmp(a.last_used()))
+            .take(MAX_RECENT_PROFILES)
+            .map(|(name, _)| name.to_owned())
+            .collect::<Vec<_>>();
+
+        if recent_profiles.is_empty() {
+            let action = menu.add_action_q_string(&qtr("recent_profiles_empty"));
+            action.set_enabled(false);
+            return;
+        }
+
+        for profile_name in recent_profiles {
+            let action = menu.add_action_q_string(&QString::from_std_str(&profile_name));
+            let slot = SlotNoArgs::new(menu, clone!(
+                app_ui => move || {
+                    app_ui.actions_ui().profile_combobox().set_current_text(&QString::from_std_str(&profile_name));
+                    if let Err(error) = app_ui.load_profile(Some(profile_name.to_owned()), false) {
+                        show_dialog(app_ui.main_window(), error, false);
+                    }
+                }
+            ));
+
+            action.triggered().connect(&slot);
+        }
+    }
+
     pub unsafe fn generate_open_in_tools_submenu(app_ui: &Rc<AppUI>) {
         let menu = app_ui.mod_list_ui().open_in_tool_menu();
         menu.clear();
@@ -1874,6 +4650,62 @@ impl AppUI {
         }
     }
 
+    /// This function fills the submenu to open the folders containing the packs of the selected mod, one entry per location the mod is in.
+    pub unsafe fn generate_open_mod_folder_submenu(app_ui: &Rc<AppUI>) {
+        let menu = app_ui.mod_list_ui().open_mod_folder_menu();
+        menu.clear();
+
+        let selection = app_ui.mod_list_selection();
+        let mod_id = match selection.first() {
+            Some(index) if selection.len() == 1 && !index.data_1a(VALUE_IS_CATEGORY).to_bool() => index.data_1a(VALUE_MOD_ID).to_string().to_std_string(),
+            _ => {
+                menu.set_enabled(false);
+                return;
+            }
+        };
+
+        let paths = match *app_ui.game_config().read().unwrap() {
+            Some(ref game_config) => game_config.mods().get(&mod_id).map(|modd| modd.paths().to_vec()).unwrap_or_default(),
+            None => vec![],
+        };
+
+        let game = app_ui.game_selected().read().unwrap();
+        let game_path = setting_path(game.key());
+        let data_path = game.data_path(&game_path).ok();
+        let secondary_paths = secondary_mods_paths(game.key());
+        let content_path = game.content_path(&game_path).ok();
+
+        for path in &paths {
+            let folder = match path.parent() {
+                Some(folder) => folder.to_path_buf(),
+                None => continue,
+            };
+
+            let location = if data_path.as_ref().map_or(false, |data_path| path.starts_with(data_path)) {
+                tr("open_mod_folder_data")
+            } else if secondary_paths.iter().any(|secondary_path| path.starts_with(secondary_path)) {
+                tr("open_mod_folder_secondary")
+            } else if content_path.as_ref().map_or(false, |content_path| path.starts_with(content_path)) {
+                tr("open_mod_folder_content")
+            } else {
+                tr("open_mod_folder_other")
+            };
+
+            let action = menu.add_action_q_string(&QString::from_std_str(format!("{}: {}", location, folder.to_string_lossy())));
+            let slot = SlotNoArgs::new(menu, clone!(
+                app_ui => move || {
+                    if let Err(error) = open::that(&folder) {
+                        show_dialog(app_ui.main_window(), error, false);
+                    }
+                }
+            ));
+
+            action.triggered().connect(&slot);
+        }
+
+        menu.set_enabled(!paths.is_empty());
+    }
+
     /// Function to move files from /content to /secondary, or /data.
     fn move_to_destination(&self, data_path: &Path, secondary_path: &Option<PathBuf>, steam_user_id: &str, game: &GameInfo, modd: &mut Mod, mod_name: &str, pack: &mut Pack, new_pack_type: bool) -> Result<()> {
 
@@ -2262,109 +5094,548 @@ impl AppUI {
                         PreUploadInfo::default()
                     };
 
-                    // If no errors were found, load the UI Template.
-                    let template_path = if cfg!(debug_assertions) { WORKSHOP_UPLOAD_VIEW_DEBUG } else { WORKSHOP_UPLOAD_VIEW_RELEASE };
-                    let main_widget = load_template(self.main_window(), template_path)?;
-                    let dialog = main_widget.static_downcast::<QDialog>();
+                    // If no errors were found, load the UI Template.
+                    let template_path = if cfg!(debug_assertions) { WORKSHOP_UPLOAD_VIEW_DEBUG } else { WORKSHOP_UPLOAD_VIEW_RELEASE };
+                    let main_widget = load_template(self.main_window(), template_path)?;
+                    let dialog = main_widget.static_downcast::<QDialog>();
+
+                    let title_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "title_label")?;
+                    let description_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "description_label")?;
+                    let changelog_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "changelog_label")?;
+                    let tag_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "tag_label")?;
+                    let visibility_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "visibility_label")?;
+
+                    let title_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "title_line_edit")?;
+                    let description_text_edit: QPtr<QTextEdit> = find_widget(&main_widget.static_upcast(), "description_text_edit")?;
+                    let changelog_text_edit: QPtr<QTextEdit> = find_widget(&main_widget.static_upcast(), "changelog_text_edit")?;
+                    let tag_combo_box: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "tag_combo_box")?;
+                    let visibility_combo_box: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "visibility_combo_box")?;
+
+                    let button_box: QPtr<QDialogButtonBox> = find_widget(&main_widget.static_upcast(), "button_box")?;
+                    button_box.button(StandardButton::Ok).released().connect(dialog.slot_accept());
+
+                    dialog.set_window_title(&qtr("upload_to_workshop_title"));
+                    title_label.set_text(&qtr("upload_workshop_title"));
+                    description_label.set_text(&qtr("upload_workshop_description"));
+                    changelog_label.set_text(&qtr("upload_workshop_changelog"));
+                    tag_label.set_text(&qtr("upload_workshop_tag"));
+                    visibility_label.set_text(&qtr("upload_workshop_visibility"));
+
+                    let tags = game.steam_workshop_tags()?;
+                    for tag in &tags {
+                        tag_combo_box.add_item_q_string(&QString::from_std_str(tag));
+                    }
+
+                    visibility_combo_box.add_item_q_string(&qtr("upload_workshop_visibility_public"));
+                    visibility_combo_box.add_item_q_string(&qtr("upload_workshop_visibility_friends_only"));
+                    visibility_combo_box.add_item_q_string(&qtr("upload_workshop_visibility_private"));
+                    visibility_combo_box.add_item_q_string(&qtr("upload_workshop_visibility_unlisted"));
+
+                    // If we got data from the workshop, populate it with that.
+                    if mod_data.published_file_id > 0 {
+                        title_line_edit.set_text(&QString::from_std_str(mod_data.title));
+                        description_text_edit.set_plain_text(&QString::from_std_str(mod_data.description));
+                        changelog_text_edit.set_plain_text(&QString::from_std_str("me forgot changelog. Me sorry."));
+
+                        // For tag selection, we expect to have two. We need to pick the one that's not "mod".
+                        if let Some(selected_tag) = mod_data.tags.iter().find_or_first(|x| &**x != "mod") {
+                            tag_combo_box.set_current_text(&QString::from_std_str(selected_tag));
+                        }
+
+                        visibility_combo_box.set_current_index(match mod_data.visibility {
+                            PublishedFileVisibilityDerive::Public => 0,
+                            PublishedFileVisibilityDerive::FriendsOnly => 1,
+                            PublishedFileVisibilityDerive::Private => 2,
+                            PublishedFileVisibilityDerive::Unlisted => 3,
+                        });
+                    }
+
+                    // Otherwise, put default data there.
+                    else {
+                        title_line_edit.set_text(&QString::from_std_str(modd.id()));
+                        changelog_text_edit.set_plain_text(&QString::from_std_str("Initial release."));
+                        visibility_combo_box.set_current_index(2);
+                    }
+
+                    if dialog.exec() == 1 {
+                        let mut title = title_line_edit.text().to_std_string();
+                        let description = description_text_edit.to_plain_text().to_std_string();
+                        let changelog = changelog_text_edit.to_plain_text().to_std_string();
+                        let tags = vec![tag_combo_box.current_text().to_std_string()];
+                        let visibility = visibility_combo_box.current_index() as u32;
+
+                        // We need at least a title. So if we don't have one, use the default one.
+                        if title.is_empty() {
+                            title = modd.id().to_string();
+                        }
+
+                        crate::mod_manager::integrations::upload_mod_to_workshop(&game, modd, &title, &description, &tags, &changelog, &Some(visibility), true).map(Some)
+                    } else {
+                        Ok(None)
+                    }
+
+                    // All the following elses should never really trigger unless it's a bug.
+                } else {
+                    Ok(None)
+                }
+            } else {
+                Ok(None)
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// This function downloads the provided mods, or all the missing subscribed mods if none are provided, and reloads the mod list.
+    ///
+    /// Returns the mods that got updated in the process.
+    pub unsafe fn download_subscribed_mods(&self, published_file_ids: &Option<Vec<String>>) -> Result<Vec<ModUpdate>> {
+
+        // Warn the user before starting if the mods are not going to fit in the drive they're downloaded to.
+        if !self.confirm_download_space(published_file_ids) {
+            return Ok(vec![]);
+        }
+
+        self.download_subscribed_mods_no_space_check(published_file_ids)
+    }
+
+    /// This function asks the user to confirm the download if the provided mods are not going to fit in the drive they're downloaded to.
+    ///
+    /// Returns false if the user cancelled it.
+    pub unsafe fn confirm_download_space(&self, published_file_ids: &Option<Vec<String>>) -> bool {
+        match self.download_space_shortage(published_file_ids) {
+            Some((required, available, path)) => {
+                let message = tre("download_disk_space_warning", &[
+                    &format!("{:.2} MB", required as f64 / 1024.0 / 1024.0),
+                    &path.to_string_lossy(),
+                    &format!("{:.2} MB", available as f64 / 1024.0 / 1024.0),
+                ]);
+
+                self.are_you_sure_text(&message)
+            }
+            None => true,
+        }
+    }
+
+    /// This function downloads the provided mods like [Self::download_subscribed_mods], for when the free space has already been checked.
+    unsafe fn download_subscribed_mods_no_space_check(&self, published_file_ids: &Option<Vec<String>>) -> Result<Vec<ModUpdate>> {
+        let times_updated_old = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => ModUpdates::times_updated(game_config.mods()),
+            None => HashMap::new(),
+        };
+
+        self.toggle_main_window(false);
+
+        crate::mod_manager::integrations::download_subscribed_mods(&self.game_selected().read().unwrap(), published_file_ids)?;
+
+        self.toggle_main_window(true);
+
+        // Once done, do a reload of the mod list.
+        self.actions_ui().reload_button().click();
+
+        // Then check what got updated, and keep it so it can be checked later.
+        let game = self.game_selected().read().unwrap();
+        let mut mod_updates = ModUpdates::load(&game, true)?;
+        let updates = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => mod_updates.add_updates(game_config.mods(), &times_updated_old),
+            None => vec![],
+        };
+
+        if !updates.is_empty() {
+            mod_updates.save(&game)?;
+        }
+
+        Ok(updates)
+    }
+
+    /// This function removes the local copy of the provided workshop mod and downloads it again, to fix it when its files got corrupted.
+    ///
+    /// Returns the mods that got updated in the process, or None if the user cancelled it.
+    pub unsafe fn repair_from_workshop(&self, mod_id: &str) -> Result<Option<Vec<ModUpdate>>> {
+        let steam_id = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => game_config.mods()
+                .get(mod_id)
+                .and_then(|modd| modd.steam_id().clone())
+                .ok_or_else(|| anyhow!(tre("repair_from_workshop_not_workshop", &[mod_id])))?,
+            None => return Ok(None),
+        };
+
+        if !self.are_you_sure_text(&tre("repair_from_workshop_confirm", &[mod_id])) {
+            return Ok(None);
+        }
+
+        // Check the space before removing anything, so a cancelled download doesn't leave the mod removed.
+        let published_file_ids = Some(vec![steam_id]);
+        if !self.confirm_download_space(&published_file_ids) {
+            return Ok(None);
+        }
+
+        {
+            let game = self.game_selected().read().unwrap();
+            if let Some(ref game_config) = *self.game_config().read().unwrap() {
+                if !remove_from_content(&game, game_config, &[mod_id.to_owned()])?.is_empty() {
+                    return Err(anyhow!(tre("repair_from_workshop_remove_failed", &[mod_id])));
+                }
+            }
+        }
+
+        self.download_subscribed_mods_no_space_check(&published_file_ids).map(Some)
+    }
+
+    /// This function estimates the space needed to download the provided mods, or all the missing subscribed mods if none are provided,
+    /// and compares it against the free space of the drive they're downloaded to.
+    ///
+    /// Returns the required space, the free space and the download path if the mods are not going to fit.
+    /// The estimate uses the sizes reported by the workshop, so mods without online data are not counted.
+    pub unsafe fn download_space_shortage(&self, published_file_ids: &Option<Vec<String>>) -> Option<(u64, u64, PathBuf)> {
+        let game = self.game_selected().read().unwrap();
+        let content_path = game.content_path(&setting_path(game.key())).ok()?;
+        let available = free_disk_space(&content_path)?;
+
+        let required = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => game_config.mods()
+                .values()
+                .filter(|modd| match (published_file_ids, modd.steam_id()) {
+                    (Some(ids), Some(steam_id)) => ids.contains(steam_id),
+                    (None, Some(_)) => modd.paths().is_empty(),
+                    (_, None) => false,
+                })
+                .map(|modd| *modd.file_size())
+                .sum(),
+            None => 0,
+        };
+
+        if required + DOWNLOAD_MIN_FREE_SPACE > available {
+            Some((required, available, content_path))
+        } else {
+            None
+        }
+    }
+
+    /// This function shows a dialog with the provided mod updates, or with the last updates detected for the game selected if none are provided.
+    pub unsafe fn mod_updates_dialog(&self, updates: Option<&[ModUpdate]>) -> Result<()> {
+        let template_path = if cfg!(debug_assertions) { MOD_UPDATES_VIEW_DEBUG } else { MOD_UPDATES_VIEW_RELEASE };
+        let main_widget = load_template(self.main_window(), template_path)?;
+        let dialog = main_widget.static_downcast::<QDialog>();
+
+        let info_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "info_label")?;
+        let updates_text_browser: QPtr<QTextBrowser> = find_widget(&main_widget.static_upcast(), "updates_text_browser")?;
+        updates_text_browser.set_open_external_links(true);
+
+        let cached_updates;
+        let updates = match updates {
+            Some(updates) => {
+                dialog.set_window_title(&qtr("mod_updates_downloaded_title"));
+                info_label.set_text(&qtr("mods_downloaded"));
+                updates
+            }
+            None => {
+                dialog.set_window_title(&qtr("mod_updates_title"));
+                info_label.set_text(&qtr("mod_updates_info"));
+                cached_updates = ModUpdates::load(&self.game_selected().read().unwrap(), true)?;
+                cached_updates.updates()
+            }
+        };
+
+        let date_format = time::format_description::parse(&setting_string("date_format")).ok();
+        let format_date = |timestamp: u64| date_format.as_ref()
+            .and_then(|date_format| OffsetDateTime::from_unix_timestamp(timestamp as i64).ok()?.format(date_format).ok())
+            .unwrap_or_default();
+
+        let mut html = String::new();
+        if updates.is_empty() {
+            html.push_str(&tr("mod_updates_empty"));
+        } else {
+            html.push_str("<ul>");
+            for update in updates {
+                html.push_str(&format!("<li><b>{}</b>: {} -> {} <a href=\"{}\">({})</a></li>",
+                    update.name(),
+                    format_date(*update.time_updated_old()),
+                    format_date(*update.time_updated_new()),
+                    update.change_notes_url(),
+                    tr("mod_updates_change_notes"),
+                ));
+            }
+            html.push_str("</ul>");
+        }
+
+        updates_text_browser.set_html(&QString::from_std_str(html));
+
+        dialog.set_modal(true);
+        dialog.exec();
+
+        Ok(())
+    }
+
+    /// This function creates a zip with the latest crash dump of the game selected, its load order, mod list and launch options, to attach it to bug reports.
+    ///
+    /// Paths to the user's home folder and the user name are redacted from the text files. Files that cannot be read are skipped.
+    /// Returns the path of the zip, which is created in the error folder of Runcher.
+    pub unsafe fn create_crash_report(&self) -> Result<PathBuf> {
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+
+        // Not all crashes leave a dump behind, so we still create the report without it.
+        let latest_dump = crash_dumps_path(&game).ok()
+            .filter(|path| path.is_dir())
+            .and_then(|path| files_from_subdir(&path, false).ok())
+            .and_then(|paths| paths.into_iter()
+                .filter(|path| path.extension().map(|extension| extension.eq_ignore_ascii_case("dmp")).unwrap_or_default())
+                .max_by_key(|path| path.metadata().and_then(|metadata| metadata.modified()).unwrap_or(UNIX_EPOCH))
+            );
+
+        let load_order = self.load_order_report(&game)?;
+        let mod_list_path = Self::mod_list_file_path(&game, &game_path);
+        let launch_options = self.launch_options_report(&game);
+
+        let report_path = error_path()?.join(format!("crash_report_{}_{}.zip", game.key(), SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs()));
+        let mut zip = ZipWriter::new(BufWriter::new(File::create(&report_path)?));
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+        // Dumps are binary, so they cannot be redacted. They can also be big, so they're streamed instead of read into memory.
+        if let Some(ref dump_path) = latest_dump {
+            if let Some(file_name) = dump_path.file_name() {
+                match File::open(dump_path) {
+                    Ok(file) => {
+                        zip.start_file(file_name.to_string_lossy().to_string(), options)?;
+                        std::io::copy(&mut BufReader::new(file), &mut zip)?;
+                    }
+                    Err(error) => warn!("Skipping {} from the report, as it cannot be read: {}", dump_path.to_string_lossy(), error),
+                }
+            }
+        }
+
+        if let Some(ref mod_list_path) = mod_list_path {
+            if let Some(file_name) = mod_list_path.file_name().filter(|_| mod_list_path.is_file()) {
+                if let Some(data) = Self::read_report_file(mod_list_path) {
+                    zip.start_file(file_name.to_string_lossy().to_string(), options)?;
+                    zip.write_all(Self::redact_diagnostics(&String::from_utf8_lossy(&data)).as_bytes())?;
+                }
+            }
+        }
+
+        zip.start_file("load_order.txt", options)?;
+        zip.write_all(Self::redact_diagnostics(&load_order).as_bytes())?;
+
+        zip.start_file("launch_options.txt", options)?;
+        zip.write_all(Self::redact_diagnostics(&launch_options).as_bytes())?;
+
+        zip.finish()?;
+        Ok(report_path)
+    }
+
+    /// This function creates a zip with the latest logs of the game selected and Runcher, the effective launch settings,
+    /// the load order and some basic system info, to attach it to help requests.
+    ///
+    /// Paths to the user's home folder and the user name are redacted from the text files. Files that cannot be read, like logs
+    /// still locked by the game, are skipped. Returns the path of the zip, which is created in the error folder of Runcher.
+    pub unsafe fn create_diagnostics_bundle(&self) -> Result<PathBuf> {
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+
+        let load_order = self.load_order_report(&game)?;
+        let mod_list_path = Self::mod_list_file_path(&game, &game_path);
+        let launch_options = self.launch_options_report(&game);
+
+        let system_info = [
+            format!("runcher version: {}", crate::VERSION),
+            format!("os: {} ({})", std::env::consts::OS, std::env::consts::ARCH),
+            format!("game: {}", game.key()),
+            format!("game path: {}", game_path.to_string_lossy()),
+            format!("game last update date: {}", last_game_update_date(&game, &game_path).unwrap_or_default()),
+            format!("secondary mods path: {}", secondary_mods_path(game.key()).map(|path| path.to_string_lossy().to_string()).unwrap_or_default()),
+            format!("secondary mods extra paths: {}", secondary_mods_extra_paths(game.key()).iter().map(|path| path.to_string_lossy().to_string()).collect::<Vec<_>>().join(", ")),
+            format!("generated packs path: {}", generated_packs_path().map(|path| path.to_string_lossy().to_string()).unwrap_or_default()),
+        ].join("\n");
+
+        // Only the latest logs are useful, and the older ones can get really big.
+        let game_logs = LogUI::log_paths(&game_path, &UNIX_EPOCH);
+        let game_logs = &game_logs[game_logs.len().saturating_sub(DIAGNOSTICS_MAX_LOGS)..];
+
+        let mut runcher_logs = files_from_subdir(&error_path()?, false)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|path| path.extension().map(|extension| extension.eq_ignore_ascii_case("log")).unwrap_or_default())
+            .collect::<Vec<_>>();
+        runcher_logs.sort_by_key(|path| path.metadata().and_then(|metadata| metadata.modified()).unwrap_or(UNIX_EPOCH));
+        let runcher_logs = &runcher_logs[runcher_logs.len().saturating_sub(DIAGNOSTICS_MAX_LOGS)..];
+
+        let report_path = error_path()?.join(format!("diagnostics_{}_{}.zip", game.key(), SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs()));
+        let mut zip = ZipWriter::new(BufWriter::new(File::create(&report_path)?));
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+        let logs = game_logs.iter().map(|path| ("game_logs", path))
+            .chain(runcher_logs.iter().map(|path| ("runcher_logs", path)))
+            .chain(mod_list_path.iter().filter(|path| path.is_file()).map(|path| ("", path)));
+
+        for (folder, path) in logs {
+            if let Some(file_name) = path.file_name() {
+                let data = match Self::read_report_file(path) {
+                    Some(data) => data,
+                    None => continue,
+                };
+
+                let zip_path = if folder.is_empty() { file_name.to_string_lossy().to_string() } else { format!("{folder}/{}", file_name.to_string_lossy()) };
 
-                    let title_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "title_label")?;
-                    let description_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "description_label")?;
-                    let changelog_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "changelog_label")?;
-                    let tag_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "tag_label")?;
-                    let visibility_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "visibility_label")?;
+                zip.start_file(zip_path, options)?;
+                zip.write_all(Self::redact_diagnostics(&String::from_utf8_lossy(&data)).as_bytes())?;
+            }
+        }
 
-                    let title_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "title_line_edit")?;
-                    let description_text_edit: QPtr<QTextEdit> = find_widget(&main_widget.static_upcast(), "description_text_edit")?;
-                    let changelog_text_edit: QPtr<QTextEdit> = find_widget(&main_widget.static_upcast(), "changelog_text_edit")?;
-                    let tag_combo_box: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "tag_combo_box")?;
-                    let visibility_combo_box: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "visibility_combo_box")?;
+        zip.start_file("load_order.txt", options)?;
+        zip.write_all(Self::redact_diagnostics(&load_order).as_bytes())?;
 
-                    let button_box: QPtr<QDialogButtonBox> = find_widget(&main_widget.static_upcast(), "button_box")?;
-                    button_box.button(StandardButton::Ok).released().connect(dialog.slot_accept());
+        zip.start_file("launch_options.txt", options)?;
+        zip.write_all(Self::redact_diagnostics(&launch_options).as_bytes())?;
 
-                    dialog.set_window_title(&qtr("upload_to_workshop_title"));
-                    title_label.set_text(&qtr("upload_workshop_title"));
-                    description_label.set_text(&qtr("upload_workshop_description"));
-                    changelog_label.set_text(&qtr("upload_workshop_changelog"));
-                    tag_label.set_text(&qtr("upload_workshop_tag"));
-                    visibility_label.set_text(&qtr("upload_workshop_visibility"));
+        zip.start_file("system_info.txt", options)?;
+        zip.write_all(Self::redact_diagnostics(&system_info).as_bytes())?;
 
-                    let tags = game.steam_workshop_tags()?;
-                    for tag in &tags {
-                        tag_combo_box.add_item_q_string(&QString::from_std_str(tag));
-                    }
+        zip.finish()?;
+        Ok(report_path)
+    }
 
-                    visibility_combo_box.add_item_q_string(&qtr("upload_workshop_visibility_public"));
-                    visibility_combo_box.add_item_q_string(&qtr("upload_workshop_visibility_friends_only"));
-                    visibility_combo_box.add_item_q_string(&qtr("upload_workshop_visibility_private"));
-                    visibility_combo_box.add_item_q_string(&qtr("upload_workshop_visibility_unlisted"));
+    /// This function exports the entire configuration of Runcher to a bundle file chosen by the user, to move it to another machine.
+    ///
+    /// Returns the path of the bundle, or None if the user cancelled it.
+    pub unsafe fn export_config_bundle(&self) -> Result<Option<PathBuf>> {
+        let file_dialog = QFileDialog::from_q_widget_q_string(
+            self.main_window(),
+            &qtr("export_config_bundle"),
+        );
+
+        file_dialog.set_accept_mode(AcceptMode::AcceptSave);
+        file_dialog.set_name_filter(&qtr("config_bundle_filter"));
+        file_dialog.set_default_suffix(&QString::from_std_str("zip"));
+        file_dialog.select_file(&QString::from_std_str(format!("runcher_config_{}.zip", SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs())));
+
+        if file_dialog.exec() == 1 {
+            let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+            ConfigBundle::new()?.save(&path)?;
+            Ok(Some(path))
+        } else {
+            Ok(None)
+        }
+    }
 
-                    // If we got data from the workshop, populate it with that.
-                    if mod_data.published_file_id > 0 {
-                        title_line_edit.set_text(&QString::from_std_str(mod_data.title));
-                        description_text_edit.set_plain_text(&QString::from_std_str(mod_data.description));
-                        changelog_text_edit.set_plain_text(&QString::from_std_str("me forgot changelog. Me sorry."));
+    /// This function imports the configuration of a bundle file chosen by the user, and reloads everything with it.
+    ///
+    /// Returns what was skipped because it failed validation, or None if the user cancelled it.
+    pub unsafe fn import_config_bundle(&self) -> Result<Option<Vec<String>>> {
+        let file_dialog = QFileDialog::from_q_widget_q_string(
+            self.main_window(),
+            &qtr("import_config_bundle"),
+        );
 
-                        // For tag selection, we expect to have two. We need to pick the one that's not "mod".
-                        if let Some(selected_tag) = mod_data.tags.iter().find_or_first(|x| &**x != "mod") {
-                            tag_combo_box.set_current_text(&QString::from_std_str(selected_tag));
-                        }
+        file_dialog.set_file_mode(FileMode::ExistingFile);
+        file_dialog.set_name_filter(&qtr("config_bundle_filter"));
 
-                        visibility_combo_box.set_current_index(match mod_data.visibility {
-                            PublishedFileVisibilityDerive::Public => 0,
-                            PublishedFileVisibilityDerive::FriendsOnly => 1,
-                            PublishedFileVisibilityDerive::Private => 2,
-                            PublishedFileVisibilityDerive::Unlisted => 3,
-                        });
-                    }
+        if file_dialog.exec() != 1 {
+            return Ok(None);
+        }
 
-                    // Otherwise, put default data there.
-                    else {
-                        title_line_edit.set_text(&QString::from_std_str(modd.id()));
-                        changelog_text_edit.set_plain_text(&QString::from_std_str("Initial release."));
-                        visibility_combo_box.set_current_index(2);
-                    }
+        let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+        let bundle = ConfigBundle::load(&path)?;
 
-                    if dialog.exec() == 1 {
-                        let mut title = title_line_edit.text().to_std_string();
-                        let description = description_text_edit.to_plain_text().to_std_string();
-                        let changelog = changelog_text_edit.to_plain_text().to_std_string();
-                        let tags = vec![tag_combo_box.current_text().to_std_string()];
-                        let visibility = visibility_combo_box.current_index() as u32;
+        let message = tre("config_bundle_import_confirm", &[
+            bundle.runcher_version(),
+            &bundle.files().len().to_string(),
+            &bundle.settings().len().to_string(),
+        ]);
 
-                        // We need at least a title. So if we don't have one, use the default one.
-                        if title.is_empty() {
-                            title = modd.id().to_string();
-                        }
+        if !self.are_you_sure_text(&message) {
+            return Ok(None);
+        }
 
-                        crate::mod_manager::integrations::upload_mod_to_workshop(&game, modd, &title, &description, &tags, &changelog, &Some(visibility), true).map(Some)
-                    } else {
-                        Ok(None)
-                    }
+        let mut applied = self.applied_settings();
+        bundle.install()?;
 
-                    // All the following elses should never really trigger unless it's a bug.
-                } else {
-                    Ok(None)
-                }
-            } else {
-                Ok(None)
+        // Apply the settings first, as they may change the game paths, then reload the game to load the imported profiles and configs.
+        self.apply_settings(&mut applied);
+        self.actions_ui().reload_button().click();
+
+        Ok(Some(bundle.skipped().to_vec()))
+    }
+
+    /// This function reads a file to include in a crash report or diagnostics bundle.
+    ///
+    /// A file we cannot read shouldn't stop the rest of the report from being made, so errors are logged and None is returned.
+    fn read_report_file(path: &Path) -> Option<Vec<u8>> {
+        match std::fs::read(path) {
+            Ok(data) => Some(data),
+            Err(error) => {
+                warn!("Skipping {} from the report, as it cannot be read: {}", path.to_string_lossy(), error);
+                None
             }
-        } else {
-            Ok(None)
         }
     }
 
-    pub unsafe fn download_subscribed_mods(&self, published_file_ids: &Option<Vec<String>>) -> Result<()> {
-        self.toggle_main_window(false);
+    /// This function replaces the paths to the user's home folder and the user name in the provided text, so they don't end up in shared diagnostics.
+    fn redact_diagnostics(text: &str) -> String {
+        let mut text = text.to_owned();
+        for (var, replacement) in [("USERPROFILE", "<HOME>"), ("HOME", "<HOME>"), ("USERNAME", "<USER>"), ("USER", "<USER>")] {
+            if let Ok(value) = std::env::var(var) {
+                if value.len() > 1 {
+                    text = text.replace(&value, replacement);
 
-        crate::mod_manager::integrations::download_subscribed_mods(&self.game_selected().read().unwrap(), published_file_ids)?;
+                    // Windows paths may appear with either separator.
+                    text = text.replace(&value.replace('\\', "/"), replacement);
+                }
+            }
+        }
 
-        self.toggle_main_window(true);
+        text
+    }
 
-        // Once done, do a reload of the mod list.
-        self.actions_ui().reload_button().click();
+    /// This function returns the load order of the provided game as text, like when copying it.
+    unsafe fn load_order_report(&self, game: &GameInfo) -> Result<String> {
+        let game_path = setting_path(game.key());
+        let data_path = game.data_path(&game_path)?;
 
-        Ok(())
+        match *self.game_config().read().unwrap() {
+            Some(ref game_config) => {
+                let load_order = self.game_load_order().read().unwrap().clone();
+                let receiver = CENTRAL_COMMAND.send_background(Command::GetStringFromLoadOrder(game_config.clone(), game.clone(), data_path, load_order));
+                let response = CENTRAL_COMMAND.recv_try(&receiver);
+                match response {
+                    Response::String(response) => Ok(response),
+                    Response::Error(error) => Err(error),
+                    _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+                }
+            }
+            None => Err(anyhow!(tr("game_config_error"))),
+        }
+    }
+
+    /// This function returns the path of the file the mod list is written to on launch, for the provided game.
+    ///
+    /// Same logic as when launching the game: older games use the user script instead of the custom mod list.
+    fn mod_list_file_path(game: &GameInfo, game_path: &Path) -> Option<PathBuf> {
+        if *game.raw_db_version() >= 1 {
+            Some(game_path.join(CUSTOM_MOD_LIST_FILE_NAME))
+        } else {
+            game.config_path(game_path).map(|config_path| config_path.join("scripts").join(if game.key() == KEY_EMPIRE { USER_SCRIPT_EMPIRE_FILE_NAME } else { USER_SCRIPT_FILE_NAME }))
+        }
+    }
+
+    /// This function returns the launch options of the provided game as text.
+    ///
+    /// Launch options are stored in the settings with the game key in them, so we just dump those, alongside the global launch settings.
+    unsafe fn launch_options_report(&self, game: &GameInfo) -> String {
+        let q_settings = settings();
+        let all_keys = q_settings.all_keys();
+        let game_key_suffix = format!("_{}", game.key());
+        let game_key_infix = format!("_{}_", game.key());
+        let mut launch_options = (0..all_keys.size())
+            .map(|index| all_keys.at(index).to_std_string())
+            .filter(|key| key.ends_with(&game_key_suffix) || key.contains(&game_key_infix) || key == "launch_wrapper_command" || key == "launch_environment_variables")
+            .sorted()
+            .map(|key| format!("{key} = {}", q_settings.value_1a(&QString::from_std_str(&key)).to_string().to_std_string()))
+            .join("\n");
+
+        launch_options.push_str(&format!("\n\ncustom launch options args: {}", self.actions_ui().custom_launch_options_args().join(" ")));
+        launch_options.push_str(&format!("\nextra launch args: {}", self.launch_extra_args().0.join(" ")));
+        launch_options
     }
 
     pub unsafe fn check_logs(&self, game: &GameInfo, game_path: &Path, start_date: &SystemTime) -> Result<()> {
@@ -2669,6 +5940,424 @@ impl AppUI {
         Ok(())
     }
 
+    /// This function checks if any pack of the enabled mods has more than one copy, and lets the user disable the mods of the duplicates.
+    ///
+    /// Returns false if the user cancelled the dialog.
+    pub unsafe fn check_duplicated_packs(&self) -> Result<bool> {
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+        let game_data_path = game.data_path(&game_path)?;
+
+        let duplicates = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => game_config.duplicated_packs(&game, &game_data_path),
+            None => return Ok(true),
+        };
+
+        if duplicates.is_empty() {
+            return Ok(true);
+        }
+
+        let template_path = if cfg!(debug_assertions) { DUPLICATED_PACKS_VIEW_DEBUG } else { DUPLICATED_PACKS_VIEW_RELEASE };
+        let main_widget = load_template(self.main_window(), template_path)?;
+        let dialog = main_widget.static_downcast::<QDialog>();
+
+        let explanation_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "explanation_label")?;
+        let explanation_groupbox: QPtr<QGroupBox> = find_widget(&main_widget.static_upcast(), "explanation_groupbox")?;
+        let duplicates_table_view: QPtr<QTableView> = find_widget(&main_widget.static_upcast(), "duplicates_table_view")?;
+        explanation_label.set_text(&qtr("duplicated_packs_explanation"));
+        explanation_groupbox.set_title(&qtr("duplicated_packs_explanation_title"));
+        dialog.set_window_title(&qtr("duplicated_packs_title"));
+
+        let duplicates_table_model = QStandardItemModel::new_1a(&duplicates_table_view);
+        duplicates_table_view.set_model(&duplicates_table_model);
+
+        // Setup the table.
+        duplicates_table_model.set_column_count(3);
+
+        let item_mod_name = QStandardItem::from_q_string(&qtr("mod_name"));
+        let item_pack_name = QStandardItem::from_q_string(&qtr("pack_name"));
+        let item_pack_path = QStandardItem::from_q_string(&qtr("pack_path"));
+
+        duplicates_table_model.set_horizontal_header_item(0, item_mod_name.into_ptr());
+        duplicates_table_model.set_horizontal_header_item(1, item_pack_name.into_ptr());
+        duplicates_table_model.set_horizontal_header_item(2, item_pack_path.into_ptr());
+        duplicates_table_view.horizontal_header().set_stretch_last_section(true);
+
+        // Load the data to the table. All duplicates start checked, as they're all enabled.
+        if let Some(ref game_config) = *self.game_config().read().unwrap() {
+            for copies in duplicates.values() {
+                for (mod_id, path) in copies {
+                    if let Some(modd) = game_config.mods().get(mod_id) {
+                        let row = QListOfQStandardItem::new();
+
+                        let item_mod = QStandardItem::from_q_string(&QString::from_std_str(modd.name()));
+                        let item_pack = QStandardItem::from_q_string(&QString::from_std_str(path.file_name().unwrap().to_string_lossy()));
+                        let item_path = QStandardItem::from_q_string(&QString::from_std_str(path_to_absolute_string(path)));
+
+                        item_mod.set_checkable(true);
+                        item_mod.set_check_state(CheckState::Checked);
+                        item_mod.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(mod_id)), VALUE_MOD_ID);
+                        item_mod.set_editable(false);
+                        item_pack.set_editable(false);
+                        item_path.set_editable(false);
+
+                        row.append_q_standard_item(&item_mod.into_ptr().as_mut_raw_ptr());
+                        row.append_q_standard_item(&item_pack.into_ptr().as_mut_raw_ptr());
+                        row.append_q_standard_item(&item_path.into_ptr().as_mut_raw_ptr());
+
+                        duplicates_table_model.append_row_q_list_of_q_standard_item(row.into_ptr().as_ref().unwrap());
+                    }
+                }
+            }
+        }
+
+        duplicates_table_view.resize_columns_to_contents();
+
+        dialog.set_modal(true);
+        if dialog.exec() != 1 {
+            return Ok(false);
+        }
+
+        // The copies of a pack may belong to the same mod, so a mod may be unchecked in more than one row.
+        let mods_to_disable = (0..duplicates_table_model.row_count_0a())
+            .map(|row| duplicates_table_model.item_2a(row, 0))
+            .filter(|item| item.check_state() == CheckState::Unchecked)
+            .map(|item| item.data_1a(VALUE_MOD_ID).to_string().to_std_string())
+            .unique()
+            .collect::<Vec<_>>();
+
+        if mods_to_disable.is_empty() {
+            return Ok(true);
+        }
+
+        self.set_mods_enabled(&mods_to_disable, false)?;
+
+        Ok(true)
+    }
+
+    /// This function checks if all the dependencies of the enabled mods are installed and enabled, and asks the user what to do if they're not.
+    ///
+    /// Returns false if the user cancelled the launch.
+    pub unsafe fn check_missing_dependencies(&self) -> Result<bool> {
+        let (disabled, missing) = self.missing_dependencies()?;
+        if disabled.is_empty() && missing.is_empty() {
+            return Ok(true);
+        }
+
+        let list = |dependencies: &BTreeMap<String, Vec<String>>| dependencies.iter()
+            .map(|(dependency, required_by)| format!("<li><b>{}</b>: {}</li>", dependency, required_by.iter().sorted().join(", ")))
+            .join("");
+
+        let mut message = String::new();
+        if !missing.is_empty() {
+            message.push_str(&tre("missing_dependencies_not_installed", &[&list(&missing)]));
+        }
+
+        if !disabled.is_empty() {
+            message.push_str(&tre("missing_dependencies_disabled", &[&list(&disabled)]));
+        }
+
+        let message_box = QMessageBox::from_q_widget(self.main_window());
+        message_box.set_window_title(&qtr("missing_dependencies_title"));
+        message_box.set_icon(q_message_box::Icon::Warning);
+        message_box.set_text(&QString::from_std_str(message));
+
+        if !disabled.is_empty() {
+            message_box.add_button_q_string_button_role(&qtr("missing_dependencies_enable"), q_message_box::ButtonRole::AcceptRole);
+        }
+
+        message_box.add_button_q_string_button_role(&qtr("missing_dependencies_launch_anyway"), q_message_box::ButtonRole::DestructiveRole);
+        message_box.add_button_standard_button(q_message_box::StandardButton::Cancel);
+        message_box.exec();
+
+        match message_box.button_role(&message_box.clicked_button()) {
+            q_message_box::ButtonRole::AcceptRole => {
+                let mods_to_enable = disabled.into_keys().collect::<Vec<_>>();
+                self.set_mods_enabled(&mods_to_enable, true)?;
+                Ok(true)
+            }
+            q_message_box::ButtonRole::DestructiveRole => Ok(true),
+            _ => Ok(false),
+        }
+    }
+
+    /// This function checks if the fonts of the game and the enabled mods can render all the characters of the translation selected in the launch options,
+    /// and asks the user if they want to continue if they can't.
+    ///
+    /// The translation must have already been generated. Games without fonts we can read are not checked.
+    /// Returns false if the user cancelled the launch.
+    pub unsafe fn check_translation_font_coverage(&self, game: &GameInfo, game_path: &Path, data_path: &Path) -> Result<bool> {
+        let translations_combobox = self.actions_ui().enable_translations_combobox();
+        if !translations_combobox.is_enabled() || translations_combobox.current_index() == 0 {
+            return Ok(true);
+        }
+
+        let game_config = self.game_config().read().unwrap().clone().ok_or_else(|| anyhow!(tr("game_config_error")))?;
+        let load_order = self.game_load_order().read().unwrap().clone();
+        let pack_path = reserved_pack_path(game, data_path)?;
+
+        // Fonts are read from the game, the enabled mods and the translation itself, in the background, as it needs to read a lot of packs.
+        self.toggle_main_window(false);
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetFontCoverageData(game_config, game.clone(), game_path.to_path_buf(), load_order, pack_path));
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        self.toggle_main_window(true);
+
+        let data = match response {
+            Response::FontCoverageData(data) => data,
+            Response::Error(error) => return Err(error),
+            _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+        };
+
+        let missing = match data.missing_characters() {
+            Some(missing) => missing,
+            None => {
+                info!("No readable fonts found for {}. Skipping the font coverage check of the translation.", game.key());
+                return Ok(true);
+            }
+        };
+
+        if missing.is_empty() {
+            return Ok(true);
+        }
+
+        let language = self.actions_ui().selected_translation();
+        let characters = missing.iter()
+            .take(TRANSLATION_FONT_COVERAGE_MAX_CHARACTERS)
+            .map(|character| format!("{} (U+{:04X})", character, *character as u32))
+            .join(", ")
+            .replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;");
+
+        Ok(self.are_you_sure_text(&tre("translation_font_coverage_confirm", &[&missing.len().to_string(), &language, &characters])))
+    }
+
+    /// This function returns the dependencies of the enabled mods that are not enabled, and the ones that are not installed.
+    ///
+    /// Dependencies are grouped by the mods requiring them, and split between disabled and not installed.
+    pub fn missing_dependencies(&self) -> Result<(BTreeMap<String, Vec<String>>, BTreeMap<String, Vec<String>>)> {
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+        let game_data_path = game.data_path(&game_path)?;
+
+        let mut disabled: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        let mut missing: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        if let Some(ref game_config) = *self.game_config().read().unwrap() {
+            for modd in game_config.mods().values().filter(|modd| modd.enabled(&game, &game_data_path)) {
+                for dependency in modd.dependencies() {
+                    let required_by = if modd.name().is_empty() { modd.id().to_owned() } else { modd.name().to_owned() };
+                    match game_config.mods().get(dependency) {
+                        Some(dependency_mod) if dependency_mod.enabled(&game, &game_data_path) => continue,
+                        Some(dependency_mod) if !dependency_mod.paths().is_empty() => disabled.entry(dependency.to_owned()).or_default().push(required_by),
+
+                        // Vanilla packs are not in the mod list, but they're always loaded.
+                        _ if game_data_path.join(dependency).is_file() => continue,
+                        _ => missing.entry(dependency.to_owned()).or_default().push(required_by),
+                    }
+                }
+            }
+        }
+
+        Ok((disabled, missing))
+    }
+
+    /// This function returns the dependencies of the provided mod that are not enabled, and the ones that are not installed.
+    ///
+    /// Dependencies of the installed dependencies are included too, as enabling those without their own dependencies is just as broken.
+    pub fn mod_dependencies(&self, mod_id: &str) -> Result<(Vec<String>, Vec<String>)> {
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+        let game_data_path = game.data_path(&game_path)?;
+
+        let mut disabled = vec![];
+        let mut missing = vec![];
+        if let Some(ref game_config) = *self.game_config().read().unwrap() {
+            let mut visited = HashSet::from([mod_id.to_owned()]);
+            let mut pending = vec![mod_id.to_owned()];
+
+            while let Some(mod_id) = pending.pop() {
+                let dependencies = match game_config.mods().get(&mod_id) {
+                    Some(modd) => modd.dependencies(),
+                    None => continue,
+                };
+
+                for dependency in dependencies {
+                    if !visited.insert(dependency.to_owned()) {
+                        continue;
+                    }
+
+                    match game_config.mods().get(dependency) {
+                        Some(dependency_mod) if !dependency_mod.paths().is_empty() => {
+                            if !dependency_mod.enabled(&game, &game_data_path) {
+                                disabled.push(dependency.to_owned());
+                            }
+
+                            pending.push(dependency.to_owned());
+                        }
+
+                        // Vanilla packs are not in the mod list, but they're always loaded.
+                        _ if game_data_path.join(dependency).is_file() => continue,
+                        _ => missing.push(dependency.to_owned()),
+                    }
+                }
+            }
+        }
+
+        Ok((disabled, missing))
+    }
+
+    /// This function offers to enable the dependencies of a mod the user just enabled, and warns about the ones not installed.
+    ///
+    /// If the user chose to always enable them, the installed ones are enabled without asking.
+    pub unsafe fn enable_mod_dependencies(&self, mod_id: &str) -> Result<()> {
+        let (disabled, missing) = self.mod_dependencies(mod_id)?;
+        if disabled.is_empty() && missing.is_empty() {
+            return Ok(());
+        }
+
+        let list = |dependencies: &[String]| dependencies.iter()
+            .map(|dependency| format!("<li><b>{}</b></li>", dependency))
+            .join("");
+
+        if setting_bool(AUTO_ENABLE_DEPENDENCIES) {
+            if !disabled.is_empty() {
+                self.set_mods_enabled(&disabled, true)?;
+                self.main_window().status_bar().show_message_2a(&qtre("enable_dependencies_enabled", &[&disabled.len().to_string()]), 5000);
+            }
+
+            if !missing.is_empty() {
+                show_dialog(self.main_window(), tre("enable_dependencies_missing", &[&list(&missing)]), false);
+            }
+
+            return Ok(());
+        }
+
+        let mod_name = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => game_config.mods().get(mod_id)
+                .map(|modd| if modd.name().is_empty() { modd.id().to_owned() } else { modd.name().to_owned() })
+                .unwrap_or_else(|| mod_id.to_owned()),
+            None => mod_id.to_owned(),
+        };
+
+        let mut message = tre("enable_dependencies", &[&mod_name]);
+        if !disabled.is_empty() {
+            message.push_str(&tre("enable_dependencies_disabled", &[&list(&disabled)]));
+        }
+
+        if !missing.is_empty() {
+            message.push_str(&tre("enable_dependencies_not_installed", &[&list(&missing)]));
+        }
+
+        let message_box = QMessageBox::from_q_widget(self.main_window());
+        message_box.set_window_title(&qtr("enable_dependencies_title"));
+        message_box.set_icon(q_message_box::Icon::Warning);
+        message_box.set_text(&QString::from_std_str(message));
+
+        if disabled.is_empty() {
+            message_box.add_button_standard_button(q_message_box::StandardButton::Ok);
+        } else {
+            message_box.add_button_q_string_button_role(&qtr("enable_dependencies_enable"), q_message_box::ButtonRole::AcceptRole);
+            message_box.add_button_q_string_button_role(&qtr("enable_dependencies_skip"), q_message_box::ButtonRole::RejectRole);
+            message_box.set_check_box(QCheckBox::from_q_string(&qtr("enable_dependencies_always")).into_ptr());
+        }
+
+        message_box.exec();
+
+        if !disabled.is_empty() && message_box.button_role(&message_box.clicked_button()) == q_message_box::ButtonRole::AcceptRole {
+            if message_box.check_box().is_checked() {
+                set_setting_bool(AUTO_ENABLE_DEPENDENCIES, true);
+            }
+
+            self.set_mods_enabled(&disabled, true)?;
+        }
+
+        Ok(())
+    }
+
+    /// This function checks if the load order differs from the one of the selected profile, and asks the user what to do with the changes if it does.
+    ///
+    /// Returns false if the user wants to cancel the operation.
+    pub unsafe fn check_unsaved_profile_changes(&self) -> Result<bool> {
+        let profile_name = self.actions_ui().profile_combobox().current_text().to_std_string();
+        let profile = self.game_profiles().read().unwrap().get(&profile_name).cloned();
+        let diff = match profile {
+            Some(profile) => profile.diff(&self.game_load_order().read().unwrap()),
+            None => return Ok(true),
+        };
+
+        if diff.is_empty() {
+            return Ok(true);
+        }
+
+        let mut details = vec![];
+        details.extend(diff.added().iter().map(|mod_id| format!("+ {mod_id}")));
+        details.extend(diff.removed().iter().map(|mod_id| format!("- {mod_id}")));
+        if *diff.reordered() {
+            details.push(tr("unsaved_profile_changes_reordered"));
+        }
+
+        let message_box = QMessageBox::from_q_widget(self.main_window());
+        message_box.set_window_title(&qtr("unsaved_profile_changes_title"));
+        message_box.set_icon(q_message_box::Icon::Warning);
+        message_box.set_text(&qtre("unsaved_profile_changes", &[&profile_name]));
+        message_box.set_detailed_text(&QString::from_std_str(details.join("\n")));
+
+        message_box.add_button_q_string_button_role(&qtr("unsaved_profile_changes_save"), q_message_box::ButtonRole::AcceptRole);
+        message_box.add_button_q_string_button_role(&qtr("unsaved_profile_changes_discard"), q_message_box::ButtonRole::DestructiveRole);
+        message_box.add_button_standard_button(q_message_box::StandardButton::Cancel);
+        message_box.exec();
+
+        match message_box.button_role(&message_box.clicked_button()) {
+            q_message_box::ButtonRole::AcceptRole => {
+                self.save_profile()?;
+                Ok(true)
+            }
+            q_message_box::ButtonRole::DestructiveRole => Ok(true),
+            _ => Ok(false),
+        }
+    }
+
+    /// This function enables or disables the provided mods, both in the UI and in the backend.
+    pub unsafe fn set_mods_enabled(&self, mod_ids: &[String], enabled: bool) -> Result<()> {
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+        let game_data_path = game.data_path(&game_path)?;
+        let check_state = if enabled { CheckState::Checked } else { CheckState::Unchecked };
+
+        // Update the mods in the UI first, then in the backend.
+        self.mod_list_ui().model().block_signals(true);
+
+        for cat in 0..self.mod_list_ui().model().row_count_0a() {
+            let category = self.mod_list_ui().model().item_1a(cat);
+            for row in 0..category.row_count() {
+                let item = category.child_1a(row);
+                if !item.is_null() && mod_ids.contains(&item.data_1a(VALUE_MOD_ID).to_string().to_std_string()) {
+                    item.set_check_state(check_state);
+                }
+            }
+        }
+
+        self.mod_list_ui().model().block_signals(false);
+
+        if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
+            for mod_id in mod_ids {
+                if let Some(ref mut modd) = game_config.mods_mut().get_mut(mod_id) {
+                    modd.set_enabled(enabled);
+                }
+            }
+
+            let mut load_order = self.game_load_order().write().unwrap();
+            load_order.update(game_config, &game, &game_data_path);
+            load_order.save(&game)?;
+
+            setup_actions(self, &game, game_config, &game_path, &load_order)?;
+
+            self.pack_list_ui().load(game_config, &game, &game_path, &load_order)?;
+            self.data_list_ui().set_enabled(false);
+            game_config.save(&game)?;
+        }
+
+        Ok(())
+    }
+
     pub unsafe fn open_data_file_with_rpfm(&self) -> Result<()> {
         let tools = self.tools().read().unwrap();
         if let Some(tool) = tools.tools().iter().find(|tool| tool.path().ends_with("rpfm_ui.exe")) {
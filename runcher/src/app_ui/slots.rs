@@ -9,8 +9,11 @@
 //---------------------------------------------------------------------------//
 
 use qt_widgets::QMessageBox;
+use qt_widgets::SlotOfActivationReason;
+use qt_widgets::SlotOfQPoint;
 
 use qt_gui::QDesktopServices;
+use qt_gui::QGuiApplication;
 use qt_gui::SlotOfQStandardItem;
 
 use qt_core::QBox;
@@ -19,17 +22,22 @@ use qt_core::QUrl;
 use qt_core::SlotNoArgs;
 use qt_core::SlotOfBool;
 use qt_core::SlotOfDouble;
+use qt_core::SlotOfInt;
 use qt_core::SlotOfQModelIndexInt;
 use qt_core::SlotOfQString;
+use qt_core::SlotOfQStringList;
 
+use std::path::PathBuf;
 use std::rc::Rc;
 
 use rpfm_ui_common::clone;
 
+use crate::actions_ui::ActionsUI;
+use crate::custom_launch_options_ui::CustomLaunchOptionsUI;
 use crate::DISCORD_URL;
 use crate::GITHUB_URL;
 use crate::mod_list_ui::VALUE_MOD_ID;
-use crate::mod_manager::secondary_mods_path;
+use crate::mod_manager::{load_mods_from, mod_source_setting_key, secondary_mods_path};
 use crate::PATREON_URL;
 use crate::profiles_ui::ProfilesUI;
 use crate::VERSION;
@@ -45,15 +53,35 @@ use super::*;
 #[getset(get = "pub")]
 pub struct AppUISlots {
     launch_game: QBox<SlotNoArgs>,
+    manage_saves: QBox<SlotNoArgs>,
+    tray_activated: QBox<SlotOfActivationReason>,
+    check_launch_status: QBox<SlotNoArgs>,
+    profile_backups_check: QBox<SlotNoArgs>,
+    show_last_launch_logs: QBox<SlotOfQString>,
+    mod_folders_changed: QBox<SlotOfQString>,
+    mod_folders_reload: QBox<SlotNoArgs>,
+    tray_show_hide: QBox<SlotNoArgs>,
+    tray_recent_profiles_menu_open: QBox<SlotNoArgs>,
     toggle_logging: QBox<SlotOfBool>,
     toggle_skip_intros: QBox<SlotOfBool>,
     toggle_remove_trait_limit: QBox<SlotOfBool>,
     toggle_remove_siege_attacker: QBox<SlotOfBool>,
     toggle_merge_all_mods: QBox<SlotOfBool>,
-    toggle_enable_translations: QBox<SlotOfQString>,
+    merge_categories_menu_open: QBox<SlotNoArgs>,
+    rebalancer_excluded_categories_menu_open: QBox<SlotNoArgs>,
+    change_game_executable: QBox<SlotOfInt>,
+    toggle_multiplayer_safe: QBox<SlotOfBool>,
+    multiplayer_safe_options_menu_open: QBox<SlotNoArgs>,
+    toggle_enable_translations: QBox<SlotOfInt>,
     change_unit_multiplier: QBox<SlotOfDouble>,
+    change_unit_multiplier_preset: QBox<SlotOfInt>,
+    save_unit_multiplier_preset: QBox<SlotNoArgs>,
+    delete_unit_multiplier_preset: QBox<SlotNoArgs>,
     toggle_universal_rebalancer: QBox<SlotOfQString>,
     toggle_dev_only_ui: QBox<SlotOfBool>,
+    change_preset_group: QBox<SlotOfQString>,
+    filter_scripts: QBox<SlotOfQString>,
+    change_scripts_sort: QBox<SlotOfInt>,
     open_settings: QBox<SlotNoArgs>,
     open_folders_submenu: QBox<SlotNoArgs>,
     open_game_root_folder: QBox<SlotNoArgs>,
@@ -62,8 +90,15 @@ pub struct AppUISlots {
     open_game_secondary_folder: QBox<SlotNoArgs>,
     open_game_config_folder: QBox<SlotNoArgs>,
     open_runcher_config_folder: QBox<SlotNoArgs>,
+    open_game_crash_dumps_folder: QBox<SlotNoArgs>,
     open_runcher_error_folder: QBox<SlotNoArgs>,
+    create_crash_report: QBox<SlotNoArgs>,
+    create_diagnostics_bundle: QBox<SlotNoArgs>,
+    export_config_bundle: QBox<SlotNoArgs>,
+    import_config_bundle: QBox<SlotNoArgs>,
+    toggle_mod_sources: QBox<SlotNoArgs>,
     change_game_selected: QBox<SlotNoArgs>,
+    game_selected_context_menu: QBox<SlotOfQPoint>,
 
     update_pack_list: QBox<SlotOfQStandardItem>,
 
@@ -75,17 +110,47 @@ pub struct AppUISlots {
     patreon_link: QBox<SlotNoArgs>,
 
     copy_load_order: QBox<SlotNoArgs>,
+    copy_load_order_markdown: QBox<SlotNoArgs>,
+    copy_load_order_markdown_names_only: QBox<SlotOfBool>,
+    copy_workshop_ids: QBox<SlotNoArgs>,
     paste_load_order: QBox<SlotNoArgs>,
+    import_load_order_file: QBox<SlotNoArgs>,
     reload: QBox<SlotNoArgs>,
     download_subscribed_mods: QBox<SlotNoArgs>,
     load_profile: QBox<SlotNoArgs>,
+    recent_profiles_menu_open: QBox<SlotNoArgs>,
     save_profile: QBox<SlotNoArgs>,
     open_profile_manager: QBox<SlotNoArgs>,
+    open_custom_launch_options_manager: QBox<SlotNoArgs>,
 
     enable_selected: QBox<SlotNoArgs>,
     disable_selected: QBox<SlotNoArgs>,
+    toggle_matching: QBox<SlotNoArgs>,
+    enable_random_subset: QBox<SlotNoArgs>,
+    find_crashing_mod: QBox<SlotNoArgs>,
+    global_search: QBox<SlotNoArgs>,
+    reorder_launch_options: QBox<SlotNoArgs>,
+    script_sandbox: QBox<SlotNoArgs>,
+    effective_settings: QBox<SlotNoArgs>,
+    export_launch_script: QBox<SlotNoArgs>,
+    restore_script_backup: QBox<SlotNoArgs>,
+    reapply_scripts: QBox<SlotNoArgs>,
+    rebuild_translations: QBox<SlotNoArgs>,
+    merge_summary: QBox<SlotNoArgs>,
+    validate_launch: QBox<SlotNoArgs>,
+    test_launch_options: QBox<SlotNoArgs>,
+    modification_summary: QBox<SlotNoArgs>,
+    launch_history: QBox<SlotNoArgs>,
+    export_script_bundle: QBox<SlotNoArgs>,
+    import_script_bundle: QBox<SlotNoArgs>,
+    play_menu_about_to_hide: QBox<SlotNoArgs>,
+    play_menu_triggered: QBox<SlotNoArgs>,
+    reopen_play_menu: QBox<SlotNoArgs>,
     upload_to_workshop: QBox<SlotNoArgs>,
     download_from_workshop: QBox<SlotNoArgs>,
+    repair_from_workshop: QBox<SlotNoArgs>,
+    redownload_broken_mods: QBox<SlotNoArgs>,
+    show_mod_updates: QBox<SlotNoArgs>,
     category_create: QBox<SlotNoArgs>,
     category_delete: QBox<SlotNoArgs>,
     category_rename: QBox<SlotNoArgs>,
@@ -94,8 +159,10 @@ pub struct AppUISlots {
     mod_list_context_menu_open: QBox<SlotNoArgs>,
     copy_to_secondary: QBox<SlotNoArgs>,
     move_to_secondary: QBox<SlotNoArgs>,
+    move_to_data: QBox<SlotNoArgs>,
 
     pack_toggle_auto_sorting: QBox<SlotOfBool>,
+    install_dropped_packs: QBox<SlotOfQStringList>,
     pack_move: QBox<SlotOfQModelIndexInt>,
 
     data_view_reload: QBox<SlotNoArgs>,
@@ -117,6 +184,84 @@ impl AppUISlots {
             }
         ));
 
+        let manage_saves = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.manage_saves() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let tray_activated = SlotOfActivationReason::new(&view.main_window, clone!(
+            view => move |reason| {
+                if AppUI::is_tray_toggle(reason) {
+                    view.toggle_from_tray();
+                }
+            }
+        ));
+
+        let check_launch_status = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                view.check_launch_status();
+            }
+        ));
+
+        let profile_backups_check = SlotNoArgs::new(&view.main_window, || {
+            AppUI::backup_profiles_if_due();
+        });
+
+        let mod_folders_changed = SlotOfQString::new(&view.main_window, clone!(
+            view => move |path| {
+                view.mod_folders_changed(&path.to_std_string());
+            }
+        ));
+
+        let mod_folders_reload = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+
+                // Don't interrupt a reload. The watcher gets updated after it anyway.
+                if *view.reloading().read().unwrap() {
+                    return;
+                }
+
+                // If we're busy with something else, like launching the game, try again later.
+                if !view.main_window().is_enabled() {
+                    view.mod_folders_watcher_timer().start_0a();
+                    return;
+                }
+
+                // Ignore changes while asking, or we may end up with a stack of dialogs.
+                view.mod_folders_watcher().block_signals(true);
+                let reload = setting_int(MOD_FOLDERS_WATCHER) == MOD_FOLDERS_WATCHER_RELOAD || view.are_you_sure("mod_folders_changed");
+                view.mod_folders_watcher().block_signals(false);
+
+                // Packs overwritten by deleting them first are no longer watched, so refresh the watcher even if we don't reload.
+                if reload {
+                    view.actions_ui().reload_button().click();
+                } else {
+                    view.update_mod_folders_watcher();
+                }
+            }
+        ));
+
+        let show_last_launch_logs = SlotOfQString::new(&view.main_window, clone!(
+            view => move |_| {
+                view.show_last_launch_logs();
+            }
+        ));
+
+        let tray_show_hide = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                view.toggle_from_tray();
+            }
+        ));
+
+        let tray_recent_profiles_menu_open = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                AppUI::generate_recent_profiles_menu(&view, view.tray_recent_profiles_menu());
+            }
+        ));
+
         let toggle_logging = SlotOfBool::new(view.main_window(), clone!(
             view => move |state| {
                 let game = view.game_selected().read().unwrap();
@@ -128,7 +273,7 @@ impl AppUISlots {
         let toggle_skip_intros = SlotOfBool::new(view.main_window(), clone!(
             view => move |state| {
                 let game = view.game_selected().read().unwrap();
-                let setting = format!("enable_skip_intros_{}", game.key());
+                let setting = format!("enable_skip_intro_{}", game.key());
                 set_setting_bool(&setting, state);
             }
         ));
@@ -157,10 +302,85 @@ impl AppUISlots {
             }
         ));
 
-        let toggle_enable_translations = SlotOfQString::new(view.main_window(), clone!(
-            view => move |lang| {
+        let merge_categories_menu_open = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+                let game = view.game_selected().read().unwrap();
+                if let Some(ref game_config) = *view.game_config().read().unwrap() {
+                    view.actions_ui().load_merge_categories(game.key(), game_config.categories_order());
+                }
+            }
+        ));
+
+        // The categories are read from the pack being rebalanced, so they're only decoded when the user wants to pick them.
+        let rebalancer_excluded_categories_menu_open = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+                let game = view.game_selected().read().unwrap();
+                let pack_name = view.actions_ui().universal_rebalancer_combobox().current_text().to_std_string();
+                let pack_path = if view.actions_ui().universal_rebalancer_combobox().current_index() != 0 {
+                    view.game_config().read().unwrap()
+                        .as_ref()
+                        .and_then(|game_config| game_config.mods().get(&pack_name))
+                        .and_then(|modd| modd.paths().first().cloned())
+                } else {
+                    None
+                };
+
+                // Decoding the tables of big packs takes a while, so it's done in the background.
+                let categories = match pack_path {
+                    Some(pack_path) => {
+                        let receiver = CENTRAL_COMMAND.send_background(Command::GetUnitCategories(pack_path));
+                        let response = CENTRAL_COMMAND.recv_try(&receiver);
+                        match response {
+                            Response::VecString(categories) => categories,
+                            Response::Error(error) => {
+                                show_dialog(view.main_window(), error, false);
+                                vec![]
+                            }
+                            _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+                        }
+                    }
+                    None => vec![],
+                };
+
+                view.actions_ui().load_rebalancer_excluded_categories(game.key(), &categories);
+            }
+        ));
+
+        let toggle_multiplayer_safe = SlotOfBool::new(view.main_window(), clone!(
+            view => move |state| {
+                if let Err(error) = view.set_multiplayer_safe(state) {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let multiplayer_safe_options_menu_open = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+                let game = view.game_selected().read().unwrap();
+                view.actions_ui().load_multiplayer_safe_options(game.key());
+            }
+        ));
+
+        // The standard executable is stored as an empty string, so we keep following it if it ever gets renamed.
+        let change_game_executable = SlotOfInt::new(view.main_window(), clone!(
+            view => move |index| {
+                let game = view.game_selected().read().unwrap();
+                let setting = format!("game_executable_{}", game.key());
+                let executable = if index > 0 {
+                    view.actions_ui().game_executable_combobox().item_text(index).to_std_string()
+                } else {
+                    String::new()
+                };
+
+                set_setting_string(&setting, &executable);
+            }
+        ));
+
+        let toggle_enable_translations = SlotOfInt::new(view.main_window(), clone!(
+            view => move |index| {
                 let game = view.game_selected().read().unwrap();
                 let setting = format!("enable_translations_{}", game.key());
+                let lang = view.actions_ui().enable_translations_combobox().item_text(index);
                 set_setting_string(&setting, &lang.to_std_string());
             }
         ));
@@ -170,6 +390,39 @@ impl AppUISlots {
                 let game = view.game_selected().read().unwrap();
                 let setting = format!("unit_multiplier_{}", game.key());
                 set_setting_f32(&setting, value as f32);
+
+                // Warn the user if the value is getting close to the ones known to crash the game.
+                if value >= ActionsUI::unit_multiplier_warning_threshold() {
+                    view.main_window().status_bar().show_message_2a(&qtr("unit_multiplier_warning"), 10000);
+                }
+
+                view.actions_ui().select_unit_multiplier_preset();
+            }
+        ));
+
+        // Selecting a preset just changes the multiplier, so its own slot takes care of saving it.
+        let change_unit_multiplier_preset = SlotOfInt::new(view.main_window(), clone!(
+            view => move |index| {
+                let combobox = view.actions_ui().unit_multiplier_preset_combobox();
+                if index > 0 {
+                    view.actions_ui().unit_multiplier_spinbox().set_value(combobox.item_data_1a(index).to_double_0a());
+                }
+
+                view.actions_ui().unit_multiplier_preset_delete_button().set_enabled(index > 0);
+            }
+        ));
+
+        let save_unit_multiplier_preset = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+                if let Err(error) = view.save_unit_multiplier_preset() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let delete_unit_multiplier_preset = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+                view.delete_unit_multiplier_preset();
             }
         ));
 
@@ -189,9 +442,35 @@ impl AppUISlots {
             }
         ));
 
+        let change_scripts_sort = SlotOfInt::new(view.main_window(), clone!(
+            view => move |index| {
+                set_setting_int("scripts_sort", index);
+                view.actions_ui().sort_scripts();
+            }
+        ));
+
+        let filter_scripts = SlotOfQString::new(view.main_window(), clone!(
+            view => move |_| {
+                view.actions_ui().filter_scripts();
+            }
+        ));
+
+        let change_preset_group = SlotOfQString::new(view.main_window(), clone!(
+            view => move |preset_group| {
+                let game = view.game_selected().read().unwrap();
+                let setting = format!("preset_group_{}", game.key());
+                set_setting_string(&setting, &preset_group.to_std_string());
+
+                // The first item is for not using a preset group. In that case we leave the scripts as they are.
+                if view.actions_ui().preset_group_combobox().current_index() > 0 {
+                    view.actions_ui().apply_preset_group(&preset_group.to_std_string());
+                }
+            }
+        ));
+
         let open_settings = SlotNoArgs::new(&view.main_window, clone!(
             view => move || {
-            view.open_settings();
+            AppUI::open_settings(&view);
         }));
 
         let open_folders_submenu = SlotNoArgs::new(&view.main_window, clone!(
@@ -259,6 +538,15 @@ impl AppUISlots {
             }
         }));
 
+        let open_game_crash_dumps_folder = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+            let game = view.game_selected().read().unwrap();
+            match crash_dumps_path(&game) {
+                Ok(path) if path.is_dir() => { let _ = open::that(path); },
+                _ => show_dialog(view.main_window(), "Runcher cannot open that folder (maybe it doesn't exists/is misconfigured?). Also, this folder is only created after the game crashes.", false),
+            }
+        }));
+
         let open_runcher_error_folder = SlotNoArgs::new(&view.main_window, clone!(
             view => move || {
             if let Ok(path) = error_path() {
@@ -268,9 +556,88 @@ impl AppUISlots {
             }
         }));
 
+        let toggle_mod_sources = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                let game_key = view.game_selected().read().unwrap().key().to_owned();
+                let sources = view.actions_ui().mod_sources_actions();
+
+                // We need at least one source to load mods from. If the user disabled all of them, revert the change.
+                if sources.iter().all(|(_, action)| !action.is_enabled() || !action.is_checked()) {
+                    for (source, action) in sources {
+                        action.set_checked(load_mods_from(&game_key, source));
+                    }
+
+                    show_dialog(view.main_window(), tr("load_mods_from_none"), false);
+                    return;
+                }
+
+                for (source, action) in sources {
+                    set_setting_bool(&mod_source_setting_key(&game_key, source), action.is_checked());
+                }
+
+                // Changing the sources changes which mods are installed, so we need a full reload.
+                match view.change_game_selected(true, false, false) {
+                    Ok(network_receiver) => if let Err(error) = view.update_mod_list_with_online_data(&network_receiver) {
+                        show_dialog(view.main_window(), error, false);
+                    }
+                    Err(error) => show_dialog(view.main_window(), error, false),
+                }
+            }
+        ));
+
+        let create_crash_report = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+            match view.create_crash_report() {
+                Ok(path) => {
+                    if let Some(parent) = path.parent() {
+                        let _ = open::that(parent);
+                    }
+
+                    show_dialog(view.main_window(), tre("crash_report_created", &[&path.to_string_lossy()]), true);
+                }
+                Err(error) => show_dialog(view.main_window(), error, false),
+            }
+        }));
+
+        let create_diagnostics_bundle = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+            match view.create_diagnostics_bundle() {
+                Ok(path) => {
+                    if let Some(parent) = path.parent() {
+                        let _ = open::that(parent);
+                    }
+
+                    show_dialog(view.main_window(), tre("diagnostics_bundle_created", &[&path.to_string_lossy()]), true);
+                }
+                Err(error) => show_dialog(view.main_window(), error, false),
+            }
+        }));
+
+        let export_config_bundle = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+            match view.export_config_bundle() {
+                Ok(Some(path)) => show_dialog(view.main_window(), tre("config_bundle_exported", &[&path.to_string_lossy()]), true),
+                Ok(None) => {},
+                Err(error) => show_dialog(view.main_window(), error, false),
+            }
+        }));
+
+        let import_config_bundle = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+            match view.import_config_bundle() {
+                Ok(Some(skipped)) if skipped.is_empty() => show_dialog(view.main_window(), tr("config_bundle_imported"), true),
+                Ok(Some(skipped)) => {
+                    let skipped = skipped.iter().map(|skipped| format!("<li>{skipped}</li>")).join("");
+                    show_dialog(view.main_window(), tre("config_bundle_imported_skipped", &[&skipped]), true);
+                }
+                Ok(None) => {},
+                Err(error) => show_dialog(view.main_window(), error, false),
+            }
+        }));
+
         let change_game_selected = SlotNoArgs::new(&view.main_window, clone!(
             view => move || {
-                match view.change_game_selected(true, false) {
+                match view.change_game_selected(true, false, true) {
                     Ok(network_receiver) => {
                         if let Err(error) = view.update_mod_list_with_online_data(&network_receiver) {
                             show_dialog(view.main_window(), error, false);
@@ -281,21 +648,37 @@ impl AppUISlots {
             }
         ));
 
+        let game_selected_context_menu = SlotOfQPoint::new(&view.main_window, clone!(
+            view => move |pos| {
+            view.game_selected_bar_context_menu(pos);
+        }));
+
         let update_pack_list = SlotOfQStandardItem::new(&view.main_window, clone!(
             view => move |item| {
             if item.column() == 0 {
+                let mut newly_enabled = None;
                 if let Some(ref mut game_config) = *view.game_config().write().unwrap() {
                     let mod_id = item.data_1a(VALUE_MOD_ID).to_string().to_std_string();
+                    let game_info = view.game_selected().read().unwrap();
+                    let game_path = setting_path(game_info.key());
+                    let game_data_path = game_info.data_path(&game_path);
 
                     // Update the mod's status.
                     if let Some(modd) = game_config.mods_mut().get_mut(&mod_id) {
-                        modd.set_enabled(item.check_state() == CheckState::Checked);
+                        let enabled = item.check_state() == CheckState::Checked;
+
+                        // Other changes to the item trigger this too, so only treat it as enabled if it wasn't enabled before.
+                        if let Ok(ref game_data_path) = game_data_path {
+                            if enabled && !modd.enabled(&game_info, game_data_path) {
+                                newly_enabled = Some(mod_id.to_owned());
+                            }
+                        }
+
+                        modd.set_enabled(enabled);
                     }
 
                     // Reload the pack view.
-                    let game_info = view.game_selected().read().unwrap();
-                    let game_path = setting_path(game_info.key());
-                    if let Ok(game_data_path) = game_info.data_path(&game_path) {
+                    if let Ok(game_data_path) = game_data_path {
 
                         let mut load_order = view.game_load_order().write().unwrap();
                         load_order.update(game_config, &game_info, &game_data_path);
@@ -319,6 +702,12 @@ impl AppUISlots {
                         }
                     }
                 }
+
+                if let Some(mod_id) = newly_enabled {
+                    if let Err(error) = view.enable_mod_dependencies(&mod_id) {
+                        show_dialog(view.main_window(), error, false);
+                    }
+                }
             }
         }));
 
@@ -391,36 +780,115 @@ impl AppUISlots {
             }
         ));
 
-        let paste_load_order = SlotNoArgs::new(&view.main_window, clone!(
+        let copy_load_order_markdown = SlotNoArgs::new(&view.main_window, clone!(
             view => move || {
-                match view.load_order_string_dialog(None) {
-                    Ok(mode) => if let Some(mode) = mode {
-                        view.toggle_main_window(false);
+                if let Some(ref game_config) = *view.game_config().read().unwrap() {
+                    view.toggle_main_window(false);
+
+                    let game_info = view.game_selected().read().unwrap();
+                    let game_path = setting_path(game_info.key());
+                    if let Ok(game_data_path) = game_info.data_path(&game_path) {
 
-                        let receiver = CENTRAL_COMMAND.send_background(Command::GetLoadOrderFromString(mode));
+                        let load_order = view.game_load_order().read().unwrap().clone();
+                        let names_only = view.actions_ui().copy_load_order_markdown_names_only().is_checked();
+                        let receiver = CENTRAL_COMMAND.send_background(Command::GetMarkdownFromLoadOrder(game_config.clone(), game_info.clone(), game_data_path, load_order, names_only));
                         let response = CENTRAL_COMMAND.recv_try(&receiver);
                         match response {
-                            Response::VecShareableMods(response) => {
-                                if let Err(error) = view.load_order_from_shareable_mod_list(&response) {
-                                    show_dialog(view.main_window(), error, false);
-                                }
+                            Response::String(response) => {
+                                QGuiApplication::clipboard().set_text_1a(&QString::from_std_str(response));
+                                view.main_window().status_bar().show_message_2a(&qtr("copy_load_order_markdown_copied"), 2500);
                             }
                             Response::Error(error) => show_dialog(view.main_window(), error, false),
                             _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
                         }
+                    }
 
-                        view.toggle_main_window(true);
+                    view.toggle_main_window(true);
+                }
+            }
+        ));
+
+        let copy_load_order_markdown_names_only = SlotOfBool::new(&view.main_window, move |state| {
+            set_setting_bool("copy_load_order_markdown_names_only", state);
+        });
+
+        let copy_workshop_ids = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                match view.enabled_workshop_ids() {
+                    Ok((ids, skipped)) => {
+                        QGuiApplication::clipboard().set_text_1a(&QString::from_std_str(ids.join("\n")));
+
+                        let message = if skipped == 0 {
+                            qtre("copy_workshop_ids_copied", &[&ids.len().to_string()])
+                        } else {
+                            qtre("copy_workshop_ids_copied_skipped", &[&ids.len().to_string(), &skipped.to_string()])
+                        };
+
+                        view.main_window().status_bar().show_message_2a(&message, 2500);
                     }
                     Err(error) => show_dialog(view.main_window(), error, false),
                 }
             }
         ));
 
+        let paste_load_order = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                match view.load_order_string_dialog(None) {
+                    Ok(mode) => if let Some(mode) = mode {
+                        view.import_load_order(mode);
+                    }
+                    Err(error) => show_dialog(view.main_window(), error, false),
+                }
+            }
+        ));
+
+        let import_load_order_file = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                match view.load_order_file_dialog() {
+                    Ok(mode) => if let Some(mode) = mode {
+                        view.import_load_order(mode);
+                    }
+                    Err(error) => show_dialog(view.main_window(), error, false),
+                }
+            }
+        ));
+
+        let install_dropped_packs = SlotOfQStringList::new(&view.main_window, clone!(
+            view => move |paths| {
+                let paths = (0..paths.count_0a())
+                    .map(|index| PathBuf::from(paths.at(index).to_std_string()))
+                    .collect::<Vec<_>>();
+
+                match view.install_dropped_packs(&paths) {
+                    Ok(Some((installed, rejected))) => {
+
+                        // Reload the mod list so the new packs show up.
+                        match view.change_game_selected(true, false, false) {
+                            Ok(network_receiver) => if let Err(error) = view.update_mod_list_with_online_data(&network_receiver) {
+                                show_dialog(view.main_window(), error, false);
+                            }
+                            Err(error) => show_dialog(view.main_window(), error, false),
+                        }
+
+                        let mut message = tre("drop_packs_installed", &[&installed.join(", ")]);
+                        if !rejected.is_empty() {
+                            message.push_str("<br/><br/>");
+                            message.push_str(&tre("drop_packs_rejected", &[&rejected.join(", ")]));
+                        }
+
+                        show_dialog(view.main_window(), message, true);
+                    }
+                    Ok(None) => {},
+                    Err(error) => show_dialog(view.main_window(), error, false),
+                }
+            }
+        ));
+
         let reload = SlotNoArgs::new(&view.main_window, clone!(
             view => move || {
 
                 // We just re-use the game selected logic
-                match view.change_game_selected(true, false) {
+                match view.change_game_selected(true, false, false) {
                     Ok(network_receiver) => if let Err(error) = view.update_mod_list_with_online_data(&network_receiver) {
                         show_dialog(view.main_window(), error, false);
                     }
@@ -432,7 +900,11 @@ impl AppUISlots {
         let download_subscribed_mods = SlotNoArgs::new(&view.main_window, clone!(
             view => move || {
                 match view.download_subscribed_mods(&None) {
-                    Ok(_) => show_dialog(view.main_window(), tr("mods_downloaded"), true),
+                    Ok(updates) => if updates.is_empty() {
+                        show_dialog(view.main_window(), tr("mods_downloaded"), true);
+                    } else if let Err(error) = view.mod_updates_dialog(Some(&updates)) {
+                        show_dialog(view.main_window(), error, false);
+                    }
                     Err(error) => show_dialog(view.main_window(), error, false),
                 }
             }
@@ -455,12 +927,72 @@ impl AppUISlots {
                 };
 
                 match view.download_subscribed_mods(&Some(published_file_ids)) {
-                    Ok(_) => show_dialog(view.main_window(), tr("mods_downloaded"), true),
+                    Ok(updates) => if updates.is_empty() {
+                        show_dialog(view.main_window(), tr("mods_downloaded"), true);
+                    } else if let Err(error) = view.mod_updates_dialog(Some(&updates)) {
+                        show_dialog(view.main_window(), error, false);
+                    }
                     Err(error) => show_dialog(view.main_window(), error, false),
                 }
             }
         ));
 
+        let repair_from_workshop = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                let selection = view.mod_list_selection();
+                if selection.len() != 1 {
+                    return;
+                }
+
+                let mod_id = selection[0].data_1a(VALUE_MOD_ID).to_string().to_std_string();
+                match view.repair_from_workshop(&mod_id) {
+                    Ok(Some(updates)) => if updates.is_empty() {
+                        show_dialog(view.main_window(), tr("mods_downloaded"), true);
+                    } else if let Err(error) = view.mod_updates_dialog(Some(&updates)) {
+                        show_dialog(view.main_window(), error, false);
+                    }
+                    Ok(None) => {},
+                    Err(error) => show_dialog(view.main_window(), error, false),
+                }
+            }
+        ));
+
+        // Broken mods are the ones whose pack failed to load in the last scan. Only the ones from the workshop can be re-downloaded.
+        let redownload_broken_mods = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                let published_file_ids = if let Some(ref game_config) = *view.game_config().read().unwrap() {
+                    game_config.mods().values()
+                        .filter(|modd| modd.load_error().is_some())
+                        .filter_map(|modd| modd.steam_id().clone())
+                        .collect::<Vec<_>>()
+                } else {
+                    vec![]
+                };
+
+                if published_file_ids.is_empty() {
+                    show_dialog(view.main_window(), tr("redownload_broken_mods_none"), true);
+                    return;
+                }
+
+                match view.download_subscribed_mods(&Some(published_file_ids)) {
+                    Ok(updates) => if updates.is_empty() {
+                        show_dialog(view.main_window(), tr("mods_downloaded"), true);
+                    } else if let Err(error) = view.mod_updates_dialog(Some(&updates)) {
+                        show_dialog(view.main_window(), error, false);
+                    }
+                    Err(error) => show_dialog(view.main_window(), error, false),
+                }
+            }
+        ));
+
+        let show_mod_updates = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.mod_updates_dialog(None) {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
         let load_profile = SlotNoArgs::new(&view.main_window, clone!(
             view => move || {
                 if let Err(error) = view.load_profile(None, false) {
@@ -469,6 +1001,12 @@ impl AppUISlots {
             }
         ));
 
+        let recent_profiles_menu_open = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                AppUI::generate_recent_profiles_menu(&view, view.actions_ui().profile_recent_menu());
+            }
+        ));
+
         let save_profile = SlotNoArgs::new(&view.main_window, clone!(
             view => move || {
                 if let Err(error) = view.save_profile() {
@@ -484,9 +1022,14 @@ impl AppUISlots {
                 }
 
                 // Always reload the profiles list.
-                view.actions_ui().profile_model().clear();
-                for profile in view.game_profiles().read().unwrap().keys().sorted() {
-                    view.actions_ui().profile_combobox().add_item_q_string(&QString::from_std_str(profile));
+                view.load_profiles_to_combobox();
+            }
+        ));
+
+        let open_custom_launch_options_manager = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = CustomLaunchOptionsUI::new(&view) {
+                    show_dialog(view.main_window(), error, false);
                 }
             }
         ));
@@ -507,6 +1050,199 @@ impl AppUISlots {
             }
         ));
 
+        let enable_random_subset = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.enable_random_subset() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let find_crashing_mod = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.find_crashing_mod() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let toggle_matching = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.toggle_matching_mods() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let global_search = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.global_search() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let reorder_launch_options = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.reorder_launch_options() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let script_sandbox = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.script_sandbox() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let effective_settings = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.effective_settings() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let export_launch_script = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+            match view.export_launch_script() {
+                Ok(Some(path)) => show_dialog(view.main_window(), tre("launch_script_exported", &[&path.to_string_lossy()]), true),
+                Ok(None) => {},
+                Err(error) => show_dialog(view.main_window(), error, false),
+            }
+        }));
+
+        let restore_script_backup = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+            if view.are_you_sure("restore_script_backup_confirm") {
+                match view.restore_script_backup() {
+                    Ok(Some(path)) => show_dialog(view.main_window(), tre("script_backup_restored", &[&path.to_string_lossy()]), true),
+                    Ok(None) => show_dialog(view.main_window(), tr("script_backup_none"), false),
+                    Err(error) => show_dialog(view.main_window(), error, false),
+                }
+            }
+        }));
+
+        let reapply_scripts = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+            match view.reapply_scripts() {
+                Ok(true) => show_dialog(view.main_window(), tr("scripts_reapplied"), true),
+                Ok(false) => {},
+                Err(error) => show_dialog(view.main_window(), error, false),
+            }
+        }));
+
+        let rebuild_translations = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+            match view.rebuild_translations() {
+                Ok(Some(loc_files)) => {
+                    let language = view.actions_ui().selected_translation();
+                    show_dialog(view.main_window(), tre("translations_rebuilt", &[&language, &loc_files.to_string()]), true);
+                },
+                Ok(None) => {},
+                Err(error) => show_dialog(view.main_window(), error, false),
+            }
+        }));
+
+        let merge_summary = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+            match view.merge_summary_message() {
+                Ok(message) => show_dialog(view.main_window(), message, true),
+                Err(error) => show_dialog(view.main_window(), error, false),
+            }
+        }));
+
+        let export_script_bundle = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+            match view.export_script_bundle() {
+                Ok(Some(path)) => show_dialog(view.main_window(), tre("script_bundle_exported", &[&path.to_string_lossy()]), true),
+                Ok(None) => {},
+                Err(error) => show_dialog(view.main_window(), error, false),
+            }
+        }));
+
+        // Imported scripts are only loaded on reload, so reload after importing them.
+        let import_script_bundle = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+            match view.import_script_bundle() {
+                Ok(Some(scripts)) => {
+                    view.actions_ui().reload_button().click();
+                    show_dialog(view.main_window(), tre("script_bundle_imported", &[&scripts.to_string()]), true);
+                }
+                Ok(None) => {},
+                Err(error) => show_dialog(view.main_window(), error, false),
+            }
+        }));
+
+        let validate_launch = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+            match view.validate_launch() {
+                Ok(report) => {
+                    let passed = report.iter().all(|(_, problems)| problems.is_empty());
+                    let checks = report.iter()
+                        .map(|(check, problems)| if problems.is_empty() {
+                            format!("<li>{}: {}</li>", check, tr("validate_launch_pass"))
+                        } else {
+                            format!("<li>{}: <b>{}</b><ul>{}</ul></li>", check, tr("validate_launch_fail"), problems.iter().map(|problem| format!("<li>{}</li>", problem)).join(""))
+                        })
+                        .join("");
+
+                    let summary = if passed { tr("validate_launch_passed") } else { tr("validate_launch_failed") };
+                    show_dialog(view.main_window(), format!("<p>{}</p><ul>{}</ul>", summary, checks), passed);
+                },
+                Err(error) => show_dialog(view.main_window(), error, false),
+            }
+        }));
+
+        let test_launch_options = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+            match view.test_launch_options() {
+                Ok((report, passed)) => show_dialog(view.main_window(), report, passed),
+                Err(error) => show_dialog(view.main_window(), error, false),
+            }
+        }));
+
+        let modification_summary = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+            match view.modification_summary() {
+                Ok(summary) => show_dialog(view.main_window(), summary, true),
+                Err(error) => show_dialog(view.main_window(), error, false),
+            }
+        }));
+
+        let launch_history = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+            if let Err(error) = view.launch_history_dialog() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let play_menu_about_to_hide = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+            if view.actions_ui().play_menu_pinned_checkbox().is_checked() {
+                view.actions_ui().play_menu_reopen_timer().start_0a();
+            }
+        }));
+
+        // Actions close the menu before being triggered. Don't reopen it in that case, as the action may need the focus.
+        let play_menu_triggered = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+            view.actions_ui().play_menu_reopen_timer().stop();
+        }));
+
+        let reopen_play_menu = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+
+            // Do not reopen it over dialogs, or while the main window is disabled.
+            let menu = view.actions_ui().play_button().menu();
+            if QApplication::active_modal_widget().is_null() && view.actions_ui().play_button().is_enabled() && !menu.is_visible() {
+                menu.popup_1a(&menu.pos());
+            }
+        }));
+
         let upload_to_workshop = SlotNoArgs::new(&view.main_window, clone!(
             view => move || {
                 if let Err(error) = view.upload_mod_to_workshop() {
@@ -559,6 +1295,14 @@ impl AppUISlots {
             view => move || {
                 AppUI::generate_move_to_category_submenu(&view);
                 AppUI::generate_open_in_tools_submenu(&view);
+                AppUI::generate_open_mod_folder_submenu(&view);
+
+                // Secondary folder actions only make sense if we have a secondary folder configured and supported.
+                if secondary_mods_path(view.game_selected().read().unwrap().key()).is_err() {
+                    view.mod_list_ui().copy_to_secondary().set_enabled(false);
+                    view.mod_list_ui().move_to_secondary().set_enabled(false);
+                    view.mod_list_ui().move_to_data().set_enabled(false);
+                }
             }
         ));
 
@@ -610,6 +1354,30 @@ impl AppUISlots {
             }
         ));
 
+        let move_to_data = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                {
+                    let selection = view.mod_list_selection()
+                        .iter()
+                        .map(|x| x.data_1a(VALUE_MOD_ID).to_string().to_std_string())
+                        .collect::<Vec<_>>();
+
+                    let game = view.game_selected().read().unwrap();
+                    if let Some(ref game_config) = *view.game_config().read().unwrap() {
+                        match move_to_data(&game, game_config, &selection) {
+                            Ok(failed_mods) => if !failed_mods.is_empty() {
+                                let string = failed_mods.iter().map(|string| format!("<li>{}</li>", string)).join("");
+                                show_dialog(view.main_window(), tre("move_to_data_failed", &[&string]), false)
+                            }
+                            Err(error) => show_dialog(view.main_window(), error, false),
+                        }
+                    }
+                }
+
+                view.actions_ui().reload_button().click();
+            }
+        ));
+
         let github_link = SlotNoArgs::new(view.main_window(), || { QDesktopServices::open_url(&QUrl::new_1a(&QString::from_std_str(GITHUB_URL))); });
         let discord_link = SlotNoArgs::new(view.main_window(), || { QDesktopServices::open_url(&QUrl::new_1a(&QString::from_std_str(DISCORD_URL))); });
         let patreon_link = SlotNoArgs::new(view.main_window(), || { QDesktopServices::open_url(&QUrl::new_1a(&QString::from_std_str(PATREON_URL))); });
@@ -689,15 +1457,35 @@ impl AppUISlots {
 
         Self {
             launch_game,
+            manage_saves,
+            tray_activated,
+            check_launch_status,
+            profile_backups_check,
+            show_last_launch_logs,
+            mod_folders_changed,
+            mod_folders_reload,
+            tray_show_hide,
+            tray_recent_profiles_menu_open,
             toggle_logging,
             toggle_skip_intros,
             toggle_remove_trait_limit,
             toggle_remove_siege_attacker,
             toggle_merge_all_mods,
+            merge_categories_menu_open,
+            rebalancer_excluded_categories_menu_open,
+            change_game_executable,
+            toggle_multiplayer_safe,
+            multiplayer_safe_options_menu_open,
             toggle_enable_translations,
             change_unit_multiplier,
+            change_unit_multiplier_preset,
+            save_unit_multiplier_preset,
+            delete_unit_multiplier_preset,
             toggle_universal_rebalancer,
             toggle_dev_only_ui,
+            change_preset_group,
+            filter_scripts,
+            change_scripts_sort,
             open_settings,
             open_folders_submenu,
             open_game_root_folder,
@@ -706,8 +1494,15 @@ impl AppUISlots {
             open_game_secondary_folder,
             open_game_config_folder,
             open_runcher_config_folder,
+            open_game_crash_dumps_folder,
             open_runcher_error_folder,
+            create_crash_report,
+            create_diagnostics_bundle,
+            export_config_bundle,
+            import_config_bundle,
+            toggle_mod_sources,
             change_game_selected,
+            game_selected_context_menu,
 
             update_pack_list,
 
@@ -719,18 +1514,48 @@ impl AppUISlots {
             patreon_link,
 
             copy_load_order,
+            copy_load_order_markdown,
+            copy_load_order_markdown_names_only,
+            copy_workshop_ids,
             paste_load_order,
+            import_load_order_file,
             reload,
             download_subscribed_mods,
 
             load_profile,
+            recent_profiles_menu_open,
             save_profile,
             open_profile_manager,
+            open_custom_launch_options_manager,
 
             enable_selected,
             disable_selected,
+            toggle_matching,
+            enable_random_subset,
+            find_crashing_mod,
+            global_search,
+            reorder_launch_options,
+            script_sandbox,
+            effective_settings,
+            export_launch_script,
+            restore_script_backup,
+            reapply_scripts,
+            rebuild_translations,
+            merge_summary,
+            validate_launch,
+            test_launch_options,
+            modification_summary,
+            launch_history,
+            export_script_bundle,
+            import_script_bundle,
+            play_menu_about_to_hide,
+            play_menu_triggered,
+            reopen_play_menu,
             upload_to_workshop,
             download_from_workshop,
+            repair_from_workshop,
+            redownload_broken_mods,
+            show_mod_updates,
             category_create,
             category_delete,
             category_rename,
@@ -739,9 +1564,11 @@ impl AppUISlots {
             mod_list_context_menu_open,
             copy_to_secondary,
             move_to_secondary,
+            move_to_data,
 
             pack_toggle_auto_sorting,
             pack_move,
+            install_dropped_packs,
             data_view_reload,
             open_file_with_rpfm,
         }
@@ -8,16 +8,18 @@
 // https://github.com/Frodo45127/runcher/blob/master/LICENSE.
 //---------------------------------------------------------------------------//
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use base64::{Engine as _, engine::general_purpose};
 use crossbeam::channel::Sender;
 use rayon::prelude::*;
 use zstd::stream::*;
 
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::path::{Path, PathBuf};
 
 use common_utils::updater::Updater;
 
+use rpfm_lib::files::{Container, ContainerPath, DecodeableExtraData, FileType, pack::Pack, RFileDecoded};
 use rpfm_lib::games::GameInfo;
 use rpfm_lib::integrations::{git::*, log::*};
 use rpfm_lib::schema::*;
@@ -26,7 +28,8 @@ use rpfm_ui_common::settings::error_path;
 
 use crate::{sql_scripts_remote_path, CENTRAL_COMMAND, SQL_SCRIPTS_BRANCH, SQL_SCRIPTS_REMOTE, SQL_SCRIPTS_REPO};
 use crate::communications::*;
-use crate::mod_manager::{game_config::GameConfig, load_order::{ImportedLoadOrderMode, LoadOrder}, mods::ShareableMod};
+use crate::games::run_script_sandbox;
+use crate::mod_manager::{font_coverage::FontCoverageData, game_config::GameConfig, load_order::{ImportedLoadOrderMode, importers, LoadOrder}, mods::ShareableMod};
 use crate::settings_ui::schemas_path;
 use crate::SCHEMA;
 use crate::{REPO_NAME, REPO_OWNER};
@@ -97,6 +100,34 @@ pub fn background_loop() {
                 }
             }
 
+            Command::GetMarkdownFromLoadOrder(game_config, game, game_data_path, load_order, names_only) => {
+                match get_markdown_from_load_order(game_config, &game, &game_data_path, load_order, names_only) {
+                    Ok(markdown) => CentralCommand::send_back(&sender, Response::String(markdown)),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+            }
+
+            Command::GetScriptSandboxDiff(game_config, game, game_path, load_order, mod_list_path, script_arg) => {
+                match get_script_sandbox_diff(game_config, &game, &game_path, load_order, &mod_list_path, &script_arg) {
+                    Ok(diff) => CentralCommand::send_back(&sender, Response::String(diff)),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+            }
+
+            Command::GetFontCoverageData(game_config, game, game_path, load_order, text_pack_path) => {
+                match get_font_coverage_data(game_config, &game, &game_path, load_order, &text_pack_path) {
+                    Ok(data) => CentralCommand::send_back(&sender, Response::FontCoverageData(data)),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+            }
+
+            Command::GetUnitCategories(pack_path) => {
+                match get_unit_categories(&pack_path) {
+                    Ok(categories) => CentralCommand::send_back(&sender, Response::VecString(categories)),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+            }
+
             Command::GetLoadOrderFromString(mode) => {
                 match get_load_order_from_string(mode) {
                     Ok(mods) => CentralCommand::send_back(&sender, Response::VecShareableMods(mods)),
@@ -104,7 +135,14 @@ pub fn background_loop() {
                 }
             }
 
-            Command::CheckUpdates(_) | Command::CheckSchemaUpdates | Command::CheckSqlScriptsUpdates | Command::RequestModsData(_,_) => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+            Command::UpdateModList(game, game_path, mut game_config, mut load_order, skip_network_update) => {
+                match game_config.update_mod_list(&game, &game_path, &mut load_order, skip_network_update) {
+                    Ok(network_receiver) => CentralCommand::send_back(&sender, Response::GameConfigLoadOrderReceiver(game_config, load_order, network_receiver)),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+            }
+
+            Command::CheckUpdates(_) | Command::CheckSchemaUpdates | Command::CheckSqlScriptsUpdates | Command::RequestModsData(_,_) | Command::UpdateModAdvisories(_) => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
         }
     }
 }
@@ -124,6 +162,231 @@ fn get_string_from_load_order(game_config: GameConfig, game: &GameInfo, game_dat
     Ok(general_purpose::STANDARD_NO_PAD.encode(compressed))
 }
 
+/// This function returns the enabled mods of the load order as a markdown table, ready to paste in Reddit or forums.
+fn get_markdown_from_load_order(game_config: GameConfig, game: &GameInfo, game_data_path: &Path, load_order: LoadOrder, names_only: bool) -> Result<String> {
+    let mods = load_order.mods()
+        .iter()
+        .filter_map(|mod_id| game_config.mods().get(mod_id))
+        .filter(|modd| modd.enabled(game, game_data_path) && !modd.paths().is_empty())
+        .collect::<Vec<_>>();
+
+    let mut markdown = if names_only {
+        String::from("| # | Mod |\n|---|---|\n")
+    } else {
+        String::from("| # | Mod | Workshop Link |\n|---|---|---|\n")
+    };
+
+    for (index, modd) in mods.iter().enumerate() {
+        let name = if modd.name().is_empty() { modd.id() } else { modd.name() };
+
+        // Pipes break the table, so escape them.
+        let name = name.replace('|', "\\|");
+
+        if names_only {
+            markdown.push_str(&format!("| {} | {} |\n", index + 1, name));
+        } else {
+            let link = match modd.steam_id() {
+                Some(steam_id) => format!("[Link](https://steamcommunity.com/sharedfiles/filedetails/?id={steam_id})"),
+                None => "-".to_owned(),
+            };
+
+            markdown.push_str(&format!("| {} | {} | {} |\n", index + 1, name, link));
+        }
+    }
+
+    Ok(markdown)
+}
+
+/// This function reads the fonts and the translated text needed to check the font coverage of a translation.
+///
+/// Fonts are read from the game packs, the enabled mods and the text pack itself, as mods and translations may ship their own fonts.
+fn get_font_coverage_data(game_config: GameConfig, game: &GameInfo, game_path: &Path, load_order: LoadOrder, text_pack_path: &Path) -> Result<FontCoverageData> {
+    let game_data_path = game.data_path(game_path)?;
+    let mut font_pack_paths = game.ca_packs_paths(game_path)?;
+    font_pack_paths.extend(load_order.mods()
+        .iter()
+        .filter_map(|mod_id| game_config.mods().get(mod_id))
+        .filter(|modd| modd.enabled(game, &game_data_path))
+        .filter_map(|modd| modd.paths().first().cloned()));
+    font_pack_paths.push(text_pack_path.to_path_buf());
+
+    FontCoverageData::read(&font_pack_paths, text_pack_path)
+}
+
+/// This function executes a script in the sandbox, and compares the tables in the pack it generated against the same tables in the provided load order.
+///
+/// Returns a plain text report with the rows the script added, changed or removed. Rows can only be removed by replacing a table file
+/// with one with the same path, so only the rows of the replaced files are checked for removals.
+fn get_script_sandbox_diff(game_config: GameConfig, game: &GameInfo, game_path: &Path, load_order: LoadOrder, mod_list_path: &Path, script_arg: &str) -> Result<String> {
+    let sandbox_path = run_script_sandbox(game, mod_list_path, script_arg)?;
+    let schema = SCHEMA.read().unwrap();
+    let schema = schema.as_ref().ok_or_else(|| anyhow!("There is no schema loaded for the game selected. Without it, we cannot decode the tables the script changed."))?;
+    let game_data_path = game.data_path(game_path)?;
+
+    let mut extra_data = DecodeableExtraData::default();
+    extra_data.set_schema(Some(schema));
+    let extra_data = Some(extra_data);
+
+    let mut sandbox_pack = Pack::read_and_merge(&[sandbox_path], true, false, false)?;
+    let folders = sandbox_pack.files_by_type(&[FileType::DB])
+        .iter()
+        .filter_map(|file| file.path_in_container_raw().rsplit_once('/').map(|(folder, _)| folder.to_owned() + "/"))
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .map(ContainerPath::Folder)
+        .collect::<Vec<_>>();
+
+    let mut sandbox_rows = TableRows::new();
+    let mut sandbox_file_keys = TableFileKeys::new();
+    table_rows(&mut sandbox_pack, &folders, &extra_data, &mut sandbox_rows, &mut sandbox_file_keys);
+
+    // Only read the tables the script touched. Mods first in the load order take priority, so they're read last.
+    let mut base_rows = TableRows::new();
+    let mut base_file_keys = TableFileKeys::new();
+    let mut base_paths = game.ca_packs_paths(game_path)?;
+    base_paths.extend(load_order.mods()
+        .iter()
+        .rev()
+        .filter_map(|mod_id| game_config.mods().get(mod_id))
+        .filter(|modd| modd.enabled(game, &game_data_path))
+        .filter_map(|modd| modd.paths().first().cloned()));
+
+    for path in &base_paths {
+        if let Ok(mut pack) = Pack::read_and_merge(&[path.to_path_buf()], true, false, false) {
+            table_rows(&mut pack, &folders, &extra_data, &mut base_rows, &mut base_file_keys);
+        }
+    }
+
+    // Rows of the replaced files that are not in the generated tables, nor in any other file of the same table.
+    let mut removed_rows: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for (path, keys) in base_file_keys.iter().filter(|(path, _)| sandbox_file_keys.contains_key(*path)) {
+        let folder = path.rsplit_once('/').map(|(folder, _)| folder.to_owned()).unwrap_or_default();
+        let new_rows = sandbox_rows.get(&folder).map(|(_, rows)| rows);
+        let other_keys = base_file_keys.iter()
+            .filter(|(other_path, _)| *other_path != path && other_path.rsplit_once('/').map(|(other_folder, _)| other_folder) == Some(folder.as_str()))
+            .flat_map(|(_, keys)| keys)
+            .collect::<HashSet<_>>();
+
+        removed_rows.entry(folder).or_default().extend(keys.iter()
+            .filter(|key| !new_rows.map_or(false, |rows| rows.contains_key(*key)) && !other_keys.contains(key))
+            .cloned());
+    }
+
+    let mut diff = String::new();
+    for (folder, (fields, rows)) in &sandbox_rows {
+        let old_rows = base_rows.get(folder).map(|(_, rows)| rows);
+        let mut table_diff = String::new();
+
+        for (key, row) in rows {
+            match old_rows.and_then(|old_rows| old_rows.get(key)) {
+                Some(old_row) => {
+                    let changes = fields.iter()
+                        .enumerate()
+                        .filter_map(|(column, field)| {
+                            let old_value = old_row.get(column)?;
+                            let new_value = row.get(column)?;
+                            if old_value != new_value {
+                                Some(format!("    {field}: {old_value} -> {new_value}\n"))
+                            } else {
+                                None
+                            }
+                        })
+                        .collect::<String>();
+
+                    if !changes.is_empty() {
+                        table_diff.push_str(&format!("  ~ {key}\n{changes}"));
+                    }
+                }
+                None => table_diff.push_str(&format!("  + {key}\n")),
+            }
+        }
+
+        if let Some(removed_rows) = removed_rows.get(folder) {
+            for key in removed_rows {
+                table_diff.push_str(&format!("  - {key}\n"));
+            }
+        }
+
+        if !table_diff.is_empty() {
+            diff.push_str(&format!("{folder}\n{table_diff}\n"));
+        }
+    }
+
+    // Non-table files cannot be compared, but it's useful to know the script generated them.
+    let other_files = sandbox_pack.files()
+        .keys()
+        .filter(|path| !path.starts_with("db/"))
+        .cloned()
+        .collect::<BTreeSet<_>>();
+
+    for path in &other_files {
+        diff.push_str(&format!("+ {path}\n"));
+    }
+
+    Ok(diff)
+}
+
+/// Field names and rows of each table, by table folder, then by row key.
+type TableRows = BTreeMap<String, (Vec<String>, BTreeMap<String, Vec<String>>)>;
+
+/// Keys of the rows of each table file, by path.
+type TableFileKeys = BTreeMap<String, BTreeSet<String>>;
+
+/// This function returns the unit categories used in the land_units tables of the provided pack, sorted and without duplicates.
+///
+/// These are the categories the user can pick to be skipped by the universal rebalancer.
+fn get_unit_categories(pack_path: &Path) -> Result<Vec<String>> {
+    let schema = SCHEMA.read().unwrap();
+    let schema = schema.as_ref().ok_or_else(|| anyhow!("There is no schema loaded for the game selected. Without it, we cannot decode the unit tables."))?;
+
+    let mut extra_data = DecodeableExtraData::default();
+    extra_data.set_schema(Some(schema));
+    let extra_data = Some(extra_data);
+
+    let mut pack = Pack::read_and_merge(&[pack_path.to_path_buf()], true, false, false)?;
+    let mut categories = BTreeSet::new();
+    for file in pack.files_by_type_and_paths_mut(&[FileType::DB], &[ContainerPath::Folder("db/land_units_tables/".to_owned())], true) {
+        if let Ok(Some(RFileDecoded::DB(table))) = file.decode(&extra_data, false, true) {
+            if let Some(column) = table.column_position_by_name("category") {
+                categories.extend(table.data().iter().filter_map(|row| row.get(column)).map(|cell| cell.data_to_string().to_string()));
+            }
+        }
+    }
+
+    Ok(categories.into_iter().filter(|category| !category.is_empty()).collect())
+}
+
+/// This function decodes the tables in the provided folders of the pack, adding their rows to the provided ones.
+///
+/// Rows with a key already in the list overwrite the existing ones, and files with a path already in the list replace the existing ones.
+fn table_rows(pack: &mut Pack, folders: &[ContainerPath], extra_data: &Option<DecodeableExtraData>, rows: &mut TableRows, file_keys: &mut TableFileKeys) {
+    for file in pack.files_by_type_and_paths_mut(&[FileType::DB], folders, true) {
+        let path = file.path_in_container_raw().to_owned();
+        let folder = path.rsplit_once('/').map(|(folder, _)| folder.to_owned()).unwrap_or_default();
+        if let Ok(Some(RFileDecoded::DB(table))) = file.decode(extra_data, false, true) {
+            let fields = table.definition().fields_processed().iter().map(|field| field.name().to_owned()).collect::<Vec<_>>();
+            let key_columns = table.definition().key_column_positions();
+            let (_, table_rows) = rows.entry(folder).or_insert_with(|| (fields, BTreeMap::new()));
+            let keys = file_keys.entry(path).or_default();
+            keys.clear();
+
+            for row in table.data().iter() {
+                let row = row.iter().map(|cell| cell.data_to_string().to_string()).collect::<Vec<_>>();
+
+                // Tables without keys are compared using the full row.
+                let key = if key_columns.is_empty() {
+                    row.join("|")
+                } else {
+                    key_columns.iter().filter_map(|column| row.get(*column)).cloned().collect::<Vec<_>>().join("|")
+                };
+
+                keys.insert(key.to_owned());
+                table_rows.insert(key, row);
+            }
+        }
+    }
+}
+
 fn get_load_order_from_string(mode: ImportedLoadOrderMode) -> Result<Vec<ShareableMod>> {
     match mode {
         ImportedLoadOrderMode::Runcher(string) => {
@@ -147,5 +410,6 @@ fn get_load_order_from_string(mode: ImportedLoadOrderMode) -> Result<Vec<Shareab
             }
             Ok(mods)
         }
+        ImportedLoadOrderMode::External(string) => importers::parse(&string),
     }
 }
@@ -144,7 +144,9 @@ impl Cli {
         // Also, the game we already have loaded is arena. We don't need to force a manual reload with that one.
         //
         // Note: if we're autostarting, skip the network update to start the game 1-5 seconds faster.
-        let network_receiver = app_ui.change_game_selected(false, cli.autostart)?;
+        // Note: a profile passed through args overrides the default profile of the game, so don't load the default one in that case.
+        let load_default_profile = !game_passed || cli.profile.is_none();
+        let network_receiver = app_ui.change_game_selected(false, cli.autostart, load_default_profile)?;
 
         // If we're not autostarting, enable the UI here.
         if !cli.autostart {
@@ -22,7 +22,7 @@ use common_utils::updater::*;
 use rpfm_lib::games::GameInfo;
 use rpfm_lib::integrations::{log::info, git::GitResponse};
 
-use crate::mod_manager::{game_config::GameConfig, load_order::{ImportedLoadOrderMode, LoadOrder}, mods::{Mod, ShareableMod}};
+use crate::mod_manager::{font_coverage::FontCoverageData, game_config::GameConfig, load_order::{ImportedLoadOrderMode, LoadOrder}, mods::{Mod, ShareableMod}};
 
 /// This const is the standard message in case of message communication error. If this happens, crash the program.
 pub const THREADS_COMMUNICATION_ERROR: &str = "Error in thread communication system. Response received: ";
@@ -59,8 +59,14 @@ pub enum Command {
     UpdateSqlScripts,
     UpdateSchemas(String),
     GetStringFromLoadOrder(GameConfig, GameInfo, PathBuf, LoadOrder),
+    GetMarkdownFromLoadOrder(GameConfig, GameInfo, PathBuf, LoadOrder, bool),
+    GetScriptSandboxDiff(GameConfig, GameInfo, PathBuf, LoadOrder, PathBuf, String),
+    GetFontCoverageData(GameConfig, GameInfo, PathBuf, LoadOrder, PathBuf),
     GetLoadOrderFromString(ImportedLoadOrderMode),
+    GetUnitCategories(PathBuf),
     RequestModsData(Box<GameInfo>, Vec<String>),
+    UpdateModAdvisories(String),
+    UpdateModList(Box<GameInfo>, PathBuf, GameConfig, LoadOrder, bool),
 }
 
 /// This enum defines the responses (messages) you can send to the to the UI thread as result of a command.
@@ -71,10 +77,13 @@ pub enum Response {
     Success,
     Error(Error),
     String(String),
+    VecString(Vec<String>),
     APIResponse(APIResponse),
     APIResponseGit(GitResponse),
     VecShareableMods(Vec<ShareableMod>),
     VecMod(Vec<Mod>),
+    FontCoverageData(FontCoverageData),
+    GameConfigLoadOrderReceiver(GameConfig, LoadOrder, Option<Receiver<Response>>),
 }
 
 //-------------------------------------------------------------------------------//
@@ -0,0 +1,209 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+use qt_widgets::QDialog;
+use qt_widgets::QGroupBox;
+use qt_widgets::QLabel;
+use qt_widgets::QTableView;
+use qt_widgets::QToolButton;
+use qt_widgets::QWidget;
+use qt_widgets::q_header_view::ResizeMode;
+
+use qt_gui::QListOfQStandardItem;
+use qt_gui::QStandardItem;
+use qt_gui::QStandardItemModel;
+
+use qt_core::CheckState;
+use qt_core::QBox;
+use qt_core::QPtr;
+use qt_core::QString;
+use qt_core::QVariant;
+
+use anyhow::{anyhow, Result};
+use getset::*;
+
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use rpfm_ui_common::locale::{qtr, tre};
+use rpfm_ui_common::utils::*;
+
+use crate::AppUI;
+use crate::custom_launch_options_ui::slots::CustomLaunchOptionsUISlots;
+use crate::mod_manager::custom_launch_options::{CustomLaunchOption, CustomLaunchOptionKind, CustomLaunchOptions};
+
+const VIEW_DEBUG: &str = "ui_templates/custom_launch_options_dialog.ui";
+const VIEW_RELEASE: &str = "ui/custom_launch_options_dialog.ui";
+
+mod slots;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Debug, Getters)]
+#[getset(get = "pub")]
+pub struct CustomLaunchOptionsUI {
+    main_widget: QBox<QWidget>,
+    options_table_view: QPtr<QTableView>,
+    options_model: QBox<QStandardItemModel>,
+    add_button: QPtr<QToolButton>,
+    remove_button: QPtr<QToolButton>,
+}
+
+//---------------------------------------------------------------------------//
+//                              UI functions
+//---------------------------------------------------------------------------//
+
+impl CustomLaunchOptionsUI {
+
+    pub unsafe fn new(app_ui: &Rc<AppUI>) -> Result<()> {
+
+        // Load the UI Template.
+        let template_path = if cfg!(debug_assertions) { VIEW_DEBUG } else { VIEW_RELEASE };
+        let main_widget = load_template(app_ui.main_window(), template_path)?;
+
+        let explanation_groupbox: QPtr<QGroupBox> = find_widget(&main_widget.static_upcast(), "explanation_groupbox")?;
+        let explanation_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "explanation_label")?;
+        let options_table_view: QPtr<QTableView> = find_widget(&main_widget.static_upcast(), "options_table_view")?;
+        let add_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "add_button")?;
+        let remove_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "remove_button")?;
+        let options_model = QStandardItemModel::new_1a(&options_table_view);
+        options_table_view.set_model(&options_model);
+
+        explanation_groupbox.set_title(&qtr("custom_launch_options_explanation_title"));
+        explanation_label.set_text(&qtr("custom_launch_options_explanation"));
+        add_button.set_tool_tip(&qtr("custom_launch_options_add"));
+        remove_button.set_tool_tip(&qtr("custom_launch_options_remove"));
+
+        let ui = Rc::new(Self {
+            main_widget,
+            options_table_view,
+            options_model,
+            add_button,
+            remove_button,
+        });
+
+        let slots = CustomLaunchOptionsUISlots::new(&ui);
+        ui.set_connections(&slots);
+
+        let game = app_ui.game_selected().read().unwrap().clone();
+        let mut options = CustomLaunchOptions::load(&game, true)?;
+        ui.load_data(&options);
+
+        ui.dialog().set_window_title(&qtr("custom_launch_options_title"));
+        if ui.dialog().exec() == 1 {
+            options.set_options(ui.options()?);
+            options.save(&game)?;
+
+            app_ui.actions_ui().load_custom_launch_options(&game)?;
+        }
+
+        Ok(())
+    }
+
+    pub unsafe fn set_connections(&self, slots: &CustomLaunchOptionsUISlots) {
+        self.add_button().released().connect(slots.add());
+        self.remove_button().released().connect(slots.remove());
+    }
+
+    pub unsafe fn dialog(&self) -> QPtr<QDialog> {
+        self.main_widget().static_downcast::<QDialog>()
+    }
+
+    pub unsafe fn load_data(&self, options: &CustomLaunchOptions) {
+        for option in options.options() {
+            self.add_row(option);
+        }
+
+        self.setup_columns();
+        self.options_table_view().horizontal_header().resize_sections(ResizeMode::ResizeToContents);
+    }
+
+    pub unsafe fn setup_columns(&self) {
+        let name = QStandardItem::from_q_string(&qtr("custom_launch_options_name"));
+        let flag = QStandardItem::from_q_string(&qtr("custom_launch_options_flag"));
+        let has_value = QStandardItem::from_q_string(&qtr("custom_launch_options_has_value"));
+        let default_value = QStandardItem::from_q_string(&qtr("custom_launch_options_default_value"));
+
+        self.options_model().set_horizontal_header_item(0, name.into_ptr());
+        self.options_model().set_horizontal_header_item(1, flag.into_ptr());
+        self.options_model().set_horizontal_header_item(2, has_value.into_ptr());
+        self.options_model().set_horizontal_header_item(3, default_value.into_ptr());
+    }
+
+    pub unsafe fn add_row(&self, option: &CustomLaunchOption) {
+        let row = QListOfQStandardItem::new();
+        let name = QStandardItem::from_q_string(&QString::from_std_str(option.name()));
+        let flag = QStandardItem::from_q_string(&QString::from_std_str(option.flag()));
+        let has_value = QStandardItem::new();
+        let default_value = QStandardItem::new();
+
+        has_value.set_checkable(true);
+        has_value.set_editable(false);
+        has_value.set_check_state(if *option.kind() == CustomLaunchOptionKind::Value { CheckState::Checked } else { CheckState::Unchecked });
+        default_value.set_data_2a(&QVariant::from_double(*option.default_value()), 2);
+
+        row.append_q_standard_item(&name.into_ptr().as_mut_raw_ptr());
+        row.append_q_standard_item(&flag.into_ptr().as_mut_raw_ptr());
+        row.append_q_standard_item(&has_value.into_ptr().as_mut_raw_ptr());
+        row.append_q_standard_item(&default_value.into_ptr().as_mut_raw_ptr());
+
+        self.options_model().append_row_q_list_of_q_standard_item(row.into_ptr().as_ref().unwrap());
+    }
+
+    /// This function removes the selected rows from the table.
+    pub unsafe fn remove_selected_rows(&self) {
+        let indexes = self.options_table_view().selection_model().selection().indexes();
+        let mut rows = (0..indexes.count_0a())
+            .map(|x| indexes.at(x).row())
+            .collect::<Vec<_>>();
+
+        rows.sort();
+        rows.dedup();
+        rows.reverse();
+
+        for row in rows {
+            self.options_model().remove_row_1a(row);
+        }
+    }
+
+    /// This function returns the options from the table. Rows without name or flag are ignored.
+    pub unsafe fn options(&self) -> Result<Vec<CustomLaunchOption>> {
+        let mut options = vec![];
+        let mut names = HashSet::new();
+
+        for row in 0..self.options_model().row_count_0a() {
+            let name = self.options_model().item_2a(row, 0).text().to_std_string().trim().to_owned();
+            let flag = self.options_model().item_2a(row, 1).text().to_std_string().trim().to_owned();
+            if name.is_empty() || flag.is_empty() {
+                continue;
+            }
+
+            if !names.insert(name.to_owned()) {
+                return Err(anyhow!(tre("custom_launch_options_duplicated_name", &[&name])));
+            }
+
+            let mut option = CustomLaunchOption::default();
+            option.set_name(name);
+            option.set_flag(flag);
+            option.set_kind(if self.options_model().item_2a(row, 2).check_state() == CheckState::Checked {
+                CustomLaunchOptionKind::Value
+            } else {
+                CustomLaunchOptionKind::Bool
+            });
+            option.set_default_value(self.options_model().item_2a(row, 3).data_1a(2).to_double_0a());
+
+            options.push(option);
+        }
+
+        Ok(options)
+    }
+}
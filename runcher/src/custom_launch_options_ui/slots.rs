@@ -0,0 +1,60 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+use qt_core::SlotNoArgs;
+
+use qt_core::QBox;
+
+use getset::*;
+
+use std::rc::Rc;
+
+use rpfm_ui_common::clone;
+
+use crate::mod_manager::custom_launch_options::CustomLaunchOption;
+
+use super::CustomLaunchOptionsUI;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Debug, Getters)]
+#[getset(get = "pub")]
+pub struct CustomLaunchOptionsUISlots {
+    add: QBox<SlotNoArgs>,
+    remove: QBox<SlotNoArgs>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl CustomLaunchOptionsUISlots {
+
+    pub unsafe fn new(ui: &Rc<CustomLaunchOptionsUI>) -> Self {
+        let add = SlotNoArgs::new(ui.main_widget(), clone!(
+            ui => move || {
+                ui.add_row(&CustomLaunchOption::default());
+            }
+        ));
+
+        let remove = SlotNoArgs::new(ui.main_widget(), clone!(
+            ui => move || {
+                ui.remove_selected_rows();
+            }
+        ));
+
+        Self {
+            add,
+            remove,
+        }
+    }
+}
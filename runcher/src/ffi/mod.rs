@@ -77,6 +77,12 @@ pub fn new_mod_list_model_safe(parent: QPtr<QWidget>) -> QPtr<QStandardItemModel
     unsafe { QPtr::from_raw(new_mod_list_model(parent.as_mut_raw_ptr())) }
 }
 
+// This function toggles the accent color painted behind the enabled mods.
+extern "C" { fn mod_list_model_set_color_enabled(model: *mut QStandardItemModel, enabled: bool); }
+pub fn mod_list_model_set_color_enabled_safe(model: &QPtr<QStandardItemModel>, enabled: bool) {
+    unsafe { mod_list_model_set_color_enabled(model.as_mut_raw_ptr(), enabled); }
+}
+
 // This function allow us to create a QTreeView compatible with draggable items
 extern "C" { fn new_mod_list_tree_view(parent: *mut QWidget) -> *mut QTreeView; }
 pub fn new_mod_list_tree_view_safe(parent: QPtr<QWidget>) -> QPtr<QTreeView> {
@@ -124,3 +130,14 @@ pub fn draggable_tree_view_drop_signal(widget: QPtr<QWidget>) -> Signal<(*const
         )
     }
 }
+
+pub fn launcher_window_files_dropped_signal(widget: QPtr<QWidget>) -> Signal<(*const QStringList,)> {
+    unsafe {
+        Signal::new(
+            ::cpp_core::Ref::from_raw(widget.as_raw_ptr()).expect("attempted to construct a null Ref"),
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"2filesDropped(QStringList const &)\0",
+            ),
+        )
+    }
+}
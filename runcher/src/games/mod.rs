@@ -12,14 +12,22 @@ use qt_widgets::{QCheckBox, QComboBox, QDoubleSpinBox, QGridLayout, QSpinBox};
 
 use qt_gui::QResizeEvent;
 
-use qt_core::{QSize, QString};
+use qt_core::{QBox, QSize, QString};
+
+use cpp_core::Ptr;
 
 use anyhow::{anyhow, Result};
+use getset::Getters;
+use itertools::Itertools;
+use serde::Deserialize;
 
 use std::collections::HashMap;
+use std::fs::{DirBuilder, File};
+use std::io::{BufReader, BufWriter};
 #[cfg(target_os = "windows")]use std::os::windows::process::CommandExt;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use common_utils::sql::{ParamType, Preset, SQLScript};
 
@@ -29,12 +37,14 @@ use rpfm_lib::utils::files_from_subdir;
 
 use rpfm_ui_common::settings::*;
 
+use crate::actions_ui::ActionsUI;
 use crate::app_ui::{AppUI, CUSTOM_MOD_LIST_FILE_NAME};
 use crate::mod_manager::game_config::GameConfig;
 #[cfg(target_os = "windows")]use crate::mod_manager::integrations::DETACHED_PROCESS;
 use crate::mod_manager::load_order::LoadOrder;
+use crate::mod_manager::{is_safe_relative_path, load_mods_from, secondary_mods_extra_paths, MOD_SOURCE_CONTENT, MOD_SOURCE_SECONDARY};
 use crate::SCHEMA;
-use crate::settings_ui::{temp_packs_folder, sql_scripts_extracted_path, sql_scripts_extracted_extended_path, sql_scripts_local_path, sql_scripts_remote_path};
+use crate::settings_ui::{script_backups_folder, script_sandbox_folder, temp_packs_folder, sql_scripts_extracted_path, sql_scripts_extracted_extended_path, sql_scripts_local_path, sql_scripts_local_presets_path, sql_scripts_remote_path};
 
 pub const RESERVED_PACK_NAME: &str = "zzzzzzzzzzzzzzzzzzzzrun_you_fool_thron.pack";
 pub const RESERVED_PACK_NAME_ALTERNATIVE: &str = "!!!!!!!!!!!!!!!!!!!!!run_you_fool_thron.pack";
@@ -49,30 +59,137 @@ lazy_static::lazy_static! {
 
 const PATCHER_EXE: &str = "twpatcher.exe";
 
+/// Executables in the game folder that are never the game itself, in lowercase.
+const NON_GAME_EXECUTABLES: [&str; 6] = ["launcher", "crash", "unins", "redist", "dxsetup", "twpatcher"];
+
+/// Default values of the built-in launch options, by the setting storing them (followed by the game key).
+const LAUNCH_OPTION_DEFAULTS: [(&str, &str); 10] = [
+    ("enable_logging", "false"),
+    ("enable_skip_intro", "false"),
+    ("remove_trait_limit", "false"),
+    ("remove_siege_attacker", "false"),
+    ("merge_all_mods", "false"),
+    ("merge_all_mods_categories", ""),
+    ("enable_translations", "--"),
+    ("unit_multiplier", "1"),
+    ("universal_rebalancer", "--"),
+    ("enable_dev_only_ui", "false"),
+];
+
+/// Default values of the built-in launch options that differ per game.
+const LAUNCH_OPTION_GAME_DEFAULTS: [LaunchOptionGameDefault; 1] = [
+
+    // Newer games play several intro movies on every start, so skipping them is on by default there.
+    LaunchOptionGameDefault {
+        option: "enable_skip_intro",
+        value: "true",
+        games: &[KEY_PHARAOH_DYNASTIES, KEY_PHARAOH, KEY_WARHAMMER_3, KEY_TROY, KEY_THREE_KINGDOMS, KEY_WARHAMMER_2, KEY_WARHAMMER],
+    },
+];
+
 //-------------------------------------------------------------------------------//
-//                             Implementations
+//                              Enums & Structs
 //-------------------------------------------------------------------------------//
 
-pub unsafe fn prepare_launch_options(app_ui: &AppUI, game: &GameInfo, data_path: &Path, folder_list: &mut String) -> Result<()> {
-    let actions_ui = app_ui.actions_ui();
+/// Default value of a built-in launch option for a group of games, overriding the one in [LAUNCH_OPTION_DEFAULTS].
+struct LaunchOptionGameDefault {
 
-    // We only use the reserved pack if we need to.
-    if (actions_ui.enable_logging_checkbox().is_enabled() && actions_ui.enable_logging_checkbox().is_checked()) ||
+    // Setting storing the option, without the game key.
+    option: &'static str,
+
+    // Default value of the option in the games of this group.
+    value: &'static str,
+
+    // Keys of the games using this default.
+    games: &'static [&'static str],
+}
+
+/// Condition for a script param to be visible, set through the optional `visible_when` key of the param in the script's metadata.
+///
+/// For example, `visible_when: { param: mode, values: ["2", "3"] }` only shows the param when the `mode` param is 2 or 3.
+#[derive(Clone, Debug, Default, Getters, Deserialize)]
+#[getset(get = "pub")]
+pub struct VisibleWhen {
+
+    // Key of the param controlling the visibility of this one.
+    param: String,
+
+    // Values of the controlling param that make this one visible.
+    values: Vec<String>,
+}
+
+/// Metadata of a script that Runcher reads by itself, as the script parser doesn't know about it.
+#[derive(Clone, Debug, Default, Getters)]
+#[getset(get = "pub")]
+pub struct ScriptExtraMetadata {
+
+    // Visibility conditions of the params of the script, by param key.
+    param_conditions: HashMap<String, VisibleWhen>,
+
+    // If the script modifies game files irreversibly. Set through the optional `destructive` key of the script's metadata.
+    destructive: bool,
+
+    // Files the script modifies, relative to the game folder. Set through the optional `affected_files` key of the script's metadata.
+    affected_files: Vec<String>,
+
+    // Category of the script, used to group the scripts list. Set through the optional `category` key of the script's metadata.
+    category: Option<String>,
+
+    // Packs that must be enabled for an automatic script to run. Set through the optional `requires_mods` key of the script's metadata.
+    requires_mods: Vec<String>,
+}
+
+/// Subset of the script file we need to read the extra metadata.
+#[derive(Default, Deserialize)]
+struct ScriptExtra {
+    #[serde(default)]
+    metadata: ScriptExtraMetadataRaw,
+}
+
+#[derive(Default, Deserialize)]
+struct ScriptExtraMetadataRaw {
+    #[serde(default)]
+    parameters: Vec<ParamConditions>,
+    #[serde(default)]
+    destructive: bool,
+    #[serde(default)]
+    affected_files: Vec<String>,
+    #[serde(default)]
+    category: Option<String>,
+    #[serde(default)]
+    requires_mods: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct ParamConditions {
+    key: String,
+    #[serde(default)]
+    visible_when: Option<VisibleWhen>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// This function returns if any of the built-in launch options enabled in the UI needs the reserved pack generated by the patcher.
+///
+/// Scripts also need it, but they're not checked here.
+pub unsafe fn launch_options_need_patch(actions_ui: &ActionsUI) -> bool {
+    (actions_ui.enable_logging_checkbox().is_enabled() && actions_ui.enable_logging_checkbox().is_checked()) ||
         (actions_ui.enable_skip_intro_checkbox().is_enabled() && actions_ui.enable_skip_intro_checkbox().is_checked()) ||
         (actions_ui.remove_trait_limit_checkbox().is_enabled() && actions_ui.remove_trait_limit_checkbox().is_checked()) ||
         (actions_ui.remove_siege_attacker_checkbox().is_enabled() && actions_ui.remove_siege_attacker_checkbox().is_checked()) ||
         (actions_ui.enable_translations_combobox().is_enabled() && actions_ui.enable_translations_combobox().current_index() != 0) ||
         (actions_ui.universal_rebalancer_combobox().is_enabled() && actions_ui.universal_rebalancer_combobox().current_index() != 0) ||
         (actions_ui.enable_dev_only_ui_checkbox().is_enabled() && actions_ui.enable_dev_only_ui_checkbox().is_checked()) ||
-        (actions_ui.unit_multiplier_spinbox().is_enabled() && actions_ui.unit_multiplier_spinbox().value() != 1.00) ||
-        actions_ui.scripts_to_execute().read().unwrap().iter().any(|(_, item)| item.is_checked()) {
+        (actions_ui.unit_multiplier_spinbox().is_enabled() && actions_ui.unit_multiplier_spinbox().value() != 1.00)
+}
 
-        // We need to use an alternative name for Shogun 2, Rome 2, Attila and Thrones because their load order logic for movie packs seems... either different or broken.
-        let reserved_pack_name = if game.key() == KEY_SHOGUN_2 || game.key() == KEY_ROME_2 || game.key() == KEY_ATTILA || game.key() == KEY_THRONES_OF_BRITANNIA {
-            RESERVED_PACK_NAME_ALTERNATIVE
-        } else {
-            RESERVED_PACK_NAME
-        };
+pub unsafe fn prepare_launch_options(app_ui: &AppUI, game: &GameInfo, data_path: &Path, folder_list: &mut String) -> Result<()> {
+    let actions_ui = app_ui.actions_ui();
+
+    // We only use the reserved pack if we need to.
+    if launch_options_need_patch(actions_ui) || actions_ui.scripts_to_execute().read().unwrap().iter().any(|(_, item)| item.is_checked()) {
 
         // If the reserved pack is loaded from a custom folder we need to CLEAR SAID FOLDER before anything else. Otherwise we may end up with old packs messing up stuff.
         if *game.raw_db_version() >= 1 {
@@ -83,15 +200,10 @@ pub unsafe fn prepare_launch_options(app_ui: &AppUI, game: &GameInfo, data_path:
             }
         }
 
-        // Support for add_working_directory seems to be only present in rome 2 and newer games. For older games, we drop the pack into /data.
-        let temp_path = if *game.raw_db_version() >= 1 {
-            let temp_packs_folder = temp_packs_folder(game)?;
-            let temp_path = temp_packs_folder.join(reserved_pack_name);
-            folder_list.push_str(&format!("add_working_directory \"{}\";\n", temp_packs_folder.to_string_lossy()));
-            temp_path
-        } else {
-            data_path.join(reserved_pack_name)
-        };
+        let temp_path = reserved_pack_path(game, data_path)?;
+        if *game.raw_db_version() >= 1 {
+            folder_list.push_str(&format!("add_working_directory \"{}\";\n", temp_packs_folder(game)?.to_string_lossy()));
+        }
 
         // Prepare the command to generate the temp pack.
         let mut cmd = Command::new("cmd");
@@ -133,7 +245,7 @@ pub unsafe fn prepare_launch_options(app_ui: &AppUI, game: &GameInfo, data_path:
         // Translations check.
         if actions_ui.enable_translations_combobox().is_enabled() && actions_ui.enable_translations_combobox().current_index() != 0 {
             cmd.arg("-t");
-            cmd.arg(app_ui.actions_ui().enable_translations_combobox().current_text().to_std_string());
+            cmd.arg(app_ui.actions_ui().selected_translation());
         }
 
         // Universal Rebalancer check.
@@ -149,99 +261,10 @@ pub unsafe fn prepare_launch_options(app_ui: &AppUI, game: &GameInfo, data_path:
         }
 
         // Script checks.
-        let sql_folder_extracted = sql_scripts_extracted_extended_path()?;
-        let sql_folder_local = sql_scripts_local_path()?.join(game.key());
-        let sql_folder_remote = sql_scripts_remote_path()?.join(game.key());
-        actions_ui.scripts_to_execute().read().unwrap()
-            .iter()
-            .filter(|(_, item)| item.is_checked())
-            .for_each(|(script, item)| {
-                cmd.arg("--sql-script");
-
-                let script_params = if script.metadata().parameters().is_empty() {
-                    vec![]
-                } else {
-                    let mut script_params = vec![];
-                    let script_container = item.parent_widget().parent_widget();
-
-                    // First check if we have a preset set. If not, we can check each param.
-                    let preset_combo_name = format!("{}_preset_combo", script.metadata().key());
-                    let preset_key = if let Ok(widget) = script_container.find_child::<QComboBox>(&preset_combo_name) {
-                        widget.current_text().to_std_string()
-                    } else {
-                        String::new()
-                    };
-
-                    let preset = if !preset_key.is_empty() {
-                        let preset_path = sql_scripts_extracted_path().unwrap().join("twpatcher/presets");
-                        if preset_path.is_dir() {
-                            files_from_subdir(&preset_path, false).unwrap()
-                                .iter()
-                                .filter_map(|x| Preset::read(x).ok())
-                                .find(|x| *x.key() == preset_key)
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    };
-
-                    match preset {
-                        Some(preset) => {
-                            for param in script.metadata().parameters() {
-                                match preset.params().get(param.key()) {
-                                    Some(value) => script_params.push(value.to_string()),
-                                    None => script_params.push(param.default_value().to_string()),
-                                }
-                            }
-                        }
-                        None => {
-                            for param in script.metadata().parameters() {
-                                let object_name = format!("{}_{}", script.metadata().key(), param.key());
-                                match param.r#type() {
-                                    ParamType::Bool => {
-                                        if let Ok(widget) = script_container.find_child::<QCheckBox>(&object_name) {
-                                            script_params.push(widget.is_checked().to_string());
-                                        }
-                                    },
-                                    ParamType::Integer => {
-                                        if let Ok(widget) = script_container.find_child::<QSpinBox>(&object_name) {
-                                            script_params.push(widget.value().to_string());
-                                        }
-                                    },
-                                    ParamType::Float => {
-                                        if let Ok(widget) = script_container.find_child::<QDoubleSpinBox>(&object_name) {
-                                            script_params.push(widget.value().to_string());
-                                        }
-                                    },
-                                }
-                            }
-                        }
-                    }
-
-
-                    script_params
-                };
-
-                // When there's a collision, default to the local script path.
-                let script_name = format!("{}.yml", script.metadata().key());
-                let local_script_path = sql_folder_local.join(&script_name);
-                let extracted_script_path = sql_folder_extracted.join(&script_name);
-                let remote_script_path = sql_folder_remote.join(&script_name);
-                let script_path = if PathBuf::from(&local_script_path).is_file() {
-                    local_script_path
-                } else if PathBuf::from(&extracted_script_path).is_file() {
-                    extracted_script_path
-                } else {
-                    remote_script_path
-                };
-
-                if script_params.is_empty() {
-                    cmd.arg(script_path);
-                } else {
-                    cmd.arg(format!("{};{}", script_path.to_string_lossy().to_string().replace("\\", "/"), script_params.join(";")));
-                }
-            });
+        for (script, item) in actions_ui.scripts_to_execute().read().unwrap().iter().filter(|(_, item)| item.is_checked()) {
+            cmd.arg("--sql-script");
+            cmd.arg(sql_script_arg(game, script, item.as_ptr())?);
+        }
 
         cmd.creation_flags(DETACHED_PROCESS);
 
@@ -256,10 +279,441 @@ pub unsafe fn prepare_launch_options(app_ui: &AppUI, game: &GameInfo, data_path:
     Ok(())
 }
 
+/// This function returns the default values of the built-in launch options for the provided game, by the setting storing them (without the game key).
+pub fn launch_option_defaults(game_key: &str) -> Vec<(&'static str, &'static str)> {
+    LAUNCH_OPTION_DEFAULTS.iter()
+        .map(|(key, default)| {
+            let default = LAUNCH_OPTION_GAME_DEFAULTS.iter()
+                .find(|default| default.option == *key && default.games.contains(&game_key))
+                .map(|default| default.value)
+                .unwrap_or(*default);
+
+            (*key, default)
+        })
+        .collect()
+}
+
+/// This function returns the path of the reserved pack generated by the patcher when launching the provided game.
+pub fn reserved_pack_path(game: &GameInfo, data_path: &Path) -> Result<PathBuf> {
+
+    // We need to use an alternative name for Shogun 2, Rome 2, Attila and Thrones because their load order logic for movie packs seems... either different or broken.
+    let reserved_pack_name = if game.key() == KEY_SHOGUN_2 || game.key() == KEY_ROME_2 || game.key() == KEY_ATTILA || game.key() == KEY_THRONES_OF_BRITANNIA {
+        RESERVED_PACK_NAME_ALTERNATIVE
+    } else {
+        RESERVED_PACK_NAME
+    };
+
+    // Support for add_working_directory seems to be only present in rome 2 and newer games. For older games, we drop the pack into /data.
+    if *game.raw_db_version() >= 1 {
+        Ok(temp_packs_folder(game)?.join(reserved_pack_name))
+    } else {
+        Ok(data_path.join(reserved_pack_name))
+    }
+}
+
+/// This function returns the patcher argument to execute the provided script with its current params.
+pub unsafe fn script_sandbox_arg(app_ui: &AppUI, game: &GameInfo, script_key: &str) -> Result<String> {
+    let scripts = app_ui.actions_ui().scripts_to_execute().read().unwrap();
+    let (script, item) = scripts.iter()
+        .find(|(script, _)| script.metadata().key() == script_key)
+        .ok_or_else(|| anyhow!("Script {} not found.", script_key))?;
+
+    sql_script_arg(game, script, item.as_ptr())
+}
+
+/// This function executes a script alone, from its patcher argument, into a pack in the sandbox folder of the game.
+///
+/// Nothing is written to the game folders: both the load order file passed to the patcher and the resulting pack live in the sandbox folder.
+/// This waits until the patcher finishes, so it must be called from the background thread. Returns the path of the generated pack.
+pub fn run_script_sandbox(game: &GameInfo, mod_list_path: &Path, script_arg: &str) -> Result<PathBuf> {
+    let sandbox_path = script_sandbox_folder(game)?.join(RESERVED_PACK_NAME);
+    if sandbox_path.is_file() {
+        std::fs::remove_file(&sandbox_path)?;
+    }
+
+    let mut cmd = Command::new("cmd");
+    cmd.arg("/C");
+    cmd.arg(&*PATCHER_PATH);
+    cmd.arg("-g");
+    cmd.arg(game.key());
+    cmd.arg("-l");
+    cmd.arg(mod_list_path.to_string_lossy().to_string());
+    cmd.arg("-p");
+    cmd.arg(sandbox_path.to_string_lossy().to_string());
+    cmd.arg("-s");
+    cmd.arg("--sql-script");
+    cmd.arg(script_arg);
+
+    cmd.creation_flags(DETACHED_PROCESS);
+
+    let mut h = cmd.spawn().map_err(|err| anyhow!("Error when executing the script in the sandbox: {}", err))?;
+    if let Ok(status) = h.wait() {
+        if !status.success() {
+            return Err(anyhow!("Something failed while executing the script in the sandbox. Check the patcher terminal to see what happened."))
+        }
+    }
+
+    if !sandbox_path.is_file() {
+        return Err(anyhow!("The script didn't generate any data, so there is nothing to compare."))
+    }
+
+    Ok(sandbox_path)
+}
+
+/// This function returns the executables found next to the game's executable, with the standard one first.
+///
+/// Some games ship variants of their executable (dx11/dx12, benchmark,...), so we let the user pick which one to launch.
+pub fn game_executables(game: &GameInfo, game_path: &Path) -> Vec<PathBuf> {
+    let default = match game.executable_path(game_path) {
+        Some(default) => default,
+        None => return vec![],
+    };
+
+    let mut executables = default.parent()
+        .and_then(|folder| folder.read_dir().ok())
+        .map(|entries| entries.flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file() && *path != default)
+            .filter(|path| path.extension().map(|ext| ext.eq_ignore_ascii_case("exe")).unwrap_or_default())
+            .filter(|path| {
+                let name = path.file_name().unwrap_or_default().to_string_lossy().to_lowercase();
+                !NON_GAME_EXECUTABLES.iter().any(|non_game| name.contains(non_game))
+            })
+            .sorted()
+            .collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    executables.insert(0, default);
+    executables
+}
+
+/// This function returns the path of the executable we have to launch for the provided game.
+///
+/// If the variant selected by the user no longer exists, we fall back to the standard one.
+pub fn game_executable_path(game: &GameInfo, game_path: &Path) -> Option<PathBuf> {
+    let selected = setting_string(&format!("game_executable_{}", game.key()));
+    let default = game.executable_path(game_path)?;
+    if selected.is_empty() {
+        return Some(default);
+    }
+
+    let variant = default.with_file_name(&selected);
+    if variant.is_file() {
+        Some(variant)
+    } else {
+        Some(default)
+    }
+}
+
+/// This function returns the extra metadata of the script in the provided path.
+///
+/// All the extra metadata is optional, so any error reading it just means the script has none.
+pub fn script_extra_metadata(path: &Path) -> ScriptExtraMetadata {
+    File::open(path).ok()
+        .and_then(|file| serde_yml::from_reader::<_, ScriptExtra>(BufReader::new(file)).ok())
+        .map(|extra| ScriptExtraMetadata {
+            param_conditions: extra.metadata.parameters.into_iter()
+                .filter_map(|param| Some((param.key, param.visible_when?)))
+                .collect(),
+            destructive: extra.metadata.destructive,
+            affected_files: extra.metadata.affected_files,
+            category: extra.metadata.category.filter(|category| !category.trim().is_empty()),
+            requires_mods: extra.metadata.requires_mods.iter()
+                .map(|pack_name| pack_name.trim().to_owned())
+                .filter(|pack_name| !pack_name.is_empty())
+                .collect(),
+        })
+        .unwrap_or_default()
+}
+
+impl ScriptExtraMetadata {
+
+    /// This function returns the packs required by the script that are not enabled in the provided load order.
+    ///
+    /// Automatic scripts missing any of them stay dormant until they're enabled.
+    pub fn missing_required_mods(&self, load_order: &LoadOrder) -> Vec<String> {
+        self.requires_mods.iter()
+            .filter(|pack_name| !load_order.mods().contains(pack_name) && !load_order.movies().contains(pack_name))
+            .cloned()
+            .collect()
+    }
+}
+
+/// This function returns the paths of the scripts available for the provided game. Priority is:
+/// - Local scripts.
+/// - Extracted scripts.
+/// - Remote scripts.
+///
+/// Scripts with the same file name as one with more priority are skipped.
+pub fn sql_script_paths(game: &GameInfo) -> Result<Vec<PathBuf>> {
+    sql_script_paths_in(
+        &sql_scripts_local_path()?.join(game.key()),
+        &sql_scripts_extracted_extended_path()?,
+        &sql_scripts_remote_path()?.join(game.key()),
+    )
+}
+
+/// This function returns the paths of the scripts in the provided local, extracted and remote folders of a game.
+///
+/// Scripts with the same file name in more than one folder are only returned once, with the same priority used when launching:
+/// local first, then extracted, then remote.
+pub fn sql_script_paths_in(local_folder: &Path, extracted_folder: &Path, remote_folder: &Path) -> Result<Vec<PathBuf>> {
+    let mut sql_script_paths = files_from_subdir(local_folder, false)?;
+
+    // Only add extracted paths if they don't collide with local paths, as local paths take priority.
+    if let Ok(extracted_files) = files_from_subdir(extracted_folder, false) {
+        for extracted_file in &extracted_files {
+            if let Some(file_name) = extracted_file.file_name() {
+                if !local_folder.join(file_name).is_file() {
+                    sql_script_paths.push(extracted_file.to_path_buf());
+                }
+            }
+        }
+    }
+
+    // Only add remote paths if they don't collide with local or extracted paths, as they take priority.
+    if let Ok(remote_files) = files_from_subdir(remote_folder, false) {
+        for remote_file in &remote_files {
+            if let Some(file_name) = remote_file.file_name() {
+                if !local_folder.join(file_name).is_file() && !extracted_folder.join(file_name).is_file() {
+                    sql_script_paths.push(remote_file.to_path_buf());
+                }
+            }
+        }
+    }
+
+    Ok(sql_script_paths)
+}
+
+/// This function returns the paths of all the preset files, local ones first.
+pub fn preset_paths() -> Result<Vec<PathBuf>> {
+    let mut paths = files_from_subdir(&sql_scripts_local_presets_path()?, false).unwrap_or_default();
+    paths.append(&mut files_from_subdir(&sql_scripts_extracted_path()?.join("twpatcher/presets"), false).unwrap_or_default());
+    Ok(paths)
+}
+
+/// This function returns all the presets available. If a preset is both local and extracted, the local one is used.
+pub fn presets() -> Result<Vec<Preset>> {
+    Ok(preset_paths()?
+        .iter()
+        .filter_map(|path| Preset::read(path).ok())
+        .unique_by(|preset| preset.key().to_owned())
+        .collect())
+}
+
+/// This function adds the provided params, with their values, to the preset with the provided key.
+///
+/// This is for presets made before their script got new params. Params already in the preset are left untouched.
+pub fn complete_preset(preset_key: &str, params: &[(String, String)]) -> Result<()> {
+    let path = preset_paths()?
+        .into_iter()
+        .find(|path| Preset::read(path).map(|preset| *preset.key() == preset_key).unwrap_or_default())
+        .ok_or_else(|| anyhow!("Preset {} not found.", preset_key))?;
+
+    // The preset is edited as a generic yaml so we keep any field we don't know about.
+    let mut preset: serde_yml::Value = serde_yml::from_reader(BufReader::new(File::open(&path)?))?;
+    let preset_params = preset.get_mut("params")
+        .and_then(|params| params.as_mapping_mut())
+        .ok_or_else(|| anyhow!("Preset {} has no params.", preset_key))?;
+
+    for (key, value) in params {
+        let key = serde_yml::Value::String(key.to_owned());
+        if !preset_params.contains_key(&key) {
+            preset_params.insert(key, serde_yml::from_str(value)?);
+        }
+    }
+
+    serde_yml::to_writer(BufWriter::new(File::create(&path)?), &preset)?;
+    Ok(())
+}
+
+/// This function backs up the provided files, relative to the game folder, into a new backup in the script backups folder of the game.
+///
+/// Only files not in any previous backup are copied, as the ones already backed up were saved before any script touched them,
+/// and the current ones may have been modified by a previous launch. For the same reason, backups are never rotated out.
+/// Files that don't exist yet are skipped. Returns the path of the new backup, or None if there was nothing new to back up.
+pub fn backup_script_files(game: &GameInfo, game_path: &Path, files: &[String]) -> Result<Option<PathBuf>> {
+    if let Some(file) = files.iter().find(|file| !is_safe_relative_path(Path::new(file))) {
+        return Err(anyhow!("Invalid affected file in script metadata: {}. Affected files must be relative to the game folder.", file));
+    }
+
+    let backups = script_backups(game)?;
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let backup_path = script_backups_folder(game)?.join(timestamp.to_string());
+
+    let mut backed_up = false;
+    for file in files {
+        if backups.iter().any(|backup| backup.join(file).is_file()) {
+            continue;
+        }
+
+        let source = game_path.join(file);
+        if source.is_file() {
+            let destination = backup_path.join(file);
+            if let Some(parent) = destination.parent() {
+                DirBuilder::new().recursive(true).create(parent)?;
+            }
+
+            std::fs::copy(&source, &destination)?;
+            backed_up = true;
+        }
+    }
+
+    Ok(if backed_up { Some(backup_path) } else { None })
+}
+
+/// This function restores the script backups of the game into the game folder, bringing back the files as they were before any script modified them.
+///
+/// Each file is only in one backup, so the order they're restored in doesn't matter.
+/// Returns the path of the folder with the backups, or None if there are no backups to restore.
+pub fn restore_script_backup(game: &GameInfo, game_path: &Path) -> Result<Option<PathBuf>> {
+    let backups = script_backups(game)?;
+    if backups.is_empty() {
+        return Ok(None);
+    }
+
+    for backup_path in &backups {
+        for file in files_from_subdir(backup_path, true)? {
+            let relative_path = file.strip_prefix(backup_path)?;
+            if !is_safe_relative_path(relative_path) {
+                return Err(anyhow!("Invalid file in the script backup: {}.", relative_path.to_string_lossy()));
+            }
+
+            let destination = game_path.join(relative_path);
+            if let Some(parent) = destination.parent() {
+                DirBuilder::new().recursive(true).create(parent)?;
+            }
+
+            std::fs::copy(&file, &destination)?;
+        }
+    }
+
+    Ok(Some(script_backups_folder(game)?))
+}
+
+/// This function returns the paths of the script backups of the game, from newest to oldest.
+fn script_backups(game: &GameInfo) -> Result<Vec<PathBuf>> {
+    let backups_path = script_backups_folder(game)?;
+    if !backups_path.is_dir() {
+        return Ok(vec![]);
+    }
+
+    Ok(std::fs::read_dir(backups_path)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter_map(|path| Some((path.file_name()?.to_string_lossy().parse::<u64>().ok()?, path)))
+        .sorted_by(|(a, _), (b, _)| b.cmp(a))
+        .map(|(_, path)| path)
+        .collect())
+}
+
+/// This function returns the params the provided script is going to be executed with, alongside the key of the preset they come from, if any.
+///
+/// Params are taken from the preset selected for the script, if any, or from the param widgets of the script otherwise.
+/// Params hidden due to their visibility conditions use their default value.
+pub unsafe fn sql_script_params(script: &SQLScript, item: Ptr<QCheckBox>) -> Result<(String, Vec<String>)> {
+    if script.metadata().parameters().is_empty() {
+        return Ok((String::new(), vec![]));
+    }
+
+    let mut script_params = vec![];
+    let script_container = item.parent_widget().parent_widget();
+
+    // First check if we have a preset set. If not, we can check each param.
+    let preset_combo_name = format!("{}_preset_combo", script.metadata().key());
+    let preset_key = if let Ok(widget) = script_container.find_child::<QComboBox>(&preset_combo_name) {
+        widget.current_text().to_std_string()
+    } else {
+        String::new()
+    };
+
+    let preset = if !preset_key.is_empty() {
+        presets()?.into_iter().find(|x| *x.key() == preset_key)
+    } else {
+        None
+    };
+
+    // "No Preset" is also in the combo, so only report the preset if it's a real one.
+    let preset_key = preset.as_ref().map(|preset| preset.key().to_owned()).unwrap_or_default();
+
+    match preset {
+        Some(preset) => {
+
+            // The widgets show the values of the preset, so the params hidden by them are also hidden in the UI.
+            for param in script.metadata().parameters() {
+                let object_name = format!("{}_{}", script.metadata().key(), param.key());
+                let hidden = match param.r#type() {
+                    ParamType::Bool => script_container.find_child::<QCheckBox>(&object_name).map(|widget| !widget.is_visible_to(&widget.parent_widget())),
+                    ParamType::Integer => script_container.find_child::<QSpinBox>(&object_name).map(|widget| !widget.is_visible_to(&widget.parent_widget())),
+                    ParamType::Float => script_container.find_child::<QDoubleSpinBox>(&object_name).map(|widget| !widget.is_visible_to(&widget.parent_widget())),
+                }.unwrap_or_default();
+
+                match preset.params().get(param.key()) {
+                    Some(value) if !hidden => script_params.push(value.to_string()),
+                    _ => script_params.push(param.default_value().to_string()),
+                }
+            }
+        }
+        None => {
+            for param in script.metadata().parameters() {
+                let object_name = format!("{}_{}", script.metadata().key(), param.key());
+                match param.r#type() {
+                    ParamType::Bool => {
+                        if let Ok(widget) = script_container.find_child::<QCheckBox>(&object_name) {
+                            script_params.push(if !widget.is_visible_to(&widget.parent_widget()) { param.default_value().to_string() } else { widget.is_checked().to_string() });
+                        }
+                    },
+                    ParamType::Integer => {
+                        if let Ok(widget) = script_container.find_child::<QSpinBox>(&object_name) {
+                            script_params.push(if !widget.is_visible_to(&widget.parent_widget()) { param.default_value().to_string() } else { widget.value().to_string() });
+                        }
+                    },
+                    ParamType::Float => {
+                        if let Ok(widget) = script_container.find_child::<QDoubleSpinBox>(&object_name) {
+                            script_params.push(if !widget.is_visible_to(&widget.parent_widget()) { param.default_value().to_string() } else { widget.value().to_string() });
+                        }
+                    },
+                }
+            }
+        }
+    }
+
+    Ok((preset_key, script_params))
+}
+
+/// This function returns the argument we need to pass to the patcher to execute the provided script, with its params.
+pub unsafe fn sql_script_arg(game: &GameInfo, script: &SQLScript, item: Ptr<QCheckBox>) -> Result<String> {
+    let sql_folder_extracted = sql_scripts_extracted_extended_path()?;
+    let sql_folder_local = sql_scripts_local_path()?.join(game.key());
+    let sql_folder_remote = sql_scripts_remote_path()?.join(game.key());
+
+    let (_, script_params) = sql_script_params(script, item)?;
+
+    // When there's a collision, default to the local script path.
+    let script_name = format!("{}.yml", script.metadata().key());
+    let local_script_path = sql_folder_local.join(&script_name);
+    let extracted_script_path = sql_folder_extracted.join(&script_name);
+    let remote_script_path = sql_folder_remote.join(&script_name);
+    let script_path = if PathBuf::from(&local_script_path).is_file() {
+        local_script_path
+    } else if PathBuf::from(&extracted_script_path).is_file() {
+        extracted_script_path
+    } else {
+        remote_script_path
+    };
+
+    if script_params.is_empty() {
+        Ok(script_path.to_string_lossy().to_string())
+    } else {
+        Ok(format!("{};{}", script_path.to_string_lossy().to_string().replace("\\", "/"), script_params.join(";")))
+    }
+}
+
 pub unsafe fn setup_actions(app_ui: &AppUI, game: &GameInfo, game_config: &GameConfig, game_path: &Path, load_order: &LoadOrder) -> Result<()> {
 
     // The blockers are needed to avoid issues with game change causing incorrect status to be saved.
     app_ui.actions_ui().play_button().block_signals(true);
+    app_ui.actions_ui().game_executable_combobox().block_signals(true);
     app_ui.actions_ui().copy_load_order_button().block_signals(true);
     app_ui.actions_ui().paste_load_order_button().block_signals(true);
     app_ui.actions_ui().profile_load_button().block_signals(true);
@@ -279,6 +733,9 @@ pub unsafe fn setup_actions(app_ui: &AppUI, game: &GameInfo, game_config: &GameC
     app_ui.actions_ui().open_game_content_folder().block_signals(true);
     app_ui.actions_ui().save_combobox().block_signals(true);
 
+    // Icons may be overridden per game, so update them before anything else.
+    app_ui.actions_ui().set_icon_overrides(game_config.icon_overrides());
+
     // Master check to know if we even have a game path setup correctly.
     let path_is_valid = game_path.exists() && game_path.is_dir() && !game_path.to_string_lossy().is_empty();
     app_ui.actions_ui().play_button().set_enabled(path_is_valid);
@@ -456,10 +913,24 @@ pub unsafe fn setup_actions(app_ui: &AppUI, game: &GameInfo, game_config: &GameC
 
         // Disable this until I figure out how to fix the performance problems, and I change the pack to be on /data
         app_ui.actions_ui().merge_all_mods_checkbox().parent().static_downcast::<qt_widgets::QWidget>().set_enabled(false);
+        app_ui.actions_ui().merge_categories_button().parent().static_downcast::<qt_widgets::QWidget>().set_enabled(app_ui.actions_ui().merge_all_mods_checkbox().parent().static_downcast::<qt_widgets::QWidget>().is_enabled());
+        ActionsUI::update_merge_categories_text(&app_ui.actions_ui().merge_categories_button().as_ptr(), game.key());
+        app_ui.actions_ui().rebalancer_excluded_categories_button().parent().static_downcast::<qt_widgets::QWidget>().set_enabled(app_ui.actions_ui().universal_rebalancer_combobox().parent().static_downcast::<qt_widgets::QWidget>().is_enabled());
+        ActionsUI::update_rebalancer_excluded_categories_text(&app_ui.actions_ui().rebalancer_excluded_categories_button().as_ptr(), game.key());
+
+        // Only allow toggling the mod sources the game supports.
+        for (source, action) in app_ui.actions_ui().mod_sources_actions() {
+            action.set_checked(load_mods_from(game.key(), source));
+            action.set_enabled(match *source {
+                MOD_SOURCE_SECONDARY => *game.raw_db_version() >= 1,
+                MOD_SOURCE_CONTENT => app_ui.actions_ui().open_game_content_folder().is_enabled(),
+                _ => true,
+            });
+        }
 
         // Update the launch options for the new game.
         app_ui.actions_ui().enable_logging_checkbox().set_checked(setting_bool(&format!("enable_logging_{}", game.key())));
-        app_ui.actions_ui().enable_skip_intro_checkbox().set_checked(setting_bool(&format!("enable_skip_intros_{}", game.key())));
+        app_ui.actions_ui().enable_skip_intro_checkbox().set_checked(setting_bool(&format!("enable_skip_intro_{}", game.key())));
         app_ui.actions_ui().remove_trait_limit_checkbox().set_checked(setting_bool(&format!("remove_trait_limit_{}", game.key())));
         app_ui.actions_ui().remove_siege_attacker_checkbox().set_checked(setting_bool(&format!("remove_siege_attacker_{}", game.key())));
         app_ui.actions_ui().merge_all_mods_checkbox().set_checked(setting_bool(&format!("merge_all_mods_{}", game.key())));
@@ -472,6 +943,21 @@ pub unsafe fn setup_actions(app_ui: &AppUI, game: &GameInfo, game_config: &GameC
                 value
             }
         } as f64);
+        app_ui.actions_ui().load_unit_multiplier_presets(game.key());
+
+        // Populate the list of executables the game has. The standard one always goes first.
+        app_ui.actions_ui().game_executable_combobox().clear();
+        let executables = game_executables(game, game_path);
+        for executable in &executables {
+            app_ui.actions_ui().game_executable_combobox().add_item_q_string(&QString::from_std_str(executable.file_name().unwrap_or_default().to_string_lossy()));
+        }
+
+        if let Some(executable) = game_executable_path(game, game_path) {
+            app_ui.actions_ui().game_executable_combobox().set_current_text(&QString::from_std_str(executable.file_name().unwrap_or_default().to_string_lossy()));
+        }
+
+        // No point on letting the user choose if there's nothing to choose from.
+        app_ui.actions_ui().game_executable_combobox().parent().static_downcast::<qt_widgets::QWidget>().set_enabled(executables.len() > 1);
 
         // Populate the list of translations depending on what local_XX packs the game has.
         app_ui.actions_ui().enable_translations_combobox().clear();
@@ -493,8 +979,10 @@ pub unsafe fn setup_actions(app_ui: &AppUI, game: &GameInfo, game_config: &GameC
                 app_ui.actions_ui().enable_translations_combobox().insert_item_int_q_string(index as i32 + 1, &QString::from_std_str(language));
             }
 
+            // The combobox is editable, so setting its text would only change the text, not the selected item.
             let language_to_select = setting_string(&format!("enable_translations_{}", game.key()));
-            app_ui.actions_ui().enable_translations_combobox().set_current_text(&QString::from_std_str(language_to_select));
+            let index = app_ui.actions_ui().enable_translations_combobox().find_text_1a(&QString::from_std_str(language_to_select));
+            app_ui.actions_ui().enable_translations_combobox().set_current_index(index.max(0));
         }
 
         // Populate the list of mods to rebalance over.
@@ -531,6 +1019,7 @@ pub unsafe fn setup_actions(app_ui: &AppUI, game: &GameInfo, game_config: &GameC
 
     // Unblock all blocked signals.
     app_ui.actions_ui().play_button().block_signals(false);
+    app_ui.actions_ui().game_executable_combobox().block_signals(false);
     app_ui.actions_ui().copy_load_order_button().block_signals(false);
     app_ui.actions_ui().paste_load_order_button().block_signals(false);
     app_ui.actions_ui().profile_load_button().block_signals(false);
@@ -550,6 +1039,9 @@ pub unsafe fn setup_actions(app_ui: &AppUI, game: &GameInfo, game_config: &GameC
     app_ui.actions_ui().save_combobox().block_signals(false);
     app_ui.actions_ui().open_game_content_folder().block_signals(false);
 
+    // The additional secondary folders depend on the game, so their actions in the folders menu are rebuilt here.
+    app_ui.actions_ui().load_secondary_extra_folders(&secondary_mods_extra_paths(game.key()));
+
     // Scripts are done in a separate step, because they're dynamic. Priority is:
     // - Local scripts.
     // - Extracted scripts.
@@ -570,40 +1062,8 @@ pub unsafe fn setup_actions(app_ui: &AppUI, game: &GameInfo, game_config: &GameC
             }
         }
 
-        let extracted_folder = sql_scripts_extracted_path()?;
-        let extracted_scripts_folder = extracted_folder.join("twpatcher/scripts");
-        let presets_folder = extracted_folder.join("twpatcher/presets");
-
-        let local_folder = sql_scripts_local_path()?;
-        let remote_folder = sql_scripts_remote_path()?;
-        let mut sql_script_paths = files_from_subdir(&local_folder.join(game.key()), false)?;
-
-        // Only add extracted paths if they don't collide with local paths, as local paths take priority.
-        if let Ok(extracted_files) = files_from_subdir(&extracted_scripts_folder, false) {
-            for extracted_file in &extracted_files {
-                if let Ok(relative_path) = extracted_file.strip_prefix(&extracted_scripts_folder) {
-                    if !local_folder.join(relative_path).is_file() {
-                        sql_script_paths.push(extracted_file.to_path_buf());
-                    }
-                }
-            }
-        }
-
-        // Only add remote paths if they don't collide with local or extracted paths, as they take priority.
-        if let Ok(remote_files) = files_from_subdir(&remote_folder.join(game.key()), false) {
-            for remote_file in &remote_files {
-                if let Ok(relative_path) = remote_file.strip_prefix(&remote_folder) {
-                    if !local_folder.join(relative_path).is_file() && !extracted_scripts_folder.join(relative_path).is_file() {
-                        sql_script_paths.push(remote_file.to_path_buf());
-                    }
-                }
-            }
-        }
-
-        let presets = files_from_subdir(&presets_folder, false).unwrap_or_default()
-            .iter()
-            .filter_map(|x| Preset::read(x).ok())
-            .collect::<Vec<_>>();
+        let sql_script_paths = sql_script_paths(game)?;
+        let presets = presets().unwrap_or_default();
 
         let mut presets_by_script: HashMap<String, Vec<Preset>> = HashMap::new();
         for preset in &presets {
@@ -616,6 +1076,9 @@ pub unsafe fn setup_actions(app_ui: &AppUI, game: &GameInfo, game_config: &GameC
         let mut script_items = app_ui.actions_ui().scripts_to_execute().write().unwrap();
         script_items.clear();
 
+        let mut scripts_extra_metadata = app_ui.actions_ui().scripts_extra_metadata().write().unwrap();
+        scripts_extra_metadata.clear();
+
         for path in sql_script_paths {
             if let Some(extension) = path.extension() {
 
@@ -623,18 +1086,68 @@ pub unsafe fn setup_actions(app_ui: &AppUI, game: &GameInfo, game_config: &GameC
                 if extension == "yml" {
                     if let Ok(script) = SQLScript::from_path(&path) {
                         let presets = presets_by_script.get(script.metadata().key()).cloned().unwrap_or_else(|| vec![]);
-                        let script_item = app_ui.actions_ui().new_launch_script_option(game.key(), "autocorrection", &script, &presets);
+                        let extra_metadata = script_extra_metadata(&path);
+                        let requirements_met = extra_metadata.missing_required_mods(load_order).is_empty();
+                        let icon_key = app_ui.actions_ui().icon_key(script.metadata().key(), "autocorrection");
+                        let script_item = app_ui.actions_ui().new_launch_script_option(game.key(), &icon_key, &script, &presets, &extra_metadata, requirements_met);
+                        scripts_extra_metadata.insert(script.metadata().key().to_owned(), extra_metadata);
                         script_items.push((script, script_item));
                     }
                 }
             }
         }
 
+        // Presets with the same name in multiple scripts form a preset group, which can be applied to all of them at once.
+        let preset_groups = script_items.iter()
+            .filter_map(|(script, _)| presets_by_script.get(script.metadata().key()))
+            .flat_map(|presets| presets.iter().map(|preset| preset.name().to_owned()).unique())
+            .counts()
+            .into_iter()
+            .filter(|(_, count)| *count > 1)
+            .map(|(preset_group, _)| preset_group)
+            .sorted()
+            .collect::<Vec<_>>();
+
+        app_ui.actions_ui().load_preset_groups(game.key(), &preset_groups);
+
+        // Automatic scripts requiring mods that are not enabled are listed with them, so the user knows why they won't run.
+        let automatic_scripts = script_items.iter()
+            .filter(|(script, _)| *script.metadata().automatic())
+            .map(|(script, _)| {
+                let missing_mods = scripts_extra_metadata.get(script.metadata().key())
+                    .map(|extra_metadata| extra_metadata.missing_required_mods(load_order))
+                    .unwrap_or_default();
+
+                (script.metadata().key().to_owned(), script.metadata().name().trim().to_owned(), missing_mods)
+            })
+            .sorted_by(|(_, a, _), (_, b, _)| a.cmp(b))
+            .collect::<Vec<_>>();
+        app_ui.actions_ui().load_automatic_scripts(game.key(), &automatic_scripts);
+
+        // Sorting needs to read the scripts, so release them first.
+        drop(script_items);
+        drop(scripts_extra_metadata);
+        app_ui.actions_ui().sort_scripts();
+
+        app_ui.actions_ui().preset_group_combobox().parent_widget().set_enabled(path_is_valid && !preset_groups.is_empty());
+
+        // Custom launch options are also dynamic. They're passed as arguments to the executable, which we only do since Shogun 2.
+        let supports_custom_launch_options = *game.raw_db_version() >= 1;
+        app_ui.actions_ui().load_custom_launch_options(game)?;
+        app_ui.actions_ui().custom_launch_options_manager().set_enabled(supports_custom_launch_options);
+        for (_, _, widget) in app_ui.actions_ui().custom_launch_options().read().unwrap().iter() {
+            widget.parent_widget().set_enabled(path_is_valid && supports_custom_launch_options);
+        }
+
         // Trigger a resize of the menu, so it's not compressed.
         let menu = app_ui.actions_ui().play_button().menu();
         let event = QResizeEvent::new(&QSize::new_0a(), &menu.size());
         qt_core::QCoreApplication::send_event(menu, &event);
     }
 
+    // Multiplayer-safe mode goes last, as it locks options enabled above. The scripts panel goes after it, as it mirrors the lock.
+    app_ui.actions_ui().update_multiplayer_safe(game.key());
+    app_ui.scripts_ui().load(app_ui.actions_ui());
+
     Ok(())
 }
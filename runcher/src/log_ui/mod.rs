@@ -0,0 +1,278 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the live view of the game logs.
+//!
+//! This view follows the log files the game writes in its folder after a launch,
+//! appending new lines as they're written, and picking up new files if the game rotates them.
+
+use qt_widgets::QLineEdit;
+use qt_widgets::QPlainTextEdit;
+use qt_widgets::QTabWidget;
+use qt_widgets::QToolButton;
+use qt_widgets::QWidget;
+
+use qt_core::QBox;
+use qt_core::QPtr;
+use qt_core::QString;
+use qt_core::QTimer;
+
+use anyhow::Result;
+use getset::*;
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::RwLock;
+use std::time::SystemTime;
+
+use rpfm_lib::utils::files_from_subdir;
+
+use rpfm_ui_common::locale::*;
+use rpfm_ui_common::utils::*;
+
+use self::slots::LogUISlots;
+
+mod slots;
+
+const VIEW_DEBUG: &str = "ui_templates/log_widget.ui";
+const VIEW_RELEASE: &str = "ui/log_widget.ui";
+
+/// Interval, in ms, between checks for new lines in the log.
+const TAIL_INTERVAL: i32 = 500;
+
+/// Max amount of lines we keep in memory and in the view. Older lines are discarded once we reach this.
+const MAX_LINES: usize = 50_000;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Debug, Getters)]
+#[getset(get = "pub")]
+pub struct LogUI {
+    main_widget: QBox<QWidget>,
+    log_text_edit: QPtr<QPlainTextEdit>,
+    filter_line_edit: QPtr<QLineEdit>,
+    filter_case_sensitive_button: QPtr<QToolButton>,
+    follow_button: QPtr<QToolButton>,
+    filter_timer: QBox<QTimer>,
+    tail_timer: QBox<QTimer>,
+
+    tail: Rc<RwLock<LogTail>>,
+}
+
+/// Status of the log we're following.
+#[derive(Debug, Default)]
+pub struct LogTail {
+    game_path: PathBuf,
+    start_date: Option<SystemTime>,
+    last_log_path: Option<PathBuf>,
+
+    /// Read position and unfinished line of each log we're following.
+    logs: HashMap<PathBuf, (u64, String)>,
+    lines: Vec<String>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl LogUI {
+
+    pub unsafe fn new(parent: &QBox<QTabWidget>) -> Result<Rc<Self>> {
+
+        // Load the UI Template.
+        let template_path = if cfg!(debug_assertions) { VIEW_DEBUG } else { VIEW_RELEASE };
+        let main_widget = load_template(parent, template_path)?;
+
+        let log_text_edit: QPtr<QPlainTextEdit> = find_widget(&main_widget.static_upcast(), "log_text_edit")?;
+        let filter_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "filter_line_edit")?;
+        let filter_case_sensitive_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "filter_case_sensitive_button")?;
+        let follow_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "follow_button")?;
+        follow_button.set_tool_tip(&qtr("game_log_follow"));
+        log_text_edit.set_placeholder_text(&qtr("game_log_placeholder"));
+        log_text_edit.set_maximum_block_count(MAX_LINES as i32);
+
+        let filter_timer = QTimer::new_1a(&main_widget);
+        filter_timer.set_single_shot(true);
+
+        let tail_timer = QTimer::new_1a(&main_widget);
+        tail_timer.set_interval(TAIL_INTERVAL);
+
+        parent.add_tab_2a(&main_widget, &qtr("game_log_title"));
+
+        let list = Rc::new(Self {
+            main_widget,
+            log_text_edit,
+            filter_line_edit,
+            filter_case_sensitive_button,
+            follow_button,
+            filter_timer,
+            tail_timer,
+            tail: Rc::new(RwLock::new(LogTail::default())),
+        });
+
+        let slots = LogUISlots::new(&list);
+        list.set_connections(&slots);
+
+        Ok(list)
+    }
+
+    pub unsafe fn set_connections(&self, slots: &LogUISlots) {
+        self.filter_line_edit().text_changed().connect(slots.filter_line_edit());
+        self.filter_case_sensitive_button().toggled().connect(slots.filter_case_sensitive_button());
+        self.filter_timer().timeout().connect(slots.filter_trigger());
+        self.follow_button().toggled().connect(slots.follow());
+        self.tail_timer().timeout().connect(slots.tail());
+    }
+
+    /// This function starts following the logs the game writes in the provided folder after the provided date.
+    pub unsafe fn start(&self, game_path: &Path, start_date: SystemTime) {
+        *self.tail.write().unwrap() = LogTail {
+            game_path: game_path.to_path_buf(),
+            start_date: Some(start_date),
+            ..Default::default()
+        };
+
+        self.log_text_edit().clear();
+
+        if self.follow_button().is_checked() {
+            self.tail_timer().start_0a();
+        }
+    }
+
+    /// This function stops following the current log, if any.
+    pub unsafe fn stop(&self) {
+        self.tail_timer().stop();
+    }
+
+    /// This function checks the logs for new lines, and appends them to the view.
+    pub unsafe fn update(&self) {
+        let new_lines = {
+            let mut tail = self.tail.write().unwrap();
+            let start_date = match tail.start_date {
+                Some(start_date) => start_date,
+                None => return,
+            };
+
+            let mut new_lines = vec![];
+
+            // New files (like rotated logs) are picked up here, as they're just files we have not read yet.
+            for log_path in Self::log_paths(&tail.game_path, &start_date) {
+                let (mut position, mut partial_line) = tail.logs.get(&log_path).cloned().unwrap_or_default();
+
+                let mut file = match File::open(&log_path) {
+                    Ok(file) => file,
+                    Err(_) => continue,
+                };
+
+                // If the file shrunk, it got truncated. Start reading again from the beginning.
+                let len = file.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+                if len < position {
+                    position = 0;
+                    partial_line.clear();
+                }
+
+                if len > position && file.seek(SeekFrom::Start(position)).is_ok() {
+                    let mut data = vec![];
+                    if let Ok(read) = file.read_to_end(&mut data) {
+                        position += read as u64;
+
+                        // Logs may not be valid UTF-8, so decode them lossy. Keep the last line apart if it's not finished yet.
+                        let text = partial_line + &String::from_utf8_lossy(&data);
+                        let mut split = text.split('\n').map(|line| line.trim_end_matches('\r').to_owned()).collect::<Vec<_>>();
+                        partial_line = split.pop().unwrap_or_default();
+
+                        if !split.is_empty() {
+                            if tail.last_log_path.as_ref() != Some(&log_path) {
+                                new_lines.push(format!("--- {} ---", log_path.file_name().unwrap_or_default().to_string_lossy()));
+                                tail.last_log_path = Some(log_path.to_path_buf());
+                            }
+
+                            new_lines.extend(split);
+                        }
+                    }
+                }
+
+                tail.logs.insert(log_path, (position, partial_line));
+            }
+
+            tail.lines.extend_from_slice(&new_lines);
+            if tail.lines.len() > MAX_LINES {
+                let excess = tail.lines.len() - MAX_LINES;
+                tail.lines.drain(..excess);
+            }
+
+            new_lines
+        };
+
+        let pattern = self.filter_line_edit().text().to_std_string();
+        let case_sensitive = self.filter_case_sensitive_button().is_checked();
+        for line in &new_lines {
+            if Self::line_matches(line, &pattern, case_sensitive) {
+                self.log_text_edit().append_plain_text(&QString::from_std_str(line));
+            }
+        }
+    }
+
+    pub unsafe fn filter_list(&self) {
+        let pattern = self.filter_line_edit().text().to_std_string();
+        let case_sensitive = self.filter_case_sensitive_button().is_checked();
+
+        let text = self.tail.read().unwrap().lines.iter()
+            .filter(|line| Self::line_matches(line, &pattern, case_sensitive))
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        self.log_text_edit().set_plain_text(&QString::from_std_str(text));
+
+        let scroll_bar = self.log_text_edit().vertical_scroll_bar();
+        scroll_bar.set_value(scroll_bar.maximum());
+    }
+
+    pub unsafe fn delayed_updates(&self) {
+        self.filter_timer.set_interval(500);
+        self.filter_timer.start_0a();
+    }
+
+    /// This function returns the logs in the game folder modified after the provided date, from oldest to newest.
+    pub fn log_paths(game_path: &Path, start_date: &SystemTime) -> Vec<PathBuf> {
+        let mut paths = files_from_subdir(game_path, false)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|path| path.extension().is_some() && path.extension().unwrap() == "txt")
+            .filter_map(|path| {
+                let modified = path.metadata().ok()?.modified().ok()?;
+                if modified > *start_date {
+                    Some((modified, path))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        paths.sort_by_key(|(modified, _)| *modified);
+        paths.into_iter().map(|(_, path)| path).collect()
+    }
+
+    fn line_matches(line: &str, pattern: &str, case_sensitive: bool) -> bool {
+        if pattern.is_empty() {
+            true
+        } else if case_sensitive {
+            line.contains(pattern)
+        } else {
+            line.to_lowercase().contains(&pattern.to_lowercase())
+        }
+    }
+}
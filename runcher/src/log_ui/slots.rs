@@ -0,0 +1,79 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+use qt_core::QBox;
+use qt_core::{SlotNoArgs, SlotOfBool, SlotOfQString};
+
+use std::rc::Rc;
+
+use rpfm_ui_common::clone;
+
+use super::*;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Getters)]
+#[getset(get = "pub")]
+pub struct LogUISlots {
+    filter_line_edit: QBox<SlotOfQString>,
+    filter_case_sensitive_button: QBox<SlotNoArgs>,
+    filter_trigger: QBox<SlotNoArgs>,
+    follow: QBox<SlotOfBool>,
+    tail: QBox<SlotNoArgs>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl LogUISlots {
+    pub unsafe fn new(view: &Rc<LogUI>) -> Self {
+
+        let filter_line_edit = SlotOfQString::new(view.log_text_edit(), clone!(
+            view => move |_| {
+            view.delayed_updates();
+        }));
+
+        let filter_case_sensitive_button = SlotNoArgs::new(view.log_text_edit(), clone!(
+            view => move || {
+            view.filter_list();
+        }));
+
+        let filter_trigger = SlotNoArgs::new(view.log_text_edit(), clone!(
+            view => move || {
+            view.filter_list();
+        }));
+
+        // Only resume following if we have something to follow.
+        let follow = SlotOfBool::new(view.log_text_edit(), clone!(
+            view => move |state| {
+            if !state {
+                view.stop();
+            } else if view.tail.read().unwrap().start_date.is_some() {
+                view.tail_timer().start_0a();
+            }
+        }));
+
+        let tail = SlotNoArgs::new(view.log_text_edit(), clone!(
+            view => move || {
+            view.update();
+        }));
+
+        Self {
+            filter_line_edit,
+            filter_case_sensitive_button,
+            filter_trigger,
+            follow,
+            tail,
+        }
+    }
+}
@@ -60,14 +60,17 @@ mod app_ui;
 mod background_thread;
 mod cli;
 mod communications;
+mod custom_launch_options_ui;
 mod data_ui;
 mod ffi;
 mod games;
+mod log_ui;
 mod mod_manager;
 mod mod_list_ui;
 mod network_thread;
 mod profiles_ui;
 mod pack_list_ui;
+mod scripts_ui;
 mod settings_ui;
 mod updater_ui;
 
@@ -28,9 +28,11 @@ use qt_gui::QStandardItemModel;
 use qt_core::AlignmentFlag;
 use qt_core::CaseSensitivity;
 use qt_core::CheckState;
+use qt_core::ContextMenuPolicy;
 use qt_core::ItemFlag;
 use qt_core::QBox;
 use qt_core::QFlags;
+use qt_core::QByteArray;
 use qt_core::QModelIndex;
 use qt_core::QObject;
 use qt_core::QPtr;
@@ -39,6 +41,7 @@ use qt_core::QSortFilterProxyModel;
 use qt_core::QString;
 use qt_core::QTimer;
 use qt_core::QVariant;
+use qt_core::SortOrder;
 
 use cpp_core::CppBox;
 use cpp_core::CppDeletable;
@@ -52,9 +55,11 @@ use time::OffsetDateTime;
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::UNIX_EPOCH;
 
 use rpfm_lib::games::GameInfo;
+use rpfm_lib::integrations::log::*;
 use rpfm_lib::utils::path_to_absolute_string;
 
 use rpfm_ui_common::locale::*;
@@ -62,7 +67,7 @@ use rpfm_ui_common::settings::*;
 use rpfm_ui_common::utils::*;
 
 use crate::ffi::*;
-use crate::mod_manager::{game_config::GameConfig, icon_data, mods::Mod, secondary_mods_path};
+use crate::mod_manager::{game_config::GameConfig, icon_data, integrations::game_build, mod_advisories::{ModAdvisories, ModAdvisory}, mods::Mod, secondary_mods_paths};
 use crate::settings_ui::last_game_update_date;
 
 use self::slots::ModListUISlots;
@@ -75,6 +80,16 @@ const VIEW_RELEASE: &str = "ui/filterable_tree_widget.ui";
 const CATEGORY_NEW_VIEW_DEBUG: &str = "ui_templates/category_new_dialog.ui";
 const CATEGORY_NEW_VIEW_RELEASE: &str = "ui/category_new_dialog.ui";
 
+/// Key of the setting with the state of the mod list header: column widths, order, visibility and sort order.
+const HEADER_STATE: &str = "mod_list_header_state";
+
+/// Columns of the mod list, by their locale key.
+const COLUMNS: [&str; 8] = ["mod_name", "flags", "location", "creator", "pack_type", "file_size", "time_created", "time_updated"];
+
+/// Column with the mod's creator. Only shown if we have a steam api key, as we cannot get it without one.
+const COLUMN_CREATOR: i32 = 3;
+
+// Roles used by the C++ side must match the ones in mod_list_roles.h.
 pub const VALUE_MOD_ID: i32 = 21;
 pub const VALUE_PACK_PATH: i32 = 22;
 pub const VALUE_MOD_STEAM_ID: i32 = 23;
@@ -86,6 +101,8 @@ pub const FLAG_MOD_IS_OUTDATED: i32 = 31;
 pub const FLAG_MOD_DATA_IS_OLDER_THAN_SECONDARY: i32 = 32;
 pub const FLAG_MOD_DATA_IS_OLDER_THAN_CONTENT: i32 = 33;
 pub const FLAG_MOD_SECONDARY_IS_OLDER_THAN_CONTENT: i32 = 34;
+pub const FLAG_MOD_FAILED_TO_LOAD: i32 = 35;
+pub const FLAG_MOD_HAS_ADVISORY: i32 = 36;
 
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
@@ -109,18 +126,32 @@ pub struct ModListUI {
     categories_send_to_menu: QBox<QMenu>,
     enable_selected: QPtr<QAction>,
     disable_selected: QPtr<QAction>,
+    toggle_matching: QPtr<QAction>,
+    enable_random_subset: QPtr<QAction>,
+    find_crashing_mod: QPtr<QAction>,
     expand_all: QPtr<QAction>,
     collapse_all: QPtr<QAction>,
 
     open_in_explorer: QPtr<QAction>,
+    open_mod_folder_menu: QBox<QMenu>,
     open_in_steam: QPtr<QAction>,
     open_in_tool_menu: QBox<QMenu>,
 
     upload_to_workshop: QPtr<QAction>,
     download_from_workshop: QPtr<QAction>,
+    repair_from_workshop: QPtr<QAction>,
+    redownload_broken_mods: QPtr<QAction>,
 
     copy_to_secondary: QPtr<QAction>,
     move_to_secondary: QPtr<QAction>,
+    move_to_data: QPtr<QAction>,
+
+    header_context_menu: QBox<QMenu>,
+    header_column_actions: Vec<QPtr<QAction>>,
+    reset_layout: QPtr<QAction>,
+
+    // To avoid saving the header state while the list is being rebuilt.
+    header_state_frozen: AtomicBool,
 }
 
 //-------------------------------------------------------------------------------//
@@ -147,6 +178,7 @@ impl ModListUI {
         tree_view_placeholder.delete();
 
         let model = new_mod_list_model_safe(tree_view.static_upcast());
+        mod_list_model_set_color_enabled_safe(&model, setting_bool("mod_list_color_enabled"));
         let filter = mod_list_filter_safe(main_widget.static_upcast());
         filter.set_source_model(&model);
         model.set_parent(&tree_view);
@@ -161,6 +193,9 @@ impl ModListUI {
         let context_menu = QMenu::from_q_widget(&main_widget);
         let enable_selected = context_menu.add_action_q_string(&qtr("enable_selected"));
         let disable_selected = context_menu.add_action_q_string(&qtr("disable_selected"));
+        let toggle_matching = context_menu.add_action_q_string(&qtr("toggle_matching"));
+        let enable_random_subset = context_menu.add_action_q_string(&qtr("random_subset"));
+        let find_crashing_mod = context_menu.add_action_q_string(&qtr("bisect"));
 
         let category_new = context_menu.add_action_q_string(&qtr("category_new"));
         let category_delete = context_menu.add_action_q_string(&qtr("category_delete"));
@@ -170,6 +205,9 @@ impl ModListUI {
         context_menu.add_menu_q_menu(&categories_send_to_menu);
 
         let open_in_explorer = context_menu.add_action_q_string(&qtr("open_in_explorer"));
+        let open_mod_folder_menu = QMenu::from_q_string(&qtr("open_mod_folder_menu"));
+        open_mod_folder_menu.set_enabled(false);
+        context_menu.add_menu_q_menu(&open_mod_folder_menu);
         let open_in_steam = context_menu.add_action_q_string(&qtr("open_in_steam"));
         let open_in_tool_menu = QMenu::from_q_string(&qtr("open_in_tool_menu"));
         open_in_tool_menu.set_enabled(false);
@@ -179,16 +217,36 @@ impl ModListUI {
 
         let upload_to_workshop = context_menu.add_action_q_string(&qtr("upload_to_workshop"));
         let download_from_workshop = context_menu.add_action_q_string(&qtr("download_from_workshop"));
+        let repair_from_workshop = context_menu.add_action_q_string(&qtr("repair_from_workshop"));
+        let redownload_broken_mods = context_menu.add_action_q_string(&qtr("redownload_broken_mods"));
         context_menu.insert_separator(&upload_to_workshop);
 
         let copy_to_secondary = context_menu.add_action_q_string(&qtr("copy_to_secondary"));
         let move_to_secondary = context_menu.add_action_q_string(&qtr("move_to_secondary"));
+        let move_to_data = context_menu.add_action_q_string(&qtr("move_to_data"));
         context_menu.insert_separator(&copy_to_secondary);
 
         let expand_all = context_menu.add_action_q_string(&qtr("expand_all"));
         let collapse_all = context_menu.add_action_q_string(&qtr("collapse_all"));
         context_menu.insert_separator(&expand_all);
 
+        // Header context menu, to toggle columns and reset the layout of the list.
+        tree_view.header().set_context_menu_policy(ContextMenuPolicy::CustomContextMenu);
+        let header_context_menu = QMenu::from_q_widget(&main_widget);
+        let header_column_actions = COLUMNS.iter()
+            .map(|column| {
+                let action = header_context_menu.add_action_q_string(&qtr(column));
+                action.set_checkable(true);
+                action
+            })
+            .collect::<Vec<_>>();
+
+        // The mod name column is always visible.
+        header_column_actions[0].set_enabled(false);
+
+        header_context_menu.add_separator();
+        let reset_layout = header_context_menu.add_action_q_string(&qtr("mod_list_reset_layout"));
+
         let list = Rc::new(Self {
             tree_view,
             model,
@@ -205,18 +263,31 @@ impl ModListUI {
             categories_send_to_menu,
             enable_selected,
             disable_selected,
+            toggle_matching,
+            enable_random_subset,
+            find_crashing_mod,
             expand_all,
             collapse_all,
 
             open_in_explorer,
+            open_mod_folder_menu,
             open_in_steam,
             open_in_tool_menu,
 
             upload_to_workshop,
             download_from_workshop,
+            repair_from_workshop,
+            redownload_broken_mods,
 
             copy_to_secondary,
             move_to_secondary,
+            move_to_data,
+
+            header_context_menu,
+            header_column_actions,
+            reset_layout,
+
+            header_state_frozen: AtomicBool::new(false),
         });
 
         let slots = ModListUISlots::new(&list);
@@ -239,9 +310,21 @@ impl ModListUI {
         self.open_in_steam().triggered().connect(slots.open_in_steam());
         self.expand_all().triggered().connect(slots.expand_all());
         self.collapse_all().triggered().connect(slots.collapse_all());
+
+        let header = self.tree_view().header();
+        header.custom_context_menu_requested().connect(slots.header_context_menu());
+        header.section_resized().connect(slots.header_state_changed());
+        header.section_moved().connect(slots.header_state_changed());
+        header.sort_indicator_changed().connect(slots.header_state_changed());
+        for (action, slot) in self.header_column_actions().iter().zip(slots.header_column_toggled().iter()) {
+            action.triggered().connect(slot);
+        }
+
+        self.reset_layout().triggered().connect(slots.reset_layout());
     }
 
     pub unsafe fn load(&self, game: &GameInfo, game_config: &GameConfig) -> Result<()> {
+        self.header_state_frozen.store(true, Ordering::SeqCst);
         self.model().clear();
         self.setup_columns();
 
@@ -253,7 +336,10 @@ impl ModListUI {
         let game_data_path = game.data_path(&game_path)?;
 
         let data_path = path_to_absolute_string(&game_data_path);
-        let secondary_path = path_to_absolute_string(&secondary_mods_path(game.key()).unwrap_or_else(|_| PathBuf::default()));
+        let secondary_paths = secondary_mods_paths(game.key())
+            .iter()
+            .map(|path| path_to_absolute_string(path))
+            .collect::<Vec<_>>();
         let content_path = path_to_absolute_string(&game.content_path(&game_path).unwrap_or_else(|_| PathBuf::default()));
 
         // Initialize these here so they can be re-use.
@@ -269,6 +355,15 @@ impl ModListUI {
         let secondary_older_than_content_icon = icon_data("secondary_older_than_content.png").unwrap_or_else(|_| vec![]);
         let secondary_older_than_content = tre("mod_secondary_older_than_content", &[&BASE64_STANDARD.encode(secondary_older_than_content_icon)]);
 
+        let load_error_icon = BASE64_STANDARD.encode(icon_data("load_error.png").unwrap_or_else(|_| vec![]));
+
+        let game_build = game_build(game, &game_path);
+        let mod_advisories = ModAdvisories::load().unwrap_or_else(|error| {
+            error!("Error when loading the mod advisories: {}", error);
+            ModAdvisories::default()
+        });
+        let advisory_icon = BASE64_STANDARD.encode(icon_data("advisory.png").unwrap_or_else(|_| vec![]));
+
         // This loads mods per category, meaning all installed mod have to be in the categories list!!!!
         for category in game_config.categories_order() {
             let item = QStandardItem::from_q_string(&QString::from_std_str(category));
@@ -348,12 +443,22 @@ impl ModListUI {
                                 };
 
                                 let mut flags_description = String::new();
+                                if let Some(load_error) = modd.load_error() {
+                                    item_flags.set_data_2a(&QVariant::from_bool(true), FLAG_MOD_FAILED_TO_LOAD);
+                                    flags_description.push_str(&Self::load_error_description(&load_error_icon, load_error, modd.steam_id().is_some()));
+                                }
+
                                 if modd.outdated(game_last_update_date) {
                                     item_flags.set_data_2a(&QVariant::from_bool(true), FLAG_MOD_IS_OUTDATED);
                                     flags_description.push_str(&outdated);
                                 }
 
-                                if let Ok(flags) = modd.priority_dating_flags(&data_path, &secondary_path, &content_path) {
+                                if let Some(advisory) = mod_advisories.advisory(game, modd, game_build.as_deref()) {
+                                    item_flags.set_data_2a(&QVariant::from_bool(true), FLAG_MOD_HAS_ADVISORY);
+                                    flags_description.push_str(&Self::advisory_description(&advisory_icon, advisory));
+                                }
+
+                                if let Ok(flags) = modd.priority_dating_flags(&data_path, &secondary_paths, &content_path) {
                                     item_flags.set_data_2a(&QVariant::from_bool(flags.0), FLAG_MOD_DATA_IS_OLDER_THAN_SECONDARY);
                                     item_flags.set_data_2a(&QVariant::from_bool(flags.1), FLAG_MOD_DATA_IS_OLDER_THAN_CONTENT);
                                     item_flags.set_data_2a(&QVariant::from_bool(flags.2), FLAG_MOD_SECONDARY_IS_OLDER_THAN_CONTENT);
@@ -376,7 +481,7 @@ impl ModListUI {
                                     item_flags.set_tool_tip(&QString::from_std_str(&flags_description));
                                 }
 
-                                let (l_data, l_secondary, l_content) = modd.location(&data_path, &secondary_path, &content_path);
+                                let (l_data, l_secondary, l_content) = modd.location(&data_path, &secondary_paths, &content_path);
                                 let mut locations = vec![];
 
                                 if l_data {
@@ -413,7 +518,16 @@ impl ModListUI {
                                 item_mod_name.set_data_2a(&QVariant::from_bool(false), VALUE_IS_CATEGORY);
                                 item_mod_name.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(modd.pack_type().to_string())), VALUE_PACK_TYPE);
 
-                                if modd.can_be_toggled(game, &game_data_path) {
+                                // Broken mods cannot be enabled until their pack loads again, as they'd crash the game.
+                                if modd.load_error().is_some() {
+                                    item_mod_name.set_checkable(true);
+
+                                    let mut flags = item_mod_name.flags().to_int();
+                                    flags &= !ItemFlag::ItemIsUserCheckable.to_int();
+                                    item_mod_name.set_flags(QFlags::from(flags));
+                                }
+
+                                else if modd.can_be_toggled(game, &game_data_path) {
                                     item_mod_name.set_checkable(true);
 
                                     if modd.enabled(game, &game_data_path) {
@@ -449,13 +563,21 @@ impl ModListUI {
             }
         }
 
+        self.tree_view().expand_all();
+        self.tree_view().header().resize_sections(ResizeMode::ResizeToContents);
+
+        // Restore the layout the user left the list with, if any.
+        let header_state = setting_byte_array(HEADER_STATE);
+        if !header_state.is_empty() {
+            self.tree_view().header().restore_state(&header_state);
+        }
+
         // If we have no api key, don't show the author column, as we cannot get it without api key.
         if setting_string("steam_api_key").is_empty() {
-            self.tree_view().hide_column(3);
+            self.tree_view().hide_column(COLUMN_CREATOR);
         }
 
-        self.tree_view().expand_all();
-        self.tree_view().header().resize_sections(ResizeMode::ResizeToContents);
+        self.header_state_frozen.store(false, Ordering::SeqCst);
 
         // Add the full flags description to the column title.
         let mut full_desc = tr("mod_flags_description") + "<ul>";
@@ -463,6 +585,8 @@ impl ModListUI {
         full_desc.push_str(&data_older_than_secondary);
         full_desc.push_str(&data_older_than_content);
         full_desc.push_str(&secondary_older_than_content);
+        full_desc.push_str(&tre("mod_load_error_description", &[&load_error_icon, &tr("mod_load_error_generic")]));
+        full_desc.push_str(&tre("mod_advisory_description", &[&advisory_icon, &tr("mod_advisory_status_generic"), &tr("mod_advisory_generic"), ""]));
         full_desc.push_str("</ul>");
 
         self.model.horizontal_header_item(1).set_tool_tip(&QString::from_std_str(full_desc));
@@ -470,6 +594,28 @@ impl ModListUI {
         Ok(())
     }
 
+    /// This function returns the description of the load error flag for the flags tooltip, with the error escaped so it shows as plain text.
+    fn load_error_description(icon: &str, load_error: &str, from_workshop: bool) -> String {
+        let load_error = load_error.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;");
+        let mut description = tre("mod_load_error_description", &[icon, &load_error]);
+        if from_workshop {
+            description.push_str(&tr("mod_load_error_redownload"));
+        }
+
+        description
+    }
+
+    /// This function returns the description of an advisory for the flags tooltip, with the texts from the source escaped so they show as plain text.
+    fn advisory_description(icon: &str, advisory: &ModAdvisory) -> String {
+        let escape = |text: &str| text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;");
+        let link = match advisory.url() {
+            Some(url) if url.starts_with("https://") || url.starts_with("http://") => format!("<p><a href=\"{0}\">{0}</a></p>", escape(url)),
+            _ => String::new(),
+        };
+
+        tre("mod_advisory_description", &[icon, &tr(advisory.status().locale_key()), &escape(advisory.reason()), &link])
+    }
+
     pub unsafe fn update(&self, game: &GameInfo, mods: &HashMap<String, Mod>, mods_to_delete: &[String]) -> Result<()> {
         self.model().block_signals(true);
 
@@ -481,7 +627,10 @@ impl ModListUI {
         let game_data_path = game.data_path(&game_path)?;
 
         let data_path = path_to_absolute_string(&game_data_path);
-        let secondary_path = path_to_absolute_string(&secondary_mods_path(game.key()).unwrap_or_else(|_| PathBuf::default()));
+        let secondary_paths = secondary_mods_paths(game.key())
+            .iter()
+            .map(|path| path_to_absolute_string(path))
+            .collect::<Vec<_>>();
         let content_path = path_to_absolute_string(&game.content_path(&game_path).unwrap_or_else(|_| PathBuf::default()));
 
         // Initialize these here so they can be re-use.
@@ -497,6 +646,15 @@ impl ModListUI {
         let secondary_older_than_content_icon = icon_data("secondary_older_than_content.png").unwrap_or_else(|_| vec![]);
         let secondary_older_than_content = tre("mod_secondary_older_than_content", &[&BASE64_STANDARD.encode(secondary_older_than_content_icon)]);
 
+        let load_error_icon = BASE64_STANDARD.encode(icon_data("load_error.png").unwrap_or_else(|_| vec![]));
+
+        let game_build = game_build(game, &game_path);
+        let mod_advisories = ModAdvisories::load().unwrap_or_else(|error| {
+            error!("Error when loading the mod advisories: {}", error);
+            ModAdvisories::default()
+        });
+        let advisory_icon = BASE64_STANDARD.encode(icon_data("advisory.png").unwrap_or_else(|_| vec![]));
+
         for category_index in 0..self.model().row_count_0a() {
             let category = self.model().item_2a(category_index, 0);
             let mut index_to_delete = vec![];
@@ -560,13 +718,29 @@ impl ModListUI {
                             "-".to_string()
                         };
 
+                        // Keep the checkbox in sync with the backend, as the enabled state may have changed while the row was around.
+                        if modd.can_be_toggled(game, &game_data_path) {
+                            item_mod_name.set_check_state(if modd.enabled(game, &game_data_path) { CheckState::Checked } else { CheckState::Unchecked });
+                        }
+
                         let mut flags_description = String::new();
+                        item_flags.set_data_2a(&QVariant::from_bool(modd.load_error().is_some()), FLAG_MOD_FAILED_TO_LOAD);
+                        if let Some(load_error) = modd.load_error() {
+                            flags_description.push_str(&Self::load_error_description(&load_error_icon, load_error, modd.steam_id().is_some()));
+                        }
+
                         if modd.outdated(game_last_update_date) {
                             item_flags.set_data_2a(&QVariant::from_bool(true), FLAG_MOD_IS_OUTDATED);
                             flags_description.push_str(&outdated);
                         }
 
-                        if let Ok(flags) = modd.priority_dating_flags(&data_path, &secondary_path, &content_path) {
+                        let advisory = mod_advisories.advisory(game, modd, game_build.as_deref());
+                        item_flags.set_data_2a(&QVariant::from_bool(advisory.is_some()), FLAG_MOD_HAS_ADVISORY);
+                        if let Some(advisory) = advisory {
+                            flags_description.push_str(&Self::advisory_description(&advisory_icon, advisory));
+                        }
+
+                        if let Ok(flags) = modd.priority_dating_flags(&data_path, &secondary_paths, &content_path) {
                             item_flags.set_data_2a(&QVariant::from_bool(flags.0), FLAG_MOD_DATA_IS_OLDER_THAN_SECONDARY);
                             item_flags.set_data_2a(&QVariant::from_bool(flags.1), FLAG_MOD_DATA_IS_OLDER_THAN_CONTENT);
                             item_flags.set_data_2a(&QVariant::from_bool(flags.2), FLAG_MOD_SECONDARY_IS_OLDER_THAN_CONTENT);
@@ -589,7 +763,7 @@ impl ModListUI {
                             item_flags.set_tool_tip(&QString::from_std_str(&flags_description));
                         }
 
-                        let (l_data, l_secondary, l_content) = modd.location(&data_path, &secondary_path, &content_path);
+                        let (l_data, l_secondary, l_content) = modd.location(&data_path, &secondary_paths, &content_path);
                         let mut locations = vec![];
 
                         if l_data {
@@ -637,6 +811,37 @@ impl ModListUI {
         Ok(())
     }
 
+    /// This function updates the checkboxes of the mod list to match the enabled state of each mod in the backend.
+    ///
+    /// Mods that cannot be toggled are left untouched.
+    pub unsafe fn sync_enabled_state(&self, game: &GameInfo, game_config: &GameConfig) -> Result<()> {
+        let game_path = setting_path(game.key());
+        let game_data_path = game.data_path(&game_path)?;
+
+        self.model().block_signals(true);
+
+        for cat in 0..self.model().row_count_0a() {
+            let category = self.model().item_1a(cat);
+            for row in 0..category.row_count() {
+                let item = category.child_1a(row);
+                if item.is_null() || !item.is_checkable() {
+                    continue;
+                }
+
+                let mod_id = item.data_1a(VALUE_MOD_ID).to_string().to_std_string();
+                if let Some(modd) = game_config.mods().get(&mod_id) {
+                    if modd.can_be_toggled(game, &game_data_path) {
+                        item.set_check_state(if modd.enabled(game, &game_data_path) { CheckState::Checked } else { CheckState::Unchecked });
+                    }
+                }
+            }
+        }
+
+        self.model().block_signals(false);
+
+        Ok(())
+    }
+
     pub unsafe fn setup_columns(&self) {
         self.model.set_column_count(7);
 
@@ -664,6 +869,34 @@ impl ModListUI {
         self.tree_view.header().set_minimum_section_size(24 * 4);
     }
 
+    /// This function saves the current state of the header, so the layout of the list is kept between restarts.
+    pub unsafe fn save_header_state(&self) {
+        if !self.header_state_frozen.load(Ordering::SeqCst) {
+            set_setting_q_byte_array(HEADER_STATE, &self.tree_view().header().save_state());
+        }
+    }
+
+    /// This function resets the layout of the list to its default: all columns visible in their original order, sized to their contents, and unsorted.
+    pub unsafe fn reset_header_state(&self) {
+        self.header_state_frozen.store(true, Ordering::SeqCst);
+
+        let header = self.tree_view().header();
+        for column in 0..header.count() {
+            header.move_section(header.visual_index(column), column);
+            header.show_section(column);
+        }
+
+        if setting_string("steam_api_key").is_empty() {
+            header.hide_section(COLUMN_CREATOR);
+        }
+
+        header.set_sort_indicator(-1, SortOrder::AscendingOrder);
+        header.resize_sections(ResizeMode::ResizeToContents);
+
+        set_setting_q_byte_array(HEADER_STATE, &QByteArray::new());
+        self.header_state_frozen.store(false, Ordering::SeqCst);
+    }
+
     pub unsafe fn category_new_dialog(&self, rename: bool) -> Result<Option<String>> {
 
         // Load the UI Template.
@@ -13,7 +13,7 @@ use qt_widgets::SlotOfQPoint;
 use qt_gui::QCursor;
 
 use qt_core::QBox;
-use qt_core::{SlotNoArgs, SlotOfQString};
+use qt_core::{SlotNoArgs, SlotOfBool, SlotOfQString};
 
 use std::path::PathBuf;
 use std::rc::Rc;
@@ -40,6 +40,11 @@ pub struct ModListUISlots {
     open_in_steam: QBox<SlotNoArgs>,
     expand_all: QBox<SlotNoArgs>,
     collapse_all: QBox<SlotNoArgs>,
+
+    header_context_menu: QBox<SlotOfQPoint>,
+    header_column_toggled: Vec<QBox<SlotOfBool>>,
+    header_state_changed: QBox<SlotNoArgs>,
+    reset_layout: QBox<SlotNoArgs>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -86,9 +91,11 @@ impl ModListUISlots {
 
             view.upload_to_workshop.set_enabled(all_mods && selection.len() == 1);
             view.download_from_workshop.set_enabled(all_mods);
+            view.repair_from_workshop.set_enabled(all_mods && selection.len() == 1);
 
             view.copy_to_secondary.set_enabled(all_mods);
             view.move_to_secondary.set_enabled(all_mods);
+            view.move_to_data.set_enabled(all_mods);
         }));
 
         let open_in_explorer = SlotNoArgs::new(&view.tree_view, clone!(
@@ -131,6 +138,35 @@ impl ModListUISlots {
             view.tree_view.collapse_all();
         }));
 
+        let header_context_menu = SlotOfQPoint::new(&view.tree_view, clone!(
+            view => move |_| {
+            let header = view.tree_view().header();
+            for (column, action) in view.header_column_actions().iter().enumerate() {
+                action.set_checked(!header.is_section_hidden(column as i32));
+            }
+
+            view.header_column_actions()[COLUMN_CREATOR as usize].set_enabled(!setting_string("steam_api_key").is_empty());
+            view.header_context_menu().exec_1a_mut(&QCursor::pos_0a());
+        }));
+
+        let header_column_toggled = (0..COLUMNS.len())
+            .map(|column| SlotOfBool::new(&view.tree_view, clone!(
+                view => move |visible| {
+                view.tree_view().header().set_section_hidden(column as i32, !visible);
+                view.save_header_state();
+            })))
+            .collect::<Vec<_>>();
+
+        let header_state_changed = SlotNoArgs::new(&view.tree_view, clone!(
+            view => move || {
+            view.save_header_state();
+        }));
+
+        let reset_layout = SlotNoArgs::new(&view.tree_view, clone!(
+            view => move || {
+            view.reset_header_state();
+        }));
+
         Self {
             filter_line_edit,
             filter_case_sensitive_button,
@@ -142,6 +178,11 @@ impl ModListUISlots {
             open_in_steam,
             expand_all,
             collapse_all,
+
+            header_context_menu,
+            header_column_toggled,
+            header_state_changed,
+            reset_layout,
         }
     }
 }
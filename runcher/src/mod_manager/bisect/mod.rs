@@ -0,0 +1,258 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the code for finding which mod of a crashing mod list causes the crash, by bisection.
+//!
+//! The mods are split into mods known to work together (cleared) and mods that may cause the crash (suspects). Each test
+//! enables the cleared mods plus half of the suspects. If the game crashes, the culprit is in that half. If it doesn't,
+//! that half gets cleared and the culprit is in the other half. This also finds mods that only crash alongside other mods,
+//! as the mods already cleared are kept enabled in all tests.
+
+use anyhow::{anyhow, Result};
+use getset::*;
+use serde::{Deserialize, Serialize};
+use serde_json::to_string_pretty;
+
+use std::fs::{DirBuilder, File};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::PathBuf;
+
+use rpfm_lib::games::GameInfo;
+
+use crate::settings_ui::game_config_path;
+
+const FILE_NAME_START: &str = "bisect_";
+const FILE_NAME_END: &str = ".json";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct BisectSession {
+
+    // Key of the game.
+    game_key: String,
+
+    // Mods enabled when the search started, in load order. They're enabled again once the search ends.
+    mods: Vec<String>,
+
+    // Mods known to not cause the crash.
+    cleared: Vec<String>,
+
+    // Mods that may cause the crash.
+    suspects: Vec<String>,
+
+    // Results of the tests done so far, in order.
+    results: Vec<BisectResult>,
+}
+
+#[derive(Clone, Debug, Default, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct BisectResult {
+
+    // Amount of suspects enabled in the test.
+    suspects_tested: usize,
+
+    // If the game crashed with them.
+    crashed: bool,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl BisectSession {
+
+    /// This function starts a new search over the provided mods, which are expected to crash the game when enabled together.
+    pub fn new(game: &GameInfo, mods: &[String]) -> Result<Self> {
+        if mods.len() < 2 {
+            return Err(anyhow!("At least two mods are needed to search for the one causing a crash."));
+        }
+
+        Ok(Self {
+            game_key: game.key().to_owned(),
+            mods: mods.to_vec(),
+            cleared: vec![],
+            suspects: mods.to_vec(),
+            results: vec![],
+        })
+    }
+
+    /// This function loads the search in progress for the provided game, if any.
+    pub fn load(game: &GameInfo) -> Result<Option<Self>> {
+        let path = Self::path(game)?;
+        if !path.is_file() {
+            return Ok(None);
+        }
+
+        let mut file = BufReader::new(File::open(path)?);
+        let mut data = Vec::with_capacity(file.get_ref().metadata()?.len() as usize);
+        file.read_to_end(&mut data)?;
+
+        let session: Self = serde_json::from_slice(&data)?;
+        Ok(Some(session))
+    }
+
+    pub fn save(&self, game: &GameInfo) -> Result<()> {
+        let path = Self::path(game)?;
+
+        // Make sure the path exists to avoid problems with updating schemas.
+        if let Some(parent_folder) = path.parent() {
+            DirBuilder::new().recursive(true).create(parent_folder)?;
+        }
+
+        let mut file = BufWriter::new(File::create(path)?);
+        file.write_all(to_string_pretty(&self)?.as_bytes())?;
+        Ok(())
+    }
+
+    /// This function removes the search in progress for the provided game, if any.
+    pub fn delete(game: &GameInfo) -> Result<()> {
+        let path = Self::path(game)?;
+        if path.is_file() {
+            std::fs::remove_file(path)?;
+        }
+
+        Ok(())
+    }
+
+    /// This function returns the mods to enable for the current test, in load order: the cleared mods, plus the first half of the suspects.
+    pub fn testing(&self) -> Vec<String> {
+        let half = self.suspects_half();
+        self.mods.iter()
+            .filter(|mod_id| self.cleared.contains(mod_id) || half.contains(mod_id))
+            .cloned()
+            .collect()
+    }
+
+    /// This function records the result of the current test, and narrows the suspects accordingly.
+    pub fn report(&mut self, crashed: bool) {
+        let half = self.suspects_half();
+        self.results.push(BisectResult {
+            suspects_tested: half.len(),
+            crashed,
+        });
+
+        if crashed {
+            self.suspects = half;
+        } else {
+            self.suspects.retain(|mod_id| !half.contains(mod_id));
+            self.cleared.extend(half);
+        }
+    }
+
+    /// This function returns the mod causing the crash, once there's only one suspect left.
+    pub fn culprit(&self) -> Option<&String> {
+        if self.suspects.len() == 1 {
+            self.suspects.first()
+        } else {
+            None
+        }
+    }
+
+    /// This function returns the amount of tests left, at most, to find the culprit.
+    pub fn steps_left(&self) -> usize {
+        self.suspects.len().next_power_of_two().trailing_zeros() as usize
+    }
+
+    fn suspects_half(&self) -> Vec<String> {
+        self.suspects[..(self.suspects.len() + 1) / 2].to_vec()
+    }
+
+    fn path(game: &GameInfo) -> Result<PathBuf> {
+        Ok(game_config_path()?.join(format!("{FILE_NAME_START}{}{FILE_NAME_END}", game.key())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rpfm_lib::games::supported_games::KEY_WARHAMMER_3;
+    use crate::SUPPORTED_GAMES;
+
+    fn mods(count: usize) -> Vec<String> {
+        (0..count).map(|index| format!("mod_{index}.pack")).collect()
+    }
+
+    /// Runs the search to the end, with the provided check telling if the game crashes with the enabled mods. Returns the culprit and the amount of tests done.
+    fn bisect(mods: &[String], crashes: impl Fn(&[String]) -> bool) -> (String, usize) {
+        let game = SUPPORTED_GAMES.game(KEY_WARHAMMER_3).unwrap();
+        let mut session = BisectSession::new(game, mods).unwrap();
+        let max_steps = session.steps_left();
+
+        while session.culprit().is_none() {
+            let testing = session.testing();
+
+            // Tests always keep the load order, and never enable more mods than the search started with.
+            assert!(testing.windows(2).all(|pair| mods.iter().position(|id| id == &pair[0]) < mods.iter().position(|id| id == &pair[1])));
+            assert!(session.results().len() < max_steps, "search took more tests than expected");
+
+            session.report(crashes(&testing));
+        }
+
+        assert_eq!(session.steps_left(), 0);
+        (session.culprit().unwrap().to_owned(), session.results().len())
+    }
+
+    #[test]
+    fn new_needs_at_least_two_mods() {
+        let game = SUPPORTED_GAMES.game(KEY_WARHAMMER_3).unwrap();
+        assert!(BisectSession::new(game, &mods(1)).is_err());
+        assert!(BisectSession::new(game, &mods(2)).is_ok());
+    }
+
+    #[test]
+    fn report_narrows_the_suspects() {
+        let game = SUPPORTED_GAMES.game(KEY_WARHAMMER_3).unwrap();
+        let mods = mods(5);
+        let mut session = BisectSession::new(game, &mods).unwrap();
+        assert_eq!(session.steps_left(), 3);
+        assert_eq!(session.testing(), mods[..3].to_vec());
+
+        // No crash: the tested half is cleared, and stays enabled in the next tests.
+        session.report(false);
+        assert_eq!(session.cleared(), &mods[..3].to_vec());
+        assert_eq!(session.suspects(), &mods[3..].to_vec());
+        assert_eq!(session.testing(), mods[..4].to_vec());
+        assert_eq!(session.steps_left(), 1);
+        assert!(session.culprit().is_none());
+
+        // Crash: the culprit is in the tested half.
+        session.report(true);
+        assert_eq!(session.culprit(), Some(&mods[3]));
+        assert_eq!(session.steps_left(), 0);
+        assert_eq!(session.results().len(), 2);
+        assert!(!session.results()[0].crashed());
+        assert_eq!(*session.results()[1].suspects_tested(), 1);
+    }
+
+    #[test]
+    fn bisect_finds_the_culprit() {
+        for count in 2..=9 {
+            let mods = mods(count);
+            for culprit in &mods {
+                let (found, _) = bisect(&mods, |testing| testing.contains(culprit));
+                assert_eq!(&found, culprit);
+            }
+        }
+    }
+
+    #[test]
+    fn bisect_finds_mods_crashing_alongside_others() {
+        let mods = mods(8);
+
+        // Only crashes when both mods are enabled. The last one of them to be tested is the one found.
+        let (found, tests) = bisect(&mods, |testing| testing.contains(&mods[2]) && testing.contains(&mods[5]));
+        assert_eq!(found, mods[5]);
+        assert_eq!(tests, 3);
+    }
+}
@@ -0,0 +1,464 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the code for moving the entire configuration of Runcher to another machine.
+//!
+//! Configuration bundles are zip files with a manifest, all the settings in a json file, and the files of the config folders
+//! that cannot be regenerated: profiles, game configs (which include the launch history and other per-game data) and local scripts.
+//! Downloaded scripts, schemas, generated packs and backups are not included, as they're either downloaded again or machine-specific.
+
+use anyhow::{anyhow, Result};
+use getset::*;
+use qt_core::QString;
+use serde::{Deserialize, Serialize};
+use serde_json::to_string_pretty;
+use zip::{CompressionMethod, ZipArchive, ZipWriter, write::SimpleFileOptions};
+
+use std::collections::BTreeMap;
+use std::fs::{DirBuilder, File};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+use rpfm_lib::games::supported_games::KEY_ARENA;
+use rpfm_lib::utils::files_from_subdir;
+
+use rpfm_ui_common::settings::*;
+
+use crate::mod_manager::integrations::{LAST_LAUNCH_EXIT_CODE, LAST_LAUNCH_START_DATE};
+use crate::mod_manager::is_safe_relative_path;
+use crate::mod_manager::launch_hooks::{LAUNCH_HOOK_ON_EXIT, LAUNCH_HOOK_ON_LAUNCH};
+use crate::mod_manager::mod_advisories::MOD_ADVISORIES_LAST_UPDATE;
+use crate::mod_manager::profile_backups::{self, PROFILE_BACKUPS_LAST_DATE};
+use crate::settings_ui::{game_config_path, migrated_setting_key, profiles_path, sql_scripts_local_path, SettingValue};
+use crate::SUPPORTED_GAMES;
+
+const MANIFEST_FILE_NAME: &str = "manifest.json";
+const SETTINGS_FILE_NAME: &str = "settings.json";
+
+const PROFILES_FOLDER: &str = "profiles";
+const GAME_CONFIG_FOLDER: &str = "game_config";
+const SQL_SCRIPTS_LOCAL_FOLDER: &str = "sql_scripts_local";
+
+/// Version of the bundle format. Bump it if the format changes, and migrate the older bundles when loading them.
+///
+/// - 1: settings stored as text.
+/// - 2: settings stored with their type.
+const BUNDLE_VERSION: u32 = 2;
+
+/// Extension of the temporary files written while installing a bundle, before replacing the real ones.
+const INSTALL_TEMP_EXTENSION: &str = "runcher_import";
+
+/// Prefixes of settings that record the state of this machine, not configuration, so they're not exported.
+const EXCLUDED_SETTINGS_PREFIXES: [&str; 5] = [
+    LAST_LAUNCH_EXIT_CODE,
+    LAST_LAUNCH_START_DATE,
+    MOD_ADVISORIES_LAST_UPDATE,
+    PROFILE_BACKUPS_LAST_DATE,
+    "factoryReset",
+];
+
+/// Settings that are private, or run commands on the machine, so they're neither exported nor imported.
+const EXCLUDED_SETTINGS: [&str; 4] = [
+    "steam_api_key",
+    LAUNCH_HOOK_ON_LAUNCH,
+    LAUNCH_HOOK_ON_EXIT,
+    "launch_wrapper_command",
+];
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, Getters)]
+#[getset(get = "pub")]
+pub struct ConfigBundle {
+
+    // Version of Runcher that made the bundle.
+    runcher_version: String,
+
+    // Settings, by key.
+    settings: BTreeMap<String, SettingValue>,
+
+    // Files of the config folders, as (path relative to the config folder, contents of the file).
+    files: Vec<(String, Vec<u8>)>,
+
+    // Parts of the bundle that failed validation when loading it, and why. They're not installed.
+    skipped: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct Manifest {
+    version: u32,
+    runcher_version: String,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl ConfigBundle {
+
+    /// This function creates a bundle with the current configuration.
+    pub unsafe fn new() -> Result<Self> {
+        let mut bundle = Self {
+            runcher_version: crate::VERSION.to_owned(),
+            ..Default::default()
+        };
+
+        let q_settings = settings();
+        let all_keys = q_settings.all_keys();
+        bundle.settings = (0..all_keys.size())
+            .map(|index| all_keys.at(index).to_std_string())
+            .filter(|key| !Self::is_excluded_setting(key))
+            .map(|key| {
+                let value = SettingValue::from_q_setting(&q_settings, &key);
+                (key, value)
+            })
+            .collect();
+
+        for (folder, path) in Self::folders()? {
+            if !path.is_dir() {
+                continue;
+            }
+
+            for file_path in files_from_subdir(&path, true)? {
+                if let Ok(relative_path) = file_path.strip_prefix(&path) {
+                    let relative_path = relative_path.to_string_lossy().replace('\\', "/");
+                    bundle.files.push((format!("{folder}/{relative_path}"), std::fs::read(&file_path)?));
+                }
+            }
+        }
+
+        Ok(bundle)
+    }
+
+    /// This function reads a bundle from the provided path.
+    ///
+    /// Files and settings that fail validation are not loaded. They're listed in the skipped list of the bundle instead.
+    pub unsafe fn load(path: &Path) -> Result<Self> {
+        let mut bundle = Self::load_file(path)?;
+
+        // Only import settings Runcher knows about: the ones already in the settings file, or the ones of a supported game.
+        let q_settings = settings();
+        let game_keys = SUPPORTED_GAMES.games_sorted()
+            .iter()
+            .filter(|game| game.key() != KEY_ARENA)
+            .map(|game| (format!("_{}", game.key()), format!("_{}_", game.key())))
+            .collect::<Vec<_>>();
+
+        let unknown_keys = bundle.settings.keys()
+            .filter(|key| !q_settings.contains(&QString::from_std_str(key)) && !game_keys.iter().any(|(suffix, infix)| key.ends_with(suffix) || key.contains(infix)))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for key in unknown_keys {
+            bundle.settings.remove(&key);
+            bundle.skipped.push(format!("Setting {key}: not a setting of this version of Runcher."));
+        }
+
+        // Bundles made by hand, or by older versions, may contain settings we never export. Those are never imported.
+        let excluded_keys = bundle.settings.keys()
+            .filter(|key| Self::is_excluded_setting(key))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for key in excluded_keys {
+            bundle.settings.remove(&key);
+            bundle.skipped.push(format!("Setting {key}: private or runs commands, so it cannot be imported."));
+        }
+
+        Ok(bundle)
+    }
+
+    /// This function reads and validates a bundle from the provided path, without checking it against the current settings.
+    fn load_file(path: &Path) -> Result<Self> {
+        let mut zip = ZipArchive::new(BufReader::new(File::open(path)?))?;
+        let manifest: Manifest = match zip.by_name(MANIFEST_FILE_NAME) {
+            Ok(file) => serde_json::from_reader(file)?,
+            Err(_) => return Err(anyhow!("The file {} is not a configuration bundle.", path.to_string_lossy())),
+        };
+
+        if manifest.version > BUNDLE_VERSION {
+            return Err(anyhow!("This configuration bundle was made with a newer version of Runcher ({}). Update Runcher to import it.", manifest.runcher_version));
+        }
+
+        let mut bundle = Self {
+            runcher_version: manifest.runcher_version,
+            ..Default::default()
+        };
+
+        for index in 0..zip.len() {
+            let mut file = zip.by_index(index)?;
+            if !file.is_file() {
+                continue;
+            }
+
+            let name = file.name().to_owned();
+            let mut data = vec![];
+            file.read_to_end(&mut data)?;
+
+            if name == MANIFEST_FILE_NAME {
+                continue;
+            }
+
+            if name == SETTINGS_FILE_NAME {
+                let settings = if manifest.version < 2 {
+                    serde_json::from_slice::<BTreeMap<String, String>>(&data)
+                        .map(|settings| settings.into_iter().map(|(key, value)| (key, SettingValue::String(value))).collect())
+                } else {
+                    serde_json::from_slice::<BTreeMap<String, SettingValue>>(&data)
+                };
+
+                match settings {
+                    Ok(settings) => bundle.settings = settings,
+                    Err(error) => bundle.skipped.push(format!("{name}: the settings are not valid: {error}")),
+                }
+
+                continue;
+            }
+
+            match Self::validate_file(&name, &data) {
+                Ok(()) => bundle.files.push((name, data)),
+                Err(error) => bundle.skipped.push(format!("{name}: {error}")),
+            }
+        }
+
+        // Game paths are machine-specific. Keep the current ones if the imported ones don't exist here.
+        for game in SUPPORTED_GAMES.games_sorted().iter().filter(|game| game.key() != KEY_ARENA) {
+            if let Some(SettingValue::String(game_path)) = bundle.settings.get(game.key()) {
+                if !game_path.is_empty() && !Path::new(game_path).is_dir() {
+                    bundle.skipped.push(format!("Path of {}: {} doesn't exist in this machine. The current path is kept.", game.display_name(), game_path));
+                    bundle.settings.remove(game.key());
+                }
+            }
+        }
+
+        Ok(bundle)
+    }
+
+    /// This function saves the bundle to the provided path.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut zip = ZipWriter::new(BufWriter::new(File::create(path)?));
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+        let manifest = Manifest {
+            version: BUNDLE_VERSION,
+            runcher_version: self.runcher_version.to_owned(),
+        };
+
+        zip.start_file(MANIFEST_FILE_NAME, options)?;
+        zip.write_all(to_string_pretty(&manifest)?.as_bytes())?;
+
+        zip.start_file(SETTINGS_FILE_NAME, options)?;
+        zip.write_all(to_string_pretty(&self.settings)?.as_bytes())?;
+
+        for (name, data) in &self.files {
+            zip.start_file(name, options)?;
+            zip.write_all(data)?;
+        }
+
+        zip.finish()?;
+        Ok(())
+    }
+
+    /// This function installs the configuration of the bundle, overwriting the current files and settings with the same names.
+    ///
+    /// Files and settings not in the bundle are left as they are. A backup of the current profiles and launch settings is made first.
+    ///
+    /// Either the entire bundle is installed, or nothing is: if a file fails to be written, the ones already replaced are restored and no setting is changed.
+    pub unsafe fn install(&self) -> Result<()> {
+        profile_backups::backup()?;
+
+        let folders = Self::folders()?;
+        let mut paths = Vec::with_capacity(self.files.len());
+        for (name, _) in &self.files {
+            Self::validate_path(name).map_err(|error| anyhow!("Invalid file in the bundle: {}: {}", name, error))?;
+
+            let path = name.split_once('/')
+                .and_then(|(folder, relative_path)| folders.iter().find(|(bundle_folder, _)| *bundle_folder == folder).map(|(_, path)| path.join(relative_path)))
+                .ok_or_else(|| anyhow!("Invalid file in the bundle: {}.", name))?;
+
+            paths.push(path);
+        }
+
+        // Write everything to temporary files first, so a failure here doesn't touch the current config.
+        let mut temp_paths = Vec::with_capacity(paths.len());
+        let result = paths.iter().zip(self.files.iter()).try_for_each(|(path, (_, data))| {
+            let temp_path = Self::temp_path(path);
+            if let Some(parent_folder) = path.parent() {
+                DirBuilder::new().recursive(true).create(parent_folder)?;
+            }
+
+            temp_paths.push(temp_path.clone());
+            let mut file = BufWriter::new(File::create(&temp_path)?);
+            file.write_all(data)?;
+            file.flush()?;
+            Ok::<(), anyhow::Error>(())
+        });
+
+        if let Err(error) = result {
+            temp_paths.iter().for_each(|temp_path| { let _ = std::fs::remove_file(temp_path); });
+            return Err(error);
+        }
+
+        // Then replace the real files, keeping the old ones in memory to restore them if a replacement fails.
+        let mut replaced: Vec<(&PathBuf, Option<Vec<u8>>)> = Vec::with_capacity(paths.len());
+        for (path, temp_path) in paths.iter().zip(temp_paths.iter()) {
+            let old_data = if path.is_file() { std::fs::read(path).map(Some) } else { Ok(None) };
+            match old_data.and_then(|old_data| std::fs::rename(temp_path, path).map(|_| old_data)) {
+                Ok(old_data) => replaced.push((path, old_data)),
+                Err(error) => {
+                    for (path, old_data) in replaced.iter().rev() {
+                        let _ = match old_data {
+                            Some(old_data) => std::fs::write(path, old_data),
+                            None => std::fs::remove_file(path),
+                        };
+                    }
+
+                    temp_paths.iter().for_each(|temp_path| { let _ = std::fs::remove_file(temp_path); });
+                    return Err(error.into());
+                }
+            }
+        }
+
+        let q_settings = settings();
+        for (key, value) in &self.settings {
+            value.to_q_setting(&q_settings, &migrated_setting_key(key));
+        }
+
+        q_settings.sync();
+        Ok(())
+    }
+
+    /// This function returns the temporary path used to write the provided file before replacing it.
+    fn is_excluded_setting(key: &str) -> bool {
+        EXCLUDED_SETTINGS.contains(&key) || EXCLUDED_SETTINGS_PREFIXES.iter().any(|prefix| key.starts_with(prefix))
+    }
+
+    fn temp_path(path: &Path) -> PathBuf {
+        let mut temp_path = path.as_os_str().to_owned();
+        temp_path.push(".");
+        temp_path.push(INSTALL_TEMP_EXTENSION);
+        PathBuf::from(temp_path)
+    }
+
+    /// This function returns the config folders included in bundles, with the name of their folder in the bundle.
+    fn folders() -> Result<Vec<(&'static str, PathBuf)>> {
+        Ok(vec![
+            (PROFILES_FOLDER, profiles_path()?),
+            (GAME_CONFIG_FOLDER, game_config_path()?),
+            (SQL_SCRIPTS_LOCAL_FOLDER, sql_scripts_local_path()?),
+        ])
+    }
+
+    /// This function checks that a file of a bundle goes to one of the config folders, without escaping it, and that it can be parsed.
+    fn validate_file(name: &str, data: &[u8]) -> Result<()> {
+        let relative_path = Self::validate_path(name)?;
+
+        let extension = relative_path.rsplit_once('.').map(|(_, extension)| extension.to_lowercase()).unwrap_or_default();
+        match extension.as_str() {
+            "json" => serde_json::from_slice::<serde_json::Value>(data).map(|_| ()).map_err(|error| anyhow!("invalid file: {}", error)),
+            "yml" | "yaml" => serde_yml::from_slice::<serde_yml::Value>(data).map(|_| ()).map_err(|error| anyhow!("invalid file: {}", error)),
+            _ => Ok(()),
+        }
+    }
+
+    /// This function checks that the name of a file of a bundle is in one of the config folders, without escaping it.
+    ///
+    /// Returns the path of the file relative to its folder.
+    fn validate_path(name: &str) -> Result<&str> {
+        let (folder, relative_path) = name.split_once('/').ok_or_else(|| anyhow!("not part of the configuration."))?;
+        if ![PROFILES_FOLDER, GAME_CONFIG_FOLDER, SQL_SCRIPTS_LOCAL_FOLDER].contains(&folder) {
+            return Err(anyhow!("not part of the configuration."));
+        }
+
+        // Do not allow files to escape their folder. Backslashes and colons are checked here because they're only
+        // separators and drive prefixes on Windows, and the bundle may have been made on another system.
+        if relative_path.contains(['\\', ':']) || !is_safe_relative_path(Path::new(relative_path)) {
+            return Err(anyhow!("invalid path."));
+        }
+
+        Ok(relative_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_file_accepts_config_files() {
+        assert!(ConfigBundle::validate_file("profiles/warhammer_3/default.json", b"{}").is_ok());
+        assert!(ConfigBundle::validate_file("sql_scripts_local/script.sql", b"SELECT 1;").is_ok());
+        assert!(ConfigBundle::validate_file("game_config/script.yml", b"key: value").is_ok());
+    }
+
+    #[test]
+    fn validate_file_rejects_escaping_paths() {
+        for name in [
+            "profiles/../settings.json",
+            "profiles/a/../../b.json",
+            "profiles//etc/passwd",
+            "profiles/C:/Windows/file.txt",
+            "profiles/C:file.txt",
+            "profiles/..\\..\\file.txt",
+            "profiles/",
+            "profiles/.",
+            "other/file.json",
+            "file.json",
+        ] {
+            assert!(ConfigBundle::validate_file(name, b"{}").is_err(), "{name} should be rejected");
+        }
+    }
+
+    #[test]
+    fn validate_file_rejects_broken_files() {
+        assert!(ConfigBundle::validate_file("profiles/default.json", b"{").is_err());
+        assert!(ConfigBundle::validate_file("game_config/script.yml", b"key: [").is_err());
+    }
+
+    #[test]
+    fn private_and_command_settings_are_excluded() {
+        assert!(ConfigBundle::is_excluded_setting("steam_api_key"));
+        assert!(ConfigBundle::is_excluded_setting(LAUNCH_HOOK_ON_LAUNCH));
+        assert!(ConfigBundle::is_excluded_setting(LAUNCH_HOOK_ON_EXIT));
+        assert!(ConfigBundle::is_excluded_setting("launch_wrapper_command"));
+        assert!(ConfigBundle::is_excluded_setting(PROFILE_BACKUPS_LAST_DATE));
+        assert!(!ConfigBundle::is_excluded_setting("launch_environment_variables"));
+        assert!(!ConfigBundle::is_excluded_setting("steam_api_key_extra"));
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let path = std::env::temp_dir().join(format!("runcher_config_bundle_test_{}.zip", std::process::id()));
+        let mut bundle = ConfigBundle {
+            runcher_version: "1.2.3".to_owned(),
+            ..Default::default()
+        };
+
+        bundle.settings.insert("bool_setting".to_owned(), SettingValue::Bool(true));
+        bundle.settings.insert("int_setting".to_owned(), SettingValue::Int(42));
+        bundle.settings.insert("string_setting".to_owned(), SettingValue::String("text with spaces".to_owned()));
+        bundle.settings.insert("list_setting".to_owned(), SettingValue::StringList(vec!["a".to_owned(), "b c".to_owned()]));
+        bundle.settings.insert("bytes_setting".to_owned(), SettingValue::ByteArray(vec![0, 1, 255]));
+        bundle.files.push(("profiles/default.json".to_owned(), b"{\"id\": \"default\"}".to_vec()));
+        bundle.files.push(("sql_scripts_local/script.sql".to_owned(), b"SELECT 1;".to_vec()));
+
+        bundle.save(&path).unwrap();
+        let loaded = ConfigBundle::load_file(&path);
+        let _ = std::fs::remove_file(&path);
+        let loaded = loaded.unwrap();
+
+        assert_eq!(loaded.runcher_version(), bundle.runcher_version());
+        assert_eq!(loaded.settings(), bundle.settings());
+        assert_eq!(loaded.files(), bundle.files());
+        assert!(loaded.skipped().is_empty());
+    }
+}
@@ -0,0 +1,116 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the code for user-defined launch options.
+//!
+//! These are launch options we don't support out of the box, defined by the user and passed as-is to the game executable.
+
+use anyhow::Result;
+use getset::*;
+use serde::{Deserialize, Serialize};
+use serde_json::to_string_pretty;
+
+use std::fs::{DirBuilder, File};
+use std::io::{BufReader, BufWriter, Read, Write};
+
+use rpfm_lib::games::GameInfo;
+
+use crate::settings_ui::*;
+
+const FILE_NAME_START: &str = "custom_launch_options_";
+const FILE_NAME_END: &str = ".json";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct CustomLaunchOptions {
+
+    // Key of the game.
+    game_key: String,
+
+    // Options defined for the game, in the order they should show up in the UI.
+    options: Vec<CustomLaunchOption>,
+}
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct CustomLaunchOption {
+
+    // Name of the option, as shown in the UI. Must be unique for the game.
+    name: String,
+
+    // Flag passed to the game executable when the option is enabled.
+    flag: String,
+
+    // Kind of option. It determines the widget used for it in the UI.
+    kind: CustomLaunchOptionKind,
+
+    // Default value for value options. The flag is only passed if the value is different from this.
+    default_value: f64,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum CustomLaunchOptionKind {
+
+    // Option that only passes the flag.
+    #[default]
+    Bool,
+
+    // Option that passes the flag, followed by a value.
+    Value,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl CustomLaunchOptions {
+
+    pub fn load(game: &GameInfo, new_if_missing: bool) -> Result<Self> {
+        let path = game_config_path()?.join(format!("{FILE_NAME_START}{}{FILE_NAME_END}", game.key()));
+        if !path.is_file() && new_if_missing {
+            return Ok(Self {
+                game_key: game.key().to_string(),
+                ..Default::default()
+            });
+        }
+
+        let mut file = BufReader::new(File::open(path)?);
+        let mut data = Vec::with_capacity(file.get_ref().metadata()?.len() as usize);
+        file.read_to_end(&mut data)?;
+
+        let options: Self = serde_json::from_slice(&data)?;
+        Ok(options)
+    }
+
+    pub fn save(&mut self, game: &GameInfo) -> Result<()> {
+        let path = game_config_path()?.join(format!("{FILE_NAME_START}{}{FILE_NAME_END}", game.key()));
+
+        // Make sure the path exists to avoid problems with updating schemas.
+        if let Some(parent_folder) = path.parent() {
+            DirBuilder::new().recursive(true).create(parent_folder)?;
+        }
+
+        let mut file = BufWriter::new(File::create(path)?);
+        file.write_all(to_string_pretty(&self)?.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl CustomLaunchOption {
+
+    /// This function returns the key of the setting where the status of this option is stored.
+    pub fn setting_key(&self, game_key: &str) -> String {
+        format!("custom_launch_option_{}_{}", game_key, self.name)
+    }
+}
@@ -0,0 +1,119 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the code for checking if the fonts of a game can render the text of a translation.
+//!
+//! A character is considered covered if any of the fonts in the provided packs has it. Only TrueType and OpenType fonts can be
+//! checked. Older games use bitmap fonts we cannot read, so there's no coverage data for them.
+//!
+//! Reading the packs is slow, so it's done in the background thread. The fonts can only be loaded in the UI thread, so they're
+//! sent back undecoded, and checked there.
+
+use anyhow::Result;
+use getset::*;
+use qt_core::QByteArray;
+use qt_gui::QRawFont;
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use rpfm_lib::files::{Container, FileType, pack::Pack, RFileDecoded};
+
+/// Key of the setting to check the font coverage of the selected translation before launching.
+pub const CHECK_TRANSLATION_FONT_COVERAGE: &str = "check_translation_font_coverage";
+
+const FONT_EXTENSIONS: [&str; 2] = [".ttf", ".otf"];
+
+/// Size the fonts are loaded with. Coverage doesn't depend on it.
+const FONT_PIXEL_SIZE: f64 = 12.0;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, Getters)]
+#[getset(get = "pub")]
+pub struct FontCoverageData {
+
+    // Raw data of the fonts found in the font packs.
+    fonts: Vec<Vec<u8>>,
+
+    // Characters used in the loc files of the text pack.
+    characters: BTreeSet<char>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl FontCoverageData {
+
+    /// This function reads the fonts in the font packs, and the characters in the loc files of the text pack.
+    ///
+    /// Font packs that cannot be read are skipped.
+    pub fn read(font_pack_paths: &[PathBuf], text_pack_path: &Path) -> Result<Self> {
+        let mut fonts = vec![];
+        for path in font_pack_paths {
+            if let Ok(mut pack) = Pack::read_and_merge(&[path.to_path_buf()], true, false, false) {
+                let font_files = pack.files_mut()
+                    .values_mut()
+                    .filter(|file| {
+                        let path = file.path_in_container_raw().to_lowercase();
+                        FONT_EXTENSIONS.iter().any(|extension| path.ends_with(extension))
+                    });
+
+                for file in font_files {
+                    if let Ok(Some(data)) = file.encode(&None, false, false, true) {
+                        fonts.push(data);
+                    }
+                }
+            }
+        }
+
+        // Control characters like line breaks are never drawn, so they don't need a glyph.
+        let mut pack = Pack::read_and_merge(&[text_pack_path.to_path_buf()], true, false, false)?;
+        let mut characters = BTreeSet::new();
+        for file in pack.files_by_type_mut(&[FileType::Loc]) {
+            if let Ok(Some(RFileDecoded::Loc(loc))) = file.decode(&None, false, true) {
+                for row in loc.data().iter() {
+                    if let Some(text) = row.get(1) {
+                        characters.extend(text.data_to_string().chars().filter(|character| !character.is_control()));
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            fonts,
+            characters,
+        })
+    }
+
+    /// This function returns the characters none of the fonts can render. It must be called from the UI thread.
+    ///
+    /// Returns None if there are no fonts we can read, as then we cannot know what's covered.
+    pub unsafe fn missing_characters(&self) -> Option<BTreeSet<char>> {
+        let fonts = self.fonts.iter()
+            .map(|data| QRawFont::from_q_byte_array_double(&QByteArray::from_slice(data), FONT_PIXEL_SIZE))
+            .filter(|font| font.is_valid())
+            .collect::<Vec<_>>();
+
+        if fonts.is_empty() {
+            return None;
+        }
+
+        let missing = self.characters.iter()
+            .filter(|character| !fonts.iter().any(|font| font.supports_character_uint(**character as u32)))
+            .copied()
+            .collect();
+
+        Some(missing)
+    }
+}
@@ -20,7 +20,7 @@ use serde_json::to_string_pretty;
 use std::collections::{BTreeMap, HashMap};
 use std::fs::{DirBuilder, File};
 use std::io::{BufReader, BufWriter, Cursor, Read, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::UNIX_EPOCH;
 
 use rpfm_lib::files::pack::Pack;
@@ -32,7 +32,7 @@ use crate::communications::{Command, Response};
 use crate::mod_manager::{load_order::LoadOrder, mods::Mod};
 use crate::{settings_ui::*, CENTRAL_COMMAND};
 
-use super::secondary_mods_packs_paths;
+use super::{load_mods_from, secondary_mods_packs_paths, MOD_SOURCE_CONTENT, MOD_SOURCE_DATA, MOD_SOURCE_SECONDARY};
 
 mod versions;
 
@@ -63,6 +63,10 @@ pub struct GameConfig {
 
     // List of categories in order.
     categories_order: Vec<String>,
+
+    // Theme icon keys to use instead of the default ones, by launch option key or script key.
+    #[serde(default)]
+    icon_overrides: HashMap<String, String>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -174,13 +178,52 @@ impl GameConfig {
         self.categories_order_mut().retain(|x| x != category);
     }
 
+    /// This function sets the enabled state of all mods by id, enabling only the provided ones.
+    ///
+    /// Ids of mods that are not installed are ignored.
+    pub fn set_enabled_mods(&mut self, mod_ids: &[String]) {
+        self.mods_mut().values_mut().for_each(|modd| { modd.set_enabled(false); });
+
+        for mod_id in mod_ids {
+            if let Some(modd) = self.mods_mut().get_mut(mod_id) {
+                modd.set_enabled(true);
+            }
+        }
+    }
+
+    /// This function returns the copies of the packs of the enabled mods, as mod id and path, grouped by pack name, but only for packs with more than one copy.
+    ///
+    /// All the paths of each mod are checked, so a pack installed in more than one folder (like a workshop copy and a manual copy in /data)
+    /// is detected even if both copies belong to the same mod. Packs are compared by name in lowercase, because the game doesn't care about the case of the pack names.
+    pub fn duplicated_packs(&self, game: &GameInfo, game_data_path: &Path) -> BTreeMap<String, Vec<(String, PathBuf)>> {
+        let mut packs: BTreeMap<String, Vec<(String, PathBuf)>> = BTreeMap::new();
+
+        for modd in self.mods().values() {
+            if modd.enabled(game, game_data_path) && *modd.pack_type() == PFHFileType::Mod {
+                for path in modd.paths() {
+                    if let Some(pack_name) = path.file_name() {
+                        let pack_name = pack_name.to_string_lossy().to_lowercase();
+                        packs.entry(pack_name).or_default().push((modd.id().to_owned(), path.to_path_buf()));
+                    }
+                }
+            }
+        }
+
+        packs.retain(|_, copies| copies.len() > 1);
+        packs.values_mut().for_each(|copies| copies.sort());
+        packs
+    }
+
     /// NOTE: This returns a channel receiver for the workshop/equivalent service data request.
     /// This is done so the request doesn't hang the entire load process, as it usually takes 2 or 3 seconds to complete.
     pub fn update_mod_list(&mut self, game: &GameInfo, game_path: &Path, load_order: &mut LoadOrder, skip_network_update: bool) -> Result<Option<Receiver<Response>>> {
         let mut receiver = None;
 
-        // Clear the mod paths, just in case a failure while loading them leaves them unclean.
-        self.mods_mut().values_mut().for_each(|modd| modd.paths_mut().clear());
+        // Clear the mod paths and load errors, just in case a failure while loading them leaves them unclean.
+        self.mods_mut().values_mut().for_each(|modd| {
+            modd.paths_mut().clear();
+            modd.set_load_error(None);
+        });
 
         // If we have a path, load all the mods to the UI.
         if game_path.components().count() > 1 && game_path.is_dir() {
@@ -188,10 +231,12 @@ impl GameConfig {
             // Vanilla paths may fail if the game path is incorrect, or the game is not properly installed.
             // In that case, we assume there are no packs nor mods to load to avoid further errors.
             if let Ok(vanilla_packs) = game.ca_packs_paths(game_path) {
-                let data_paths = game.data_packs_paths(game_path);
+
+                // Sources disabled by the user are skipped, so their mods are treated like they're not installed.
+                let data_paths = if load_mods_from(game.key(), MOD_SOURCE_DATA) { game.data_packs_paths(game_path) } else { None };
                 let content_path = game.content_path(game_path).map(|path| std::fs::canonicalize(path.clone()).unwrap_or(path));
-                let content_paths = game.content_packs_paths(game_path);
-                let secondary_mods_paths = secondary_mods_packs_paths(game.key());
+                let content_paths = if load_mods_from(game.key(), MOD_SOURCE_CONTENT) { game.content_packs_paths(game_path) } else { None };
+                let secondary_mods_paths = if load_mods_from(game.key(), MOD_SOURCE_SECONDARY) { secondary_mods_packs_paths(game.key()) } else { None };
 
                 let mut steam_ids = vec![];
 
@@ -203,7 +248,7 @@ impl GameConfig {
                         let (packs, maps): (Vec<_>, Vec<_>) = paths.par_iter()
                             .partition_map(|path| match Pack::read_and_merge(&[path.to_path_buf()], true, false, false) {
                                 Ok(pack) => Either::Left((path, pack)),
-                                Err(_) => Either::Right(path),
+                                Err(error) => Either::Right((path, error)),
                             });
 
                         for (path, pack) in packs {
@@ -216,6 +261,7 @@ impl GameConfig {
                                         }
 
                                         modd.set_pack_type(pack.pfh_file_type());
+                                        modd.set_dependencies(Mod::pack_dependencies(&pack));
 
                                         let metadata = modd.paths().last().unwrap().metadata()?;
                                         #[cfg(target_os = "windows")] modd.set_time_created(metadata.created()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
@@ -236,6 +282,7 @@ impl GameConfig {
                                         modd.set_id(pack_name.to_owned());
                                         modd.set_paths(vec![path.to_path_buf()]);
                                         modd.set_pack_type(pack.pfh_file_type());
+                                        modd.set_dependencies(Mod::pack_dependencies(&pack));
 
                                         let metadata = modd.paths()[0].metadata()?;
                                         #[cfg(target_os = "windows")] modd.set_time_created(metadata.created()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
@@ -256,11 +303,22 @@ impl GameConfig {
                             }
                         }
 
-                        // Maps use their own logic.
-                        for path in &maps {
+                        // Maps use their own logic. Anything else that failed to load is a broken pack.
+                        for (path, load_error) in &maps {
                             let pack_name = path.file_name().unwrap().to_string_lossy().as_ref().to_owned();
                             if let Some(extension) = path.extension() {
-                                if extension == "bin" {
+                                if extension == "pack" {
+                                    let modd = self.set_load_error(path, load_error.to_string(), true);
+
+                                    // Get the steam id from the path, if possible, so it can be re-downloaded.
+                                    let path_strip = path.strip_prefix(content_path)?.to_string_lossy().replace("\\", "/");
+                                    let path_strip_split = path_strip.split("/").collect::<Vec<_>>();
+                                    if !path_strip_split.is_empty() {
+                                        let steam_id = path_strip_split[0].to_owned();
+                                        steam_ids.push(steam_id.to_owned());
+                                        modd.set_steam_id(Some(steam_id));
+                                    }
+                                } else if extension == "bin" {
                                     let mut file = BufReader::new(File::open(path)?);
                                     let mut data = Vec::with_capacity(file.get_ref().metadata()?.len() as usize);
                                     file.read_to_end(&mut data)?;
@@ -348,8 +406,16 @@ impl GameConfig {
                         .map(|path| (path, Pack::read_and_merge(&[path.to_path_buf()], true, false, false)))
                         .collect::<Vec<_>>();
 
-                    for (path, pack) in packs {
+                    // Packs come by folder precedence. Process them in reverse so, if a pack is in more than one
+                    // secondary folder, the one in the folder with the highest precedence ends up first.
+                    for (path, pack) in packs.into_iter().rev() {
                         let pack_name = path.file_name().unwrap().to_string_lossy().as_ref().to_owned();
+                        if let Err(ref load_error) = pack {
+                            if path.extension().map_or(false, |extension| extension == "pack") {
+                                self.set_load_error(path, load_error.to_string(), false);
+                            }
+                        }
+
                         if let Ok(pack) = pack {
                             if pack.pfh_file_type() == PFHFileType::Mod || pack.pfh_file_type() == PFHFileType::Movie {
 
@@ -359,6 +425,7 @@ impl GameConfig {
                                             modd.paths_mut().insert(0, path.to_path_buf());
                                         }
                                         modd.set_pack_type(pack.pfh_file_type());
+                                        modd.set_dependencies(Mod::pack_dependencies(&pack));
 
                                         let metadata = modd.paths()[0].metadata()?;
                                         #[cfg(target_os = "windows")] modd.set_time_created(metadata.created()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
@@ -376,6 +443,7 @@ impl GameConfig {
                                                     modd.paths_mut().insert(0, path.to_path_buf());
                                                 }
                                                 modd.set_pack_type(pack.pfh_file_type());
+                                                modd.set_dependencies(Mod::pack_dependencies(&pack));
 
                                                 let metadata = modd.paths()[0].metadata()?;
                                                 #[cfg(target_os = "windows")] modd.set_time_created(metadata.created()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
@@ -389,6 +457,7 @@ impl GameConfig {
                                                 modd.set_id(pack_name.to_owned());
                                                 modd.set_paths(vec![path.to_path_buf()]);
                                                 modd.set_pack_type(pack.pfh_file_type());
+                                                modd.set_dependencies(Mod::pack_dependencies(&pack));
 
                                                 let metadata = modd.paths()[0].metadata()?;
                                                 #[cfg(target_os = "windows")] modd.set_time_created(metadata.created()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
@@ -423,6 +492,12 @@ impl GameConfig {
 
                     for (path, pack) in packs {
                         let pack_name = path.file_name().unwrap().to_string_lossy().as_ref().to_owned();
+                        if let Err(ref load_error) = pack {
+                            if path.extension().map_or(false, |extension| extension == "pack") {
+                                self.set_load_error(&std::fs::canonicalize(path)?, load_error.to_string(), false);
+                            }
+                        }
+
                         if let Ok(pack) = pack {
                             if pack.pfh_file_type() == PFHFileType::Mod || pack.pfh_file_type() == PFHFileType::Movie {
 
@@ -445,6 +520,7 @@ impl GameConfig {
                                                 modd.paths_mut().insert(0, path.to_path_buf());
                                             }
                                             modd.set_pack_type(pack.pfh_file_type());
+                                            modd.set_dependencies(Mod::pack_dependencies(&pack));
 
                                             let metadata = modd.paths()[0].metadata()?;
                                             #[cfg(target_os = "windows")] modd.set_time_created(metadata.created()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
@@ -462,6 +538,7 @@ impl GameConfig {
                                                         modd.paths_mut().insert(0, path.to_path_buf());
                                                     }
                                                     modd.set_pack_type(pack.pfh_file_type());
+                                                    modd.set_dependencies(Mod::pack_dependencies(&pack));
 
                                                     let metadata = modd.paths()[0].metadata()?;
                                                     #[cfg(target_os = "windows")] modd.set_time_created(metadata.created()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
@@ -474,6 +551,7 @@ impl GameConfig {
                                                     modd.set_id(pack_name.to_owned());
                                                     modd.set_paths(vec![path.to_path_buf()]);
                                                     modd.set_pack_type(pack.pfh_file_type());
+                                                    modd.set_dependencies(Mod::pack_dependencies(&pack));
 
                                                     let metadata = modd.paths()[0].metadata()?;
                                                     #[cfg(target_os = "windows")] modd.set_time_created(metadata.created()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
@@ -531,4 +609,86 @@ impl GameConfig {
 
         Ok(receiver)
     }
+
+    /// This function records that the pack in the provided path failed to load, so its mod shows up with the error instead of vanishing from the list.
+    ///
+    /// If the pack doesn't belong to any known mod, a new one is registered for it.
+    fn set_load_error(&mut self, path: &Path, load_error: String, lowest_priority: bool) -> &mut Mod {
+        let pack_name = path.file_name().unwrap().to_string_lossy().to_string();
+        let mod_id = if self.mods.contains_key(&pack_name) {
+            pack_name.to_owned()
+        } else {
+            self.mods.values()
+                .find(|modd| modd.alt_name().map_or(false, |alt_name| alt_name == pack_name))
+                .map(|modd| modd.id().to_owned())
+                .unwrap_or_else(|| pack_name.to_owned())
+        };
+
+        let modd = self.mods.entry(mod_id).or_insert_with(|| {
+            let mut modd = Mod::default();
+            modd.set_name(pack_name.to_owned());
+            modd.set_id(pack_name.to_owned());
+            modd.set_pack_type(PFHFileType::Mod);
+            modd
+        });
+
+        if !modd.paths().iter().any(|mod_path| mod_path == path) {
+            if lowest_priority {
+                modd.paths_mut().push(path.to_path_buf());
+            } else {
+                modd.paths_mut().insert(0, path.to_path_buf());
+            }
+        }
+
+        modd.set_load_error(Some(load_error));
+        modd
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rpfm_lib::games::supported_games::KEY_WARHAMMER_3;
+    use crate::SUPPORTED_GAMES;
+
+    fn game_config(mods: &[(&str, bool)]) -> GameConfig {
+        let mut game_config = GameConfig::default();
+        for (id, enabled) in mods {
+            let mut modd = Mod::default();
+            modd.set_id(id.to_string());
+            modd.set_pack_type(PFHFileType::Mod);
+            modd.set_enabled(*enabled);
+            game_config.mods_mut().insert(id.to_string(), modd);
+        }
+
+        game_config
+    }
+
+    #[test]
+    fn set_enabled_mods_enables_only_the_provided_ones() {
+        let game = SUPPORTED_GAMES.game(KEY_WARHAMMER_3).unwrap();
+        let data_path = Path::new("data");
+        let mut game_config = game_config(&[("a.pack", true), ("b.pack", false), ("c.pack", true)]);
+
+        // Mods that are not installed are ignored, instead of being added to the list.
+        game_config.set_enabled_mods(&["b.pack".to_owned(), "missing.pack".to_owned()]);
+
+        let enabled = game_config.mods().values()
+            .filter(|modd| modd.enabled(game, data_path))
+            .map(|modd| modd.id().to_owned())
+            .collect::<Vec<_>>();
+
+        assert_eq!(enabled, vec!["b.pack".to_owned()]);
+        assert_eq!(game_config.mods().len(), 3);
+    }
+
+    #[test]
+    fn set_enabled_mods_with_no_mods_disables_all() {
+        let game = SUPPORTED_GAMES.game(KEY_WARHAMMER_3).unwrap();
+        let mut game_config = game_config(&[("a.pack", true), ("b.pack", true)]);
+
+        game_config.set_enabled_mods(&[]);
+
+        assert!(game_config.mods().values().all(|modd| !modd.enabled(game, Path::new("data"))));
+    }
 }
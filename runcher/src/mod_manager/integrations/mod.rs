@@ -13,14 +13,24 @@
 //! For now we only support steam workshop, so all calls are redirected to the steam module.
 
 use anyhow::Result;
+use qt_core::{QStorageInfo, QString};
 use serde::Deserialize;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
+use std::process::{Command, ExitStatus};
+#[cfg(target_os = "windows")] use std::os::windows::process::CommandExt;
+use std::sync::{Mutex, RwLock};
+use std::thread::sleep;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use rpfm_lib::games::GameInfo;
+use rpfm_lib::integrations::log::{error, info, warn};
 
-use crate::mod_manager::mods::Mod;
+use rpfm_ui_common::settings::{set_setting_string, setting_path, setting_string};
+
+use crate::games::game_executable_path;
+use crate::mod_manager::{launch_history::{LaunchHistory, LaunchOutcome}, launch_hooks::{self, LAUNCH_HOOK_ON_EXIT, LAUNCH_HOOK_ON_LAUNCH}, launch_script::{is_valid_env_var_name, is_valid_env_var_value}, mods::Mod};
 
 mod steam;
 
@@ -28,6 +38,33 @@ mod steam;
 #[cfg(target_os = "windows")] pub const DETACHED_PROCESS: u32 = 0x00000008;
 #[cfg(target_os = "windows")] const CREATE_NEW_CONSOLE: u32 = 0x00000010;
 
+/// Highest values allowed in the settings with the max amount of mods to download at the same time, and the max average download speed in KB/s.
+///
+/// In both settings, 0 means no limit.
+pub const DOWNLOAD_MAX_CONCURRENT_MAX: i32 = 64;
+pub const DOWNLOAD_MAX_SPEED_MAX: i32 = 1_000_000;
+
+/// Free space, in bytes, below which we stop a download in progress, so it doesn't fill the drive and leave broken mods behind.
+pub const DOWNLOAD_MIN_FREE_SPACE: u64 = 256 * 1024 * 1024;
+
+/// Prefixes of the settings with the exit code of the last launch of each game if the game failed to start, and the date it was launched.
+pub const LAST_LAUNCH_EXIT_CODE: &str = "last_launch_exit_code_";
+pub const LAST_LAUNCH_START_DATE: &str = "last_launch_start_date_";
+
+/// Time we give Steam to start the game before assuming it didn't start, and interval between checks of the game process.
+const GAME_PROCESS_START_TIMEOUT: Duration = Duration::from_secs(120);
+const GAME_PROCESS_CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+lazy_static::lazy_static! {
+
+    /// Keys of the games launched from here that are still starting or running.
+    static ref RUNNING_GAMES: RwLock<HashSet<String>> = RwLock::new(HashSet::new());
+
+    /// Launches that finished in the background and haven't been recorded yet, with the exit status of their launch process,
+    /// and if the game process was seen closing.
+    static ref FINISHED_LAUNCHES: Mutex<Vec<(GameInfo, Option<ExitStatus>, bool)>> = Mutex::new(vec![]);
+}
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -69,18 +106,244 @@ pub fn upload_mod_to_workshop(game: &GameInfo, modd: &Mod, title: &str, descript
     steam::upload_mod_to_workshop(game, modd, title, description, tags, changelog, visibility, force_update)
 }
 
-pub fn launch_game(game: &GameInfo, command_to_pass: &str, wait_for_finish: bool) -> Result<()> {
-    steam::launch_game(game, command_to_pass, wait_for_finish)
+/// This function launches the game, running the launch and exit hooks around it.
+///
+/// The launch process only asks Steam to start the game, so both it and then the game process are followed from a separate thread,
+/// and how the launch ended is kept until it's recorded with [record_finished_launches] from the UI thread.
+/// The exit hook is run from that thread once the game closes, so a slow hook never blocks the UI. If the game process
+/// could not be followed until it closed, we don't know when it exits, so the exit hook is not run.
+///
+/// To wait for the game to finish, wait until [is_game_launched] returns false.
+pub fn launch_game(game: &GameInfo, command_to_pass: &str) -> Result<()> {
+    let mut handle = steam::launch_game(game, command_to_pass)?;
+    RUNNING_GAMES.write().unwrap().insert(game.key().to_owned());
+
+    let executable = game_executable_path(game, &setting_path(game.key()))
+        .and_then(|path| path.file_name().map(|name| name.to_string_lossy().to_string()));
+
+    let start_date = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    set_setting_string(&format!("{LAST_LAUNCH_START_DATE}{}", game.key()), &start_date.to_string());
+
+    let game_hook = game.clone();
+    std::thread::spawn(move || launch_hooks::run(LAUNCH_HOOK_ON_LAUNCH, &game_hook));
+
+    let game = game.clone();
+    std::thread::spawn(move || {
+        let status = handle.wait().ok();
+        let closed = status.map_or(false, |status| status.success()) && follow_game_process(executable.as_deref());
+        FINISHED_LAUNCHES.lock().unwrap().push((game.clone(), status, closed));
+        RUNNING_GAMES.write().unwrap().remove(game.key());
+
+        if closed {
+            launch_hooks::run(LAUNCH_HOOK_ON_EXIT, &game);
+        } else {
+            info!("Hook {LAUNCH_HOOK_ON_EXIT} not run, as the game process could not be followed until it closed.");
+        }
+    });
+
+    Ok(())
+}
+
+/// This function waits until the process of the provided game executable closes, and returns if it was seen running.
+///
+/// The game takes a while to be started by Steam, so we give it some time to show up before assuming it never started.
+fn follow_game_process(executable: Option<&str>) -> bool {
+    let executable = match executable {
+        Some(executable) => executable,
+        None => return false,
+    };
+
+    let start = Instant::now();
+    while !is_executable_running(executable) {
+        if start.elapsed() > GAME_PROCESS_START_TIMEOUT {
+            return false;
+        }
+
+        sleep(GAME_PROCESS_CHECK_INTERVAL);
+    }
+
+    while is_executable_running(executable) {
+        sleep(GAME_PROCESS_CHECK_INTERVAL);
+    }
+
+    true
+}
+
+/// This function records the exit status of the launches that finished in the background since the last call.
+///
+/// This writes to the settings, so it must only be called from the UI thread.
+pub fn record_finished_launches() {
+    let finished = std::mem::take(&mut *FINISHED_LAUNCHES.lock().unwrap());
+    for (game, status, closed) in finished {
+        record_exit_status(&game, status);
+
+        if closed {
+            if let Err(error) = LaunchHistory::set_last_outcome(&game, LaunchOutcome::Closed) {
+                error!("Error when recording the outcome of the launch in the launch history: {}", error);
+            }
+        }
+    }
+}
+
+/// This function returns if there's any launch still running, or finished but not yet recorded.
+pub fn is_launch_pending() -> bool {
+    !RUNNING_GAMES.read().unwrap().is_empty() || !FINISHED_LAUNCHES.lock().unwrap().is_empty()
+}
+
+/// This function stores the exit code of the launch if the game failed to start, or clears it if it didn't.
+///
+/// The provided status is the one of the process that asks Steam to start the game, not the one of the game, which we cannot get,
+/// so a failure means the game didn't start, and that's recorded in the launch history. If we couldn't get the exit status, we keep whatever was stored from previous launches.
+fn record_exit_status(game: &GameInfo, status: Option<ExitStatus>) {
+    let setting = format!("{LAST_LAUNCH_EXIT_CODE}{}", game.key());
+    match status {
+        Some(status) if !status.success() => {
+            let code = status.code().map(|code| code.to_string()).unwrap_or_else(|| "?".to_owned());
+            warn!("{} failed to start, with code {}.", game.key(), code);
+            set_setting_string(&setting, &code);
+
+            if let Err(error) = LaunchHistory::set_last_outcome(game, LaunchOutcome::Failed) {
+                error!("Error when recording the outcome of the launch in the launch history: {}", error);
+            }
+        }
+        Some(_) => set_setting_string(&setting, ""),
+        None => {},
+    }
+}
+
+/// This function checks if the provided game is running, either from a launch done from here, or started from somewhere else.
+///
+/// Games started from somewhere else are detected by looking for a process with the name of their executable,
+/// so this shouldn't be called periodically from the UI thread. Use [is_game_launched] for that.
+pub fn is_game_running(game: &GameInfo, game_path: &Path) -> bool {
+    if is_game_launched(game) {
+        return true;
+    }
+
+    game_executable_path(game, game_path)
+        .and_then(|path| path.file_name().map(|name| name.to_string_lossy().to_string()))
+        .map(|name| is_executable_running(&name))
+        .unwrap_or_default()
+}
+
+/// This function checks if the provided game was launched from here, and it's still starting or running.
+pub fn is_game_launched(game: &GameInfo) -> bool {
+    RUNNING_GAMES.read().unwrap().contains(game.key())
+}
+
+/// This function checks if there's a process running with the provided executable name.
+///
+/// On linux, the name is compared against the file name of the program of each process, so it also matches windows paths of games running under Proton.
+/// If the process list cannot be checked, we assume it's not running, so we never block a launch because of it.
+fn is_executable_running(name: &str) -> bool {
+    if cfg!(target_os = "windows") {
+        let mut command = Command::new("tasklist");
+        command.args(["/NH", "/FO", "CSV", "/FI"]);
+        command.arg(format!("IMAGENAME eq {name}"));
+
+        #[cfg(target_os = "windows")] command.creation_flags(CREATE_NO_WINDOW);
+
+        // The csv output has the image name quoted at the start of each line, so we can match it exactly.
+        command.output()
+            .map(|output| String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .any(|line| line.to_lowercase().starts_with(&format!("\"{}\"", name.to_lowercase()))))
+            .unwrap_or_default()
+    } else {
+        std::fs::read_dir("/proc")
+            .map(|entries| entries.flatten().any(|entry| {
+                std::fs::read(entry.path().join("cmdline")).ok()
+                    .and_then(|cmdline| cmdline.split(|byte| *byte == 0).next().map(|program| String::from_utf8_lossy(program).to_string()))
+                    .and_then(|program| program.rsplit(|character| character == '/' || character == '\\').next().map(|file_name| file_name.eq_ignore_ascii_case(name)))
+                    .unwrap_or_default()
+            }))
+            .unwrap_or_default()
+    }
 }
 
 pub fn download_subscribed_mods(game: &GameInfo, published_file_ids: &Option<Vec<String>>) -> Result<()> {
     steam::download_subscribed_mods(game, published_file_ids)
 }
 
+/// This function returns the wrapper command the user wants to launch the game through, split into the program and its arguments.
+///
+/// It's split like the launch arguments, so paths with spaces can be passed within quotes.
+pub fn launch_wrapper() -> Vec<String> {
+    split_launch_args(&setting_string("launch_wrapper_command"))
+}
+
+/// This function splits the provided launch arguments the way a shell would, so arguments with spaces can be passed within quotes.
+///
+/// Both double and single quotes are supported. Backslashes are not escapes, so Windows paths can be passed as they are.
+pub fn split_launch_args(args: &str) -> Vec<String> {
+    let mut split = vec![];
+    let mut current = String::new();
+    let mut in_arg = false;
+    let mut quote = None;
+
+    for character in args.chars() {
+        match quote {
+            Some(quote_char) if character == quote_char => quote = None,
+            Some(_) => current.push(character),
+            None => match character {
+                '"' | '\'' => {
+                    quote = Some(character);
+                    in_arg = true;
+                }
+                _ if character.is_whitespace() => {
+                    if in_arg {
+                        split.push(std::mem::take(&mut current));
+                        in_arg = false;
+                    }
+                }
+                _ => {
+                    current.push(character);
+                    in_arg = true;
+                }
+            }
+        }
+    }
+
+    if in_arg {
+        split.push(current);
+    }
+
+    split
+}
+
+/// This function returns the environment variables the user wants to pass to the game, from a string in `KEY=VALUE;KEY2=VALUE2` format.
+///
+/// Variables with invalid names or values are skipped.
+pub fn launch_environment_variables() -> Vec<(String, String)> {
+    setting_string("launch_environment_variables")
+        .split(';')
+        .filter_map(|var| var.split_once('='))
+        .map(|(key, value)| (key.trim().to_owned(), value.trim().to_owned()))
+        .filter(|(key, value)| is_valid_env_var_name(key) && is_valid_env_var_value(value))
+        .collect()
+}
+
+/// This function returns the free space, in bytes, of the drive containing the provided path, or None if it cannot be checked.
+pub fn free_disk_space(path: &Path) -> Option<u64> {
+    unsafe {
+        let storage = QStorageInfo::from_q_string(&QString::from_std_str(path.to_string_lossy()));
+        if storage.is_valid() && storage.is_ready() {
+            u64::try_from(storage.bytes_available()).ok()
+        } else {
+            None
+        }
+    }
+}
+
 pub fn store_user_id(game: &GameInfo) -> Result<u64> {
     steam::user_id(game)
 }
 
+/// This function returns the build of the game installed, if the store it's installed from reports it.
+pub fn game_build(game: &GameInfo, game_path: &Path) -> Option<String> {
+    steam::game_build(game, game_path).ok().flatten()
+}
+
 pub fn can_game_locked(game: &GameInfo, game_path: &Path) -> bool {
     steam::can_game_locked(game, game_path).unwrap_or_default()
 }
@@ -15,23 +15,25 @@ use regex::Regex;
 use serde::Deserialize;
 use steam_workshop_api::{client::Workshop, interfaces::i_steam_user::*};
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::fs::File;
 use std::io::{BufWriter, Read, Write};
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::process::{Child, Command};
+use std::thread::sleep;
+use std::time::{Duration, Instant};
 #[cfg(target_os = "windows")]use std::os::windows::process::CommandExt;
 
 use rpfm_lib::files::{EncodeableExtraData, pack::Pack};
 use rpfm_lib::games::GameInfo;
-use rpfm_lib::utils::path_to_absolute_string;
+use rpfm_lib::utils::{files_from_subdir, path_to_absolute_string};
 
-use rpfm_ui_common::settings::{setting_path, setting_string};
+use rpfm_ui_common::settings::{setting_int, setting_path, setting_string};
 
 use crate::mod_manager::mods::Mod;
 
 #[cfg(target_os = "windows")]use super::{CREATE_NEW_CONSOLE, CREATE_NO_WINDOW, DETACHED_PROCESS};
-use super::{PreUploadInfo, PublishedFileVisibilityDerive};
+use super::{DOWNLOAD_MAX_CONCURRENT_MAX, DOWNLOAD_MAX_SPEED_MAX, DOWNLOAD_MIN_FREE_SPACE, free_disk_space, launch_environment_variables, PreUploadInfo, PublishedFileVisibilityDerive};
 
 lazy_static::lazy_static! {
     pub static ref REGEX_URL: Regex = Regex::new(r"(\[url=)(.*)(\])(.*)(\[/url\])").unwrap();
@@ -45,6 +47,9 @@ lazy_static::lazy_static! {
 
 const WORKSHOPPER_EXE: &str = "workshopper.exe";
 
+/// How often we check the free space of the drive mods are downloaded to while a download is in progress.
+const DOWNLOAD_DISK_SPACE_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
 const BAT_UPLOAD_TO_WORKSHOP: &str = "upload-to-workshop.bat";
 const BAT_GET_PUBLISHED_FILE_DETAILS: &str = "get-published-file-details.bat";
 
@@ -313,7 +318,9 @@ pub fn upload_mod_to_workshop(game: &GameInfo, modd: &Mod, title: &str, descript
 }
 
 /// This function launches a game through workshopper, with access to the Steam Api.
-pub fn launch_game(game: &GameInfo, command_to_pass: &str, wait_for_finish: bool) -> Result<()> {
+///
+/// Returns the handle of the launch process, which lives as long as the game.
+pub fn launch_game(game: &GameInfo, command_to_pass: &str) -> Result<Child> {
     let game_path = setting_path(game.key());
     let steam_id = game.steam_id(&game_path)? as u32;
 
@@ -322,6 +329,10 @@ pub fn launch_game(game: &GameInfo, command_to_pass: &str, wait_for_finish: bool
     command.arg(&*WORKSHOPPER_PATH);
     command.arg("launch");
 
+    // Workshopper runs the launch command as a child process, so the game inherits the environment variables we set here.
+    // The launch wrapper is already part of the command we receive.
+    command.envs(launch_environment_variables());
+
     // Due to issues passing certain characters to the terminal, we encode the strings to base64 and pass -b.
     command.arg("-b");
     command.arg("-s");
@@ -336,20 +347,126 @@ pub fn launch_game(game: &GameInfo, command_to_pass: &str, wait_for_finish: bool
         command.creation_flags(CREATE_NO_WINDOW);
     }
 
-    let mut handle = command.spawn()?;
-
-    if wait_for_finish {
-        let _ = handle.wait();
-    }
-
-    Ok(())
+    command.spawn().map_err(From::from)
 }
 
 /// This function asks workshopper to get all subscribed items, check which ones are missing, and tell steam to re-download them.
+///
+/// If we have a list of items and the user limited the amount of concurrent downloads or the download speed, items are downloaded one by one within said limits.
 pub fn download_subscribed_mods(game: &GameInfo, published_file_ids: &Option<Vec<String>>) -> Result<()> {
     let game_path = setting_path(game.key());
     let steam_id = game.steam_id(&game_path)? as u32;
+    let content_path = game.content_path(&game_path).ok();
+
+    let max_concurrent = setting_int("download_max_concurrent").clamp(0, DOWNLOAD_MAX_CONCURRENT_MAX) as usize;
+    let max_speed = setting_int("download_max_speed").clamp(0, DOWNLOAD_MAX_SPEED_MAX) as u64 * 1024;
+    match published_file_ids {
+        Some(published_file_ids) if max_concurrent > 0 || max_speed > 0 => download_items_limited(steam_id, published_file_ids, content_path.as_deref(), max_concurrent, max_speed),
+        _ => download_items(steam_id, published_file_ids.as_deref(), content_path.as_deref()),
+    }
+}
+
+/// This function asks workshopper to download the provided items, or all missing subscribed items if none are provided, and waits until it's done.
+///
+/// If a content path is provided, its drive is checked while waiting, and the download is stopped if it's about to run out of space.
+fn download_items(steam_id: u32, published_file_ids: Option<&[String]>, content_path: Option<&Path>) -> Result<()> {
+    let mut handle = spawn_download(steam_id, published_file_ids)?;
+    let mut last_check = Instant::now();
+    while handle.try_wait()?.is_none() {
+        if let Some(content_path) = content_path {
+            if last_check.elapsed() >= DOWNLOAD_DISK_SPACE_CHECK_INTERVAL {
+                last_check = Instant::now();
+
+                if let Err(error) = check_download_space(content_path) {
+                    let _ = handle.kill();
+                    return Err(error);
+                }
+            }
+        }
+
+        sleep(Duration::from_millis(250));
+    }
+
+    Ok(())
+}
+
+/// This function downloads the provided items with a workshopper instance per item, within the provided limits, and waits until it's done.
+///
+/// - Up to `max_concurrent` items are downloaded at the same time. 0 means no limit.
+/// - No new item is started while the average speed of the download, in bytes per second, is over `max_speed`. 0 means no limit.
+///
+/// Steam doesn't let us throttle an item while it's downloading, so the speed limit is an average over the whole download, enforced by delaying the next items.
+/// The downloaded size is measured from the folders of the items in the content path, so without a content path there is no speed limit.
+fn download_items_limited(steam_id: u32, published_file_ids: &[String], content_path: Option<&Path>, max_concurrent: usize, max_speed: u64) -> Result<()> {
+    let items_size = || content_path
+        .map(|content_path| published_file_ids.iter().map(|id| folder_size(&content_path.join(id))).sum::<u64>())
+        .unwrap_or_default();
+
+    let start = Instant::now();
+    let initial_size = items_size();
+    let mut downloaded = 0;
+
+    let mut pending = published_file_ids.iter().collect::<VecDeque<_>>();
+    let mut running: Vec<Child> = vec![];
+    let mut last_check = Instant::now();
+
+    while !pending.is_empty() || !running.is_empty() {
+        let mut finished = false;
+        for index in (0..running.len()).rev() {
+            if running[index].try_wait()?.is_some() {
+                running.swap_remove(index);
+                finished = true;
+            }
+        }
+
+        let check_due = last_check.elapsed() >= DOWNLOAD_DISK_SPACE_CHECK_INTERVAL;
+        if finished || check_due {
+            downloaded = items_size().saturating_sub(initial_size);
+        }
 
+        if check_due {
+            last_check = Instant::now();
+
+            if let Some(content_path) = content_path {
+                if let Err(error) = check_download_space(content_path) {
+                    running.iter_mut().for_each(|handle| { let _ = handle.kill(); });
+                    return Err(error);
+                }
+            }
+        }
+
+        let under_speed = max_speed == 0 || (downloaded as f64) <= max_speed as f64 * start.elapsed().as_secs_f64();
+        let under_concurrent = max_concurrent == 0 || running.len() < max_concurrent;
+        if under_speed && under_concurrent {
+            if let Some(published_file_id) = pending.pop_front() {
+                running.push(spawn_download(steam_id, Some(std::slice::from_ref(published_file_id)))?);
+                continue;
+            }
+        }
+
+        sleep(Duration::from_millis(250));
+    }
+
+    Ok(())
+}
+
+/// This function returns an error if the drive of the provided path is about to run out of space.
+fn check_download_space(content_path: &Path) -> Result<()> {
+    match free_disk_space(content_path) {
+        Some(free_space) if free_space < DOWNLOAD_MIN_FREE_SPACE => Err(anyhow!("The download was stopped because the drive of {} is about to run out of space ({:.2} MB left). Free some space and try again.", content_path.to_string_lossy(), free_space as f64 / 1024.0 / 1024.0)),
+        _ => Ok(()),
+    }
+}
+
+/// This function returns the size in bytes of all the files in the provided folder, or 0 if it doesn't exist.
+fn folder_size(path: &Path) -> u64 {
+    files_from_subdir(path, true)
+        .map(|files| files.iter().filter_map(|file| file.metadata().ok()).map(|metadata| metadata.len()).sum())
+        .unwrap_or_default()
+}
+
+/// This function starts a workshopper instance downloading the provided items, or all missing subscribed items if none are provided.
+fn spawn_download(steam_id: u32, published_file_ids: Option<&[String]>) -> Result<Child> {
     let mut command = Command::new("cmd");
     command.arg("/C");
     command.arg(&*WORKSHOPPER_PATH);
@@ -366,10 +483,7 @@ pub fn download_subscribed_mods(game: &GameInfo, published_file_ids: &Option<Vec
     // This is for creating the terminal window. Without it, the entire process runs in the background and there's no feedback on when it's done.
     #[cfg(target_os = "windows")]command.creation_flags(DETACHED_PROCESS);
 
-    let mut handle = command.spawn()?;
-    handle.wait()?;
-
-    Ok(())
+    command.spawn().map_err(From::from)
 }
 
 pub fn user_id(game: &GameInfo) -> Result<u64> {
@@ -418,6 +532,24 @@ fn app_manifest_path(game: &GameInfo, game_path: &Path) -> Result<PathBuf> {
     Ok(app_path)
 }
 
+/// This function returns the build id Steam has installed for the provided game, from its app manifest.
+pub fn game_build(game: &GameInfo, game_path: &Path) -> Result<Option<String>> {
+    let app_path = app_manifest_path(game, game_path)?;
+    if !app_path.is_file() {
+        return Ok(None);
+    }
+
+    let mut data = String::new();
+    File::open(app_path)?.read_to_string(&mut data)?;
+
+    // Manifest entries are quoted key-value pairs, one per line.
+    Ok(data.lines()
+        .filter_map(|line| line.trim().split_once(char::is_whitespace))
+        .find(|(key, _)| key.trim_matches('"').eq_ignore_ascii_case("buildid"))
+        .map(|(_, value)| value.trim().trim_matches('"').to_owned())
+        .filter(|build| !build.is_empty()))
+}
+
 pub fn can_game_locked(game: &GameInfo, game_path: &Path) -> Result<bool> {
     let app_path = app_manifest_path(game, game_path)?;
     Ok(app_path.is_file())
@@ -0,0 +1,157 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the code for checking which launch flags the executable of a game accepts.
+//!
+//! Games don't report the flags they accept, so we look for the flags in the executable itself, as the game needs their names
+//! to parse them. A flag found may still be ignored by the game, but a flag not found is very likely not supported by that build.
+//! Reading the executable is slow, so the results are cached per game build.
+
+use anyhow::Result;
+use getset::*;
+use itertools::Itertools;
+use regex::bytes::RegexBuilder;
+use serde::{Deserialize, Serialize};
+use serde_json::to_string_pretty;
+
+use std::collections::BTreeMap;
+use std::fs::{DirBuilder, File};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use rpfm_lib::games::GameInfo;
+
+use crate::settings_ui::game_config_path;
+
+const FILE_NAME_START: &str = "launch_flags_";
+const FILE_NAME_END: &str = ".json";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct LaunchFlagsCheck {
+
+    // Key of the game.
+    game_key: String,
+
+    // Build of the executable the results are for, from its size and modification date.
+    build: String,
+
+    // Flags checked, and if they were found in the executable.
+    flags: BTreeMap<String, bool>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl LaunchFlagsCheck {
+
+    /// This function checks the provided flags against the executable of the game.
+    ///
+    /// Cached results are reused if the executable didn't change, so it's only read if there are flags not checked yet.
+    pub fn check(game: &GameInfo, executable_path: &Path, flags: &[String]) -> Result<Self> {
+        let build = Self::build(executable_path)?;
+        let mut check = match Self::load(game)? {
+            Some(check) if check.build == build => check,
+            _ => Self {
+                game_key: game.key().to_owned(),
+                build,
+                flags: BTreeMap::new(),
+            },
+        };
+
+        let missing = flags.iter()
+            .filter(|flag| !check.flags.contains_key(*flag))
+            .unique()
+            .collect::<Vec<_>>();
+
+        if !missing.is_empty() {
+            let mut file = BufReader::new(File::open(executable_path)?);
+            let mut data = Vec::with_capacity(file.get_ref().metadata()?.len() as usize);
+            file.read_to_end(&mut data)?;
+
+            for flag in missing {
+                check.flags.insert(flag.to_owned(), Self::flag_in_executable(&data, flag)?);
+            }
+
+            check.save(game)?;
+        }
+
+        Ok(check)
+    }
+
+    /// This function returns if the provided flag is supported by the game build, or None if it hasn't been checked.
+    pub fn supported(&self, flag: &str) -> Option<bool> {
+        self.flags.get(flag).copied()
+    }
+
+    fn load(game: &GameInfo) -> Result<Option<Self>> {
+        let path = Self::path(game)?;
+        if !path.is_file() {
+            return Ok(None);
+        }
+
+        let mut file = BufReader::new(File::open(path)?);
+        let mut data = Vec::with_capacity(file.get_ref().metadata()?.len() as usize);
+        file.read_to_end(&mut data)?;
+
+        let check: Self = serde_json::from_slice(&data)?;
+        Ok(Some(check))
+    }
+
+    fn save(&self, game: &GameInfo) -> Result<()> {
+        let path = Self::path(game)?;
+
+        // Make sure the path exists to avoid problems with updating schemas.
+        if let Some(parent_folder) = path.parent() {
+            DirBuilder::new().recursive(true).create(parent_folder)?;
+        }
+
+        let mut file = BufWriter::new(File::create(path)?);
+        file.write_all(to_string_pretty(&self)?.as_bytes())?;
+        Ok(())
+    }
+
+    fn path(game: &GameInfo) -> Result<PathBuf> {
+        Ok(game_config_path()?.join(format!("{FILE_NAME_START}{}{FILE_NAME_END}", game.key())))
+    }
+
+    fn build(executable_path: &Path) -> Result<String> {
+        let metadata = executable_path.metadata()?;
+        Ok(format!("{}_{}", metadata.len(), metadata.modified()?.duration_since(UNIX_EPOCH)?.as_secs()))
+    }
+
+    /// This function looks for the flag in the executable, without its leading dashes or slashes.
+    ///
+    /// Executables store their strings either as ASCII or as UTF-16, so we look for both, ignoring the case.
+    fn flag_in_executable(data: &[u8], flag: &str) -> Result<bool> {
+        let name = flag.trim_start_matches(['-', '/']);
+        if name.is_empty() {
+            return Ok(true);
+        }
+
+        let ascii = regex::escape(name);
+        let utf16 = name.chars()
+            .map(|character| format!("{}\\x00", regex::escape(&character.to_string())))
+            .join("");
+
+        let regex = RegexBuilder::new(&format!("(?:{ascii})|(?:{utf16})"))
+            .case_insensitive(true)
+            .unicode(false)
+            .build()?;
+
+        Ok(regex.is_match(data))
+    }
+}
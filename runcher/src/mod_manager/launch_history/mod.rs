@@ -0,0 +1,261 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the history of the launches of each game.
+//!
+//! Each launch keeps the load order and launch settings it was done with, so it can be repeated later, and how the game exited,
+//! so changes can be correlated with crashes. Only the latest launches are kept, up to the amount set in the settings.
+
+use anyhow::Result;
+use getset::*;
+use serde::{Deserialize, Serialize};
+use serde_json::to_string_pretty;
+
+use std::collections::BTreeMap;
+use std::fs::{DirBuilder, File};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use rpfm_lib::games::GameInfo;
+
+use rpfm_ui_common::settings::setting_int;
+
+use crate::mod_manager::load_order::LoadOrder;
+use crate::settings_ui::game_config_path;
+
+/// Key of the setting with the amount of launches to keep per game.
+pub const LAUNCH_HISTORY_MAX_ENTRIES: &str = "launch_history_max_entries";
+
+pub const LAUNCH_HISTORY_MAX_ENTRIES_DEFAULT: i32 = 50;
+pub const LAUNCH_HISTORY_MAX_ENTRIES_MAX: i32 = 1000;
+
+const FILE_NAME_START: &str = "launch_history_";
+const FILE_NAME_END: &str = ".json";
+
+/// Lock for the history files, so a launch being added and the outcome of the previous one being recorded from another thread
+/// don't overwrite each other's changes.
+static HISTORY_LOCK: Mutex<()> = Mutex::new(());
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct LaunchHistory {
+
+    // Key of the game.
+    game_key: String,
+
+    // Launches of the game, from newest to oldest.
+    entries: Vec<LaunchHistoryEntry>,
+}
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct LaunchHistoryEntry {
+
+    // Date of the launch, as a unix timestamp.
+    date: u64,
+
+    // Profile selected when launching, if any.
+    profile: Option<String>,
+
+    // Load order used in the launch.
+    load_order: LoadOrder,
+
+    // Values of the built-in launch options used in the launch, by setting key.
+    launch_options: BTreeMap<String, String>,
+
+    // Script settings used in the launch (enabled scripts, presets and params), by setting key.
+    scripts: BTreeMap<String, String>,
+
+    // How the game exited.
+    outcome: LaunchOutcome,
+}
+
+/// How a launch ended.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LaunchOutcome {
+
+    /// The game is still running, or we couldn't follow it until it exited (for example, because the launcher got closed).
+    #[default]
+    Unknown,
+
+    /// The game failed to start.
+    Failed,
+
+    /// The game ran and closed. As the game is started by Steam, we cannot get its exit code to know if it closed cleanly or crashed.
+    Closed,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl LaunchHistory {
+
+    pub fn load(game: &GameInfo, new_if_missing: bool) -> Result<Self> {
+        Self::load_from(&Self::history_path(game)?, game.key(), new_if_missing)
+    }
+
+    pub fn save(&mut self, game: &GameInfo) -> Result<()> {
+        self.save_to(&Self::history_path(game)?)
+    }
+
+    /// This function adds a new launch to the history of the provided game, removing the oldest ones over the limit set in the settings.
+    pub fn add(game: &GameInfo, entry: LaunchHistoryEntry) -> Result<()> {
+        let max_entries = setting_int(LAUNCH_HISTORY_MAX_ENTRIES).max(1) as usize;
+        Self::add_to(&Self::history_path(game)?, game.key(), entry, max_entries)
+    }
+
+    /// This function sets how the last launch of the provided game ended.
+    ///
+    /// Only one instance of a game can be launched at the same time, so the last launch is always the one that ended.
+    pub fn set_last_outcome(game: &GameInfo, outcome: LaunchOutcome) -> Result<()> {
+        Self::set_last_outcome_in(&Self::history_path(game)?, game.key(), outcome)
+    }
+
+    fn add_to(path: &Path, game_key: &str, entry: LaunchHistoryEntry, max_entries: usize) -> Result<()> {
+        let _lock = HISTORY_LOCK.lock().unwrap();
+        let mut history = Self::load_from(path, game_key, true)?;
+        history.entries.insert(0, entry);
+        history.entries.truncate(max_entries);
+        history.save_to(path)
+    }
+
+    fn set_last_outcome_in(path: &Path, game_key: &str, outcome: LaunchOutcome) -> Result<()> {
+        let _lock = HISTORY_LOCK.lock().unwrap();
+        let mut history = Self::load_from(path, game_key, true)?;
+        if let Some(entry) = history.entries.first_mut() {
+            entry.outcome = outcome;
+            history.save_to(path)?;
+        }
+
+        Ok(())
+    }
+
+    fn load_from(path: &Path, game_key: &str, new_if_missing: bool) -> Result<Self> {
+        if !path.is_file() && new_if_missing {
+            return Ok(Self {
+                game_key: game_key.to_string(),
+                ..Default::default()
+            });
+        }
+
+        let mut file = BufReader::new(File::open(path)?);
+        let mut data = Vec::with_capacity(file.get_ref().metadata()?.len() as usize);
+        file.read_to_end(&mut data)?;
+
+        let history: Self = serde_json::from_slice(&data)?;
+        Ok(history)
+    }
+
+    fn save_to(&self, path: &Path) -> Result<()> {
+
+        // Make sure the path exists to avoid problems with updating schemas.
+        if let Some(parent_folder) = path.parent() {
+            DirBuilder::new().recursive(true).create(parent_folder)?;
+        }
+
+        let mut file = BufWriter::new(File::create(path)?);
+        file.write_all(to_string_pretty(&self)?.as_bytes())?;
+        Ok(())
+    }
+
+    fn history_path(game: &GameInfo) -> Result<PathBuf> {
+        Ok(game_config_path()?.join(format!("{FILE_NAME_START}{}{FILE_NAME_END}", game.key())))
+    }
+}
+
+impl LaunchHistoryEntry {
+
+    pub fn new(date: u64, profile: Option<String>, load_order: LoadOrder, launch_options: BTreeMap<String, String>, scripts: BTreeMap<String, String>) -> Self {
+        Self {
+            date,
+            profile,
+            load_order,
+            launch_options,
+            scripts,
+            outcome: LaunchOutcome::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("runcher_launch_history_test_{}_{name}", std::process::id())).join("launch_history.json")
+    }
+
+    fn entry(date: u64) -> LaunchHistoryEntry {
+        LaunchHistoryEntry::new(date, None, LoadOrder::default(), BTreeMap::new(), BTreeMap::new())
+    }
+
+    #[test]
+    fn add_keeps_the_newest_launches() {
+        let path = test_path("add");
+        for date in 0..5 {
+            LaunchHistory::add_to(&path, "warhammer_3", entry(date), 3).unwrap();
+        }
+
+        let history = LaunchHistory::load_from(&path, "warhammer_3", false).unwrap();
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+
+        assert_eq!(history.game_key(), "warhammer_3");
+        assert_eq!(history.entries().iter().map(|entry| *entry.date()).collect::<Vec<_>>(), vec![4, 3, 2]);
+    }
+
+    #[test]
+    fn outcome_is_set_on_the_last_launch() {
+        let path = test_path("outcome");
+
+        // Without launches there's nothing to set, and no file gets created.
+        LaunchHistory::set_last_outcome_in(&path, "warhammer_3", LaunchOutcome::Failed).unwrap();
+        assert!(!path.is_file());
+
+        LaunchHistory::add_to(&path, "warhammer_3", entry(0), 10).unwrap();
+        LaunchHistory::add_to(&path, "warhammer_3", entry(1), 10).unwrap();
+        LaunchHistory::set_last_outcome_in(&path, "warhammer_3", LaunchOutcome::Closed).unwrap();
+
+        let history = LaunchHistory::load_from(&path, "warhammer_3", false).unwrap();
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+
+        assert_eq!(history.entries()[0].outcome(), &LaunchOutcome::Closed);
+        assert_eq!(history.entries()[1].outcome(), &LaunchOutcome::Unknown);
+    }
+
+    #[test]
+    fn concurrent_changes_are_not_lost() {
+        let path = test_path("concurrent");
+        let threads = (0..8)
+            .map(|date| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    LaunchHistory::add_to(&path, "warhammer_3", entry(date), 100).unwrap();
+                    LaunchHistory::set_last_outcome_in(&path, "warhammer_3", LaunchOutcome::Closed).unwrap();
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        let history = LaunchHistory::load_from(&path, "warhammer_3", false).unwrap();
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+
+        assert_eq!(history.entries().len(), 8);
+        assert_eq!(history.entries()[0].outcome(), &LaunchOutcome::Closed);
+    }
+}
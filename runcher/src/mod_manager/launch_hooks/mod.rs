@@ -0,0 +1,83 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the code for user-defined commands executed when the game is launched and when it exits.
+//!
+//! Hooks are executed through the system shell, and their output is written to the log, as there's no UI to show it.
+
+use anyhow::{anyhow, Result};
+
+use std::process::Command;
+#[cfg(target_os = "windows")] use std::os::windows::process::CommandExt;
+
+use rpfm_lib::games::GameInfo;
+use rpfm_lib::integrations::log::{error, info, warn};
+
+use rpfm_ui_common::settings::{setting_path, setting_string};
+
+#[cfg(target_os = "windows")] use crate::mod_manager::integrations::CREATE_NO_WINDOW;
+
+/// Keys of the settings containing the hook commands.
+pub const LAUNCH_HOOK_ON_LAUNCH: &str = "launch_hook_on_launch";
+pub const LAUNCH_HOOK_ON_EXIT: &str = "launch_hook_on_exit";
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// This function runs the hook command stored in the provided setting, if any, logging its output.
+///
+/// The game key and path are passed to the command through the `RUNCHER_GAME` and `RUNCHER_GAME_PATH` environment variables.
+pub fn run(hook: &str, game: &GameInfo) {
+    let command = setting_string(hook);
+    if command.trim().is_empty() {
+        return;
+    }
+
+    match run_command(&command, game) {
+        Ok((status, stdout, stderr)) => {
+            info!("Hook {hook} (\"{command}\") finished with status {status}.");
+
+            if !stdout.trim().is_empty() {
+                info!("Hook {hook} stdout: {}", stdout.trim());
+            }
+
+            if !stderr.trim().is_empty() {
+                warn!("Hook {hook} stderr: {}", stderr.trim());
+            }
+        }
+        Err(error) => error!("Hook {hook} (\"{command}\") failed to run: {error}"),
+    }
+}
+
+fn run_command(command_str: &str, game: &GameInfo) -> Result<(String, String, String)> {
+    let mut command = if cfg!(target_os = "windows") {
+        let mut command = Command::new("cmd");
+        command.arg("/C");
+        command
+    } else {
+        let mut command = Command::new("sh");
+        command.arg("-c");
+        command
+    };
+
+    command.arg(command_str);
+    command.env("RUNCHER_GAME", game.key());
+    command.env("RUNCHER_GAME_PATH", setting_path(game.key()));
+
+    #[cfg(target_os = "windows")] command.creation_flags(CREATE_NO_WINDOW);
+
+    let output = command.output().map_err(|error| anyhow!("Error when executing the hook: {}", error))?;
+    Ok((
+        output.status.to_string(),
+        String::from_utf8_lossy(&output.stdout).to_string(),
+        String::from_utf8_lossy(&output.stderr).to_string(),
+    ))
+}
@@ -0,0 +1,349 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the code for exporting the launch command of a game to a script, so it can be launched without the launcher.
+//!
+//! Scripts start the game executable directly from the game folder, instead of through workshopper like the launcher does.
+//! They use the wrapper command and environment variables from the settings, and the arguments of the last launch preparation.
+//! As they depend on the mod list file and generated packs of that preparation, they need to be exported again if the load
+//! order or the launch options change.
+//!
+//! Batch scripts are meant for Windows. Shell scripts are meant for other systems, where the game needs to be run through
+//! a wrapper like Wine or Proton, as the games are Windows executables.
+
+use anyhow::Result;
+use itertools::Itertools;
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// Shells we can export the launch command for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LaunchScriptKind {
+    Batch,
+    Shell,
+}
+
+/// Launch command of a game, with everything needed to run it outside the launcher.
+#[derive(Clone, Debug, Default)]
+pub struct LaunchScript {
+
+    // Folder the game is launched from.
+    working_dir: PathBuf,
+
+    // Wrapper command and its arguments, if any.
+    wrapper: Vec<String>,
+
+    // Game executable.
+    executable: PathBuf,
+
+    // Arguments passed to the executable.
+    args: Vec<String>,
+
+    // Environment variables passed to the game.
+    env: Vec<(String, String)>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl LaunchScriptKind {
+
+    /// This function returns the kind of script to generate for the provided path, by its extension.
+    ///
+    /// Paths without a known extension get the script of the current OS.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().map(|extension| extension.to_string_lossy().to_lowercase()).as_deref() {
+            Some("bat") | Some("cmd") => Self::Batch,
+            Some("sh") => Self::Shell,
+            _ => Self::default(),
+        }
+    }
+
+    pub fn extension(&self) -> &str {
+        match self {
+            Self::Batch => "bat",
+            Self::Shell => "sh",
+        }
+    }
+}
+
+impl Default for LaunchScriptKind {
+    fn default() -> Self {
+        if cfg!(target_os = "windows") {
+            Self::Batch
+        } else {
+            Self::Shell
+        }
+    }
+}
+
+impl LaunchScript {
+
+    /// This function creates the launch command of a game. Environment variables with invalid names or values are skipped.
+    pub fn new(working_dir: &Path, wrapper: &[String], executable: &Path, args: &[String], env: &[(String, String)]) -> Self {
+        Self {
+            working_dir: working_dir.to_path_buf(),
+            wrapper: wrapper.to_vec(),
+            executable: executable.to_path_buf(),
+            args: args.to_vec(),
+            env: env.iter()
+                .filter(|(key, value)| is_valid_env_var_name(key) && is_valid_env_var_value(value))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// This function returns the contents of the script for the provided shell.
+    pub fn to_script(&self, kind: LaunchScriptKind) -> String {
+        match kind {
+
+            // Batch files need CRLF line endings, and every % doubled, even inside quotes.
+            LaunchScriptKind::Batch => {
+                let mut lines = vec![
+                    "@echo off".to_owned(),
+                    "rem Launch script generated by Runcher.".to_owned(),
+                    "setlocal".to_owned(),
+                ];
+
+                for (key, value) in &self.env {
+                    lines.push(format!("set \"{}={}\"", key, value.replace('%', "%%")));
+                }
+
+                lines.push(format!("cd /d {}", quote_batch(&self.working_dir.to_string_lossy())));
+                lines.push(self.command().map(|arg| quote_batch(&arg)).join(" "));
+                lines.push(String::new());
+                lines.join("\r\n")
+            }
+
+            LaunchScriptKind::Shell => {
+                let mut lines = vec![
+                    "#!/bin/sh".to_owned(),
+                    "# Launch script generated by Runcher.".to_owned(),
+                ];
+
+                for (key, value) in &self.env {
+                    lines.push(format!("export {}={}", key, quote_shell(value)));
+                }
+
+                lines.push(format!("cd {} || exit 1", quote_shell(&self.working_dir.to_string_lossy())));
+                lines.push(format!("exec {}", self.command().map(|arg| quote_shell(&arg)).join(" ")));
+                lines.push(String::new());
+                lines.join("\n")
+            }
+        }
+    }
+
+    /// This function returns the launch command as a single line for the provided shell, to paste it in a terminal.
+    ///
+    /// It changes to the game folder and sets the environment variables before running the game, like the scripts do.
+    pub fn to_command(&self, kind: LaunchScriptKind) -> String {
+        match kind {
+            LaunchScriptKind::Batch => {
+                let mut parts = vec![format!("cd /d {}", quote_command_arg(&self.working_dir.to_string_lossy()))];
+                for (key, value) in &self.env {
+                    parts.push(format!("set \"{}={}\"", key, value));
+                }
+
+                parts.push(self.command().map(|arg| quote_command_arg(&arg)).join(" "));
+                parts.join(" && ")
+            }
+
+            LaunchScriptKind::Shell => {
+                let env = self.env.iter().map(|(key, value)| format!("{}={} ", key, quote_shell(value))).join("");
+                let env = if env.is_empty() { env } else { format!("env {env}") };
+                format!("cd {} && {}{}", quote_shell(&self.working_dir.to_string_lossy()), env, self.command().map(|arg| quote_shell(&arg)).join(" "))
+            }
+        }
+    }
+
+    /// This function saves the script for the provided shell to the provided path, making it executable on systems that need it.
+    pub fn save(&self, path: &Path, kind: LaunchScriptKind) -> Result<()> {
+        let mut file = BufWriter::new(File::create(path)?);
+        file.write_all(self.to_script(kind).as_bytes())?;
+        file.flush()?;
+
+        #[cfg(unix)] {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))?;
+        }
+
+        Ok(())
+    }
+
+    /// This function returns the full command, with the wrapper, the executable and its arguments, unquoted.
+    fn command(&self) -> impl Iterator<Item = String> + '_ {
+        self.wrapper.iter()
+            .cloned()
+            .chain([self.executable.to_string_lossy().to_string()])
+            .chain(self.args.iter().cloned())
+    }
+}
+
+/// This function checks if the provided string is a valid environment variable name: letters, digits and underscores, not starting with a digit.
+///
+/// Other names either break the scripts (`=`, quotes) or cannot be set portably.
+pub fn is_valid_env_var_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().map_or(false, |character| character.is_ascii_alphabetic() || character == '_') &&
+        chars.all(|character| character.is_ascii_alphanumeric() || character == '_')
+}
+
+/// This function checks if the provided string can be used as the value of an environment variable.
+///
+/// Double quotes would close the quotes around the variable in batch files, letting the rest of the value run as commands,
+/// and line breaks would end the line. Neither can be escaped there, so values containing them are not valid.
+pub fn is_valid_env_var_value(value: &str) -> bool {
+    !value.contains(['"', '\r', '\n'])
+}
+
+/// This function quotes an argument for a command run from a terminal, or through `cmd /C`.
+///
+/// Same as for batch files, but % is left as-is, as it's only doubled in batch files.
+pub fn quote_command_arg(arg: &str) -> String {
+    quote_batch(arg).replace("%%", "%")
+}
+
+/// This function quotes an argument for a POSIX shell.
+///
+/// Arguments with anything but safe characters are single-quoted, as nothing is expanded inside single quotes.
+/// Single quotes themselves are closed, escaped and reopened.
+fn quote_shell(arg: &str) -> String {
+    if !arg.is_empty() && arg.chars().all(|c| c.is_ascii_alphanumeric() || "_-./=:,+@%".contains(c)) {
+        arg.to_owned()
+    } else {
+        format!("'{}'", arg.replace('\'', "'\\''"))
+    }
+}
+
+/// This function quotes an argument for a batch file.
+///
+/// Arguments are double-quoted if they contain spaces or characters cmd treats specially. Double quotes inside them are escaped
+/// with a backslash, which is what the argument parser of the games expects, doubling the backslashes before them. In batch files
+/// a % starts a variable even inside quotes, so those are doubled too.
+fn quote_batch(arg: &str) -> String {
+    let needs_quotes = arg.is_empty() || arg.chars().any(|c| c.is_whitespace() || "\"&|<>^()!,;=".contains(c));
+    if !needs_quotes {
+        return arg.replace('%', "%%");
+    }
+
+    let mut quoted = String::from("\"");
+    let mut backslashes = 0;
+    for c in arg.chars() {
+        match c {
+            '\\' => backslashes += 1,
+            '"' => {
+                quoted.push_str(&"\\".repeat(backslashes * 2 + 1));
+                quoted.push('"');
+                backslashes = 0;
+            }
+            _ => {
+                quoted.push_str(&"\\".repeat(backslashes));
+                quoted.push(c);
+                backslashes = 0;
+            }
+        }
+    }
+
+    // Backslashes at the end would escape the closing quote.
+    quoted.push_str(&"\\".repeat(backslashes * 2));
+    quoted.push('"');
+    quoted.replace('%', "%%")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn launch_script(env: &[(&str, &str)]) -> LaunchScript {
+        let env = env.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect::<Vec<_>>();
+        LaunchScript::new(Path::new("C:\\Games\\Total War WARHAMMER III"), &[], Path::new("C:\\Games\\Total War WARHAMMER III\\Warhammer3.exe"), &["my_mod_list.txt".to_owned()], &env)
+    }
+
+    #[test]
+    fn env_var_names_are_validated() {
+        assert!(is_valid_env_var_name("DXVK_HUD"));
+        assert!(is_valid_env_var_name("_PRIVATE1"));
+        assert!(!is_valid_env_var_name(""));
+        assert!(!is_valid_env_var_name("1VAR"));
+        assert!(!is_valid_env_var_name("MY VAR"));
+        assert!(!is_valid_env_var_name("VAR\"&calc"));
+        assert!(!is_valid_env_var_name("PATH=evil"));
+    }
+
+    #[test]
+    fn env_var_values_are_validated() {
+        assert!(is_valid_env_var_value(""));
+        assert!(is_valid_env_var_value("fps,frametimes & 50%"));
+        assert!(!is_valid_env_var_value("a\" & calc & \""));
+        assert!(!is_valid_env_var_value("a\r\ncalc"));
+        assert!(!is_valid_env_var_value("a\ncalc"));
+    }
+
+    #[test]
+    fn invalid_env_vars_are_not_exported() {
+        let env = [("DXVK_HUD", "fps"), ("BAD\"&calc&\"", "1"), ("INJECTED", "a\" & calc & \""), ("MULTILINE", "a\r\ncalc")];
+        for kind in [LaunchScriptKind::Batch, LaunchScriptKind::Shell] {
+            let script = launch_script(&env).to_script(kind);
+            assert!(script.contains("DXVK_HUD=fps"));
+            assert!(!script.contains("calc"));
+            assert!(!script.contains("INJECTED"));
+            assert!(!script.contains("MULTILINE"));
+
+            let command = launch_script(&env).to_command(kind);
+            assert!(command.contains("DXVK_HUD=fps"));
+            assert!(!command.contains("calc"));
+        }
+    }
+
+    #[test]
+    fn script_quotes_arguments_for_batch() {
+        let script = launch_script(&[("PERCENT", "50%")]).to_script(LaunchScriptKind::Batch);
+        assert!(script.contains("set \"PERCENT=50%%\"\r\n"));
+        assert!(script.contains("cd /d \"C:\\Games\\Total War WARHAMMER III\"\r\n"));
+        assert!(script.contains("\"C:\\Games\\Total War WARHAMMER III\\Warhammer3.exe\" my_mod_list.txt\r\n"));
+
+        let command = launch_script(&[("PERCENT", "50%")]).to_command(LaunchScriptKind::Batch);
+        assert!(command.contains("set \"PERCENT=50%\""));
+    }
+
+    #[test]
+    fn script_quotes_arguments_for_shell() {
+        let script = launch_script(&[("DXVK_HUD", "it's 50%")]).to_script(LaunchScriptKind::Shell);
+        assert!(script.starts_with("#!/bin/sh\n"));
+        assert!(script.contains("export DXVK_HUD='it'\\''s 50%'\n"));
+        assert!(script.contains("cd 'C:\\Games\\Total War WARHAMMER III' || exit 1\n"));
+        assert!(script.contains("exec 'C:\\Games\\Total War WARHAMMER III\\Warhammer3.exe' my_mod_list.txt\n"));
+
+        let command = launch_script(&[("DXVK_HUD", "fps")]).to_command(LaunchScriptKind::Shell);
+        assert!(command.contains("&& env DXVK_HUD=fps "));
+    }
+
+    #[test]
+    fn script_kind_is_picked_by_extension() {
+        assert_eq!(LaunchScriptKind::from_path(Path::new("launch.bat")), LaunchScriptKind::Batch);
+        assert_eq!(LaunchScriptKind::from_path(Path::new("launch.CMD")), LaunchScriptKind::Batch);
+        assert_eq!(LaunchScriptKind::from_path(Path::new("launch.sh")), LaunchScriptKind::Shell);
+        assert_eq!(LaunchScriptKind::from_path(Path::new("launch")), LaunchScriptKind::default());
+    }
+
+    #[test]
+    fn command_args_are_only_quoted_when_needed() {
+        assert_eq!(quote_command_arg("game_startup_mode"), "game_startup_mode");
+        assert_eq!(quote_command_arg("--preset=My Preset"), "\"--preset=My Preset\"");
+        assert_eq!(quote_command_arg("50% speed"), "\"50% speed\"");
+    }
+}
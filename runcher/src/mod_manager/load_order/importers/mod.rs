@@ -0,0 +1,194 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the parsers for load orders exported by other launchers and mod managers.
+//!
+//! Each format has its own parser, which only accepts files following its format. They're tried in order, and the first one that recognizes the file wins.
+//! To support a new format, add a parser for it to [PARSERS], and a sample of the format to the tests.
+
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+
+use crate::mod_manager::mods::ShareableMod;
+
+/// Parsers for the supported formats, in the order they're tried.
+const PARSERS: [fn(&str) -> Option<Vec<ShareableMod>>; 2] = [
+    parse_launcher_mod_data,
+    parse_mod_list,
+];
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// Entry of a mod in the mod data file of the official launcher. Fields we don't use are ignored.
+#[derive(Debug, Deserialize)]
+struct LauncherModData {
+    packfile: String,
+    name: Option<String>,
+    active: Option<bool>,
+    order: Option<i64>,
+    uuid: Option<String>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// This function parses a load order exported by another launcher or mod manager, returning the enabled mods in load order.
+///
+/// Mods are only identified by their pack name. Mapping them to installed mods is done when applying the load order.
+pub fn parse(string: &str) -> Result<Vec<ShareableMod>> {
+    for parser in PARSERS {
+        if let Some(mut mods) = parser(string) {
+            if !mods.is_empty() {
+
+                // Some managers list the same pack more than once. Keep the first one.
+                let mut seen = vec![];
+                mods.retain(|modd| if seen.contains(modd.id()) { false } else { seen.push(modd.id().to_owned()); true });
+                return Ok(mods);
+            }
+        }
+    }
+
+    Err(anyhow!("The file is not in a supported format, or it doesn't contain any mod."))
+}
+
+/// Parser for the mod data file of the official launcher (`moddata.dat`), a json array with an object per mod, like:
+///
+/// ```json
+/// [{ "game": "warhammer3", "packfile": "C:\\mods\\my_mod.pack", "name": "My Mod", "active": true, "order": 1, "uuid": "1234567890" }]
+/// ```
+///
+/// Every entry needs a `packfile` with the path of a pack. Inactive mods are skipped, and `uuid` is only used as workshop id if it's numeric.
+fn parse_launcher_mod_data(string: &str) -> Option<Vec<ShareableMod>> {
+    let mut entries: Vec<LauncherModData> = serde_json::from_str(string).ok()?;
+    if !entries.iter().all(|entry| is_pack_name(&entry.packfile)) {
+        return None;
+    }
+
+    // Only sort if all mods have an explicit order. Otherwise, the order of the file is the load order.
+    if entries.iter().all(|entry| entry.order.is_some()) {
+        entries.sort_by_key(|entry| entry.order.unwrap_or_default());
+    }
+
+    let mods = entries.into_iter()
+        .filter(|entry| entry.active.unwrap_or(true))
+        .map(|entry| {
+            let mut modd = ShareableMod::default();
+            modd.set_id(pack_name_from_path(&entry.packfile));
+            modd.set_name(entry.name.unwrap_or_default());
+            modd.set_steam_id(entry.uuid.filter(|uuid| !uuid.is_empty() && uuid.chars().all(|character| character.is_ascii_digit())));
+            modd
+        })
+        .collect();
+
+    Some(mods)
+}
+
+/// Parser for mod lists like the ones the game reads (`used_mods.txt`, `user.script.txt`), with lines like `mod "my_mod.pack";`,
+/// or plain lists with a pack name per line.
+///
+/// Empty lines, comments (`#` or `//`) and `add_working_directory` lines are skipped. Any other line means the file is not a mod list.
+fn parse_mod_list(string: &str) -> Option<Vec<ShareableMod>> {
+    let mut mods = vec![];
+    for line in string.lines().map(|line| line.trim()) {
+        if line.is_empty() || line.starts_with('#') || line.starts_with("//") || line.starts_with("add_working_directory ") {
+            continue;
+        }
+
+        let pack_name = match line.strip_prefix("mod ") {
+            Some(pack_name) => pack_name.trim().strip_suffix(';')?.trim().strip_prefix('"')?.strip_suffix('"')?,
+            None => line,
+        };
+
+        if !is_pack_name(pack_name) {
+            return None;
+        }
+
+        let mut modd = ShareableMod::default();
+        modd.set_id(pack_name_from_path(pack_name));
+        mods.push(modd);
+    }
+
+    Some(mods)
+}
+
+/// This function checks if the provided string looks like a pack name, or a path to a pack.
+fn is_pack_name(string: &str) -> bool {
+    string.to_lowercase().ends_with(".pack")
+}
+
+/// This function returns the pack name of the provided string, removing the path if it has one.
+fn pack_name_from_path(string: &str) -> String {
+    string.rsplit(['/', '\\']).next().unwrap_or(string).to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAUNCHER_MOD_DATA: &str = r#"[
+        { "game": "warhammer3", "packfile": "C:\\Steam\\steamapps\\workshop\\content\\1142710\\2789857593\\sfo_grimhammer.pack", "name": "SFO Grimhammer", "short": "sfo", "category": "overhaul", "owned": true, "active": true, "order": 2, "uuid": "2789857593" },
+        { "game": "warhammer3", "packfile": "C:\\Steam\\steamapps\\common\\Total War WARHAMMER III\\data\\my_local_mod.pack", "name": "Local Mod", "active": true, "order": 1, "uuid": "my_local_mod" },
+        { "game": "warhammer3", "packfile": "C:\\Steam\\steamapps\\workshop\\content\\1142710\\2800000000\\disabled_mod.pack", "name": "Disabled Mod", "active": false, "order": 3, "uuid": "2800000000" }
+    ]"#;
+
+    const USED_MODS: &str = "\
+add_working_directory \"C:/Steam/steamapps/workshop/content/1142710/2789857593\";
+mod \"sfo_grimhammer.pack\";
+mod \"my_local_mod.pack\";
+";
+
+    const PLAIN_LIST: &str = "\
+# Exported load order
+first_mod.pack
+// Second one
+second_mod.pack
+
+first_mod.pack
+";
+
+    fn ids(mods: &[ShareableMod]) -> Vec<&str> {
+        mods.iter().map(|modd| modd.id().as_str()).collect()
+    }
+
+    #[test]
+    fn launcher_mod_data_is_ordered_without_inactive_mods() {
+        let mods = parse(LAUNCHER_MOD_DATA).unwrap();
+
+        assert_eq!(ids(&mods), vec!["my_local_mod.pack", "sfo_grimhammer.pack"]);
+        assert_eq!(mods[0].steam_id(), &None);
+        assert_eq!(mods[1].steam_id(), &Some("2789857593".to_owned()));
+        assert_eq!(mods[1].name(), "SFO Grimhammer");
+    }
+
+    #[test]
+    fn used_mods_file_is_parsed() {
+        let mods = parse(USED_MODS).unwrap();
+        assert_eq!(ids(&mods), vec!["sfo_grimhammer.pack", "my_local_mod.pack"]);
+    }
+
+    #[test]
+    fn plain_list_skips_comments_and_duplicates() {
+        let mods = parse(PLAIN_LIST).unwrap();
+        assert_eq!(ids(&mods), vec!["first_mod.pack", "second_mod.pack"]);
+    }
+
+    #[test]
+    fn unknown_formats_are_rejected() {
+
+        // Ids are not pack names, so json with them is not guessed into a load order.
+        assert!(parse(r#"[{ "id": "2789857593", "name": "SFO Grimhammer" }]"#).is_err());
+        assert!(parse(r#"{ "mods": [{ "packfile": "sfo_grimhammer.pack" }] }"#).is_err());
+        assert!(parse("sfo_grimhammer.pack\nsome notes about the load order\n").is_err());
+        assert!(parse("").is_err());
+    }
+}
@@ -29,6 +29,8 @@ use super::game_config::GameConfig;
 use crate::mod_manager::SECONDARY_FOLDER_NAME;
 use super::secondary_mods_path;
 
+pub mod importers;
+
 const FILE_NAME_START: &str = "last_load_order_";
 const FILE_NAME_END: &str = ".json";
 
@@ -57,7 +59,10 @@ pub struct LoadOrder {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum ImportedLoadOrderMode {
     Runcher(String),
-    Modlist(String)
+    Modlist(String),
+
+    // Load order exported by another mod manager.
+    External(String),
 }
 
 //-------------------------------------------------------------------------------//
@@ -103,6 +108,11 @@ impl LoadOrder {
         Ok(())
     }
 
+    /// This function returns the ids of all the mods enabled in this load order, including movie packs.
+    pub fn enabled_mods(&self) -> Vec<String> {
+        self.mods.iter().chain(self.movies.iter()).cloned().collect()
+    }
+
     pub fn update(&mut self, game_config: &GameConfig, game: &GameInfo, game_data_path: &Path) {
         self.movies.clear();
 
@@ -262,7 +272,11 @@ impl LoadOrder {
                         folder_path.pop();
 
                         // If it's the secondary folder and we're using it for another pack, or it's in data, add an exclusion for it.
-                        if (secondary_mods_path.is_dir() && folder_path == secondary_mods_path && added_secondary_folder) || path.starts_with(&game_data_path) {
+                        // Same if it's in one of the additional secondary folders and we're using it for another pack.
+                        let folder_path_line = format!("add_working_directory \"{}\";\n", path_to_absolute_string(&folder_path));
+                        if (secondary_mods_path.is_dir() && folder_path == secondary_mods_path && added_secondary_folder) ||
+                            folder_paths_mods.contains(&folder_path_line) ||
+                            path.starts_with(&game_data_path) {
                             pack_string.push_str(&format!("\nexclude_pack_file \"{}\";", &pack_name));
                         }
                     }
@@ -301,6 +315,7 @@ impl LoadOrder {
                     folder_path.pop();
 
                     // If it's the secondary folder, just add it once. If it's the contents folder, add one per mod.
+                    // Additional secondary folders are shared by their mods, so they're also added only once.
                     let folder_path_str = path_to_absolute_string(&folder_path);
                     if secondary_mods_path.is_dir() && folder_path == *secondary_mods_path {
                         if !*added_secondary_folder {
@@ -314,7 +329,10 @@ impl LoadOrder {
                             *added_secondary_folder = true;
                         }
                     } else {
-                        folder_paths.push_str(&format!("add_working_directory \"{}\";\n", folder_path_str));
+                        let folder_path_line = format!("add_working_directory \"{}\";\n", folder_path_str);
+                        if !folder_paths.contains(&folder_path_line) {
+                            folder_paths.push_str(&folder_path_line);
+                        }
                     }
                 }
 
@@ -330,3 +348,69 @@ impl LoadOrder {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+    use crate::mod_manager::mods::Mod;
+    use crate::SUPPORTED_GAMES;
+
+    fn game_config(mods: &[(&str, PFHFileType, bool)]) -> GameConfig {
+        let mut game_config = GameConfig::default();
+        for (id, pack_type, enabled) in mods {
+            let mut modd = Mod::default();
+            modd.set_id(id.to_string());
+            modd.set_pack_type(*pack_type);
+            modd.set_enabled(*enabled);
+            modd.paths_mut().push(PathBuf::from("content").join(id));
+            game_config.mods_mut().insert(id.to_string(), modd);
+        }
+
+        game_config
+    }
+
+    fn enabled_set(game_config: &GameConfig, game: &GameInfo, data_path: &Path) -> BTreeSet<String> {
+        game_config.mods().values()
+            .filter(|modd| modd.enabled(game, data_path))
+            .map(|modd| modd.id().to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn moving_and_sorting_keeps_the_enabled_mods() {
+        let game = SUPPORTED_GAMES.game(KEY_WARHAMMER_3).unwrap();
+        let data_path = Path::new("data");
+        let game_config = game_config(&[
+            ("d.pack", PFHFileType::Mod, true),
+            ("a.pack", PFHFileType::Mod, false),
+            ("c.pack", PFHFileType::Mod, true),
+            ("b.pack", PFHFileType::Mod, true),
+            ("e.pack", PFHFileType::Mod, false),
+            ("movie.pack", PFHFileType::Movie, true),
+        ]);
+
+        let enabled_before = enabled_set(&game_config, game, data_path);
+
+        let mut load_order = LoadOrder::default();
+        load_order.set_automatic(false);
+        load_order.build_manual(&game_config, game, data_path);
+
+        // Move the last mod to the top, like dragging it in the pack list.
+        let moved = load_order.mods_mut().pop().unwrap();
+        load_order.mods_mut().insert(0, moved.clone());
+        load_order.build_manual(&game_config, game, data_path);
+
+        assert_eq!(load_order.mods()[0], moved);
+        assert_eq!(load_order.enabled_mods().into_iter().collect::<BTreeSet<_>>(), enabled_before);
+
+        // Then sort it automatically.
+        load_order.set_automatic(true);
+        load_order.build_automatic(&game_config, game, data_path);
+
+        assert_eq!(load_order.mods(), &vec!["b.pack".to_owned(), "c.pack".to_owned(), "d.pack".to_owned()]);
+        assert_eq!(load_order.movies(), &vec!["movie.pack".to_owned()]);
+        assert_eq!(load_order.enabled_mods().into_iter().collect::<BTreeSet<_>>(), enabled_before);
+        assert_eq!(enabled_set(&game_config, game, data_path), enabled_before);
+    }
+}
@@ -0,0 +1,140 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the summary of the last merged pack generated by the "merge all mods" launch option.
+//!
+//! The summary is saved per game, so it can be checked after the launch without merging the mods again.
+
+use anyhow::Result;
+use getset::*;
+use serde::{Deserialize, Serialize};
+use serde_json::to_string_pretty;
+
+use std::fs::{DirBuilder, File};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rpfm_lib::files::{Container, pack::Pack};
+use rpfm_lib::games::GameInfo;
+
+use rpfm_ui_common::settings::setting_path;
+
+use crate::mod_manager::integrations::game_build;
+use crate::settings_ui::game_config_path;
+
+const FILE_NAME_START: &str = "merge_summary_";
+const FILE_NAME_END: &str = ".json";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct MergeSummary {
+
+    // Key of the game.
+    game_key: String,
+
+    // Date of the merge, as a unix timestamp.
+    date: u64,
+
+    // Path of the merged pack.
+    path: PathBuf,
+
+    // Build of the game the mods were merged for, if known. Summaries from before this was recorded don't have it.
+    #[serde(default)]
+    game_build: Option<String>,
+
+    // Mods merged into the pack, in load order.
+    mods: Vec<String>,
+
+    // Amount of files in the merged pack.
+    files: usize,
+
+    // Size of the merged pack, in bytes.
+    size: u64,
+
+    // Amount of files found in more than one of the merged mods, where the one higher in the load order won.
+    conflicts: usize,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl MergeSummary {
+
+    /// This function generates the summary of a merged pack, already saved to disk.
+    ///
+    /// To count the conflicts, this reads again the index of every merged pack, synchronously, in the calling thread. Their files are
+    /// loaded lazily, so their data is never read, but it still means opening every merged pack a second time.
+    pub fn new(game: &GameInfo, path: &Path, mods: &[String], pack_paths: &[PathBuf], merged_pack: &Pack) -> Result<Self> {
+        let files = merged_pack.files().len();
+        let mut files_before_merge = 0;
+        for pack_path in pack_paths {
+            files_before_merge += Pack::read_and_merge(&[pack_path.to_path_buf()], true, false, false)?.files().len();
+        }
+
+        Ok(Self {
+            game_key: game.key().to_owned(),
+            date: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+            path: path.to_path_buf(),
+            game_build: game_build(game, &setting_path(game.key())),
+            mods: mods.to_vec(),
+            files,
+            size: path.metadata()?.len(),
+            conflicts: files_before_merge.saturating_sub(files),
+        })
+    }
+
+    /// This function loads the summary of the last merge done for the provided game, if any.
+    pub fn load(game: &GameInfo) -> Result<Option<Self>> {
+        let path = Self::summary_path(game)?;
+        if !path.is_file() {
+            return Ok(None);
+        }
+
+        let mut file = BufReader::new(File::open(path)?);
+        let mut data = Vec::with_capacity(file.get_ref().metadata()?.len() as usize);
+        file.read_to_end(&mut data)?;
+
+        let summary: Self = serde_json::from_slice(&data)?;
+        Ok(Some(summary))
+    }
+
+    /// This function returns if the game got updated since this merge, so the merged data may be stale.
+    ///
+    /// If either build is unknown we cannot tell, so it's not considered outdated.
+    pub fn is_outdated(&self, current_build: Option<&str>) -> bool {
+        match (self.game_build.as_deref(), current_build) {
+            (Some(merge_build), Some(current_build)) => merge_build != current_build,
+            _ => false,
+        }
+    }
+
+    pub fn save(&self, game: &GameInfo) -> Result<()> {
+        let path = Self::summary_path(game)?;
+
+        // Make sure the path exists to avoid problems with updating schemas.
+        if let Some(parent_folder) = path.parent() {
+            DirBuilder::new().recursive(true).create(parent_folder)?;
+        }
+
+        let mut file = BufWriter::new(File::create(path)?);
+        file.write_all(to_string_pretty(&self)?.as_bytes())?;
+        Ok(())
+    }
+
+    fn summary_path(game: &GameInfo) -> Result<PathBuf> {
+        Ok(game_config_path()?.join(format!("{FILE_NAME_START}{}{FILE_NAME_END}", game.key())))
+    }
+}
@@ -16,9 +16,10 @@ use anyhow::{anyhow, Result};
 
 use std::fs::{DirBuilder, File};
 use std::io::{Read, Write};
-use std::path::PathBuf;
+use std::path::{Component, Path, PathBuf};
 
 use rpfm_lib::games::GameInfo;
+use rpfm_lib::integrations::log::error;
 use rpfm_lib::utils::{files_from_subdir, path_to_absolute_path, path_to_absolute_string};
 
 use rpfm_ui_common::ASSETS_PATH;
@@ -28,15 +29,40 @@ use crate::SUPPORTED_GAMES;
 
 use self::game_config::GameConfig;
 
+pub mod bisect;
+pub mod config_bundle;
+pub mod custom_launch_options;
+pub mod font_coverage;
 pub mod game_config;
 pub mod integrations;
+pub mod launch_flags;
+pub mod launch_history;
+pub mod launch_hooks;
+pub mod launch_script;
 pub mod load_order;
+pub mod merge_summary;
+pub mod mod_advisories;
+pub mod mod_updates;
 pub mod mods;
+pub mod profile_backups;
 pub mod profiles;
 pub mod saves;
+pub mod script_bundles;
 
 pub const SECONDARY_FOLDER_NAME: &str = "masks";
 
+/// Key of the setting with the additional secondary mods folders, one per line.
+pub const SECONDARY_MODS_EXTRA_PATHS: &str = "secondary_mods_extra_paths";
+
+/// Name of the folder, within the config folder of the game, where the game leaves its crash dumps.
+pub const CRASH_DUMPS_FOLDER_NAME: &str = "crash_dumps";
+
+/// Folders we can load mods from. Each one can be toggled per game.
+pub const MOD_SOURCE_DATA: &str = "data";
+pub const MOD_SOURCE_SECONDARY: &str = "secondary";
+pub const MOD_SOURCE_CONTENT: &str = "content";
+pub const MOD_SOURCES: [&str; 3] = [MOD_SOURCE_DATA, MOD_SOURCE_SECONDARY, MOD_SOURCE_CONTENT];
+
 pub fn copy_to_secondary(game: &GameInfo, game_config: &GameConfig, mod_ids: &[String]) -> Result<Vec<String>> {
     let mut mods_failed = vec![];
 
@@ -155,19 +181,158 @@ pub fn move_to_secondary(game: &GameInfo, game_config: &GameConfig, mod_ids: &[S
     Ok(mods_failed)
 }
 
-pub fn secondary_mods_path(game: &str) -> Result<PathBuf> {
-    match SUPPORTED_GAMES.game(game) {
-        Some(game_info) => if game_info.raw_db_version() < &1 {
-            return Err(anyhow!("This game ({}) doesn't support secondary mod folders.", game))
+pub fn move_to_data(game: &GameInfo, game_config: &GameConfig, mod_ids: &[String]) -> Result<Vec<String>> {
+    let mut mods_failed = vec![];
+
+    let game_path = setting_path(game.key());
+    let secondary_paths = secondary_mods_paths(game.key());
+    if secondary_paths.is_empty() {
+        return Err(anyhow!("Secondary Mods Path not set."))
+    }
+
+    let data_path = game.data_path(&game_path)?;
+    let secondary_paths_str = secondary_paths.iter()
+        .map(|path| path_to_absolute_string(path))
+        .collect::<Vec<_>>();
+
+    for mod_id in mod_ids {
+        if let Some(modd) = game_config.mods().get(mod_id) {
+            let decannon_paths = modd.paths()
+                .iter()
+                .map(|path| path_to_absolute_string(path))
+                .collect::<Vec<_>>();
+
+            // If the first path is one of the /secondary folders, proceed. If not, either we have nothing to move, or the mod is already in /data.
+            if !decannon_paths.is_empty() && secondary_paths_str.iter().any(|path| decannon_paths[0].starts_with(path)) {
+                let new_path = data_path.join(modd.paths()[0].file_name().unwrap());
+
+                let mut old_image_path = PathBuf::from(&decannon_paths[0]);
+                old_image_path.set_extension("png");
+
+                let mut new_image_path = new_path.clone();
+                new_image_path.set_extension("png");
+
+                // Not all mods have an image, so do not stop the move if there is none.
+                let has_image = old_image_path.is_file();
+
+                // Do not overwrite packs or images already in /data, as they may belong to another mod.
+                if new_path.exists() || (has_image && new_image_path.exists()) || std::fs::copy(&modd.paths()[0], &new_path).is_err() {
+                    mods_failed.push(modd.id().to_string());
+                }
+
+                // Move the png too, and delete the originals if it worked. If it didn't, remove the copied pack so the mod stays where it was.
+                else if has_image && std::fs::copy(&old_image_path, &new_image_path).is_err() {
+                    let _ = std::fs::remove_file(&new_path);
+                    mods_failed.push(modd.id().to_string());
+                } else {
+                    let _ = std::fs::remove_file(&modd.paths()[0]);
+                    let _ = std::fs::remove_file(&old_image_path);
+                }
+            }
+
+            // Any other case is not supported.
+            else {
+                mods_failed.push(modd.id().to_string());
+            }
         }
-        None => return Err(anyhow!("What kind of game is {}?", game)),
     }
 
+    Ok(mods_failed)
+}
+
+/// This function removes the copy in the content folder of the provided mods, so Steam considers them missing and downloads them again.
+///
+/// Only the folder of the workshop item is removed, never anything outside it. Returns the mods that couldn't be removed.
+pub fn remove_from_content(game: &GameInfo, game_config: &GameConfig, mod_ids: &[String]) -> Result<Vec<String>> {
+    let mut mods_failed = vec![];
+
+    let game_path = setting_path(game.key());
+    let content_path = path_to_absolute_path(&game.content_path(&game_path)?, true);
+
+    for mod_id in mod_ids {
+        if let Some(modd) = game_config.mods().get(mod_id) {
+            // Workshop ids are numeric. Anything else, specially an empty id, could point the removal at the entire content folder.
+            let steam_id = match modd.steam_id() {
+                Some(steam_id) if !steam_id.is_empty() && steam_id.chars().all(|character| character.is_ascii_digit()) => steam_id,
+                _ => {
+                    mods_failed.push(modd.id().to_string());
+                    continue;
+                }
+            };
+
+            // Workshop items live in a folder named after their id, directly within the content folder.
+            let item_path = content_path.join(steam_id);
+            if item_path == content_path || item_path.parent() != Some(content_path.as_path()) {
+                mods_failed.push(modd.id().to_string());
+                continue;
+            }
+
+            let in_content = modd.paths()
+                .iter()
+                .any(|path| path_to_absolute_path(path, true).starts_with(&item_path));
+
+            if !in_content || !item_path.is_dir() || std::fs::remove_dir_all(&item_path).is_err() {
+                mods_failed.push(modd.id().to_string());
+            }
+        }
+    }
+
+    Ok(mods_failed)
+}
+
+/// This function returns the key of the setting that controls if we load mods from the provided source.
+pub fn mod_source_setting_key(game: &str, source: &str) -> String {
+    format!("load_mods_from_{source}_{game}")
+}
+
+/// This function returns if we should load mods from the provided source for the provided game.
+pub fn load_mods_from(game: &str, source: &str) -> bool {
+    setting_bool(&mod_source_setting_key(game, source))
+}
+
+pub fn secondary_mods_path(game: &str) -> Result<PathBuf> {
     let base_path_str = setting_string("secondary_mods_path");
     if base_path_str.is_empty() {
         return Err(anyhow!("Secondary Mods Path not set."))
     }
 
+    secondary_mods_game_path(game, &base_path_str)
+}
+
+/// This function returns the additional secondary mods folders of the provided game, in the order they're set in the settings.
+///
+/// Folders that cannot be used are skipped.
+pub fn secondary_mods_extra_paths(game: &str) -> Vec<PathBuf> {
+    setting_string(SECONDARY_MODS_EXTRA_PATHS)
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .filter_map(|base_path_str| secondary_mods_game_path(game, base_path_str).ok())
+        .collect()
+}
+
+/// This function returns all the secondary mods folders of the provided game, by precedence.
+///
+/// The main one goes first, then the additional ones in order. If a pack is in more than one of them, the one in the first folder is used.
+pub fn secondary_mods_paths(game: &str) -> Vec<PathBuf> {
+    let mut paths = vec![];
+    for path in secondary_mods_path(game).into_iter().chain(secondary_mods_extra_paths(game)) {
+        if !paths.contains(&path) {
+            paths.push(path);
+        }
+    }
+
+    paths
+}
+
+fn secondary_mods_game_path(game: &str, base_path_str: &str) -> Result<PathBuf> {
+    match SUPPORTED_GAMES.game(game) {
+        Some(game_info) => if game_info.raw_db_version() < &1 {
+            return Err(anyhow!("This game ({}) doesn't support secondary mod folders.", game))
+        }
+        None => return Err(anyhow!("What kind of game is {}?", game)),
+    }
+
     // Canonicalization is required due to some issues with the game not loading not properly formatted paths.
     let path = std::fs::canonicalize(PathBuf::from(base_path_str))?;
     let game_path = path.join(game);
@@ -183,18 +348,49 @@ pub fn secondary_mods_path(game: &str) -> Result<PathBuf> {
     Ok(game_path)
 }
 
+/// This function returns the folder where the game leaves its crash dumps.
+pub fn crash_dumps_path(game: &GameInfo) -> Result<PathBuf> {
+    let game_path = setting_path(game.key());
+    let config_path = game.config_path(&game_path).ok_or(anyhow!("Error getting the game's config path."))?;
+    Ok(config_path.join(CRASH_DUMPS_FOLDER_NAME))
+}
+
+/// This function returns if the provided path is relative and made only of normal parts, so joining it to a folder cannot escape it.
+///
+/// Absolute paths, drive prefixes (like `C:`), roots, `.` and `..` are all rejected, as is an empty path.
+pub fn is_safe_relative_path(path: &Path) -> bool {
+    path.components().next().is_some() && path.components().all(|component| matches!(component, Component::Normal(_)))
+}
+
+/// This function returns the packs in the secondary mods folders of the provided game, by folder precedence.
 pub fn secondary_mods_packs_paths(game: &str) -> Option<Vec<PathBuf>> {
-    let path = secondary_mods_path(game).ok()?;
+    let folders = secondary_mods_paths(game);
+    if folders.is_empty() {
+        return None;
+    }
+
     let mut paths = vec![];
+    for folder in &folders {
+        // A folder that cannot be read (like an extra folder in a disconnected drive) shouldn't hide the packs of the other ones.
+        let files = match files_from_subdir(folder, false) {
+            Ok(files) => files,
+            Err(error) => {
+                error!("Error when reading the secondary mods folder {}: {}", folder.to_string_lossy(), error);
+                continue;
+            }
+        };
 
-    for path in files_from_subdir(&path, false).ok()?.iter() {
-        match path.extension() {
-            Some(extension) => if extension == "pack" || extension == "bin" { paths.push(path.to_path_buf()); }
-            None => continue,
+        let mut folder_paths = vec![];
+        for path in files.iter() {
+            match path.extension() {
+                Some(extension) => if extension == "pack" || extension == "bin" { folder_paths.push(path.to_path_buf()); }
+                None => continue,
+            }
         }
-    }
 
-    paths.sort();
+        folder_paths.sort();
+        paths.append(&mut folder_paths);
+    }
 
     Some(paths)
 }
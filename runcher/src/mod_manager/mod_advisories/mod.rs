@@ -0,0 +1,204 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the code for the advisories about mods known to be outdated, incompatible or broken.
+//!
+//! Advisories come from a source configured by the user: either a git repository with a `mod_advisories.json` file in its root,
+//! which is cloned to the config folder and updated once a day, or a local json file the user maintains. There's no source by default.
+//! Advisories are only informative. They flag the mods in the mod list, but they never disable or block anything.
+
+use anyhow::Result;
+use getset::*;
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+use std::fs::{DirBuilder, File};
+use std::io::{BufReader, Read};
+use std::path::PathBuf;
+
+use rpfm_lib::games::GameInfo;
+use rpfm_lib::integrations::git::GitIntegration;
+
+use rpfm_ui_common::settings::*;
+
+use crate::mod_manager::mods::Mod;
+
+/// Key of the setting with the source of the advisories: a git repository url, optionally followed by `#branch`, or the path of a local file.
+pub const MOD_ADVISORIES_SOURCE: &str = "mod_advisories_source";
+
+/// Key of the setting with the last time the advisories of a remote source were updated, as a unix timestamp.
+pub const MOD_ADVISORIES_LAST_UPDATE: &str = "mod_advisories_last_update";
+
+/// Seconds between updates of the advisories of a remote source.
+pub const MOD_ADVISORIES_UPDATE_INTERVAL: u64 = 60 * 60 * 24;
+
+const FILE_NAME: &str = "mod_advisories.json";
+const DEFAULT_BRANCH: &str = "master";
+const REMOTE: &str = "origin";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct ModAdvisories {
+
+    // Advisories, by game key.
+    #[serde(default)]
+    games: HashMap<String, Vec<ModAdvisory>>,
+}
+
+#[derive(Clone, Debug, Default, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct ModAdvisory {
+
+    // Pack name of the mod. At least one of this or the workshop id must be set.
+    #[serde(default)]
+    id: Option<String>,
+
+    // Workshop id of the mod.
+    #[serde(default)]
+    steam_id: Option<String>,
+
+    // What's wrong with the mod.
+    status: ModAdvisoryStatus,
+
+    // Explanation of the problem.
+    #[serde(default)]
+    reason: String,
+
+    // Link to more details about the problem.
+    #[serde(default)]
+    url: Option<String>,
+
+    // Builds of the game the advisory applies to. Empty to apply to all of them.
+    #[serde(default)]
+    game_builds: Vec<String>,
+
+    // Only versions of the mod last updated before this unix timestamp are affected, so the advisory stops applying once the mod gets fixed.
+    #[serde(default)]
+    updated_before: Option<u64>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModAdvisoryStatus {
+    #[default]
+    Outdated,
+    Incompatible,
+    Broken,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl ModAdvisories {
+
+    /// This function loads the advisories from the configured source.
+    ///
+    /// Remote sources are read from their last downloaded copy. If there's no source or it hasn't been downloaded yet, there are no advisories.
+    pub fn load() -> Result<Self> {
+        let source = setting_string(MOD_ADVISORIES_SOURCE);
+        let source = source.trim();
+        if source.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let path = if Self::is_remote(source) {
+            Self::cache_path(source)?.join(FILE_NAME)
+        } else {
+            PathBuf::from(source)
+        };
+
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+
+        let mut file = BufReader::new(File::open(path)?);
+        let mut data = Vec::with_capacity(file.get_ref().metadata()?.len() as usize);
+        file.read_to_end(&mut data)?;
+
+        let advisories: Self = serde_json::from_slice(&data)?;
+        Ok(advisories)
+    }
+
+    /// This function downloads the latest advisories of the provided remote source, or updates the local copy of them.
+    ///
+    /// This does network stuff, so call it from the network thread.
+    pub fn update(source: &str) -> Result<()> {
+        let (url, branch) = source.trim().split_once('#').unwrap_or((source.trim(), DEFAULT_BRANCH));
+        let local_path = Self::cache_path(source)?;
+        DirBuilder::new().recursive(true).create(&local_path)?;
+
+        let git_integration = GitIntegration::new(&local_path, url, branch, REMOTE);
+        git_integration.update_repo()?;
+        Ok(())
+    }
+
+    /// This function returns if the provided source needs to be downloaded, instead of being read from disk.
+    pub fn is_remote(source: &str) -> bool {
+        let source = source.trim();
+        source.starts_with("https://") || source.starts_with("http://") || source.starts_with("git@") || source.split('#').next().map_or(false, |url| url.ends_with(".git"))
+    }
+
+    /// This function returns the advisory that applies to the provided mod in the provided build of the game, if any.
+    pub fn advisory(&self, game: &GameInfo, modd: &Mod, game_build: Option<&str>) -> Option<&ModAdvisory> {
+        self.games.get(game.key())?
+            .iter()
+            .find(|advisory| advisory.applies_to(modd, game_build))
+    }
+
+    /// This function returns the folder where the provided remote source is downloaded to.
+    ///
+    /// Each source gets its own folder, so changing the source doesn't mix the repositories.
+    fn cache_path(source: &str) -> Result<PathBuf> {
+        Ok(config_path()?.join("mod_advisories").join(sha256::digest(source.trim())))
+    }
+}
+
+impl ModAdvisory {
+
+    /// This function checks if this advisory applies to the provided mod in the provided build of the game.
+    ///
+    /// If we don't know the build of the game, advisories for specific builds are considered to apply.
+    pub fn applies_to(&self, modd: &Mod, game_build: Option<&str>) -> bool {
+        let id_matches = self.id.as_deref().map_or(false, |id| id == modd.id());
+        let steam_id_matches = self.steam_id.is_some() && self.steam_id == *modd.steam_id();
+        if !id_matches && !steam_id_matches {
+            return false;
+        }
+
+        if let Some(game_build) = game_build {
+            if !self.game_builds.is_empty() && !self.game_builds.iter().any(|build| build == game_build) {
+                return false;
+            }
+        }
+
+        // Mods without an update date are local mods, which we cannot tell apart from the affected version.
+        match self.updated_before {
+            Some(updated_before) => *modd.time_updated() == 0 || (*modd.time_updated() as u64) < updated_before,
+            None => true,
+        }
+    }
+}
+
+impl ModAdvisoryStatus {
+
+    /// This function returns the locale key of the name of the status.
+    pub fn locale_key(&self) -> &str {
+        match self {
+            Self::Outdated => "mod_advisory_status_outdated",
+            Self::Incompatible => "mod_advisory_status_incompatible",
+            Self::Broken => "mod_advisory_status_broken",
+        }
+    }
+}
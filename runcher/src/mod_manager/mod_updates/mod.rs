@@ -0,0 +1,147 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the code for keeping track of the mods that got updated after a download.
+//!
+//! The workshop api doesn't expose the change notes of each update, so we only keep track of what got updated and when,
+//! and point to the change notes page of each mod in the workshop.
+
+use anyhow::Result;
+use getset::*;
+use serde::{Deserialize, Serialize};
+use serde_json::to_string_pretty;
+
+use std::collections::HashMap;
+use std::fs::{DirBuilder, File};
+use std::io::{BufReader, BufWriter, Read, Write};
+
+use rpfm_lib::games::GameInfo;
+
+use crate::mod_manager::mods::Mod;
+use crate::settings_ui::*;
+
+const FILE_NAME_START: &str = "mod_updates_";
+const FILE_NAME_END: &str = ".json";
+
+/// Max amount of updates we keep per game. Older updates are discarded once we reach this.
+const MAX_UPDATES: usize = 500;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct ModUpdates {
+
+    // Key of the game.
+    game_key: String,
+
+    // Updates detected for the game, from newest to oldest.
+    updates: Vec<ModUpdate>,
+}
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct ModUpdate {
+
+    // Workshop id of the mod.
+    steam_id: String,
+
+    // Name of the mod at the time of the update.
+    name: String,
+
+    // Last update date of the mod before and after the update, as unix timestamps.
+    time_updated_old: u64,
+    time_updated_new: u64,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl ModUpdates {
+
+    pub fn load(game: &GameInfo, new_if_missing: bool) -> Result<Self> {
+        let path = game_config_path()?.join(format!("{FILE_NAME_START}{}{FILE_NAME_END}", game.key()));
+        if !path.is_file() && new_if_missing {
+            return Ok(Self {
+                game_key: game.key().to_string(),
+                ..Default::default()
+            });
+        }
+
+        let mut file = BufReader::new(File::open(path)?);
+        let mut data = Vec::with_capacity(file.get_ref().metadata()?.len() as usize);
+        file.read_to_end(&mut data)?;
+
+        let updates: Self = serde_json::from_slice(&data)?;
+        Ok(updates)
+    }
+
+    pub fn save(&mut self, game: &GameInfo) -> Result<()> {
+        let path = game_config_path()?.join(format!("{FILE_NAME_START}{}{FILE_NAME_END}", game.key()));
+
+        // Make sure the path exists to avoid problems with updating schemas.
+        if let Some(parent_folder) = path.parent() {
+            DirBuilder::new().recursive(true).create(parent_folder)?;
+        }
+
+        let mut file = BufWriter::new(File::create(path)?);
+        file.write_all(to_string_pretty(&self)?.as_bytes())?;
+        Ok(())
+    }
+
+    /// This function returns the last update date of each workshop mod in the provided list, by workshop id.
+    pub fn times_updated(mods: &HashMap<String, Mod>) -> HashMap<String, u64> {
+        mods.values()
+            .filter_map(|modd| Some((modd.steam_id().clone()?, *modd.time_updated() as u64)))
+            .collect()
+    }
+
+    /// This function compares the provided mods against their previous update dates, and adds the ones that got updated to the list.
+    ///
+    /// Returns the updates we found.
+    pub fn add_updates(&mut self, mods: &HashMap<String, Mod>, times_updated_old: &HashMap<String, u64>) -> Vec<ModUpdate> {
+        let mut updates = mods.values()
+            .filter_map(|modd| {
+                let steam_id = modd.steam_id().clone()?;
+                let time_updated_old = *times_updated_old.get(&steam_id)?;
+                let time_updated_new = *modd.time_updated() as u64;
+
+                if time_updated_new > time_updated_old {
+                    Some(ModUpdate {
+                        name: if modd.name().is_empty() { modd.id().to_owned() } else { modd.name().to_owned() },
+                        steam_id,
+                        time_updated_old,
+                        time_updated_new,
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        updates.sort_by(|a, b| b.time_updated_new.cmp(&a.time_updated_new));
+
+        self.updates.splice(0..0, updates.iter().cloned());
+        self.updates.truncate(MAX_UPDATES);
+
+        updates
+    }
+}
+
+impl ModUpdate {
+
+    /// This function returns the url of the change notes of the mod in the workshop.
+    pub fn change_notes_url(&self) -> String {
+        format!("https://steamcommunity.com/sharedfiles/filedetails/changelog/{}", self.steam_id)
+    }
+}
@@ -15,6 +15,7 @@ use sha256::try_digest;
 
 use std::path::{Path, PathBuf};
 
+use rpfm_lib::files::pack::Pack;
 use rpfm_lib::games::{GameInfo, pfh_file_type::PFHFileType, supported_games::{KEY_ATTILA, KEY_ROME_2, KEY_THRONES_OF_BRITANNIA}};
 use rpfm_lib::utils::{path_to_absolute_path, path_to_absolute_string};
 
@@ -72,6 +73,16 @@ pub struct Mod {
 
     /// Time the mod was last updated on the workshop.
     time_updated: usize,
+
+    /// Pack names of the mods this mod requires, as declared in its pack.
+    #[serde(default)]
+    dependencies: Vec<String>,
+
+    /// Error found when reading the pack of the mod in the last scan, if any. Mods with errors are treated as disabled.
+    ///
+    /// It's not saved, as it's checked again on every scan.
+    #[serde(skip)]
+    load_error: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
@@ -102,12 +113,19 @@ impl From<&Mod> for ShareableMod {
 
 impl Mod {
 
+    /// Returns the pack names of the mods the provided pack requires.
+    pub fn pack_dependencies(pack: &Pack) -> Vec<String> {
+        pack.dependencies().iter()
+            .map(|(_, dependency)| dependency.to_owned())
+            .collect()
+    }
+
     /// Returns if the mod is outdated or not. Requires the date of the last update of the game.
     pub fn outdated(&self, game_last_update_date: u64) -> bool {
         game_last_update_date > *self.time_updated() as u64
     }
 
-    pub fn location(&self, data_path: &str, secondary_path: &str, content_path: &str) -> (bool, bool, Option<String>) {
+    pub fn location(&self, data_path: &str, secondary_paths: &[String], content_path: &str) -> (bool, bool, Option<String>) {
 
         // Shortcut for mods with no paths.
         if self.paths().is_empty() {
@@ -122,7 +140,7 @@ impl Mod {
             let path = path_to_absolute_string(path);
             if path.starts_with(data_path) {
                 data = true;
-            } else if !secondary_path.is_empty() && path.starts_with(secondary_path) {
+            } else if Self::in_secondary_path(&path, secondary_paths) {
                 secondary = true;
             } else if !content_path.is_empty() && path.starts_with(content_path) {
                 content = self.steam_id.clone();
@@ -132,62 +150,55 @@ impl Mod {
         (data, secondary, content)
     }
 
-    pub fn priority_dating_flags(&self, data_path: &str, secondary_path: &str, content_path: &str) -> Result<(bool, bool, bool)> {
+    pub fn priority_dating_flags(&self, data_path: &str, secondary_paths: &[String], content_path: &str) -> Result<(bool, bool, bool)> {
 
         // Shortcut for mods only in one place.
         if self.paths().len() == 1 {
             return Ok((false, false, false));
         }
 
-        let mut data_older_than_secondary = false;
-        let mut data_older_than_content = false;
-        let mut secondary_older_than_content = false;
-
         let paths = self.paths()
             .iter()
             .map(|x| path_to_absolute_string(x))
             .collect::<Vec<_>>();
 
-        if paths.len() == 2 {
-            let date_0 = PathBuf::from(&paths[0]).metadata()?.modified()?;
-            let date_1 = PathBuf::from(&paths[1]).metadata()?.modified()?;
-
-            if date_1 > date_0 {
-                if paths[0].starts_with(data_path) {
-                    if !secondary_path.is_empty() && paths[1].starts_with(secondary_path) {
-                        data_older_than_secondary = true;
-                    } else if !content_path.is_empty() && paths[1].starts_with(content_path) {
-                        data_older_than_content = true;
-                    }
-                } else if !secondary_path.is_empty() && paths[0].starts_with(secondary_path) {
-                    secondary_older_than_content = true;
-                }
-            }
-        }
+        // Paths are sorted by priority, so the first one of each location is the one used. If a mod is in more
+        // than one secondary folder, only the one in the folder with the highest precedence is compared.
+        let date_data = match paths.iter().find(|path| path.starts_with(data_path)) {
+            Some(path) => Some(PathBuf::from(path).metadata()?.modified()?),
+            None => None,
+        };
 
-        if paths.len() == 3 {
-            let date_0 = PathBuf::from(&paths[0]).metadata()?.modified()?;
-            let date_1 = PathBuf::from(&paths[1]).metadata()?.modified()?;
-            let date_2 = PathBuf::from(&paths[2]).metadata()?.modified()?;
+        let date_secondary = match paths.iter().find(|path| !path.starts_with(data_path) && Self::in_secondary_path(path, secondary_paths)) {
+            Some(path) => Some(PathBuf::from(path).metadata()?.modified()?),
+            None => None,
+        };
 
-            if date_1 > date_0 {
-                data_older_than_secondary = true;
-            }
+        let date_content = match paths.iter().find(|path| !content_path.is_empty() && path.starts_with(content_path)) {
+            Some(path) => Some(PathBuf::from(path).metadata()?.modified()?),
+            None => None,
+        };
 
-            if date_2 > date_0 {
-                data_older_than_content = true;
-            }
-
-            if date_2 > date_1 {
-                secondary_older_than_content = true;
-            }
-        }
+        let data_older_than_secondary = matches!((date_data, date_secondary), (Some(data), Some(secondary)) if secondary > data);
+        let data_older_than_content = matches!((date_data, date_content), (Some(data), Some(content)) if content > data);
+        let secondary_older_than_content = matches!((date_secondary, date_content), (Some(secondary), Some(content)) if content > secondary);
 
         Ok((data_older_than_secondary, data_older_than_content, secondary_older_than_content))
     }
 
+    fn in_secondary_path(path: &str, secondary_paths: &[String]) -> bool {
+        secondary_paths.iter().any(|secondary_path| !secondary_path.is_empty() && path.starts_with(secondary_path))
+    }
+
     /// Returns if the mod is enabled or not.
+    ///
+    /// Mods whose pack failed to load are never enabled, so they don't end up in the load order. Their enabled state is kept
+    /// for when the pack loads again.
     pub fn enabled(&self, game: &GameInfo, data_path: &Path) -> bool {
+        if self.load_error.is_some() {
+            return false;
+        }
+
         let data_path = path_to_absolute_path(data_path, false);
 
         // For mod packs we just return it.
@@ -0,0 +1,269 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the code for the automatic backups of the profiles and the launch settings.
+//!
+//! Backups are zip files with the profile files of all games in a `profiles` folder, and the launch settings of all games
+//! in a json file, as those live in the settings instead of in their own files. Settings keep their type, so lists and flags are
+//! restored as they were. Only the latest backups are kept.
+
+use anyhow::{anyhow, Result};
+use serde_json::to_string_pretty;
+use zip::{CompressionMethod, ZipArchive, ZipWriter, write::SimpleFileOptions};
+
+use std::collections::BTreeMap;
+use std::fs::{DirBuilder, File};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rpfm_lib::games::supported_games::KEY_ARENA;
+use rpfm_lib::utils::files_from_subdir;
+
+use rpfm_ui_common::settings::*;
+
+use crate::mod_manager::integrations::{LAST_LAUNCH_EXIT_CODE, LAST_LAUNCH_START_DATE};
+use crate::mod_manager::launch_hooks::{LAUNCH_HOOK_ON_EXIT, LAUNCH_HOOK_ON_LAUNCH};
+use crate::settings_ui::{migrated_setting_key, profile_backups_path, profiles_path, SettingValue};
+use crate::SUPPORTED_GAMES;
+
+/// Keys of the settings with the hours between backups (0 disables them), the amount of backups to keep, and the date of the last backup.
+pub const PROFILE_BACKUPS_INTERVAL: &str = "profile_backups_interval";
+pub const PROFILE_BACKUPS_RETENTION: &str = "profile_backups_retention";
+pub const PROFILE_BACKUPS_LAST_DATE: &str = "profile_backups_last_date";
+
+pub const PROFILE_BACKUPS_INTERVAL_DEFAULT: i32 = 24;
+pub const PROFILE_BACKUPS_INTERVAL_MAX: i32 = 720;
+pub const PROFILE_BACKUPS_RETENTION_DEFAULT: i32 = 10;
+pub const PROFILE_BACKUPS_RETENTION_MAX: i32 = 100;
+
+const FILE_NAME_START: &str = "profiles_backup_";
+const FILE_NAME_END: &str = ".zip";
+
+const PROFILES_FOLDER: &str = "profiles";
+const LAUNCH_SETTINGS_FILE_NAME: &str = "launch_settings.json";
+
+/// Launch settings not tied to a game. The ones tied to a game are found by the game key in them.
+const GLOBAL_LAUNCH_SETTINGS: [&str; 7] = [
+    "launch_wrapper_command",
+    "launch_environment_variables",
+    "launch_extra_args",
+    LAUNCH_HOOK_ON_LAUNCH,
+    LAUNCH_HOOK_ON_EXIT,
+    "launch_options_order",
+    "hidden_launch_options",
+];
+
+/// Prefixes of game settings that record what happened on the last launch. They're not configuration, so they're not backed up.
+const EXCLUDED_SETTINGS_PREFIXES: [&str; 2] = [
+    LAST_LAUNCH_EXIT_CODE,
+    LAST_LAUNCH_START_DATE,
+];
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// This function creates a backup if backups are enabled and the configured interval has passed since the last one.
+///
+/// Returns the path of the new backup, if one was made.
+pub unsafe fn backup_if_due() -> Result<Option<PathBuf>> {
+    let interval = setting_int(PROFILE_BACKUPS_INTERVAL);
+    if interval <= 0 {
+        return Ok(None);
+    }
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let last_date = setting_string(PROFILE_BACKUPS_LAST_DATE).parse::<u64>().unwrap_or_default();
+    if now.saturating_sub(last_date) < interval as u64 * 3600 {
+        return Ok(None);
+    }
+
+    backup().map(Some)
+}
+
+/// This function creates a backup of the profiles and launch settings of all games, and removes the backups over the retention limit.
+///
+/// Returns the path of the new backup.
+pub unsafe fn backup() -> Result<PathBuf> {
+    let folder = profile_backups_path()?;
+    DirBuilder::new().recursive(true).create(&folder)?;
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let path = folder.join(format!("{FILE_NAME_START}{now}{FILE_NAME_END}"));
+
+    let mut zip = ZipWriter::new(BufWriter::new(File::create(&path)?));
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    for profile_path in files_from_subdir(&profiles_path()?, false)? {
+        if let Some(file_name) = profile_path.file_name() {
+            zip.start_file(format!("{PROFILES_FOLDER}/{}", file_name.to_string_lossy()), options)?;
+            std::io::copy(&mut BufReader::new(File::open(&profile_path)?), &mut zip)?;
+        }
+    }
+
+    zip.start_file(LAUNCH_SETTINGS_FILE_NAME, options)?;
+    zip.write_all(to_string_pretty(&launch_settings())?.as_bytes())?;
+    zip.finish()?;
+
+    set_setting_string(PROFILE_BACKUPS_LAST_DATE, &now.to_string());
+    remove_old_backups()?;
+
+    Ok(path)
+}
+
+/// This function returns the paths of the existing backups, from newest to oldest.
+pub fn backups() -> Result<Vec<PathBuf>> {
+    let folder = profile_backups_path()?;
+    if !folder.is_dir() {
+        return Ok(vec![]);
+    }
+
+    let mut backups = files_from_subdir(&folder, false)?
+        .into_iter()
+        .filter_map(|path| Some((backup_date(&path)?, path)))
+        .collect::<Vec<_>>();
+
+    backups.sort_by(|(a, _), (b, _)| b.cmp(a));
+    Ok(backups.into_iter().map(|(_, path)| path).collect())
+}
+
+/// This function returns the date a backup was made, as a unix timestamp, from its file name.
+pub fn backup_date(path: &Path) -> Option<u64> {
+    path.file_name()?
+        .to_string_lossy()
+        .strip_prefix(FILE_NAME_START)?
+        .strip_suffix(FILE_NAME_END)?
+        .parse()
+        .ok()
+}
+
+/// This function replaces the current profiles and launch settings with the ones in the provided backup.
+///
+/// A backup of the current state is made first, so restoring a backup can be undone by restoring that one.
+pub unsafe fn restore(path: &Path) -> Result<()> {
+
+    // Read the backup before making the new one, as the new one may rotate this one out.
+    let mut zip = ZipArchive::new(BufReader::new(File::open(path)?))?;
+    let mut profiles = vec![];
+    let mut launch_settings = None;
+
+    for index in 0..zip.len() {
+        let mut file = zip.by_index(index)?;
+        let name = file.name().to_owned();
+
+        let mut data = vec![];
+        file.read_to_end(&mut data)?;
+
+        if name == LAUNCH_SETTINGS_FILE_NAME {
+            launch_settings = Some(parse_launch_settings(&data)?);
+        } else if let Some(file_name) = name.strip_prefix(&format!("{PROFILES_FOLDER}/")) {
+
+            // Do not allow files to escape the profiles folder.
+            if file_name.is_empty() || file_name.contains(['/', '\\']) || file_name.contains("..") {
+                return Err(anyhow!("Invalid file in the backup: {}.", name));
+            }
+
+            profiles.push((file_name.to_owned(), data));
+        }
+    }
+
+    let launch_settings = launch_settings.ok_or_else(|| anyhow!("The backup is missing its launch settings. Is it a profile backup?"))?;
+
+    backup()?;
+
+    let profiles_path = profiles_path()?;
+    for profile_path in files_from_subdir(&profiles_path, false)? {
+        std::fs::remove_file(profile_path)?;
+    }
+
+    for (file_name, data) in &profiles {
+        let mut file = BufWriter::new(File::create(profiles_path.join(file_name))?);
+        file.write_all(data)?;
+    }
+
+    // Launch settings not in the backup are left as they are, as they're either new or were never set.
+    let q_settings = settings();
+    for (key, value) in &launch_settings {
+        value.to_q_setting(&q_settings, &migrated_setting_key(key));
+    }
+
+    q_settings.sync();
+    Ok(())
+}
+
+/// This function returns the launch settings of all games, by setting key.
+unsafe fn launch_settings() -> BTreeMap<String, SettingValue> {
+    let q_settings = settings();
+    let all_keys = q_settings.all_keys();
+    let game_keys = SUPPORTED_GAMES.games_sorted()
+        .iter()
+        .filter(|game| game.key() != KEY_ARENA)
+        .map(|game| (format!("_{}", game.key()), format!("_{}_", game.key())))
+        .collect::<Vec<_>>();
+
+    (0..all_keys.size())
+        .map(|index| all_keys.at(index).to_std_string())
+        .filter(|key| GLOBAL_LAUNCH_SETTINGS.contains(&key.as_str()) || game_keys.iter().any(|(suffix, infix)| key.ends_with(suffix) || key.contains(infix)))
+        .filter(|key| !EXCLUDED_SETTINGS_PREFIXES.iter().any(|prefix| key.starts_with(prefix)))
+        .map(|key| {
+            let value = SettingValue::from_q_setting(&q_settings, &key);
+            (key, value)
+        })
+        .collect()
+}
+
+/// This function parses the launch settings of a backup.
+///
+/// Older backups stored every setting as text, so those are read as text settings.
+fn parse_launch_settings(data: &[u8]) -> Result<BTreeMap<String, SettingValue>> {
+    match serde_json::from_slice::<BTreeMap<String, SettingValue>>(data) {
+        Ok(launch_settings) => Ok(launch_settings),
+        Err(_) => Ok(serde_json::from_slice::<BTreeMap<String, String>>(data)?
+            .into_iter()
+            .map(|(key, value)| (key, SettingValue::String(value)))
+            .collect()),
+    }
+}
+
+/// This function removes the oldest backups, so only the amount set in the retention setting is kept.
+fn remove_old_backups() -> Result<()> {
+    let retention = setting_int(PROFILE_BACKUPS_RETENTION).max(1) as usize;
+    for path in backups()?.iter().skip(retention) {
+        std::fs::remove_file(path)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn launch_settings_keep_their_type() {
+        let mut launch_settings = BTreeMap::new();
+        launch_settings.insert("hidden_launch_options".to_owned(), SettingValue::StringList(vec!["skip_intro".to_owned(), "no_sound".to_owned()]));
+        launch_settings.insert("enable_logging_warhammer_3".to_owned(), SettingValue::Bool(true));
+        launch_settings.insert("launch_extra_args".to_owned(), SettingValue::String("-windowed".to_owned()));
+
+        let data = to_string_pretty(&launch_settings).unwrap();
+        assert_eq!(parse_launch_settings(data.as_bytes()).unwrap(), launch_settings);
+    }
+
+    #[test]
+    fn old_launch_settings_are_read_as_text() {
+        let data = r#"{ "launch_extra_args": "-windowed", "enable_logging_warhammer_3": "true" }"#;
+        let launch_settings = parse_launch_settings(data.as_bytes()).unwrap();
+
+        assert_eq!(launch_settings.get("launch_extra_args"), Some(&SettingValue::String("-windowed".to_owned())));
+        assert_eq!(launch_settings.get("enable_logging_warhammer_3"), Some(&SettingValue::String("true".to_owned())));
+    }
+}
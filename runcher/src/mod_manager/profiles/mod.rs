@@ -12,12 +12,14 @@
 
 use anyhow::Result;
 use getset::*;
+use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use serde_json::to_string_pretty;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs::{DirBuilder, File};
 use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
 
 use rpfm_lib::games::GameInfo;
 use rpfm_lib::utils::*;
@@ -32,6 +34,8 @@ mod versions;
 const FILE_NAME_START: &str = "profile_";
 const FILE_NAME_END: &str = ".json";
 
+const SCRIPT_SETTINGS_PREFIX: &str = "script_to_execute_";
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -48,6 +52,42 @@ pub struct Profile {
 
     // Load order of this profile.
     load_order: LoadOrder,
+
+    // Last time this profile was loaded, as a unix timestamp. 0 if it has never been loaded.
+    #[serde(default)]
+    last_used: u64,
+
+    // Script settings of this profile (enabled scripts, presets and params), by setting key.
+    // Empty for profiles made before scripts were stored in them. Those leave the current scripts as they are.
+    #[serde(default)]
+    scripts: BTreeMap<String, String>,
+
+    // If the profile is protected against being overwritten, renamed or deleted.
+    #[serde(default)]
+    locked: bool,
+
+    // Extra arguments to pass to the game when launching with this profile. None to use the global ones.
+    #[serde(default)]
+    launch_args: Option<String>,
+
+    // Tags to group this profile with others in the profile manager.
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+/// Differences between the load order of a profile and another load order.
+#[derive(Clone, Debug, Default, Getters)]
+#[getset(get = "pub")]
+pub struct ProfileDiff {
+
+    // Mods enabled in the other load order, but not in the profile.
+    added: Vec<String>,
+
+    // Mods enabled in the profile, but not in the other load order.
+    removed: Vec<String>,
+
+    // If the mods both load orders have in common are in a different order.
+    reordered: bool,
 }
 
 //-------------------------------------------------------------------------------//
@@ -104,6 +144,28 @@ impl Profile {
         Ok(())
     }
 
+    /// This function exports the profile to the provided path, in the same format we use to store it.
+    pub fn export(&self, path: &Path) -> Result<()> {
+        let mut file = BufWriter::new(File::create(path)?);
+        file.write_all(to_string_pretty(&self)?.as_bytes())?;
+        Ok(())
+    }
+
+    /// This function returns the name of the profile to load when selecting the provided game, if any.
+    pub fn default_for_game(game: &GameInfo) -> Option<String> {
+        let name = setting_string(&format!("default_profile_{}", game.key()));
+        if name.is_empty() {
+            None
+        } else {
+            Some(name)
+        }
+    }
+
+    /// This function sets the profile to load when selecting the provided game. Pass None to not load any profile.
+    pub fn set_default_for_game(game: &GameInfo, name: Option<&str>) {
+        set_setting_string(&format!("default_profile_{}", game.key()), name.unwrap_or_default());
+    }
+
     #[allow(dead_code)]
     pub fn update(game_config: &GameConfig, game_info: &GameInfo) -> Result<()> {
         let _ = versions::v0::ProfileV0::update(game_config, game_info);
@@ -111,6 +173,83 @@ impl Profile {
         Ok(())
     }
 
+    /// This function returns the differences between the load order of this profile and the provided one.
+    pub fn diff(&self, load_order: &LoadOrder) -> ProfileDiff {
+        let added = load_order.mods().iter()
+            .filter(|mod_id| !self.load_order.mods().contains(mod_id))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let removed = self.load_order.mods().iter()
+            .filter(|mod_id| !load_order.mods().contains(mod_id))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let common_profile = self.load_order.mods().iter().filter(|mod_id| load_order.mods().contains(mod_id));
+        let common_current = load_order.mods().iter().filter(|mod_id| self.load_order.mods().contains(mod_id));
+        let reordered = !common_profile.eq(common_current) || self.load_order.automatic() != load_order.automatic();
+
+        ProfileDiff {
+            added,
+            removed,
+            reordered,
+        }
+    }
+
+    /// This function sets the tags of this profile from a comma-separated list, skipping empty and repeated ones.
+    pub fn set_tags_from_list(&mut self, tags: &str) {
+        self.tags = tags.split(',')
+            .map(|tag| tag.trim().to_owned())
+            .filter(|tag| !tag.is_empty())
+            .unique()
+            .collect();
+    }
+
+    /// This function replaces the script settings of this profile with the ones from the provided profile.
+    pub fn copy_scripts_from(&mut self, other: &Profile) {
+        self.scripts = other.scripts.clone();
+    }
+
+    /// This function returns the keys of all the settings of a script: if it's enabled, its preset, and each of its params.
+    ///
+    /// Keys are built from the script's params instead of by prefix, as a script key can be the start of another one's.
+    pub fn script_setting_keys(game_key: &str, script_key: &str, param_keys: &[&str]) -> Vec<String> {
+        let base = format!("{SCRIPT_SETTINGS_PREFIX}{game_key}_{script_key}");
+        let mut keys = vec![base.to_owned(), format!("{base}_preset")];
+        keys.extend(param_keys.iter().map(|param_key| format!("{base}_{param_key}")));
+        keys
+    }
+
+    /// This function returns the settings of a script in this profile that copying the provided ones into it would change,
+    /// as key, current value and new value. A new value of None means the setting would be removed.
+    ///
+    /// `keys` are all the setting keys of the script, as returned by [Profile::script_setting_keys].
+    pub fn script_settings_overwritten(&self, keys: &[String], script_settings: &BTreeMap<String, String>) -> Vec<(String, String, Option<String>)> {
+        keys.iter()
+            .filter_map(|key| {
+                let current = self.scripts.get(key)?;
+                let new = script_settings.get(key);
+                if new == Some(current) {
+                    None
+                } else {
+                    Some((key.to_owned(), current.to_owned(), new.cloned()))
+                }
+            })
+            .collect()
+    }
+
+    /// This function replaces the settings of a script in this profile with the provided ones, leaving the rest of the scripts as they are.
+    ///
+    /// `keys` are all the setting keys of the script. The ones not in the provided settings are removed from the profile.
+    pub fn copy_script_settings(&mut self, keys: &[String], script_settings: &BTreeMap<String, String>) {
+        for key in keys {
+            match script_settings.get(key) {
+                Some(value) => self.scripts.insert(key.to_owned(), value.to_owned()),
+                None => self.scripts.remove(key),
+            };
+        }
+    }
+
     pub fn delete(&self, game: &GameInfo) -> Result<()> {
         let path = profiles_path()?.join(format!("{FILE_NAME_START}{}_{}{FILE_NAME_END}", game.key(), self.id()));
         if path.is_file() {
@@ -120,3 +259,65 @@ impl Profile {
         Ok(())
     }
 }
+
+impl ProfileDiff {
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && !self.reordered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(values: &[(&str, &str)]) -> BTreeMap<String, String> {
+        values.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+    }
+
+    #[test]
+    fn script_setting_keys_do_not_match_scripts_with_longer_keys() {
+        let keys = Profile::script_setting_keys("warhammer_3", "unit", &["size"]);
+        assert_eq!(keys, vec![
+            "script_to_execute_warhammer_3_unit".to_owned(),
+            "script_to_execute_warhammer_3_unit_preset".to_owned(),
+            "script_to_execute_warhammer_3_unit_size".to_owned(),
+        ]);
+
+        assert!(!keys.contains(&"script_to_execute_warhammer_3_unit_caps".to_owned()));
+    }
+
+    #[test]
+    fn copy_script_settings_only_touches_that_script() {
+        let keys = Profile::script_setting_keys("warhammer_3", "unit", &["size", "speed"]);
+        let mut profile = Profile::default();
+        profile.set_scripts(settings(&[
+            ("script_to_execute_warhammer_3_unit", "true"),
+            ("script_to_execute_warhammer_3_unit_size", "2"),
+            ("script_to_execute_warhammer_3_unit_speed", "1"),
+            ("script_to_execute_warhammer_3_unit_caps", "true"),
+            ("script_to_execute_warhammer_3_unit_caps_limit", "20"),
+        ]));
+
+        let source = settings(&[
+            ("script_to_execute_warhammer_3_unit", "true"),
+            ("script_to_execute_warhammer_3_unit_size", "4"),
+            ("script_to_execute_warhammer_3_unit_preset", "Big"),
+        ]);
+
+        let overwritten = profile.script_settings_overwritten(&keys, &source);
+        assert_eq!(overwritten, vec![
+            ("script_to_execute_warhammer_3_unit_size".to_owned(), "2".to_owned(), Some("4".to_owned())),
+            ("script_to_execute_warhammer_3_unit_speed".to_owned(), "1".to_owned(), None),
+        ]);
+
+        profile.copy_script_settings(&keys, &source);
+        assert_eq!(profile.scripts(), &settings(&[
+            ("script_to_execute_warhammer_3_unit", "true"),
+            ("script_to_execute_warhammer_3_unit_preset", "Big"),
+            ("script_to_execute_warhammer_3_unit_size", "4"),
+            ("script_to_execute_warhammer_3_unit_caps", "true"),
+            ("script_to_execute_warhammer_3_unit_caps_limit", "20"),
+        ]));
+    }
+}
@@ -8,10 +8,17 @@
 // https://github.com/Frodo45127/runcher/blob/master/LICENSE.
 //---------------------------------------------------------------------------//
 
+use anyhow::{anyhow, Result};
 use getset::*;
 use serde::{Deserialize, Serialize};
 
+use std::fs::DirBuilder;
 use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rpfm_lib::games::GameInfo;
+
+use crate::settings_ui::save_backups_path;
 
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
@@ -28,3 +35,33 @@ pub struct Save {
 //-------------------------------------------------------------------------------//
 //                             Implementations
 //-------------------------------------------------------------------------------//
+
+/// This function copies the provided saves of the provided game to a new backup folder, named after the current date.
+///
+/// Returns the path of the backup folder.
+pub fn backup(game: &GameInfo, saves: &[Save]) -> Result<PathBuf> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let folder = save_backups_path()?.join(game.key()).join(now.to_string());
+    DirBuilder::new().recursive(true).create(&folder)?;
+
+    for save in saves {
+        std::fs::copy(save.path(), folder.join(save.name()))?;
+    }
+
+    Ok(folder)
+}
+
+/// This function deletes the provided saves from disk.
+///
+/// Saves that fail to be deleted don't stop the rest from being deleted. They're reported together in the error.
+pub fn delete(saves: &[Save]) -> Result<()> {
+    let failed = saves.iter()
+        .filter_map(|save| std::fs::remove_file(save.path()).err().map(|error| format!("{}: {}", save.name(), error)))
+        .collect::<Vec<_>>();
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow!("The following saves could not be deleted:\n{}", failed.join("\n")))
+    }
+}
@@ -0,0 +1,323 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the code for sharing scripts along with their presets.
+//!
+//! Script bundles are zip files with the scripts in a `scripts` folder, their presets in a `presets` folder, and a manifest
+//! with the game they're for. Imported scripts and presets are installed as local ones, so they take priority over the downloaded ones.
+
+use anyhow::{anyhow, Result};
+use getset::*;
+use serde::{Deserialize, Serialize};
+use serde_json::to_string_pretty;
+use zip::{CompressionMethod, ZipArchive, ZipWriter, write::SimpleFileOptions};
+
+use std::fs::{DirBuilder, File};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+use rpfm_lib::games::GameInfo;
+
+use crate::games::{preset_paths, sql_script_paths};
+use crate::settings_ui::{sql_scripts_local_path, sql_scripts_local_presets_path};
+
+const MANIFEST_FILE_NAME: &str = "manifest.json";
+const SCRIPTS_FOLDER: &str = "scripts";
+const PRESETS_FOLDER: &str = "presets";
+
+/// Version of the bundle format. Bump it if the format changes in a way older versions cannot read.
+const BUNDLE_VERSION: u32 = 1;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, Getters)]
+#[getset(get = "pub")]
+pub struct ScriptBundle {
+
+    // Key of the game the scripts are for.
+    game_key: String,
+
+    // Scripts of the bundle, as (key, contents of the script file).
+    scripts: Vec<(String, Vec<u8>)>,
+
+    // Presets of the bundle, as (key, contents of the preset file).
+    presets: Vec<(String, Vec<u8>)>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct Manifest {
+    version: u32,
+    game_key: String,
+}
+
+/// Subset of a script file we need to identify it.
+#[derive(Deserialize)]
+struct ScriptKeys {
+    metadata: ScriptKeysMetadata,
+}
+
+#[derive(Deserialize)]
+struct ScriptKeysMetadata {
+    key: String,
+}
+
+/// Subset of a preset file we need to identify it.
+#[derive(Deserialize)]
+struct PresetKeys {
+    key: String,
+    script_key: String,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl ScriptBundle {
+
+    /// This function creates a bundle with the scripts of the provided game with the provided keys, and all their presets.
+    pub fn new(game: &GameInfo, script_keys: &[String]) -> Result<Self> {
+        let mut bundle = Self {
+            game_key: game.key().to_owned(),
+            ..Default::default()
+        };
+
+        for (key, path) in Self::installed_scripts(game)? {
+            if script_keys.contains(&key) && !bundle.scripts.iter().any(|(bundled_key, _)| *bundled_key == key) {
+                bundle.scripts.push((key, std::fs::read(path)?));
+            }
+        }
+
+        for path in preset_paths()? {
+            if let Ok(data) = std::fs::read(&path) {
+                if let Ok((key, script_key)) = Self::preset_keys(&data) {
+                    if script_keys.contains(&script_key) && !bundle.presets.iter().any(|(bundled_key, _)| *bundled_key == key) {
+                        bundle.presets.push((key, data));
+                    }
+                }
+            }
+        }
+
+        if bundle.scripts.is_empty() {
+            return Err(anyhow!("None of the scripts to export could be found."));
+        }
+
+        Ok(bundle)
+    }
+
+    /// This function reads a bundle from the provided path, validating the scripts and presets in it.
+    pub fn load(path: &Path) -> Result<Self> {
+        let mut zip = ZipArchive::new(BufReader::new(File::open(path)?))?;
+        let manifest: Manifest = match zip.by_name(MANIFEST_FILE_NAME) {
+            Ok(file) => serde_json::from_reader(file)?,
+            Err(_) => return Err(anyhow!("The file {} is not a script bundle.", path.to_string_lossy())),
+        };
+
+        if manifest.version > BUNDLE_VERSION {
+            return Err(anyhow!("This script bundle was made with a newer version of Runcher. Update Runcher to import it."));
+        }
+
+        let mut bundle = Self {
+            game_key: manifest.game_key,
+            ..Default::default()
+        };
+
+        for index in 0..zip.len() {
+            let mut file = zip.by_index(index)?;
+            if !file.is_file() {
+                continue;
+            }
+
+            let name = file.name().to_owned();
+            let mut data = vec![];
+            file.read_to_end(&mut data)?;
+
+            // We don't trust the file names in the bundle. Files are identified by the keys inside them.
+            if name.starts_with(&format!("{SCRIPTS_FOLDER}/")) {
+                let key = Self::script_key(&data).map_err(|error| anyhow!("Invalid script {}: {}", name, error))?;
+                if bundle.scripts.iter().any(|(bundled_key, _)| *bundled_key == key) {
+                    return Err(anyhow!("The script {} is more than once in the bundle.", key));
+                }
+
+                bundle.scripts.push((key, data));
+            } else if name.starts_with(&format!("{PRESETS_FOLDER}/")) {
+                let (key, _) = Self::preset_keys(&data).map_err(|error| anyhow!("Invalid preset {}: {}", name, error))?;
+                if bundle.presets.iter().any(|(bundled_key, _)| *bundled_key == key) {
+                    return Err(anyhow!("The preset {} is more than once in the bundle.", key));
+                }
+
+                bundle.presets.push((key, data));
+            }
+        }
+
+        if bundle.scripts.is_empty() {
+            return Err(anyhow!("The script bundle has no scripts."));
+        }
+
+        Ok(bundle)
+    }
+
+    /// This function saves the bundle to the provided path.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut zip = ZipWriter::new(BufWriter::new(File::create(path)?));
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+        let manifest = Manifest {
+            version: BUNDLE_VERSION,
+            game_key: self.game_key.to_owned(),
+        };
+
+        zip.start_file(MANIFEST_FILE_NAME, options)?;
+        zip.write_all(to_string_pretty(&manifest)?.as_bytes())?;
+
+        for (key, data) in &self.scripts {
+            zip.start_file(format!("{SCRIPTS_FOLDER}/{key}.yml"), options)?;
+            zip.write_all(data)?;
+        }
+
+        for (key, data) in &self.presets {
+            zip.start_file(format!("{PRESETS_FOLDER}/{key}.yml"), options)?;
+            zip.write_all(data)?;
+        }
+
+        zip.finish()?;
+        Ok(())
+    }
+
+    /// This function returns the keys of the scripts and presets of the bundle that are already installed for the provided game.
+    pub fn collisions(&self, game: &GameInfo) -> Result<(Vec<String>, Vec<String>)> {
+        let installed_scripts = Self::installed_scripts(game)?;
+        let installed_presets = Self::installed_presets()?;
+
+        let scripts = self.scripts.iter()
+            .filter(|(key, _)| installed_scripts.iter().any(|(installed_key, _)| installed_key == key))
+            .map(|(key, _)| key.to_owned())
+            .collect();
+
+        let presets = self.presets.iter()
+            .filter(|(key, _)| installed_presets.iter().any(|(installed_key, _)| installed_key == key))
+            .map(|(key, _)| key.to_owned())
+            .collect();
+
+        Ok((scripts, presets))
+    }
+
+    /// This function installs the scripts and presets of the bundle as local ones, overwriting any local one with the same key.
+    pub fn install(&self, game: &GameInfo) -> Result<()> {
+        if self.game_key != game.key() {
+            return Err(anyhow!("This script bundle is for {}, not for {}.", self.game_key, game.key()));
+        }
+
+        // Scripts from other sources are only replaced by local scripts with the same file name, so reuse their file names.
+        let scripts_path = sql_scripts_local_path()?.join(game.key());
+        let installed_scripts = Self::installed_scripts(game)?;
+        DirBuilder::new().recursive(true).create(&scripts_path)?;
+
+        for (key, data) in &self.scripts {
+            let path = Self::install_path(&scripts_path, key, &installed_scripts);
+            std::fs::write(path, data)?;
+        }
+
+        let presets_path = sql_scripts_local_presets_path()?;
+        let installed_presets = Self::installed_presets()?;
+        DirBuilder::new().recursive(true).create(&presets_path)?;
+
+        for (key, data) in &self.presets {
+            let path = Self::install_path(&presets_path, key, &installed_presets);
+            std::fs::write(path, data)?;
+        }
+
+        Ok(())
+    }
+
+    /// This function returns the path in the provided folder where a file with the provided key needs to be installed.
+    fn install_path(folder: &Path, key: &str, installed: &[(String, PathBuf)]) -> PathBuf {
+        installed.iter()
+            .find(|(installed_key, _)| installed_key == key)
+            .and_then(|(_, path)| path.file_name())
+            .map(|file_name| folder.join(file_name))
+            .unwrap_or_else(|| folder.join(format!("{key}.yml")))
+    }
+
+    /// This function returns the scripts available for the provided game, as (key, path).
+    fn installed_scripts(game: &GameInfo) -> Result<Vec<(String, PathBuf)>> {
+        Ok(sql_script_paths(game)?
+            .into_iter()
+            .filter(|path| path.extension().map_or(false, |extension| extension == "yml"))
+            .filter_map(|path| Some((Self::script_key(&std::fs::read(&path).ok()?).ok()?, path)))
+            .collect())
+    }
+
+    /// This function returns the presets available, as (key, path).
+    fn installed_presets() -> Result<Vec<(String, PathBuf)>> {
+        Ok(preset_paths()?
+            .into_iter()
+            .filter_map(|path| Some((Self::preset_keys(&std::fs::read(&path).ok()?).ok()?.0, path)))
+            .collect())
+    }
+
+    fn script_key(data: &[u8]) -> Result<String> {
+        let script: ScriptKeys = serde_yml::from_slice(data)?;
+        Self::validate_key(script.metadata.key)
+    }
+
+    fn preset_keys(data: &[u8]) -> Result<(String, String)> {
+        let preset: PresetKeys = serde_yml::from_slice(data)?;
+        Ok((Self::validate_key(preset.key)?, Self::validate_key(preset.script_key)?))
+    }
+
+    /// Keys are used as file names when installing, so only allow characters that are safe for them.
+    fn validate_key(key: String) -> Result<String> {
+        if !key.is_empty() && key.chars().all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '-') {
+            Ok(key)
+        } else {
+            Err(anyhow!("Invalid key: \"{}\".", key))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::sql_script_paths_in;
+
+    #[test]
+    fn installed_script_replaces_the_extracted_one() {
+        let root = std::env::temp_dir().join(format!("runcher_script_bundles_test_{}", std::process::id()));
+        let local = root.join("local/warhammer_3");
+        let extracted = root.join("extracted");
+        let remote = root.join("remote/warhammer_3");
+        for folder in [&local, &extracted, &remote] {
+            DirBuilder::new().recursive(true).create(folder).unwrap();
+        }
+
+        // The file name of the extracted script doesn't match its key, so the installed one has to reuse it to replace it.
+        let extracted_path = extracted.join("unit_tweaks.yml");
+        std::fs::write(&extracted_path, "metadata:\n  key: tweaks\n").unwrap();
+        std::fs::write(remote.join("unit_tweaks.yml"), "metadata:\n  key: tweaks\n").unwrap();
+
+        let installed = vec![("tweaks".to_owned(), extracted_path.clone())];
+        let install_path = ScriptBundle::install_path(&local, "tweaks", &installed);
+        std::fs::write(&install_path, "metadata:\n  key: tweaks\n").unwrap();
+
+        let paths = sql_script_paths_in(&local, &extracted, &remote);
+        let _ = std::fs::remove_dir_all(&root);
+
+        assert_eq!(install_path, local.join("unit_tweaks.yml"));
+        assert_eq!(paths.unwrap(), vec![install_path]);
+    }
+
+    #[test]
+    fn new_script_is_installed_with_its_key() {
+        let local = Path::new("local");
+        assert_eq!(ScriptBundle::install_path(local, "tweaks", &[]), local.join("tweaks.yml"));
+    }
+}
@@ -21,6 +21,7 @@ use rpfm_ui_common::settings::error_path;
 use crate::{sql_scripts_remote_path, CENTRAL_COMMAND, SQL_SCRIPTS_BRANCH, SQL_SCRIPTS_REMOTE, SQL_SCRIPTS_REPO};
 use crate::communications::*;
 use crate::mod_manager::integrations::request_mods_data;
+use crate::mod_manager::mod_advisories::ModAdvisories;
 use crate::settings_ui::schemas_path;
 use crate::{REPO_NAME, REPO_OWNER};
 
@@ -91,6 +92,13 @@ pub fn network_loop() {
                 }
             }
 
+            Command::UpdateModAdvisories(source) => {
+                match ModAdvisories::update(&source) {
+                    Ok(_) => CentralCommand::send_back(&sender, Response::Success),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+            }
+
             // If you hit this, you fucked it up somewhere else.
             _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
         }
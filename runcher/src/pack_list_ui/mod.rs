@@ -47,7 +47,7 @@ use rpfm_ui_common::utils::*;
 
 use crate::ffi::*;
 use crate::mod_list_ui::VALUE_MOD_ID;
-use crate::mod_manager::{game_config::GameConfig, load_order::LoadOrder, secondary_mods_path};
+use crate::mod_manager::{game_config::GameConfig, load_order::LoadOrder, secondary_mods_paths};
 
 use self::slots::PackListUISlots;
 
@@ -134,7 +134,7 @@ impl PackListUI {
     pub unsafe fn load(&self, game_config: &GameConfig, game_info: &GameInfo, game_path: &Path, load_order: &LoadOrder) -> Result<()> {
         self.model().clear();
 
-        let secondary_mods_path = secondary_mods_path(game_config.game_key()).unwrap_or_else(|_| PathBuf::new());
+        let secondary_mods_paths = secondary_mods_paths(game_config.game_key());
 
         if !game_path.to_string_lossy().is_empty() {
             if let Ok(game_data_folder) = game_info.data_path(game_path) {
@@ -171,7 +171,7 @@ impl PackListUI {
                             location.set_text(&QString::from_std_str(
                                 if modd.paths()[0].starts_with(&game_data_folder) {
                                     "Data".to_string()
-                                } else if secondary_mods_path.is_dir() && modd.paths()[0].starts_with(&secondary_mods_path) {
+                                } else if secondary_mods_paths.iter().any(|path| modd.paths()[0].starts_with(path)) {
                                     if let Some(ref id) = modd.steam_id() {
                                         format!("Secondary ({})", id)
                                     } else {
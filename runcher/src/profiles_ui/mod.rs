@@ -9,6 +9,7 @@
 //---------------------------------------------------------------------------//
 
 #[cfg(target_os = "windows")] use qt_widgets::QCheckBox;
+use qt_widgets::QComboBox;
 use qt_widgets::QDialog;
 use qt_widgets::QDialogButtonBox;
 use qt_widgets::q_dialog_button_box::StandardButton;
@@ -17,21 +18,28 @@ use qt_widgets::QGroupBox;
 use qt_widgets::QLabel;
 use qt_widgets::QLineEdit;
 use qt_widgets::QListView;
-use qt_widgets::QToolButton;
+use qt_widgets::QMenu;
+use qt_widgets::{QMessageBox, q_message_box};
+use qt_widgets::{QToolButton, q_tool_button::ToolButtonPopupMode};
 use qt_widgets::QWidget;
 
+use qt_gui::QGuiApplication;
+use qt_gui::QIcon;
 use qt_gui::QStandardItem;
 use qt_gui::QStandardItemModel;
 
 use qt_core::QBox;
+use qt_core::CaseSensitivity;
 use qt_core::QFlags;
 use qt_core::q_item_selection_model::SelectionFlag;
 use qt_core::QModelIndex;
 use qt_core::QPtr;
+use qt_core::QSortFilterProxyModel;
 use qt_core::QString;
 use qt_core::QVariant;
 use qt_core::SlotNoArgs;
 
+use cpp_core::CppBox;
 use cpp_core::Ref;
 
 use anyhow::{anyhow, Result};
@@ -39,15 +47,22 @@ use getset::*;
 use itertools::Itertools;
 #[cfg(target_os = "windows")] use mslnk::ShellLink;
 
+use time::OffsetDateTime;
+
+use std::collections::BTreeMap;
 use std::path::{PathBuf, Path};
 use std::rc::Rc;
 
+use rpfm_lib::games::GameInfo;
+
 use rpfm_ui_common::clone;
-use rpfm_ui_common::locale::qtr;
+use rpfm_ui_common::locale::{qtr, tr, tre};
 use rpfm_ui_common::utils::*;
 
 use crate::AppUI;
+use crate::mod_manager::{profile_backups, profiles::Profile};
 use crate::profiles_ui::slots::ProfilesUISlots;
+use crate::settings_ui::setting_string;
 
 const VIEW_DEBUG: &str = "ui_templates/profile_manager_dialog.ui";
 const VIEW_RELEASE: &str = "ui/profile_manager_dialog.ui";
@@ -58,6 +73,9 @@ const RENAME_VIEW_RELEASE: &str = "ui/profile_rename_dialog.ui";
 const SHORTCUT_VIEW_DEBUG: &str = "ui_templates/profile_shortcut_dialog.ui";
 const SHORTCUT_VIEW_RELEASE: &str = "ui/profile_shortcut_dialog.ui";
 
+const BACKUPS_VIEW_DEBUG: &str = "ui_templates/profile_backups_dialog.ui";
+const BACKUPS_VIEW_RELEASE: &str = "ui/profile_backups_dialog.ui";
+
 mod slots;
 
 //-------------------------------------------------------------------------------//
@@ -70,11 +88,23 @@ pub struct ProfilesUI {
     main_widget: QBox<QWidget>,
     details_label: QPtr<QLabel>,
     profiles_list_view: QPtr<QListView>,
+    profiles_list_filter: QBox<QSortFilterProxyModel>,
     profiles_list_model: QBox<QStandardItemModel>,
+    filter_line_edit: QPtr<QLineEdit>,
     rename_profile_button: QPtr<QToolButton>,
     delete_profile_button: QPtr<QToolButton>,
+    duplicate_profile_button: QPtr<QToolButton>,
+    export_profile_button: QPtr<QToolButton>,
+    default_profile_button: QPtr<QToolButton>,
     shortcut_button: QPtr<QToolButton>,
-
+    copy_scripts_button: QPtr<QToolButton>,
+    copy_scripts_menu: QBox<QMenu>,
+    lock_button: QPtr<QToolButton>,
+    restore_backup_button: QPtr<QToolButton>,
+    launch_args_button: QPtr<QToolButton>,
+    tags_button: QPtr<QToolButton>,
+    copy_launch_command_button: QPtr<QToolButton>,
+    tag_filter_combobox: QPtr<QComboBox>,
 }
 
 //---------------------------------------------------------------------------//
@@ -94,35 +124,87 @@ impl ProfilesUI {
 
         let rename_profile_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "rename_button")?;
         let delete_profile_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "delete_button")?;
+        let duplicate_profile_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "duplicate_button")?;
+        let export_profile_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "export_button")?;
+        let default_profile_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "default_button")?;
         let shortcut_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "shortcut_button")?;
+        let copy_scripts_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "copy_scripts_button")?;
+        let lock_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "lock_button")?;
+        let restore_backup_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "restore_backup_button")?;
+        let launch_args_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "launch_args_button")?;
+        let tags_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "tags_button")?;
+        let copy_launch_command_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "copy_launch_command_button")?;
+        let tag_filter_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "tag_filter_combobox")?;
+        let filter_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "filter_line_edit")?;
         let profiles_list_view: QPtr<QListView> = find_widget(&main_widget.static_upcast(), "profiles_list_view")?;
+        let profiles_list_filter = QSortFilterProxyModel::new_1a(&profiles_list_view);
         let profiles_list_model = QStandardItemModel::new_1a(&profiles_list_view);
-        profiles_list_view.set_model(&profiles_list_model);
+        profiles_list_filter.set_source_model(&profiles_list_model);
+        profiles_list_filter.set_filter_case_sensitivity(CaseSensitivity::CaseInsensitive);
+        profiles_list_view.set_model(&profiles_list_filter);
 
         details_groupbox.set_title(&qtr("profile_details_title"));
         details_label.set_open_external_links(true);
         rename_profile_button.set_tool_tip(&qtr("profile_rename"));
         delete_profile_button.set_tool_tip(&qtr("profile_delete"));
+        duplicate_profile_button.set_tool_tip(&qtr("profile_duplicate"));
+        export_profile_button.set_tool_tip(&qtr("profile_export"));
+        default_profile_button.set_tool_tip(&qtr("profile_default"));
         shortcut_button.set_tool_tip(&qtr("profile_shortcut_new"));
+        copy_scripts_button.set_tool_tip(&qtr("profile_copy_scripts"));
+        lock_button.set_tool_tip(&qtr("profile_lock"));
+        restore_backup_button.set_tool_tip(&qtr("profile_backups_restore"));
+        launch_args_button.set_tool_tip(&qtr("profile_launch_args"));
+        tags_button.set_tool_tip(&qtr("profile_tags"));
+        copy_launch_command_button.set_tool_tip(&qtr("profile_copy_launch_command"));
+        tag_filter_combobox.set_tool_tip(&qtr("profile_tag_filter"));
+        filter_line_edit.set_placeholder_text(&qtr("profile_filter"));
 
         // Disable the buttons.
         delete_profile_button.set_enabled(false);
         rename_profile_button.set_enabled(false);
+        duplicate_profile_button.set_enabled(false);
+        export_profile_button.set_enabled(false);
+        default_profile_button.set_enabled(false);
         shortcut_button.set_enabled(false);
+        copy_scripts_button.set_enabled(false);
+        lock_button.set_enabled(false);
+        launch_args_button.set_enabled(false);
+        tags_button.set_enabled(false);
+        copy_launch_command_button.set_enabled(false);
+
+        // The profiles to copy scripts from are loaded when opening the menu, as they depend on the selected profile.
+        let copy_scripts_menu = QMenu::from_q_widget(&copy_scripts_button);
+        copy_scripts_button.set_menu(&copy_scripts_menu);
+        copy_scripts_button.set_popup_mode(ToolButtonPopupMode::InstantPopup);
 
         let ui = Rc::new(Self {
             main_widget,
             details_label,
             profiles_list_view,
+            profiles_list_filter,
             profiles_list_model,
+            filter_line_edit,
             rename_profile_button,
             delete_profile_button,
+            duplicate_profile_button,
+            export_profile_button,
+            default_profile_button,
             shortcut_button,
+            copy_scripts_button,
+            copy_scripts_menu,
+            lock_button,
+            restore_backup_button,
+            launch_args_button,
+            tags_button,
+            copy_launch_command_button,
+            tag_filter_combobox,
         });
 
         let slots = ProfilesUISlots::new(&ui, app_ui);
         ui.set_connections(&slots);
 
+        ui.load_tags(app_ui);
         ui.load_data(app_ui);
 
         ui.dialog().set_window_title(&qtr("profile_manager_title"));
@@ -133,23 +215,133 @@ impl ProfilesUI {
 
     pub unsafe fn set_connections(&self, slots: &ProfilesUISlots) {
         self.profiles_list_view().selection_model().selection_changed().connect(slots.update_details());
+        self.filter_line_edit().text_changed().connect(slots.filter_line_edit());
 
         self.rename_profile_button().released().connect(slots.profile_rename());
         self.delete_profile_button().released().connect(slots.profile_delete());
+        self.duplicate_profile_button().released().connect(slots.profile_duplicate());
+        self.export_profile_button().released().connect(slots.profile_export());
+        self.default_profile_button().released().connect(slots.profile_default());
         self.shortcut_button().released().connect(slots.profile_shorcut());
+        self.copy_scripts_menu().about_to_show().connect(slots.copy_scripts_menu_open());
+        self.lock_button().toggled().connect(slots.profile_lock());
+        self.restore_backup_button().released().connect(slots.restore_backup());
+        self.launch_args_button().released().connect(slots.profile_launch_args());
+        self.tags_button().released().connect(slots.profile_tags());
+        self.copy_launch_command_button().released().connect(slots.profile_copy_launch_command());
+        self.tag_filter_combobox().current_text_changed().connect(slots.tag_filter());
     }
 
+    /// This function loads the profiles of the game selected into the list. If a tag is selected in the tag filter, only the profiles with it are loaded.
     pub unsafe fn load_data(&self, app_ui: &Rc<AppUI>) {
+        let default_profile = Profile::default_for_game(&app_ui.game_selected().read().unwrap());
+        let tag = self.tag_filter();
         let profiles = app_ui.game_profiles().read().unwrap();
         profiles.values()
+            .filter(|profile| tag.as_ref().map_or(true, |tag| profile.tags().contains(tag)))
             .sorted_by_key(|profile| profile.id())
             .for_each(|profile| {
-                let item = QStandardItem::new();
-                item.set_text(&QString::from_std_str(profile.id()));
-                self.profiles_list_model().append_row_q_standard_item(item.into_ptr());
+                let is_default = default_profile.as_deref() == Some(profile.id());
+                self.profiles_list_model().append_row_q_standard_item(Self::new_profile_item(profile, is_default).into_ptr())
             });
     }
 
+    /// This function clears the list and loads the profiles again, so it reflects the tag filter.
+    pub unsafe fn reload_data(&self, app_ui: &Rc<AppUI>) {
+        self.profiles_list_view().selection_model().clear_selection();
+        self.profiles_list_model().clear();
+        self.clear_detailed_view();
+        self.load_data(app_ui);
+    }
+
+    /// This function fills the tag filter with the tags used by the profiles of the game selected, keeping the selected tag if it's still in use.
+    pub unsafe fn load_tags(&self, app_ui: &Rc<AppUI>) {
+        let current_tag = self.tag_filter();
+        let tags = app_ui.game_profiles().read().unwrap()
+            .values()
+            .flat_map(|profile| profile.tags().iter().cloned())
+            .unique()
+            .sorted()
+            .collect::<Vec<_>>();
+
+        let combobox = self.tag_filter_combobox();
+        combobox.block_signals(true);
+        combobox.clear();
+        combobox.add_item_q_string(&qtr("profile_tag_filter_all"));
+        for tag in &tags {
+            combobox.add_item_q_string(&QString::from_std_str(tag));
+        }
+
+        // Index 0 is the "all tags" entry, so tags start at 1.
+        let index = current_tag.and_then(|tag| tags.iter().position(|x| *x == tag)).map_or(0, |index| index as i32 + 1);
+        combobox.set_current_index(index);
+        combobox.set_enabled(!tags.is_empty());
+        combobox.block_signals(false);
+    }
+
+    /// This function returns the tag selected in the tag filter, or None if all profiles are shown.
+    pub unsafe fn tag_filter(&self) -> Option<String> {
+        if self.tag_filter_combobox().current_index() > 0 {
+            Some(self.tag_filter_combobox().current_text().to_std_string())
+        } else {
+            None
+        }
+    }
+
+    unsafe fn new_profile_item(profile: &Profile, is_default: bool) -> CppBox<QStandardItem> {
+        let item = QStandardItem::new();
+        item.set_text(&QString::from_std_str(profile.id()));
+
+        // Make the default profile stand out, so it's easy to see which one gets loaded with the game.
+        if is_default {
+            let font = item.font();
+            font.set_bold(true);
+            item.set_font(&font);
+        }
+
+        if *profile.locked() {
+            item.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("object-locked")));
+        }
+
+        item.set_tool_tip(&QString::from_std_str(Self::tool_tip(profile)));
+        item.set_editable(false);
+        item
+    }
+
+    fn tool_tip(profile: &Profile) -> String {
+        format!("Game: {}\nMods: {}\nLast Used: {}\nTags: {}",
+            profile.game(),
+            profile.load_order().mods().len(),
+            Self::last_used(profile),
+            Self::tags(profile)
+        )
+    }
+
+    /// This function returns the date the profile was last used in the user's date format, or "Never" if it was never used.
+    fn last_used(profile: &Profile) -> String {
+        if *profile.last_used() == 0 {
+            return "Never".to_owned();
+        }
+
+        let date_format_str = setting_string("date_format");
+        time::format_description::parse(&date_format_str).ok()
+            .and_then(|date_format| OffsetDateTime::from_unix_timestamp(*profile.last_used() as i64).ok()?.format(&date_format).ok())
+            .unwrap_or_default()
+    }
+
+    /// This function returns the tags of the profile as a comma-separated list, or "None" if it has no tags.
+    fn tags(profile: &Profile) -> String {
+        if profile.tags().is_empty() {
+            "None".to_owned()
+        } else {
+            profile.tags().join(", ")
+        }
+    }
+
+    pub unsafe fn filter_list(&self) {
+        self.profiles_list_filter().set_filter_fixed_string(&self.filter_line_edit().text());
+    }
+
     pub unsafe fn dialog(&self) -> QPtr<QDialog> {
         self.main_widget().static_downcast::<QDialog>()
     }
@@ -163,6 +355,13 @@ impl ProfilesUI {
         if let Some(profile) = profiles.get(&profile_id) {
             details.push_str(&format!("<li>Profile ID/Name: {}</li>", profile.id()));
             details.push_str(&format!("<li>Game: {}</li>", profile.game()));
+            details.push_str(&format!("<li>Mod Count: {}</li>", profile.load_order().mods().len()));
+            details.push_str(&format!("<li>Last Used: {}</li>", Self::last_used(profile)));
+            details.push_str(&format!("<li>Stores Scripts: {}</li>", if profile.scripts().is_empty() { "No" } else { "Yes" }));
+            details.push_str(&format!("<li>Locked: {}</li>", if *profile.locked() { "Yes" } else { "No" }));
+            details.push_str(&format!("<li>Launch Arguments: {}</li>", profile.launch_args().as_deref().unwrap_or("Global")));
+            details.push_str(&format!("<li>Tags: {}</li>", Self::tags(profile)));
+            details.push_str(&format!("<li>Default for this Game: {}</li>", if Profile::default_for_game(&app_ui.game_selected().read().unwrap()).as_deref() == Some(profile.id()) { "Yes" } else { "No" }));
 
             if profile.load_order().mods().is_empty() {
                 details.push_str("<li>Profile contains an empty load order.</li>");
@@ -203,18 +402,19 @@ impl ProfilesUI {
         self.details_label().set_text(&QString::from_std_str(String::new()));
     }
 
-    pub unsafe fn list_selection(&self) -> Vec<Ref<QModelIndex>> {
+    /// This function returns the selected indexes, mapped to the source model and sorted from bottom to top.
+    pub unsafe fn list_selection(&self) -> Vec<CppBox<QModelIndex>> {
         let indexes_visual = self.profiles_list_view().selection_model().selection().indexes();
-        let mut indexes_visual = (0..indexes_visual.count_0a())
+        let mut indexes_real = (0..indexes_visual.count_0a())
             .filter(|x| indexes_visual.at(*x).column() == 0)
-            .map(|x| indexes_visual.at(x))
+            .map(|x| self.profiles_list_filter().map_to_source(indexes_visual.at(x)))
             .collect::<Vec<_>>();
 
         // Manually sort the selection, because if the user selects with ctrl from bottom to top, this breaks hard.
-        indexes_visual.sort_by_key(|index| index.row());
-        indexes_visual.reverse();
+        indexes_real.sort_by_key(|index| index.row());
+        indexes_real.reverse();
 
-        indexes_visual
+        indexes_real
     }
 
     pub unsafe fn rename_dialog(&self, current_name: &str, in_use_names: &[String]) -> Result<Option<String>> {
@@ -258,6 +458,9 @@ impl ProfilesUI {
         let current_name = index.data_1a(2).to_string().to_std_string();
 
         let names_in_use = app_ui.game_profiles().read().unwrap().keys().cloned().collect::<Vec<_>>();
+        if app_ui.game_profiles().read().unwrap().get(&current_name).map_or(false, |profile| *profile.locked()) {
+            return Err(anyhow!("{}", tre("profile_locked_error", &[&current_name])));
+        }
 
         if let Some(new_name) = self.rename_dialog(&current_name, &names_in_use)? {
 
@@ -268,7 +471,7 @@ impl ProfilesUI {
             // Update the list. We need to re-select to avoid nullptr issues, though we didn't made a change that could cause it...
             let selection = self.list_selection();
             let index = &selection[0];
-            let item = self.profiles_list_model().item_from_index(*index);
+            let item = self.profiles_list_model().item_from_index(index.as_ref());
             item.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(&new_name)), 2);
 
             // Update the profile itself, and save it to disk.
@@ -282,6 +485,10 @@ impl ProfilesUI {
                     profile.set_id(new_name.to_owned());
                     profile.save(&game, &new_name)?;
 
+                    if Profile::default_for_game(&game).as_deref() == Some(&current_name) {
+                        Profile::set_default_for_game(&game, Some(&new_name));
+                    }
+
                     profiles.insert(new_name.to_owned(), profile);
                 }
             }
@@ -296,28 +503,560 @@ impl ProfilesUI {
     }
 
     pub unsafe fn delete_profile(&self, app_ui: &Rc<AppUI>) -> Result<()> {
-        if app_ui.are_you_sure("are_you_sure_delete_profile") {
-            let selection = self.list_selection();
-            let index = &selection[0];
-            let name = index.data_1a(2).to_string().to_std_string();
-            let row = index.row();
+        let locked = self.list_selection()
+            .iter()
+            .map(|index| index.data_1a(2).to_string().to_std_string())
+            .filter(|name| app_ui.game_profiles().read().unwrap().get(name).map_or(false, |profile| *profile.locked()))
+            .collect::<Vec<_>>();
 
-            // Remove it from the view.
-            let selection_model = self.profiles_list_view().selection_model();
-            let selection = selection_model.selection();
-            self.profiles_list_view().selection_model().select_q_item_selection_q_flags_selection_flag(&selection, SelectionFlag::Toggle.into());
-            self.profiles_list_model().remove_row_1a(row);
+        if !locked.is_empty() {
+            return Err(anyhow!("{}", tre("profile_locked_error", &[&locked.join(", ")])));
+        }
+
+        if self.delete_confirmation(app_ui)? {
+
+            // The selection is sorted from bottom to top, so we can remove the rows without invalidating the next ones.
+            let rows = self.list_selection()
+                .iter()
+                .map(|index| (index.row(), index.data_1a(2).to_string().to_std_string()))
+                .collect::<Vec<_>>();
+
+            // Remove them from the view.
+            self.profiles_list_view().selection_model().clear_selection();
+            for (row, _) in &rows {
+                self.profiles_list_model().remove_row_1a(*row);
+            }
+
+            // Remove them from the backend.
+            let game = app_ui.game_selected().read().unwrap();
+            let mut profiles = app_ui.game_profiles().write().unwrap();
+            for (_, name) in &rows {
+                if let Some(profile) = profiles.remove(name) {
+                    profile.delete(&game)?;
+
+                    if Profile::default_for_game(&game).as_deref() == Some(name) {
+                        Profile::set_default_for_game(&game, None);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// This function asks the user to confirm the deletion of the selected profiles, offering to export them first.
+    ///
+    /// Returns if the profiles should be deleted. If the user chose to export them but cancelled the export, they're kept.
+    unsafe fn delete_confirmation(&self, app_ui: &Rc<AppUI>) -> Result<bool> {
+        let message_box = QMessageBox::from_q_widget(self.dialog());
+        message_box.set_window_title(&qtr("are_you_sure_title"));
+        message_box.set_icon(q_message_box::Icon::Warning);
+        message_box.set_text(&qtr("are_you_sure_delete_profile"));
+
+        let export_button = message_box.add_button_q_string_button_role(&qtr("profile_delete_export_first"), q_message_box::ButtonRole::AcceptRole);
+        message_box.add_button_q_string_button_role(&qtr("profile_delete_without_export"), q_message_box::ButtonRole::DestructiveRole);
+        message_box.add_button_standard_button(q_message_box::StandardButton::Cancel);
+        message_box.set_default_button_q_push_button(&export_button);
+        message_box.exec();
+
+        match message_box.button_role(&message_box.clicked_button()) {
+            q_message_box::ButtonRole::AcceptRole => self.export_profile(app_ui),
+            q_message_box::ButtonRole::DestructiveRole => Ok(true),
+            _ => Ok(false),
+        }
+    }
+
+    pub unsafe fn duplicate_profile(&self, app_ui: &Rc<AppUI>) -> Result<()> {
+        let mut names = self.list_selection()
+            .iter()
+            .map(|index| index.data_1a(2).to_string().to_std_string())
+            .collect::<Vec<_>>();
+        names.reverse();
+
+        let game = app_ui.game_selected().read().unwrap();
+        let mut profiles = app_ui.game_profiles().write().unwrap();
+        for name in &names {
+            if let Some(profile) = profiles.get(name) {
+                let new_name = Self::duplicate_name(name, &profiles.keys().cloned().collect::<Vec<_>>());
+
+                // Copies start unlocked, so they can be tweaked without touching the original.
+                let mut new_profile = profile.clone();
+                new_profile.set_id(new_name.to_owned());
+                new_profile.set_last_used(0);
+                new_profile.set_locked(false);
+                new_profile.save(&game, &new_name)?;
+
+                self.profiles_list_model().append_row_q_standard_item(Self::new_profile_item(&new_profile, false).into_ptr());
+                profiles.insert(new_name, new_profile);
+            }
+        }
+
+        self.profiles_list_model().sort_1a(0);
+
+        Ok(())
+    }
+
+    /// This function fills the menu to copy scripts into the selected profile with the other profiles that have scripts stored,
+    /// and with the scripts of the game, to copy the current settings of only one of them.
+    pub unsafe fn generate_copy_scripts_menu(ui: &Rc<Self>, app_ui: &Rc<AppUI>) {
+        let menu = ui.copy_scripts_menu();
+        menu.clear();
+
+        let selection = ui.list_selection();
+        let target = match selection.first() {
+            Some(index) if selection.len() == 1 => index.data_1a(2).to_string().to_std_string(),
+            _ => return,
+        };
+
+        // Automatic scripts are not configured by the user, so there's nothing to copy from them.
+        let scripts = app_ui.actions_ui().scripts_to_execute().read().unwrap()
+            .iter()
+            .filter(|(script, _)| !*script.metadata().automatic())
+            .map(|(script, _)| (
+                script.metadata().name().trim().to_owned(),
+                script.metadata().key().to_owned(),
+                script.metadata().parameters().iter().map(|param| param.key().to_owned()).collect::<Vec<_>>()
+            ))
+            .sorted()
+            .collect::<Vec<_>>();
+
+        let script_menu = menu.add_menu_q_string(&qtr("profile_copy_script"));
+        script_menu.set_enabled(!scripts.is_empty());
+        for (name, script_key, param_keys) in scripts {
+            let action = script_menu.add_action_q_string(&QString::from_std_str(&name));
+            let slot = SlotNoArgs::new(&script_menu, clone!(
+                app_ui,
+                ui,
+                target => move || {
+                    if let Err(error) = ui.copy_script_to_profile(&app_ui, &script_key, &param_keys, &target) {
+                        show_dialog(ui.main_widget(), error, false);
+                    }
+                }
+            ));
+
+            action.triggered().connect(&slot);
+        }
+
+        menu.add_separator();
+
+        let sources = app_ui.game_profiles().read().unwrap()
+            .values()
+            .filter(|profile| profile.id() != &target && !profile.scripts().is_empty())
+            .map(|profile| profile.id().to_owned())
+            .sorted()
+            .collect::<Vec<_>>();
+
+        if sources.is_empty() {
+            let action = menu.add_action_q_string(&qtr("profile_copy_scripts_empty"));
+            action.set_enabled(false);
+            return;
+        }
+
+        for source in sources {
+            let action = menu.add_action_q_string(&QString::from_std_str(&source));
+            let slot = SlotNoArgs::new(menu, clone!(
+                app_ui,
+                ui,
+                target => move || {
+                    if let Err(error) = ui.copy_scripts_from_profile(&app_ui, &source, &target) {
+                        show_dialog(ui.main_widget(), error, false);
+                    }
+                }
+            ));
+
+            action.triggered().connect(&slot);
+        }
+    }
+
+    /// This function copies the scripts stored in a profile into another one, and saves it.
+    pub unsafe fn copy_scripts_from_profile(&self, app_ui: &Rc<AppUI>, source: &str, target: &str) -> Result<()> {
+        let game = app_ui.game_selected().read().unwrap();
+        let mut profiles = app_ui.game_profiles().write().unwrap();
+        let source = profiles.get(source).cloned().ok_or_else(|| anyhow!("No profile with said name found for the game selected."))?;
+        let target_profile = profiles.get_mut(target).ok_or_else(|| anyhow!("No profile with said name found for the game selected."))?;
+        if *target_profile.locked() {
+            return Err(anyhow!("{}", tre("profile_locked_error", &[target])));
+        }
+
+        target_profile.copy_scripts_from(&source);
+        target_profile.save(&game, target)?;
+
+        drop(profiles);
+        drop(game);
+
+        let selection = self.list_selection();
+        if selection.len() == 1 {
+            self.load_entry_to_detailed_view(app_ui, selection[0].as_ref());
+        }
+
+        Ok(())
+    }
+
+    /// This function copies the current settings of a script (if it's enabled, its preset and its params) into a profile, and saves it.
+    ///
+    /// The current settings are the ones of the active profile, as loading a profile applies its scripts. The rest of the scripts of the
+    /// target profile are left as they are. If any of its values would change, the user is asked first, with the list of them.
+    pub unsafe fn copy_script_to_profile(&self, app_ui: &Rc<AppUI>, script_key: &str, param_keys: &[String], target: &str) -> Result<()> {
+        let game = app_ui.game_selected().read().unwrap().clone();
+        let param_keys = param_keys.iter().map(|key| &key[..]).collect::<Vec<_>>();
+        let keys = Profile::script_setting_keys(game.key(), script_key, &param_keys);
+        let script_settings = AppUI::script_settings(&game)
+            .into_iter()
+            .filter(|(key, _)| keys.contains(key))
+            .collect::<BTreeMap<_, _>>();
+
+        let overwritten = {
+            let profiles = app_ui.game_profiles().read().unwrap();
+            let target_profile = profiles.get(target).ok_or_else(|| anyhow!("No profile with said name found for the game selected."))?;
+            if *target_profile.locked() {
+                return Err(anyhow!("{}", tre("profile_locked_error", &[target])));
+            }
+
+            // Profiles without scripts leave the current ones as they are when loaded. With only one, they'd disable the rest.
+            if target_profile.scripts().is_empty() {
+                return Err(anyhow!("{}", tre("profile_copy_script_no_scripts", &[target])));
+            }
+
+            target_profile.script_settings_overwritten(&keys, &script_settings)
+        };
+
+        if !overwritten.is_empty() {
+            let escape = |text: &str| text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;");
+            let prefix = format!("script_to_execute_{}_", game.key());
+            let removed = tr("profile_copy_script_removed");
+            let values = overwritten.iter()
+                .map(|(key, current, new)| format!("<li><i>{}</i>: {} -> {}</li>",
+                    escape(key.strip_prefix(&prefix).unwrap_or(key)),
+                    escape(current),
+                    escape(new.as_deref().unwrap_or(&removed))
+                ))
+                .join("");
+
+            if !app_ui.are_you_sure_text(&tre("profile_copy_script_confirm", &[target, &values])) {
+                return Ok(());
+            }
+        }
+
+        let mut profiles = app_ui.game_profiles().write().unwrap();
+        let target_profile = profiles.get_mut(target).ok_or_else(|| anyhow!("No profile with said name found for the game selected."))?;
+        target_profile.copy_script_settings(&keys, &script_settings);
+        target_profile.save(&game, target)?;
+        drop(profiles);
+
+        let selection = self.list_selection();
+        if selection.len() == 1 {
+            self.load_entry_to_detailed_view(app_ui, selection[0].as_ref());
+        }
+
+        Ok(())
+    }
+
+    /// This function locks or unlocks the selected profile, and saves it.
+    pub unsafe fn set_profile_locked(&self, app_ui: &Rc<AppUI>, locked: bool) -> Result<()> {
+        let selection = self.list_selection();
+        if selection.len() != 1 {
+            return Ok(());
+        }
+
+        let name = selection[0].data_1a(2).to_string().to_std_string();
+        let game = app_ui.game_selected().read().unwrap();
+        let mut profiles = app_ui.game_profiles().write().unwrap();
+        let profile = profiles.get_mut(&name).ok_or_else(|| anyhow!("No profile with said name found for the game selected."))?;
+        if *profile.locked() == locked {
+            return Ok(());
+        }
+
+        profile.set_locked(locked);
+        profile.save(&game, &name)?;
+
+        let item = self.profiles_list_model().item_from_index(selection[0].as_ref());
+        if locked {
+            item.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("object-locked")));
+        } else {
+            item.set_icon(&QIcon::new());
+        }
+
+        drop(profiles);
+        drop(game);
+
+        self.load_entry_to_detailed_view(app_ui, selection[0].as_ref());
+
+        Ok(())
+    }
+
+    /// This function edits the extra launch arguments of the selected profile, and saves it.
+    ///
+    /// Leaving them empty makes the profile use the global ones again.
+    pub unsafe fn edit_launch_args(&self, app_ui: &Rc<AppUI>) -> Result<()> {
+        let selection = self.list_selection();
+        if selection.len() != 1 {
+            return Ok(());
+        }
+
+        let name = selection[0].data_1a(2).to_string().to_std_string();
+        let current_args = match app_ui.game_profiles().read().unwrap().get(&name) {
+            Some(profile) if *profile.locked() => return Err(anyhow!("{}", tre("profile_locked_error", &[&name]))),
+            Some(profile) => profile.launch_args().clone().unwrap_or_default(),
+            None => return Err(anyhow!("No profile with said name found for the game selected.")),
+        };
+
+        // Same dialog as for renaming, as it's just a line edit.
+        let template_path = if cfg!(debug_assertions) { RENAME_VIEW_DEBUG } else { RENAME_VIEW_RELEASE };
+        let main_widget = load_template(self.dialog(), template_path)?;
+
+        let dialog = main_widget.static_downcast::<QDialog>();
+        dialog.set_window_title(&qtr("profile_launch_args"));
+
+        let args_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "name_line_edit")?;
+        let args_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "name_label")?;
+        let button_box: QPtr<QDialogButtonBox> = find_widget(&main_widget.static_upcast(), "button_box")?;
+        args_line_edit.set_text(&QString::from_std_str(&current_args));
+        args_line_edit.set_placeholder_text(&qtr("profile_launch_args_ph"));
+        args_label.set_text(&qtr("launch_extra_args"));
+
+        button_box.button(StandardButton::Ok).released().connect(dialog.slot_accept());
 
-            // Remove it from the backend.
-            if let Some(profile) = app_ui.game_profiles().write().unwrap().remove(&name) {
-                let game = app_ui.game_selected().read().unwrap();
-                profile.delete(&game)?;
+        if dialog.exec() != 1 {
+            return Ok(());
+        }
+
+        let args = args_line_edit.text().to_std_string().trim().to_owned();
+        let game = app_ui.game_selected().read().unwrap();
+        let mut profiles = app_ui.game_profiles().write().unwrap();
+        if let Some(profile) = profiles.get_mut(&name) {
+            profile.set_launch_args(if args.is_empty() { None } else { Some(args) });
+            profile.save(&game, &name)?;
+        }
+
+        drop(profiles);
+        drop(game);
+
+        self.load_entry_to_detailed_view(app_ui, selection[0].as_ref());
+
+        Ok(())
+    }
+
+    /// This function edits the tags of the selected profile, and saves it.
+    ///
+    /// Tags are only used to organize the profiles, so they can be edited on locked profiles too.
+    pub unsafe fn edit_tags(&self, app_ui: &Rc<AppUI>) -> Result<()> {
+        let selection = self.list_selection();
+        if selection.len() != 1 {
+            return Ok(());
+        }
+
+        let name = selection[0].data_1a(2).to_string().to_std_string();
+        let current_tags = match app_ui.game_profiles().read().unwrap().get(&name) {
+            Some(profile) => profile.tags().join(", "),
+            None => return Err(anyhow!("No profile with said name found for the game selected.")),
+        };
+
+        // Same dialog as for renaming, as it's just a line edit.
+        let template_path = if cfg!(debug_assertions) { RENAME_VIEW_DEBUG } else { RENAME_VIEW_RELEASE };
+        let main_widget = load_template(self.dialog(), template_path)?;
+
+        let dialog = main_widget.static_downcast::<QDialog>();
+        dialog.set_window_title(&qtr("profile_tags"));
+
+        let tags_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "name_line_edit")?;
+        let tags_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "name_label")?;
+        let button_box: QPtr<QDialogButtonBox> = find_widget(&main_widget.static_upcast(), "button_box")?;
+        tags_line_edit.set_text(&QString::from_std_str(&current_tags));
+        tags_line_edit.set_placeholder_text(&qtr("profile_tags_ph"));
+        tags_label.set_text(&qtr("profile_tags_label"));
+
+        button_box.button(StandardButton::Ok).released().connect(dialog.slot_accept());
+
+        if dialog.exec() != 1 {
+            return Ok(());
+        }
+
+        {
+            let game = app_ui.game_selected().read().unwrap();
+            let mut profiles = app_ui.game_profiles().write().unwrap();
+            if let Some(profile) = profiles.get_mut(&name) {
+                profile.set_tags_from_list(&tags_line_edit.text().to_std_string());
+                profile.save(&game, &name)?;
+
+                let item = self.profiles_list_model().item_from_index(selection[0].as_ref());
+                item.set_tool_tip(&QString::from_std_str(Self::tool_tip(profile)));
             }
         }
 
+        // The profile may no longer match the tag filter, so reload the list if it's in use.
+        self.load_tags(app_ui);
+        if self.tag_filter().is_some() {
+            self.reload_data(app_ui);
+        } else {
+            self.load_entry_to_detailed_view(app_ui, selection[0].as_ref());
+        }
+
         Ok(())
     }
 
+    /// This function copies to the clipboard the command that would launch the game with the selected profile, without loading it.
+    pub unsafe fn copy_launch_command(&self, app_ui: &Rc<AppUI>) -> Result<()> {
+        let selection = self.list_selection();
+        if selection.len() != 1 {
+            return Ok(());
+        }
+
+        let name = selection[0].data_1a(2).to_string().to_std_string();
+        let profile = match app_ui.game_profiles().read().unwrap().get(&name) {
+            Some(profile) => profile.clone(),
+            None => return Err(anyhow!("No profile with said name found for the game selected.")),
+        };
+
+        let command = app_ui.profile_launch_command(&profile)?;
+        QGuiApplication::clipboard().set_text_1a(&QString::from_std_str(command));
+        show_dialog(self.dialog(), tre("profile_launch_command_copied", &[&name]), true);
+
+        Ok(())
+    }
+
+    /// This function returns the first free name for a copy of the provided profile.
+    pub fn duplicate_name(name: &str, in_use_names: &[String]) -> String {
+        let mut new_name = format!("{name} (copy)");
+        let mut index = 2;
+        while in_use_names.contains(&new_name) {
+            new_name = format!("{name} (copy {index})");
+            index += 1;
+        }
+
+        new_name
+    }
+
+    /// This function exports the selected profiles to a folder chosen by the user.
+    ///
+    /// Returns if they were exported, or false if the user cancelled it.
+    pub unsafe fn export_profile(&self, app_ui: &Rc<AppUI>) -> Result<bool> {
+        let file_dialog = QFileDialog::from_q_widget_q_string(
+            self.dialog(),
+            &qtr("profile_export_folder"),
+        );
+
+        file_dialog.set_file_mode(FileMode::Directory);
+        file_dialog.set_options(QFlags::from(QFileDialogOption::ShowDirsOnly));
+
+        if file_dialog.exec() == 1 {
+            let selected_files = file_dialog.selected_files();
+            let folder = PathBuf::from(selected_files.at(0).to_std_string());
+
+            let game = app_ui.game_selected().read().unwrap();
+            let profiles = app_ui.game_profiles().read().unwrap();
+            for index in self.list_selection() {
+                let name = index.data_1a(2).to_string().to_std_string();
+                if let Some(profile) = profiles.get(&name) {
+                    profile.export(&folder.join(Self::export_file_name(&game, &name)))?;
+                }
+            }
+
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// This function sets the selected profile as the one to load when selecting the current game, or unsets it if it already was.
+    pub unsafe fn toggle_default_profile(&self, app_ui: &Rc<AppUI>) -> Result<()> {
+        let selection = self.list_selection();
+        let index = &selection[0];
+        let name = index.data_1a(2).to_string().to_std_string();
+
+        let game = app_ui.game_selected().read().unwrap();
+        let old_default = Profile::default_for_game(&game);
+        let new_default = if old_default.as_deref() == Some(&name) { None } else { Some(name.as_str()) };
+        Profile::set_default_for_game(&game, new_default);
+
+        // Update the fonts of both, the old and new default profiles.
+        for row in 0..self.profiles_list_model().row_count_0a() {
+            let item = self.profiles_list_model().item_1a(row);
+            let item_name = item.text().to_std_string();
+            if Some(&item_name) == old_default.as_ref() || Some(item_name.as_str()) == new_default {
+                let font = item.font();
+                font.set_bold(Some(item_name.as_str()) == new_default);
+                item.set_font(&font);
+            }
+        }
+
+        self.default_profile_button().set_checked(new_default.is_some());
+        self.load_entry_to_detailed_view(app_ui, index.as_ref());
+
+        Ok(())
+    }
+
+    /// This function opens a dialog to pick one of the backups of the profiles and launch settings, and restores it.
+    ///
+    /// Backups contain the profiles of all games, so restoring one replaces the profiles of all games, not only the selected one.
+    pub unsafe fn restore_backup(&self, app_ui: &Rc<AppUI>) -> Result<()> {
+        let backups = profile_backups::backups()?;
+        if backups.is_empty() {
+            return Err(anyhow!(tr("profile_backups_none")));
+        }
+
+        let template_path = if cfg!(debug_assertions) { BACKUPS_VIEW_DEBUG } else { BACKUPS_VIEW_RELEASE };
+        let main_widget = load_template(self.dialog(), template_path)?;
+        let dialog = main_widget.static_downcast::<QDialog>();
+
+        let info_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "info_label")?;
+        let backups_list_view: QPtr<QListView> = find_widget(&main_widget.static_upcast(), "backups_list_view")?;
+
+        dialog.set_window_title(&qtr("profile_backups_restore"));
+        info_label.set_text(&qtr("profile_backups_restore_info"));
+
+        let backups_model = QStandardItemModel::new_1a(&backups_list_view);
+        backups_list_view.set_model(&backups_model);
+
+        for path in &backups {
+            let item = QStandardItem::from_q_string(&QString::from_std_str(Self::backup_date(path)));
+            item.set_tool_tip(&QString::from_std_str(path.to_string_lossy()));
+            item.set_editable(false);
+            backups_model.append_row_q_standard_item(item.into_ptr());
+        }
+
+        // Backups are sorted from newest to oldest, so the newest one is selected by default.
+        backups_list_view.set_current_index(&backups_model.index_2a(0, 0));
+
+        dialog.set_modal(true);
+        if dialog.exec() != 1 {
+            return Ok(());
+        }
+
+        let path = match usize::try_from(backups_list_view.current_index().row()).ok().and_then(|row| backups.get(row)) {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+
+        if !app_ui.are_you_sure("profile_backups_restore_confirm") {
+            return Ok(());
+        }
+
+        profile_backups::restore(path)?;
+
+        // Reload the game to load the restored profiles and launch settings, then refresh the list with them.
+        app_ui.actions_ui().reload_button().click();
+
+        self.load_tags(app_ui);
+        self.reload_data(app_ui);
+
+        Ok(())
+    }
+
+    /// This function returns the date a backup was made in the user's date format, with the time, as there may be more than one backup per day.
+    fn backup_date(path: &Path) -> String {
+        let date_format_str = format!("{} [hour]:[minute]:[second]", setting_string("date_format"));
+        profile_backups::backup_date(path)
+            .and_then(|date| {
+                let date_format = time::format_description::parse(&date_format_str).ok()?;
+                OffsetDateTime::from_unix_timestamp(date as i64).ok()?.format(&date_format).ok()
+            })
+            .unwrap_or_else(|| path.file_name().unwrap_or_default().to_string_lossy().to_string())
+    }
+
+    fn export_file_name(game: &GameInfo, name: &str) -> String {
+        format!("profile_{}_{}.json", game.key(), name)
+    }
+
     pub unsafe fn create_shortcut(&self, app_ui: &Rc<AppUI>) -> Result<()> {
         let selection = self.list_selection();
         let index = &selection[0];
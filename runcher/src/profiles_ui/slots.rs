@@ -9,6 +9,8 @@
 //---------------------------------------------------------------------------//
 
 use qt_core::SlotNoArgs;
+use qt_core::SlotOfBool;
+use qt_core::SlotOfQString;
 use qt_core::SlotOfQItemSelectionQItemSelection;
 
 use qt_core::QBox;
@@ -21,6 +23,7 @@ use rpfm_ui_common::clone;
 use rpfm_ui_common::utils::show_dialog;
 
 use crate::app_ui::AppUI;
+use crate::mod_manager::profiles::Profile;
 
 use super::ProfilesUI;
 
@@ -32,9 +35,20 @@ use super::ProfilesUI;
 #[getset(get = "pub")]
 pub struct ProfilesUISlots {
     update_details: QBox<SlotOfQItemSelectionQItemSelection>,
+    filter_line_edit: QBox<SlotOfQString>,
     profile_rename: QBox<SlotNoArgs>,
     profile_delete: QBox<SlotNoArgs>,
+    profile_duplicate: QBox<SlotNoArgs>,
+    profile_export: QBox<SlotNoArgs>,
+    profile_default: QBox<SlotNoArgs>,
     profile_shorcut: QBox<SlotNoArgs>,
+    copy_scripts_menu_open: QBox<SlotNoArgs>,
+    profile_lock: QBox<SlotOfBool>,
+    restore_backup: QBox<SlotNoArgs>,
+    profile_launch_args: QBox<SlotNoArgs>,
+    profile_tags: QBox<SlotNoArgs>,
+    profile_copy_launch_command: QBox<SlotNoArgs>,
+    tag_filter: QBox<SlotOfQString>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -46,37 +60,52 @@ impl ProfilesUISlots {
     pub unsafe fn new(ui: &Rc<ProfilesUI>, app_ui: &Rc<AppUI>) -> Self {
         let update_details = SlotOfQItemSelectionQItemSelection::new(ui.main_widget(), clone!(
             app_ui,
-            ui => move |after, before| {
-
-                // Save the previous data if needed.
-                if before.count_0a() == 1 {
-                    //let indexes = before.at(0).indexes();
-                    //let index = indexes.at(0);
-                    //view.save_entry_from_detailed_view(index.as_ref());
-                }
+            ui => move |_, _| {
+                let selection = ui.list_selection();
 
                 // Load the new data.
-                if after.count_0a() == 1 {
-                    let indexes = after.at(0).indexes();
-                    let index = indexes.at(0);
-                    ui.load_entry_to_detailed_view(&app_ui, index);
-
-                    // Enable the buttons.
-                    ui.delete_profile_button().set_enabled(true);
-                    ui.rename_profile_button().set_enabled(true);
-                    ui.shortcut_button().set_enabled(true);
+                if selection.len() == 1 {
+                    ui.load_entry_to_detailed_view(&app_ui, selection[0].as_ref());
                 }
 
                 // If nothing is loaded, means we're selecting multiple things, or none.
                 // We need to clear the view to ensure no weird shenaningans happen.
                 else {
                     ui.clear_detailed_view();
-
-                    // Disable the buttons.
-                    ui.delete_profile_button().set_enabled(false);
-                    ui.rename_profile_button().set_enabled(false);
-                    ui.shortcut_button().set_enabled(false);
                 }
+
+                // Rename and shortcuts only work on one profile. The rest work on as many as you want.
+                ui.rename_profile_button().set_enabled(selection.len() == 1);
+                ui.shortcut_button().set_enabled(selection.len() == 1);
+                ui.copy_scripts_button().set_enabled(selection.len() == 1);
+                ui.delete_profile_button().set_enabled(!selection.is_empty());
+                ui.duplicate_profile_button().set_enabled(!selection.is_empty());
+
+                // Locking works on one profile, so it's clear which one is being locked.
+                ui.lock_button().block_signals(true);
+                ui.lock_button().set_enabled(selection.len() == 1);
+                ui.lock_button().set_checked(selection.len() == 1 && {
+                    let name = selection[0].data_0a().to_string().to_std_string();
+                    app_ui.game_profiles().read().unwrap().get(&name).map_or(false, |profile| *profile.locked())
+                });
+                ui.lock_button().block_signals(false);
+                ui.launch_args_button().set_enabled(selection.len() == 1);
+                ui.tags_button().set_enabled(selection.len() == 1);
+                ui.copy_launch_command_button().set_enabled(selection.len() == 1);
+                ui.export_profile_button().set_enabled(!selection.is_empty());
+
+                // Only one profile can be the default one.
+                ui.default_profile_button().set_enabled(selection.len() == 1);
+                ui.default_profile_button().set_checked(selection.len() == 1 && {
+                    let name = selection[0].data_0a().to_string().to_std_string();
+                    Profile::default_for_game(&app_ui.game_selected().read().unwrap()).as_deref() == Some(&name)
+                });
+            }
+        ));
+
+        let filter_line_edit = SlotOfQString::new(ui.main_widget(), clone!(
+            ui => move |_| {
+                ui.filter_list();
             }
         ));
 
@@ -98,6 +127,33 @@ impl ProfilesUISlots {
             }
         ));
 
+        let profile_duplicate = SlotNoArgs::new(ui.main_widget(), clone!(
+            app_ui,
+            ui => move || {
+                if let Err(error) = ui.duplicate_profile(&app_ui) {
+                    show_dialog(ui.main_widget(), error, false);
+                }
+            }
+        ));
+
+        let profile_export = SlotNoArgs::new(ui.main_widget(), clone!(
+            app_ui,
+            ui => move || {
+                if let Err(error) = ui.export_profile(&app_ui) {
+                    show_dialog(ui.main_widget(), error, false);
+                }
+            }
+        ));
+
+        let profile_default = SlotNoArgs::new(ui.main_widget(), clone!(
+            app_ui,
+            ui => move || {
+                if let Err(error) = ui.toggle_default_profile(&app_ui) {
+                    show_dialog(ui.main_widget(), error, false);
+                }
+            }
+        ));
+
         let profile_shorcut = SlotNoArgs::new(ui.main_widget(), clone!(
             app_ui,
             ui => move || {
@@ -107,12 +163,82 @@ impl ProfilesUISlots {
             }
         ));
 
+        let copy_scripts_menu_open = SlotNoArgs::new(ui.main_widget(), clone!(
+            app_ui,
+            ui => move || {
+                ProfilesUI::generate_copy_scripts_menu(&ui, &app_ui);
+            }
+        ));
+
+        let profile_lock = SlotOfBool::new(ui.main_widget(), clone!(
+            app_ui,
+            ui => move |state| {
+                if let Err(error) = ui.set_profile_locked(&app_ui, state) {
+                    show_dialog(ui.main_widget(), error, false);
+                }
+            }
+        ));
+
+        let restore_backup = SlotNoArgs::new(ui.main_widget(), clone!(
+            app_ui,
+            ui => move || {
+                if let Err(error) = ui.restore_backup(&app_ui) {
+                    show_dialog(ui.main_widget(), error, false);
+                }
+            }
+        ));
+
+        let profile_launch_args = SlotNoArgs::new(ui.main_widget(), clone!(
+            app_ui,
+            ui => move || {
+                if let Err(error) = ui.edit_launch_args(&app_ui) {
+                    show_dialog(ui.main_widget(), error, false);
+                }
+            }
+        ));
+
+        let profile_tags = SlotNoArgs::new(ui.main_widget(), clone!(
+            app_ui,
+            ui => move || {
+                if let Err(error) = ui.edit_tags(&app_ui) {
+                    show_dialog(ui.main_widget(), error, false);
+                }
+            }
+        ));
+
+        let profile_copy_launch_command = SlotNoArgs::new(ui.main_widget(), clone!(
+            app_ui,
+            ui => move || {
+                if let Err(error) = ui.copy_launch_command(&app_ui) {
+                    show_dialog(ui.main_widget(), error, false);
+                }
+            }
+        ));
+
+        let tag_filter = SlotOfQString::new(ui.main_widget(), clone!(
+            app_ui,
+            ui => move |_| {
+                ui.reload_data(&app_ui);
+            }
+        ));
+
         Self {
             update_details,
+            filter_line_edit,
 
             profile_rename,
             profile_delete,
+            profile_duplicate,
+            profile_export,
+            profile_default,
             profile_shorcut,
+            copy_scripts_menu_open,
+            profile_lock,
+            restore_backup,
+            profile_launch_args,
+            profile_tags,
+            profile_copy_launch_command,
+            tag_filter,
         }
     }
 }
@@ -0,0 +1,254 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the scripts panel of the main window.
+//!
+//! This panel mirrors the script checkboxes of the play menu, so scripts can be enabled and disabled next to the mod list.
+//! The play menu checkboxes are the source of truth: toggling a script here toggles its checkbox, which saves the setting,
+//! and toggling the checkbox updates this panel. Presets and params are only available in the play menu.
+
+use qt_widgets::q_abstract_item_view::SelectionMode;
+use qt_widgets::QCheckBox;
+use qt_widgets::QLineEdit;
+use qt_widgets::QTabWidget;
+use qt_widgets::QToolButton;
+use qt_widgets::QTreeView;
+use qt_widgets::QWidget;
+
+use qt_gui::QIcon;
+use qt_gui::QListOfQStandardItem;
+use qt_gui::QStandardItem;
+use qt_gui::QStandardItemModel;
+
+use qt_core::CaseSensitivity;
+use qt_core::CheckState;
+use qt_core::QBox;
+use qt_core::QPtr;
+use qt_core::QRegExp;
+use qt_core::QSortFilterProxyModel;
+use qt_core::QString;
+use qt_core::QTimer;
+use qt_core::QVariant;
+use qt_core::SlotOfBool;
+
+use cpp_core::CppBox;
+use cpp_core::Ptr;
+
+use anyhow::Result;
+use getset::*;
+
+use std::rc::Rc;
+use std::sync::{Arc, RwLock};
+
+use common_utils::sql::SQLScript;
+
+use rpfm_ui_common::locale::qtr;
+use rpfm_ui_common::utils::*;
+
+use crate::actions_ui::ActionsUI;
+
+use self::slots::ScriptsUISlots;
+
+mod slots;
+
+const VIEW_DEBUG: &str = "ui_templates/scripts_widget.ui";
+const VIEW_RELEASE: &str = "ui/scripts_widget.ui";
+
+/// Role of the items with the key of their script.
+const VALUE_SCRIPT_KEY: i32 = 21;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Debug, Getters)]
+#[getset(get = "pub")]
+pub struct ScriptsUI {
+    main_widget: QBox<QWidget>,
+    tree_view: QPtr<QTreeView>,
+    model: QBox<QStandardItemModel>,
+    filter: QBox<QSortFilterProxyModel>,
+    filter_line_edit: QPtr<QLineEdit>,
+    filter_case_sensitive_button: QPtr<QToolButton>,
+    filter_timer: QBox<QTimer>,
+
+    /// Scripts of the play menu, with their checkboxes.
+    scripts_to_execute: Arc<RwLock<Vec<(SQLScript, QBox<QCheckBox>)>>>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl ScriptsUI {
+
+    pub unsafe fn new(parent: &QBox<QTabWidget>, actions_ui: &ActionsUI) -> Result<Rc<Self>> {
+
+        // Load the UI Template.
+        let template_path = if cfg!(debug_assertions) { VIEW_DEBUG } else { VIEW_RELEASE };
+        let main_widget = load_template(parent, template_path)?;
+
+        let tree_view: QPtr<QTreeView> = find_widget(&main_widget.static_upcast(), "tree_view")?;
+        let filter_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "filter_line_edit")?;
+        let filter_case_sensitive_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "filter_case_sensitive_button")?;
+        filter_line_edit.set_placeholder_text(&qtr("scripts_filter"));
+
+        let model = QStandardItemModel::new_1a(&main_widget);
+        let filter = QSortFilterProxyModel::new_1a(&main_widget);
+        filter.set_source_model(&model);
+        filter.set_filter_key_column(-1);
+        tree_view.set_model(&filter);
+        tree_view.set_selection_mode(SelectionMode::SingleSelection);
+        tree_view.set_root_is_decorated(false);
+
+        let filter_timer = QTimer::new_1a(&main_widget);
+        filter_timer.set_single_shot(true);
+
+        parent.add_tab_2a(&main_widget, &qtr("scripts_panel_title"));
+
+        let list = Rc::new(Self {
+            main_widget,
+            tree_view,
+            model,
+            filter,
+            filter_line_edit,
+            filter_case_sensitive_button,
+            filter_timer,
+            scripts_to_execute: actions_ui.scripts_to_execute().clone(),
+        });
+
+        let slots = ScriptsUISlots::new(&list);
+        list.set_connections(&slots);
+
+        Ok(list)
+    }
+
+    pub unsafe fn set_connections(&self, slots: &ScriptsUISlots) {
+        self.model().item_changed().connect(slots.item_changed());
+        self.filter_line_edit().text_changed().connect(slots.filter_line_edit());
+        self.filter_case_sensitive_button().toggled().connect(slots.filter_case_sensitive_button());
+        self.filter_timer().timeout().connect(slots.filter_trigger());
+    }
+
+    /// This function reloads the panel with the scripts currently in the play menu.
+    ///
+    /// Automatic scripts are not shown, like in the play menu. Needs to be called every time the scripts of the play menu are rebuilt.
+    pub unsafe fn load(&self, actions_ui: &ActionsUI) {
+        self.model().clear();
+        self.setup_columns();
+
+        let extra_metadata = actions_ui.scripts_extra_metadata().read().unwrap();
+        for (script, checkbox) in self.scripts_to_execute().read().unwrap().iter().filter(|(script, _)| !*script.metadata().automatic()) {
+            let script_key = script.metadata().key().to_owned();
+            let row = QListOfQStandardItem::new();
+
+            let item_name = Self::new_item();
+            let item_category = Self::new_item();
+
+            item_name.set_text(&QString::from_std_str(script.metadata().name().trim()));
+            item_name.set_tool_tip(&QString::from_std_str(&script_key));
+            item_name.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(&script_key)), VALUE_SCRIPT_KEY);
+            item_name.set_checkable(true);
+            item_name.set_check_state(if checkbox.is_checked() { CheckState::Checked } else { CheckState::Unchecked });
+
+            // Destructive scripts get a warning icon, like in the play menu.
+            if extra_metadata.get(&script_key).map_or(false, |extra_metadata| *extra_metadata.destructive()) {
+                item_name.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("dialog-warning")));
+                item_name.set_tool_tip(&qtr("script_destructive"));
+            }
+
+            match ActionsUI::script_category(&extra_metadata, script) {
+                Some(category) => item_category.set_text(&QString::from_std_str(category)),
+                None => item_category.set_text(&qtr("scripts_category_uncategorized")),
+            }
+
+            // Checkboxes are deleted when the scripts are rebuilt, taking this slot with them. The item is searched by key,
+            // so a reload of the panel doesn't leave the slot pointing to a deleted item.
+            let model = self.model().as_ptr();
+            checkbox.toggled().connect(&SlotOfBool::new(checkbox, move |state| {
+                if let Some(item) = Self::item_by_key(model, &script_key) {
+                    let check_state = if state { CheckState::Checked } else { CheckState::Unchecked };
+                    if item.check_state() != check_state {
+                        item.set_check_state(check_state);
+                    }
+                }
+            }));
+
+            row.append_q_standard_item(&item_name.into_ptr().as_mut_raw_ptr());
+            row.append_q_standard_item(&item_category.into_ptr().as_mut_raw_ptr());
+            self.model().append_row_q_list_of_q_standard_item(row.into_ptr().as_ref().unwrap());
+        }
+
+        self.tree_view().resize_column_to_contents(0);
+
+        // Scripts may be locked in the play menu, like when the multiplayer-safe mode doesn't allow them.
+        self.tree_view().set_enabled(actions_ui.scripts_container().is_enabled());
+    }
+
+    /// This function toggles the checkbox of the script of the provided item in the play menu, if it doesn't match the item already.
+    ///
+    /// If the checkbox is locked, the item is reverted instead.
+    pub unsafe fn sync_item(&self, item: Ptr<QStandardItem>) {
+        if item.is_null() || !item.is_checkable() {
+            return;
+        }
+
+        let script_key = item.data_1a(VALUE_SCRIPT_KEY).to_string().to_std_string();
+        let state = item.check_state() == CheckState::Checked;
+        let scripts = self.scripts_to_execute().read().unwrap();
+        if let Some((_, checkbox)) = scripts.iter().find(|(script, _)| script.metadata().key() == script_key) {
+            if checkbox.is_checked() != state {
+                if checkbox.is_enabled() {
+                    checkbox.set_checked(state);
+                } else {
+                    item.set_check_state(if checkbox.is_checked() { CheckState::Checked } else { CheckState::Unchecked });
+                }
+            }
+        }
+    }
+
+    pub unsafe fn filter_list(&self) {
+
+        // Set the pattern to search.
+        let pattern = QRegExp::new_1a(&self.filter_line_edit.text());
+
+        // Check if the filter should be "Case Sensitive".
+        let case_sensitive = self.filter_case_sensitive_button.is_checked();
+        if case_sensitive { pattern.set_case_sensitivity(CaseSensitivity::CaseSensitive); }
+        else { pattern.set_case_sensitivity(CaseSensitivity::CaseInsensitive); }
+
+        // Filter whatever it's in that column by the text we got.
+        self.filter().set_filter_reg_exp_q_reg_exp(&pattern);
+    }
+
+    pub unsafe fn delayed_updates(&self) {
+        self.filter_timer.set_interval(500);
+        self.filter_timer.start_0a();
+    }
+
+    unsafe fn setup_columns(&self) {
+        self.model().set_column_count(2);
+        self.model().set_horizontal_header_item(0, QStandardItem::from_q_string(&qtr("scripts_panel_script")).into_ptr());
+        self.model().set_horizontal_header_item(1, QStandardItem::from_q_string(&qtr("scripts_panel_category")).into_ptr());
+    }
+
+    /// This function returns the item of the script with the provided key, if it's in the panel.
+    unsafe fn item_by_key(model: Ptr<QStandardItemModel>, script_key: &str) -> Option<Ptr<QStandardItem>> {
+        (0..model.row_count_0a())
+            .map(|row| model.item_1a(row))
+            .find(|item| !item.is_null() && item.data_1a(VALUE_SCRIPT_KEY).to_string().to_std_string() == script_key)
+    }
+
+    unsafe fn new_item() -> CppBox<QStandardItem> {
+        let item = QStandardItem::new();
+        item.set_editable(false);
+        item
+    }
+}
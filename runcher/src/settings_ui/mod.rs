@@ -20,27 +20,38 @@ use qt_widgets::{QFrame, q_frame::Shape};
 use qt_widgets::{QFileDialog, q_file_dialog::{FileMode, Option as QFileDialogOption}};
 use qt_widgets::QGridLayout;
 use qt_widgets::QGroupBox;
+use qt_widgets::QKeySequenceEdit;
 use qt_widgets::QLabel;
 use qt_widgets::QLineEdit;
 use qt_widgets::QMainWindow;
 use qt_widgets::QMenu;
+use qt_widgets::QPlainTextEdit;
 use qt_widgets::QPushButton;
+use qt_widgets::QSpinBox;
 use qt_widgets::QTableView;
 use qt_widgets::QToolButton;
 
 use qt_gui::QIcon;
+use qt_gui::QKeySequence;
 use qt_gui::QListOfQStandardItem;
 use qt_gui::QStandardItem;
 use qt_gui::QStandardItemModel;
 
 use qt_core::QBox;
+use qt_core::QByteArray;
 use qt_core::QFlags;
 use qt_core::QObject;
 use qt_core::QPtr;
+use qt_core::QSettings;
 use qt_core::QString;
+use qt_core::QStringList;
+use qt_core::QVariant;
+use qt_core::q_variant::Type;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use getset::*;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
 
 use std::cell::RefCell;
 use std::collections::BTreeMap;
@@ -58,7 +69,11 @@ use rpfm_ui_common::settings::*;
 use rpfm_ui_common::tools::*;
 use rpfm_ui_common::utils::*;
 
+use crate::actions_ui::{ActionsUI, LAUNCH_OPTIONS, SCRIPTS_ACTION_KEY, SCRIPTS_SORT_NAME, SHORTCUT_LAUNCH_OPTIONS, UNIT_MULTIPLIER_MAX_DEFAULT};
+use crate::app_ui::{AppliedSettings, AppUI};
+use crate::mod_manager::{font_coverage::CHECK_TRANSLATION_FONT_COVERAGE, integrations::{DOWNLOAD_MAX_CONCURRENT_MAX, DOWNLOAD_MAX_SPEED_MAX, LAST_LAUNCH_EXIT_CODE, LAST_LAUNCH_START_DATE}, launch_history::*, launch_hooks::{LAUNCH_HOOK_ON_EXIT, LAUNCH_HOOK_ON_LAUNCH}, mod_advisories::MOD_ADVISORIES_SOURCE, mod_source_setting_key, MOD_SOURCES, profile_backups::*, SECONDARY_MODS_EXTRA_PATHS};
 use crate::ffi::*;
+use crate::games::launch_option_defaults;
 use crate::SUPPORTED_GAMES;
 
 use self::slots::SettingsUISlots;
@@ -69,6 +84,31 @@ const SQL_SCRIPTS_EXTRACTED_FOLDER: &str = "sql_scripts_extracted";
 const SQL_SCRIPTS_LOCAL_FOLDER: &str = "sql_scripts_local";
 const SQL_SCRIPTS_REMOTE_FOLDER: &str = "sql_scripts_remote";
 
+/// Key of the setting with the custom folder for the packs generated on launch. Empty means the config folder is used.
+pub const GENERATED_PACKS_PATH: &str = "generated_packs_path";
+
+/// Key of the setting with what to do with the launcher once the game is launched, and its possible values.
+pub const POST_LAUNCH_ACTION: &str = "post_launch_action";
+pub const POST_LAUNCH_STAY_OPEN: i32 = 0;
+pub const POST_LAUNCH_MINIMIZE_TO_TRAY: i32 = 1;
+pub const POST_LAUNCH_CLOSE: i32 = 2;
+
+pub const MOD_FOLDERS_WATCHER: &str = "mod_folders_watcher";
+pub const MOD_FOLDERS_WATCHER_DISABLED: i32 = 0;
+pub const MOD_FOLDERS_WATCHER_ASK: i32 = 1;
+pub const MOD_FOLDERS_WATCHER_RELOAD: i32 = 2;
+
+/// Key of the setting to enable the installed dependencies of a mod when enabling it, without asking.
+pub const AUTO_ENABLE_DEPENDENCIES: &str = "auto_enable_dependencies";
+
+/// Prefixes of game settings kept when resetting a game to its defaults, as they record usage, not configuration.
+const RESET_GAME_KEPT_SETTINGS_PREFIXES: [&str; 4] = [
+    "game_pinned_",
+    "game_usage_count_",
+    LAST_LAUNCH_EXIT_CODE,
+    LAST_LAUNCH_START_DATE,
+];
+
 const VIEW_DEBUG: &str = "ui_templates/settings_dialog.ui";
 const VIEW_RELEASE: &str = "ui/settings_dialog.ui";
 
@@ -76,6 +116,19 @@ pub const SLASH_DMY_DATE_FORMAT_STR: &str = "[day]/[month]/[year]";
 pub const SLASH_MDY_DATE_FORMAT_STR: &str = "[month]/[day]/[year]";
 pub const SLASH_YMD_DATE_FORMAT_STR: &str = "[year]/[month]/[day]";
 
+/// Version of the settings. Bump it when a setting needs to be migrated, and add the migration to [migrate_settings].
+const SETTINGS_VERSION: i32 = 2;
+
+/// Settings keys renamed in each version, as (version, old key, new key). `{game}` is replaced with the key of each supported game.
+///
+/// Keys are renamed only on exact matches. To also rename the keys under a key (like the params of a script, under its key),
+/// end both keys with `*`: every key starting with the old one is renamed, keeping the rest of the key.
+const SETTINGS_RENAMED_KEYS: &[(i32, &str, &str)] = &[
+
+    // The setting of the skip intros launch option didn't match the key of the option.
+    (2, "enable_skip_intros_{game}", "enable_skip_intro_{game}"),
+];
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -86,13 +139,21 @@ pub struct SettingsUI {
     dialog: QPtr<QDialog>,
 
     font_data: Rc<RefCell<(String, i32)>>,
+    applied_settings: Rc<RefCell<AppliedSettings>>,
 
     paths_games_line_edits: BTreeMap<String, QBox<QLineEdit>>,
     paths_games_buttons: BTreeMap<String, QBox<QToolButton>>,
     paths_games_lock_checkboxes: BTreeMap<String, QBox<QCheckBox>>,
 
+    launch_options_visible_checkboxes: BTreeMap<String, QBox<QCheckBox>>,
+    launch_options_shortcut_edits: BTreeMap<String, QBox<QKeySequenceEdit>>,
+
     secondary_mods_folder_line_edit: QBox<QLineEdit>,
     secondary_mods_folder_button: QBox<QToolButton>,
+    secondary_mods_extra_folders_text_edit: QBox<QPlainTextEdit>,
+    secondary_mods_extra_folders_button: QBox<QToolButton>,
+    generated_packs_folder_line_edit: QBox<QLineEdit>,
+    generated_packs_folder_button: QBox<QToolButton>,
 
     tools_tableview: QPtr<QTableView>,
     tools_model: QBox<QStandardItemModel>,
@@ -112,30 +173,65 @@ pub struct SettingsUI {
     dark_mode_checkbox: QPtr<QCheckBox>,
     open_workshop_link_in_steam_checkbox: QPtr<QCheckBox>,
     check_logs_checkbox: QPtr<QCheckBox>,
+    tail_game_log_checkbox: QPtr<QCheckBox>,
+    unit_multiplier_max_spinbox: QPtr<QSpinBox>,
+    launch_wrapper_command_line_edit: QPtr<QLineEdit>,
+    launch_environment_variables_line_edit: QPtr<QLineEdit>,
+    launch_extra_args_line_edit: QPtr<QLineEdit>,
+    launch_hook_on_launch_line_edit: QPtr<QLineEdit>,
+    launch_hook_on_exit_line_edit: QPtr<QLineEdit>,
+    download_max_concurrent_spinbox: QPtr<QSpinBox>,
+    download_max_speed_spinbox: QPtr<QSpinBox>,
+    post_launch_action_combobox: QPtr<QComboBox>,
+    mod_list_color_enabled_checkbox: QPtr<QCheckBox>,
+    mod_folders_watcher_combobox: QPtr<QComboBox>,
+    profile_backups_interval_spinbox: QPtr<QSpinBox>,
+    profile_backups_retention_spinbox: QPtr<QSpinBox>,
+    launch_history_max_entries_spinbox: QPtr<QSpinBox>,
+    check_translation_font_coverage_checkbox: QPtr<QCheckBox>,
+    auto_enable_dependencies_checkbox: QPtr<QCheckBox>,
+    mod_advisories_source_line_edit: QPtr<QLineEdit>,
 
     font_button: QBox<QPushButton>,
     restore_default_button: QPtr<QPushButton>,
     accept_button: QPtr<QPushButton>,
+    apply_button: QPtr<QPushButton>,
     cancel_button: QPtr<QPushButton>,
 }
 
+/// Value of a setting stored outside the settings file, like in backups and configuration bundles, keeping its type.
+///
+/// Everything not listed here is stored as text, which is how the settings file keeps it anyway.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum SettingValue {
+    Bool(bool),
+    Int(i64),
+    String(String),
+    StringList(Vec<String>),
+    ByteArray(Vec<u8>),
+}
+
 //-------------------------------------------------------------------------------//
 //                             Implementations
 //-------------------------------------------------------------------------------//
 
 impl SettingsUI {
 
-    /// This function creates a ***Settings*** dialog, execute it, and returns a new `Settings`, or `None` if you close/cancel the dialog.
-    pub unsafe fn new(main_window: &QBox<QMainWindow>) -> Result<bool> {
-        let settings_ui = Self::new_with_parent(main_window)?;
-        let slots = SettingsUISlots::new(&settings_ui, main_window.static_upcast());
+    /// This function creates a ***Settings*** dialog, execute it, and returns if the settings were saved when closing the dialog.
+    ///
+    /// Settings saved with the apply button are applied to the UI as soon as they're saved, even if the dialog is cancelled later.
+    pub unsafe fn new(app_ui: &Rc<AppUI>) -> Result<bool> {
+        let settings_ui = Self::new_with_parent(app_ui.main_window())?;
+        let slots = SettingsUISlots::new(&settings_ui, app_ui);
         settings_ui.set_connections(&slots);
 
         // If load fails due to missing locale folder, show the error and cancel the settings edition.
         settings_ui.load()?;
+        *settings_ui.applied_settings().borrow_mut() = app_ui.applied_settings();
 
         if settings_ui.dialog.exec() == 1 {
             settings_ui.save()?;
+            app_ui.apply_settings(&mut settings_ui.applied_settings().borrow_mut());
             settings_ui.dialog.delete_later();
             Ok(true)
         } else {
@@ -174,6 +270,24 @@ impl SettingsUI {
         let dark_mode_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "dark_mode_label")?;
         let open_workshop_link_in_steam_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "open_workshop_link_in_steam_label")?;
         let check_logs_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "check_logs_label")?;
+        let tail_game_log_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "tail_game_log_label")?;
+        let unit_multiplier_max_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "unit_multiplier_max_label")?;
+        let launch_wrapper_command_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "launch_wrapper_command_label")?;
+        let launch_environment_variables_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "launch_environment_variables_label")?;
+        let launch_extra_args_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "launch_extra_args_label")?;
+        let launch_hook_on_launch_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "launch_hook_on_launch_label")?;
+        let launch_hook_on_exit_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "launch_hook_on_exit_label")?;
+        let download_max_concurrent_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "download_max_concurrent_label")?;
+        let download_max_speed_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "download_max_speed_label")?;
+        let post_launch_action_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "post_launch_action_label")?;
+        let mod_list_color_enabled_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "mod_list_color_enabled_label")?;
+        let mod_folders_watcher_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "mod_folders_watcher_label")?;
+        let profile_backups_interval_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "profile_backups_interval_label")?;
+        let profile_backups_retention_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "profile_backups_retention_label")?;
+        let launch_history_max_entries_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "launch_history_max_entries_label")?;
+        let check_translation_font_coverage_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "check_translation_font_coverage_label")?;
+        let auto_enable_dependencies_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "auto_enable_dependencies_label")?;
+        let mod_advisories_source_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "mod_advisories_source_label")?;
         let language_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "language_combobox")?;
         let default_game_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "default_game_combobox")?;
         let update_chanel_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "update_chanel_combobox")?;
@@ -185,6 +299,24 @@ impl SettingsUI {
         let dark_mode_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "dark_mode_checkbox")?;
         let open_workshop_link_in_steam_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "open_workshop_link_in_steam_checkbox")?;
         let check_logs_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "check_logs_checkbox")?;
+        let tail_game_log_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "tail_game_log_checkbox")?;
+        let unit_multiplier_max_spinbox: QPtr<QSpinBox> = find_widget(&main_widget.static_upcast(), "unit_multiplier_max_spinbox")?;
+        let launch_wrapper_command_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "launch_wrapper_command_line_edit")?;
+        let launch_environment_variables_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "launch_environment_variables_line_edit")?;
+        let launch_extra_args_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "launch_extra_args_line_edit")?;
+        let launch_hook_on_launch_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "launch_hook_on_launch_line_edit")?;
+        let launch_hook_on_exit_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "launch_hook_on_exit_line_edit")?;
+        let download_max_concurrent_spinbox: QPtr<QSpinBox> = find_widget(&main_widget.static_upcast(), "download_max_concurrent_spinbox")?;
+        let download_max_speed_spinbox: QPtr<QSpinBox> = find_widget(&main_widget.static_upcast(), "download_max_speed_spinbox")?;
+        let post_launch_action_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "post_launch_action_combobox")?;
+        let mod_list_color_enabled_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "mod_list_color_enabled_checkbox")?;
+        let mod_folders_watcher_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "mod_folders_watcher_combobox")?;
+        let profile_backups_interval_spinbox: QPtr<QSpinBox> = find_widget(&main_widget.static_upcast(), "profile_backups_interval_spinbox")?;
+        let profile_backups_retention_spinbox: QPtr<QSpinBox> = find_widget(&main_widget.static_upcast(), "profile_backups_retention_spinbox")?;
+        let launch_history_max_entries_spinbox: QPtr<QSpinBox> = find_widget(&main_widget.static_upcast(), "launch_history_max_entries_spinbox")?;
+        let check_translation_font_coverage_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "check_translation_font_coverage_checkbox")?;
+        let auto_enable_dependencies_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "auto_enable_dependencies_checkbox")?;
+        let mod_advisories_source_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "mod_advisories_source_line_edit")?;
         let paths_layout: QPtr<QGridLayout> = paths_groupbox.layout().static_downcast();
         update_chanel_combobox.add_item_q_string(&QString::from_std_str(STABLE));
         update_chanel_combobox.add_item_q_string(&QString::from_std_str(BETA));
@@ -204,6 +336,61 @@ impl SettingsUI {
         dark_mode_label.set_text(&qtr("dark_mode"));
         open_workshop_link_in_steam_label.set_text(&qtr("open_workshop_link_in_steam"));
         check_logs_label.set_text(&qtr("check_logs"));
+        tail_game_log_label.set_text(&qtr("tail_game_log"));
+        unit_multiplier_max_label.set_text(&qtr("unit_multiplier_max"));
+        launch_wrapper_command_label.set_text(&qtr("launch_wrapper_command"));
+        launch_environment_variables_label.set_text(&qtr("launch_environment_variables"));
+        launch_wrapper_command_line_edit.set_placeholder_text(&qtr("launch_wrapper_command_ph"));
+        launch_environment_variables_line_edit.set_placeholder_text(&qtr("launch_environment_variables_ph"));
+        launch_extra_args_label.set_text(&qtr("launch_extra_args"));
+        launch_extra_args_line_edit.set_placeholder_text(&qtr("launch_extra_args_ph"));
+        launch_hook_on_launch_label.set_text(&qtr("launch_hook_on_launch"));
+        launch_hook_on_exit_label.set_text(&qtr("launch_hook_on_exit"));
+        launch_hook_on_launch_line_edit.set_placeholder_text(&qtr("launch_hook_on_launch_ph"));
+        launch_hook_on_exit_line_edit.set_placeholder_text(&qtr("launch_hook_on_exit_ph"));
+        download_max_concurrent_label.set_text(&qtr("download_max_concurrent"));
+        download_max_concurrent_label.set_tool_tip(&qtr("download_max_concurrent_tt"));
+        download_max_concurrent_spinbox.set_range(0, DOWNLOAD_MAX_CONCURRENT_MAX);
+        download_max_concurrent_spinbox.set_special_value_text(&qtr("download_max_concurrent_unlimited"));
+        download_max_speed_label.set_text(&qtr("download_max_speed"));
+        download_max_speed_label.set_tool_tip(&qtr("download_max_speed_tt"));
+        download_max_speed_spinbox.set_range(0, DOWNLOAD_MAX_SPEED_MAX);
+        download_max_speed_spinbox.set_suffix(&qtr("download_max_speed_suffix"));
+        download_max_speed_spinbox.set_special_value_text(&qtr("download_max_concurrent_unlimited"));
+        post_launch_action_label.set_text(&qtr("post_launch_action"));
+        post_launch_action_label.set_tool_tip(&qtr("post_launch_action_tt"));
+        mod_list_color_enabled_label.set_text(&qtr("mod_list_color_enabled"));
+        mod_list_color_enabled_label.set_tool_tip(&qtr("mod_list_color_enabled_tt"));
+        mod_folders_watcher_label.set_text(&qtr("mod_folders_watcher"));
+        mod_folders_watcher_label.set_tool_tip(&qtr("mod_folders_watcher_tt"));
+        auto_enable_dependencies_label.set_text(&qtr("auto_enable_dependencies"));
+        auto_enable_dependencies_label.set_tool_tip(&qtr("auto_enable_dependencies_tt"));
+        profile_backups_interval_label.set_text(&qtr("profile_backups_interval"));
+        profile_backups_interval_label.set_tool_tip(&qtr("profile_backups_interval_tt"));
+        profile_backups_interval_spinbox.set_range(0, PROFILE_BACKUPS_INTERVAL_MAX);
+        profile_backups_interval_spinbox.set_suffix(&qtr("profile_backups_interval_suffix"));
+        profile_backups_interval_spinbox.set_special_value_text(&qtr("profile_backups_interval_disabled"));
+        profile_backups_retention_label.set_text(&qtr("profile_backups_retention"));
+        profile_backups_retention_label.set_tool_tip(&qtr("profile_backups_retention_tt"));
+        profile_backups_retention_spinbox.set_range(1, PROFILE_BACKUPS_RETENTION_MAX);
+        launch_history_max_entries_label.set_text(&qtr("launch_history_max_entries"));
+        launch_history_max_entries_label.set_tool_tip(&qtr("launch_history_max_entries_tt"));
+        launch_history_max_entries_spinbox.set_range(1, LAUNCH_HISTORY_MAX_ENTRIES_MAX);
+        check_translation_font_coverage_label.set_text(&qtr("check_translation_font_coverage"));
+        check_translation_font_coverage_label.set_tool_tip(&qtr("check_translation_font_coverage_tt"));
+        mod_advisories_source_label.set_text(&qtr("mod_advisories_source"));
+        mod_advisories_source_label.set_tool_tip(&qtr("mod_advisories_source_tt"));
+        mod_advisories_source_line_edit.set_placeholder_text(&qtr("mod_advisories_source_ph"));
+
+        // Order must match the POST_LAUNCH_* values, as we store the index.
+        post_launch_action_combobox.add_item_q_string(&qtr("post_launch_action_stay_open"));
+        post_launch_action_combobox.add_item_q_string(&qtr("post_launch_action_minimize_to_tray"));
+        post_launch_action_combobox.add_item_q_string(&qtr("post_launch_action_close"));
+
+        // Same with the MOD_FOLDERS_WATCHER_* values.
+        mod_folders_watcher_combobox.add_item_q_string(&qtr("mod_folders_watcher_disabled"));
+        mod_folders_watcher_combobox.add_item_q_string(&qtr("mod_folders_watcher_ask"));
+        mod_folders_watcher_combobox.add_item_q_string(&qtr("mod_folders_watcher_reload"));
 
         // Add one path at the beginning for the secondary mods folder.
         let secondary_mods_folder_label = QLabel::from_q_string_q_widget(&qtr("settings_secondary_mods_folder"), &paths_groupbox);
@@ -216,10 +403,34 @@ impl SettingsUI {
         paths_layout.add_widget_5a(&secondary_mods_folder_line_edit, 0, 1, 1, 1);
         paths_layout.add_widget_5a(&secondary_mods_folder_button, 0, 2, 1, 1);
 
+        // Below it, the additional secondary mods folders, one per line.
+        let secondary_mods_extra_folders_label = QLabel::from_q_string_q_widget(&qtr("settings_secondary_mods_extra_folders"), &paths_groupbox);
+        let secondary_mods_extra_folders_text_edit = QPlainTextEdit::from_q_widget(&paths_groupbox);
+        let secondary_mods_extra_folders_button = QToolButton::new_1a(&paths_groupbox);
+        secondary_mods_extra_folders_text_edit.set_placeholder_text(&qtr("settings_secondary_mods_extra_folders_ph"));
+        secondary_mods_extra_folders_text_edit.set_maximum_height(80);
+        secondary_mods_extra_folders_button.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("list-add")));
+        secondary_mods_extra_folders_button.set_tool_tip(&qtr("settings_secondary_mods_extra_folders_add"));
+
+        paths_layout.add_widget_5a(&secondary_mods_extra_folders_label, 1, 0, 1, 1);
+        paths_layout.add_widget_5a(&secondary_mods_extra_folders_text_edit, 1, 1, 1, 1);
+        paths_layout.add_widget_5a(&secondary_mods_extra_folders_button, 1, 2, 1, 1);
+
+        // Then another one for the folder where the merged packs and the script packs are generated.
+        let generated_packs_folder_label = QLabel::from_q_string_q_widget(&qtr("settings_generated_packs_folder"), &paths_groupbox);
+        let generated_packs_folder_line_edit = QLineEdit::from_q_widget(&paths_groupbox);
+        let generated_packs_folder_button = QToolButton::new_1a(&paths_groupbox);
+        generated_packs_folder_line_edit.set_placeholder_text(&qtr("settings_generated_packs_folder_ph"));
+        generated_packs_folder_button.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("folder")));
+
+        paths_layout.add_widget_5a(&generated_packs_folder_label, 2, 0, 1, 1);
+        paths_layout.add_widget_5a(&generated_packs_folder_line_edit, 2, 1, 1, 1);
+        paths_layout.add_widget_5a(&generated_packs_folder_button, 2, 2, 1, 1);
+
         // TODO: Maybe add a separator here.
         let line = QFrame::new_1a(&paths_groupbox);
         line.set_frame_shape(Shape::HLine);
-        paths_layout.add_widget_5a(&line, 1, 0, 1, 3);
+        paths_layout.add_widget_5a(&line, 3, 0, 1, 3);
 
         // We automatically add a Label/LineEdit/Button for each game we support.
         let mut paths_games_line_edits = BTreeMap::new();
@@ -237,10 +448,10 @@ impl SettingsUI {
                 game_line_edit.set_placeholder_text(&qtre("settings_game_line_ph", &[game.display_name()]));
                 game_button.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("folder")));
 
-                paths_layout.add_widget_5a(&game_label, index as i32 + 2, 0, 1, 1);
-                paths_layout.add_widget_5a(&game_line_edit, index as i32 + 2, 1, 1, 1);
-                paths_layout.add_widget_5a(&game_button, index as i32 + 2, 2, 1, 1);
-                paths_layout.add_widget_5a(&game_lock_checkbox, index as i32 + 2, 3, 1, 1);
+                paths_layout.add_widget_5a(&game_label, index as i32 + 4, 0, 1, 1);
+                paths_layout.add_widget_5a(&game_line_edit, index as i32 + 4, 1, 1, 1);
+                paths_layout.add_widget_5a(&game_button, index as i32 + 4, 2, 1, 1);
+                paths_layout.add_widget_5a(&game_lock_checkbox, index as i32 + 4, 3, 1, 1);
 
                 // Add the LineEdit and Button to the list.
                 paths_games_line_edits.insert(game_key.to_owned(), game_line_edit);
@@ -252,6 +463,36 @@ impl SettingsUI {
             }
         }
 
+        // One checkbox per launch option, to choose which ones show up in the play menu.
+        let launch_options_groupbox: QPtr<QGroupBox> = find_widget(&main_widget.static_upcast(), "launch_options_groupbox")?;
+        let launch_options_layout: QPtr<QGridLayout> = launch_options_groupbox.layout().static_downcast();
+        launch_options_groupbox.set_title(&qtr("launch_options_visible"));
+        launch_options_groupbox.set_tool_tip(&qtr("launch_options_visible_tt"));
+
+        let mut launch_options_visible_checkboxes = BTreeMap::new();
+        for (index, (key, _)) in LAUNCH_OPTIONS.iter().enumerate() {
+            let text = if *key == SCRIPTS_ACTION_KEY { qtr("launch_options_scripts") } else { qtr(key) };
+            let checkbox = QCheckBox::from_q_string_q_widget(&text, &launch_options_groupbox);
+            launch_options_layout.add_widget_5a(&checkbox, index as i32 / 4, index as i32 % 4, 1, 1);
+            launch_options_visible_checkboxes.insert(key.to_string(), checkbox);
+        }
+
+        // Below them, one shortcut editor per launch option that can be toggled, two per row.
+        let shortcuts_row = (LAUNCH_OPTIONS.len() as i32 + 3) / 4;
+        let shortcuts_label = QLabel::from_q_string_q_widget(&qtr("launch_options_shortcuts"), &launch_options_groupbox);
+        launch_options_layout.add_widget_5a(&shortcuts_label, shortcuts_row, 0, 1, 4);
+
+        let mut launch_options_shortcut_edits = BTreeMap::new();
+        for (index, key) in SHORTCUT_LAUNCH_OPTIONS.iter().enumerate() {
+            let label = QLabel::from_q_string_q_widget(&qtr(key), &launch_options_groupbox);
+            let edit = QKeySequenceEdit::from_q_widget(&launch_options_groupbox);
+            let row = shortcuts_row + 1 + index as i32 / 2;
+            let column = (index as i32 % 2) * 2;
+            launch_options_layout.add_widget_5a(&label, row, column, 1, 1);
+            launch_options_layout.add_widget_5a(&edit, row, column + 1, 1, 1);
+            launch_options_shortcut_edits.insert(key.to_string(), edit);
+        }
+
         if let Ok(locales) = Locale::get_available_locales() {
             for (language, _) in locales {
                 language_combobox.add_item_q_string(&QString::from_std_str(language));
@@ -264,11 +505,13 @@ impl SettingsUI {
 
         let restore_default_button: QPtr<QPushButton> = button_box.button(StandardButton::RestoreDefaults);
         let accept_button: QPtr<QPushButton> = button_box.button(StandardButton::Ok);
+        let apply_button: QPtr<QPushButton> = button_box.button(StandardButton::Apply);
         let cancel_button: QPtr<QPushButton> = button_box.button(StandardButton::Cancel);
 
         Ok(Rc::new(Self {
             dialog,
             font_data: Rc::new(RefCell::new((String::new(), -1))),
+            applied_settings: Rc::new(RefCell::new(AppliedSettings::default())),
 
             tools_tableview,
             tools_model,
@@ -280,8 +523,15 @@ impl SettingsUI {
             paths_games_buttons,
             paths_games_lock_checkboxes,
 
+            launch_options_visible_checkboxes,
+            launch_options_shortcut_edits,
+
             secondary_mods_folder_line_edit,
             secondary_mods_folder_button,
+            secondary_mods_extra_folders_text_edit,
+            secondary_mods_extra_folders_button,
+            generated_packs_folder_line_edit,
+            generated_packs_folder_button,
 
             steam_api_key_line_edit,
             language_combobox,
@@ -294,10 +544,29 @@ impl SettingsUI {
             dark_mode_checkbox,
             open_workshop_link_in_steam_checkbox,
             check_logs_checkbox,
+            tail_game_log_checkbox,
+            unit_multiplier_max_spinbox,
+            launch_wrapper_command_line_edit,
+            launch_environment_variables_line_edit,
+            launch_extra_args_line_edit,
+            launch_hook_on_launch_line_edit,
+            launch_hook_on_exit_line_edit,
+            download_max_concurrent_spinbox,
+            download_max_speed_spinbox,
+            post_launch_action_combobox,
+            mod_list_color_enabled_checkbox,
+            mod_folders_watcher_combobox,
+            profile_backups_interval_spinbox,
+            profile_backups_retention_spinbox,
+            launch_history_max_entries_spinbox,
+            check_translation_font_coverage_checkbox,
+            auto_enable_dependencies_checkbox,
+            mod_advisories_source_line_edit,
 
             font_button,
             restore_default_button,
             accept_button,
+            apply_button,
             cancel_button,
         }))
     }
@@ -342,6 +611,10 @@ impl SettingsUI {
             self.secondary_mods_folder_line_edit().set_text(&QString::from_std_str(secondary_mods_path));
         }
 
+        self.secondary_mods_extra_folders_text_edit().set_plain_text(&QString::from_std_str(setting_string_from_q_setting(&q_settings, SECONDARY_MODS_EXTRA_PATHS)));
+
+        self.generated_packs_folder_line_edit().set_text(&QString::from_std_str(setting_string_from_q_setting(&q_settings, GENERATED_PACKS_PATH)));
+
         // Load the Game Paths, if they exists.
         for (key, path) in self.paths_games_line_edits.iter() {
             let stored_path = setting_string_from_q_setting(&q_settings, key);
@@ -367,6 +640,16 @@ impl SettingsUI {
             }
         }
 
+        let hidden_launch_options = setting_string_from_q_setting(&q_settings, "hidden_launch_options");
+        for (key, checkbox) in self.launch_options_visible_checkboxes().iter() {
+            checkbox.set_checked(!hidden_launch_options.split(',').any(|hidden_key| hidden_key == key));
+        }
+
+        for (key, edit) in self.launch_options_shortcut_edits().iter() {
+            let sequence = setting_string_from_q_setting(&q_settings, &ActionsUI::launch_option_shortcut_setting(key));
+            edit.set_key_sequence(&QKeySequence::from_q_string(&QString::from_std_str(sequence)));
+        }
+
         // Get the default game.
         let default_game = setting_string_from_q_setting(&q_settings, "default_game");
         for (index, game) in SUPPORTED_GAMES.games_sorted().iter().enumerate() {
@@ -410,6 +693,24 @@ impl SettingsUI {
         self.check_schema_updates_on_start_checkbox().set_checked(setting_bool_from_q_setting(&q_settings, "check_schema_updates_on_start"));
         self.check_sql_scripts_updates_on_start_checkbox().set_checked(setting_bool_from_q_setting(&q_settings, "check_sql_scripts_updates_on_start"));
         self.check_logs_checkbox().set_checked(setting_bool_from_q_setting(&q_settings, "check_logs"));
+        self.tail_game_log_checkbox().set_checked(setting_bool_from_q_setting(&q_settings, "tail_game_log"));
+        self.unit_multiplier_max_spinbox().set_value(setting_int_from_q_setting(&q_settings, "unit_multiplier_max"));
+        self.launch_wrapper_command_line_edit().set_text(&QString::from_std_str(setting_string_from_q_setting(&q_settings, "launch_wrapper_command")));
+        self.launch_environment_variables_line_edit().set_text(&QString::from_std_str(setting_string_from_q_setting(&q_settings, "launch_environment_variables")));
+        self.launch_extra_args_line_edit().set_text(&QString::from_std_str(setting_string_from_q_setting(&q_settings, "launch_extra_args")));
+        self.launch_hook_on_launch_line_edit().set_text(&QString::from_std_str(setting_string_from_q_setting(&q_settings, LAUNCH_HOOK_ON_LAUNCH)));
+        self.launch_hook_on_exit_line_edit().set_text(&QString::from_std_str(setting_string_from_q_setting(&q_settings, LAUNCH_HOOK_ON_EXIT)));
+        self.download_max_concurrent_spinbox().set_value(setting_int_from_q_setting(&q_settings, "download_max_concurrent"));
+        self.download_max_speed_spinbox().set_value(setting_int_from_q_setting(&q_settings, "download_max_speed"));
+        self.post_launch_action_combobox().set_current_index(setting_int_from_q_setting(&q_settings, POST_LAUNCH_ACTION));
+        self.mod_list_color_enabled_checkbox().set_checked(setting_bool_from_q_setting(&q_settings, "mod_list_color_enabled"));
+        self.mod_folders_watcher_combobox().set_current_index(setting_int_from_q_setting(&q_settings, MOD_FOLDERS_WATCHER));
+        self.profile_backups_interval_spinbox().set_value(setting_int_from_q_setting(&q_settings, PROFILE_BACKUPS_INTERVAL));
+        self.profile_backups_retention_spinbox().set_value(setting_int_from_q_setting(&q_settings, PROFILE_BACKUPS_RETENTION));
+        self.launch_history_max_entries_spinbox().set_value(setting_int_from_q_setting(&q_settings, LAUNCH_HISTORY_MAX_ENTRIES));
+        self.check_translation_font_coverage_checkbox().set_checked(setting_bool_from_q_setting(&q_settings, CHECK_TRANSLATION_FONT_COVERAGE));
+        self.auto_enable_dependencies_checkbox().set_checked(setting_bool_from_q_setting(&q_settings, AUTO_ENABLE_DEPENDENCIES));
+        self.mod_advisories_source_line_edit().set_text(&QString::from_std_str(setting_string_from_q_setting(&q_settings, MOD_ADVISORIES_SOURCE)));
 
         Ok(())
     }
@@ -437,6 +738,14 @@ impl SettingsUI {
         let q_settings = settings();
         set_setting_string_to_q_setting(&q_settings, "secondary_mods_path", &self.secondary_mods_folder_line_edit().text().to_std_string());
 
+        let secondary_mods_extra_paths = self.secondary_mods_extra_folders_text_edit().to_plain_text().to_std_string()
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .join("\n");
+        set_setting_string_to_q_setting(&q_settings, SECONDARY_MODS_EXTRA_PATHS, &secondary_mods_extra_paths);
+        set_setting_string_to_q_setting(&q_settings, GENERATED_PACKS_PATH, self.generated_packs_folder_line_edit().text().to_std_string().trim());
+
         for (key, line_edit) in self.paths_games_line_edits.iter() {
             set_setting_string_to_q_setting(&q_settings, key, &line_edit.text().to_std_string());
         }
@@ -467,6 +776,34 @@ impl SettingsUI {
         set_setting_bool_to_q_setting(&q_settings, "check_schema_updates_on_start", self.check_schema_updates_on_start_checkbox().is_checked());
         set_setting_bool_to_q_setting(&q_settings, "check_sql_scripts_updates_on_start", self.check_sql_scripts_updates_on_start_checkbox().is_checked());
         set_setting_bool_to_q_setting(&q_settings, "check_logs", self.check_logs_checkbox().is_checked());
+        set_setting_bool_to_q_setting(&q_settings, "tail_game_log", self.tail_game_log_checkbox().is_checked());
+        set_setting_int_to_q_setting(&q_settings, "unit_multiplier_max", self.unit_multiplier_max_spinbox().value());
+        set_setting_string_to_q_setting(&q_settings, "launch_wrapper_command", &self.launch_wrapper_command_line_edit().text().to_std_string());
+        set_setting_string_to_q_setting(&q_settings, "launch_environment_variables", &self.launch_environment_variables_line_edit().text().to_std_string());
+        set_setting_string_to_q_setting(&q_settings, "launch_extra_args", &self.launch_extra_args_line_edit().text().to_std_string());
+        set_setting_string_to_q_setting(&q_settings, LAUNCH_HOOK_ON_LAUNCH, &self.launch_hook_on_launch_line_edit().text().to_std_string());
+        set_setting_string_to_q_setting(&q_settings, LAUNCH_HOOK_ON_EXIT, &self.launch_hook_on_exit_line_edit().text().to_std_string());
+        set_setting_int_to_q_setting(&q_settings, "download_max_concurrent", self.download_max_concurrent_spinbox().value());
+        set_setting_int_to_q_setting(&q_settings, "download_max_speed", self.download_max_speed_spinbox().value());
+        set_setting_int_to_q_setting(&q_settings, POST_LAUNCH_ACTION, self.post_launch_action_combobox().current_index());
+        set_setting_bool_to_q_setting(&q_settings, "mod_list_color_enabled", self.mod_list_color_enabled_checkbox().is_checked());
+        set_setting_int_to_q_setting(&q_settings, MOD_FOLDERS_WATCHER, self.mod_folders_watcher_combobox().current_index());
+        set_setting_int_to_q_setting(&q_settings, PROFILE_BACKUPS_INTERVAL, self.profile_backups_interval_spinbox().value());
+        set_setting_int_to_q_setting(&q_settings, PROFILE_BACKUPS_RETENTION, self.profile_backups_retention_spinbox().value());
+        set_setting_int_to_q_setting(&q_settings, LAUNCH_HISTORY_MAX_ENTRIES, self.launch_history_max_entries_spinbox().value());
+        set_setting_bool_to_q_setting(&q_settings, CHECK_TRANSLATION_FONT_COVERAGE, self.check_translation_font_coverage_checkbox().is_checked());
+        set_setting_bool_to_q_setting(&q_settings, AUTO_ENABLE_DEPENDENCIES, self.auto_enable_dependencies_checkbox().is_checked());
+        set_setting_string_to_q_setting(&q_settings, MOD_ADVISORIES_SOURCE, self.mod_advisories_source_line_edit().text().to_std_string().trim());
+
+        let hidden_launch_options = self.launch_options_visible_checkboxes().iter()
+            .filter(|(_, checkbox)| !checkbox.is_checked())
+            .map(|(key, _)| key)
+            .join(",");
+        set_setting_string_to_q_setting(&q_settings, "hidden_launch_options", &hidden_launch_options);
+
+        for (key, edit) in self.launch_options_shortcut_edits().iter() {
+            set_setting_string_to_q_setting(&q_settings, &ActionsUI::launch_option_shortcut_setting(key), &edit.key_sequence().to_string_0a().to_std_string());
+        }
 
         // Save the settings.
         q_settings.sync();
@@ -476,6 +813,8 @@ impl SettingsUI {
 
     pub unsafe fn set_connections(&self, slots: &SettingsUISlots) {
         self.secondary_mods_folder_button().released().connect(slots.select_secondary_mods_path());
+        self.secondary_mods_extra_folders_button().released().connect(slots.add_secondary_mods_extra_path());
+        self.generated_packs_folder_button().released().connect(slots.select_generated_packs_path());
         for (key, button) in self.paths_games_buttons.iter() {
             button.released().connect(&slots.select_game_paths()[key]);
         }
@@ -493,7 +832,8 @@ impl SettingsUI {
 
         self.font_button.released().connect(slots.font_settings());
         self.restore_default_button.released().connect(slots.restore_default());
-        self.accept_button.released().connect(self.dialog.slot_accept());
+        self.accept_button.released().connect(slots.accept());
+        self.apply_button.released().connect(slots.apply());
         self.cancel_button.released().connect(self.dialog.slot_close());
     }
 
@@ -540,8 +880,27 @@ impl SettingsUI {
         };
     }
 
-    unsafe fn update_secondary_mods_path(&self) {
-        let line_edit = self.secondary_mods_folder_line_edit();
+    unsafe fn update_folder_path(&self, line_edit: &QBox<QLineEdit>) {
+        if let Some(path) = self.select_folder(&line_edit.text().to_std_string()) {
+            line_edit.set_text(&QString::from_std_str(path));
+        }
+    }
+
+    /// This function adds a folder to the list of additional secondary mods folders.
+    unsafe fn add_secondary_mods_extra_path(&self) {
+        let text_edit = self.secondary_mods_extra_folders_text_edit();
+        let text = text_edit.to_plain_text().to_std_string();
+        let last_path = text.lines().map(|line| line.trim()).filter(|line| !line.is_empty()).last().unwrap_or_default().to_owned();
+
+        if let Some(path) = self.select_folder(&last_path) {
+            if !text.lines().any(|line| line.trim() == path) {
+                text_edit.append_plain_text(&QString::from_std_str(path));
+            }
+        }
+    }
+
+    /// This function opens a dialog to select a folder, starting at the provided path if it exists.
+    unsafe fn select_folder(&self, old_path: &str) -> Option<String> {
 
         // Create the `FileDialog` and configure it.
         let title = qtr("settings_select_folder");
@@ -553,12 +912,9 @@ impl SettingsUI {
         file_dialog.set_file_mode(FileMode::Directory);
         file_dialog.set_options(QFlags::from(QFileDialogOption::ShowDirsOnly));
 
-        // Get the old Path, if exists.
-        let old_path = line_edit.text().to_std_string();
-
-        // If said path is not empty, and is a dir, set it as the initial directory.
-        if !old_path.is_empty() && Path::new(&old_path).is_dir() {
-            file_dialog.set_directory_q_string(&line_edit.text());
+        // If the old path is not empty, and is a dir, set it as the initial directory.
+        if !old_path.is_empty() && Path::new(old_path).is_dir() {
+            file_dialog.set_directory_q_string(&QString::from_std_str(old_path));
         }
 
         // Run it and expect a response (1 => Accept, 0 => Cancel).
@@ -566,11 +922,21 @@ impl SettingsUI {
 
             // Get the path of the selected file.
             let selected_files = file_dialog.selected_files();
-            let path = selected_files.at(0);
+            Some(selected_files.at(0).to_std_string())
+        } else {
+            None
+        }
+    }
 
-            // Add the Path to the LineEdit.
-            line_edit.set_text(path);
+    /// This function checks that the settings that need it are valid, before accepting the dialog.
+    unsafe fn validate(&self) -> Result<()> {
+        let generated_packs_path = self.generated_packs_folder_line_edit().text().to_std_string();
+        let generated_packs_path = generated_packs_path.trim();
+        if !generated_packs_path.is_empty() && !is_folder_writable(Path::new(generated_packs_path)) {
+            return Err(anyhow!(tre("settings_generated_packs_folder_not_writable", &[generated_packs_path])));
         }
+
+        Ok(())
     }
 
     unsafe fn update_lock_status(&self, game: &str, game_path: &Path, toggle: bool) {
@@ -591,6 +957,44 @@ impl SettingsUI {
     }
 }
 
+impl SettingValue {
+
+    /// This function reads the value of the provided setting, keeping its type.
+    pub unsafe fn from_q_setting(q_settings: &QBox<QSettings>, key: &str) -> Self {
+        let value = setting_variant_from_q_setting(q_settings, key);
+        match value.type_() {
+            Type::Bool => Self::Bool(value.to_bool()),
+            Type::Int | Type::UInt | Type::LongLong => Self::Int(value.to_long_long_0a()),
+            Type::StringList => {
+                let list = value.to_string_list();
+                Self::StringList((0..list.size()).map(|index| list.at(index).to_std_string()).collect())
+            }
+            Type::ByteArray => {
+                let bytes = value.to_byte_array();
+                Self::ByteArray(std::slice::from_raw_parts(bytes.const_data() as *const u8, bytes.size() as usize).to_vec())
+            }
+            _ => Self::String(value.to_string().to_std_string()),
+        }
+    }
+
+    /// This function writes the value to the provided setting, with its original type.
+    pub unsafe fn to_q_setting(&self, q_settings: &QBox<QSettings>, key: &str) {
+        let value = match self {
+            Self::Bool(value) => QVariant::from_bool(*value),
+            Self::Int(value) => QVariant::from_i64(*value),
+            Self::String(value) => QVariant::from_q_string(&QString::from_std_str(value)),
+            Self::StringList(values) => {
+                let list = QStringList::new();
+                values.iter().for_each(|value| list.append_q_string(&QString::from_std_str(value)));
+                QVariant::from_q_string_list(&list)
+            }
+            Self::ByteArray(value) => QVariant::from_q_byte_array(&QByteArray::from_slice(value)),
+        };
+
+        set_setting_variant_to_q_setting(q_settings, key, value.as_ref());
+    }
+}
+
 //-------------------------------------------------------------------------------//
 //                         Setting-related functions
 //-------------------------------------------------------------------------------//
@@ -598,6 +1002,9 @@ impl SettingsUI {
 pub unsafe fn init_settings(main_window: &QPtr<QMainWindow>) {
     let q_settings = settings();
 
+    // Migrate old settings before filling the missing ones, or we'll end up with the defaults on the renamed keys.
+    migrate_settings(&q_settings);
+
     set_setting_if_new_q_byte_array(&q_settings, "originalGeometry", main_window.save_geometry().as_ref());
     set_setting_if_new_q_byte_array(&q_settings, "originalWindowState", main_window.save_state_0a().as_ref());
 
@@ -612,11 +1019,6 @@ pub unsafe fn init_settings(main_window: &QPtr<QMainWindow>) {
     set_setting_if_new_string(&q_settings, "steam_api_key", "");
     set_setting_if_new_string(&q_settings, "default_game", KEY_WARHAMMER_3);
 
-    // Fix for bugged update channels.
-    if setting_string("update_channel") == "stable" {
-        set_setting_string("update_channel", STABLE);
-    }
-
     set_setting_if_new_string(&q_settings, "update_channel", STABLE);
     set_setting_if_new_string(&q_settings, "language", "English_en");
     set_setting_if_new_string(&q_settings, "date_format", SLASH_DMY_DATE_FORMAT_STR);
@@ -625,19 +1027,36 @@ pub unsafe fn init_settings(main_window: &QPtr<QMainWindow>) {
     set_setting_if_new_bool(&q_settings, "check_sql_scripts_updates_on_start", true);
     set_setting_if_new_bool(&q_settings, "dark_mode", false);
     set_setting_if_new_bool(&q_settings, "check_logs", false);
+    set_setting_if_new_bool(&q_settings, "tail_game_log", false);
+    set_setting_if_new_bool(&q_settings, "copy_load_order_markdown_names_only", false);
+    set_setting_if_new_int(&q_settings, "unit_multiplier_max", UNIT_MULTIPLIER_MAX_DEFAULT);
+    set_setting_if_new_string(&q_settings, "launch_wrapper_command", "");
+    set_setting_if_new_string(&q_settings, "launch_environment_variables", "");
+    set_setting_if_new_string(&q_settings, "launch_extra_args", "");
+    set_setting_if_new_string(&q_settings, LAUNCH_HOOK_ON_LAUNCH, "");
+    set_setting_if_new_string(&q_settings, LAUNCH_HOOK_ON_EXIT, "");
+    set_setting_if_new_int(&q_settings, "download_max_concurrent", 0);
+    set_setting_if_new_int(&q_settings, "download_max_speed", 0);
+    set_setting_if_new_int(&q_settings, POST_LAUNCH_ACTION, POST_LAUNCH_STAY_OPEN);
+    set_setting_if_new_bool(&q_settings, "mod_list_color_enabled", true);
+    set_setting_if_new_int(&q_settings, MOD_FOLDERS_WATCHER, MOD_FOLDERS_WATCHER_DISABLED);
+    set_setting_if_new_int(&q_settings, PROFILE_BACKUPS_INTERVAL, PROFILE_BACKUPS_INTERVAL_DEFAULT);
+    set_setting_if_new_int(&q_settings, PROFILE_BACKUPS_RETENTION, PROFILE_BACKUPS_RETENTION_DEFAULT);
+    set_setting_if_new_int(&q_settings, LAUNCH_HISTORY_MAX_ENTRIES, LAUNCH_HISTORY_MAX_ENTRIES_DEFAULT);
+    set_setting_if_new_bool(&q_settings, CHECK_TRANSLATION_FONT_COVERAGE, false);
+    set_setting_if_new_bool(&q_settings, AUTO_ENABLE_DEPENDENCIES, false);
+    set_setting_if_new_string(&q_settings, MOD_ADVISORIES_SOURCE, "");
+    set_setting_if_new_string(&q_settings, GENERATED_PACKS_PATH, "");
+    set_setting_if_new_string(&q_settings, "launch_options_order", "");
+    set_setting_if_new_string(&q_settings, "hidden_launch_options", "");
+    for key in SHORTCUT_LAUNCH_OPTIONS {
+        set_setting_if_new_string(&q_settings, &ActionsUI::launch_option_shortcut_setting(key), "");
+    }
+    set_setting_if_new_int(&q_settings, "scripts_sort", SCRIPTS_SORT_NAME);
 
     for game in &SUPPORTED_GAMES.games_sorted() {
         if game.key() != KEY_ARENA {
-            set_setting_if_new_bool(&q_settings, &format!("enable_logging_{}", game.key()), false);
-            set_setting_if_new_bool(&q_settings, &format!("enable_skip_intros_{}", game.key()), false);
-            set_setting_if_new_bool(&q_settings, &format!("remove_trait_limit_{}", game.key()), false);
-            set_setting_if_new_bool(&q_settings, &format!("remove_siege_attacker_{}", game.key()), false);
-            set_setting_if_new_bool(&q_settings, &format!("merge_all_mods_{}", game.key()), false);
-            set_setting_if_new_string(&q_settings, &format!("enable_translations_{}", game.key()), "--");
-            set_setting_if_new_f32(&q_settings, &format!("unit_multiplier_{}", game.key()), 1.0);
-            set_setting_if_new_string(&q_settings, &format!("universal_rebalancer_{}", game.key()), "--");
-            set_setting_if_new_bool(&q_settings, &format!("enable_dev_only_ui_{}", game.key()), false);
-            set_setting_if_new_string(&q_settings, &format!("scripts_to_execute_{}", game.key()), "");
+            init_game_settings(&q_settings, game);
 
             let game_path = if let Ok(Some(game_path)) = game.find_game_install_location() {
                 game_path.to_string_lossy().to_string()
@@ -658,6 +1077,144 @@ pub unsafe fn init_settings(main_window: &QPtr<QMainWindow>) {
     q_settings.sync();
 }
 
+/// This function sets the default values of the settings of the provided game that are missing.
+unsafe fn init_game_settings(q_settings: &QBox<QSettings>, game: &GameInfo) {
+    set_setting_if_new_bool(q_settings, &format!("game_pinned_{}", game.key()), false);
+    set_setting_if_new_int(q_settings, &format!("game_usage_count_{}", game.key()), 0);
+    set_setting_if_new_string(q_settings, &format!("scripts_to_execute_{}", game.key()), "");
+
+    // Launch options only get their defaults the first time, so they don't override the values set by the user.
+    for (key, default) in launch_option_defaults(game.key()) {
+        set_setting_if_new_string(q_settings, &format!("{}_{}", key, game.key()), default);
+    }
+
+    for source in MOD_SOURCES {
+        set_setting_if_new_bool(q_settings, &mod_source_setting_key(game.key(), source), true);
+    }
+}
+
+/// This function removes the settings of the provided game, and restores their defaults.
+///
+/// The path of the game, and the settings that record usage instead of configuration, are kept. Settings of other games and global settings are not touched.
+pub unsafe fn reset_game_settings(game: &GameInfo) {
+    let q_settings = settings();
+    for key in game_setting_keys(&q_settings, game) {
+        if !RESET_GAME_KEPT_SETTINGS_PREFIXES.iter().any(|prefix| key.starts_with(prefix)) {
+            q_settings.remove(&QString::from_std_str(&key));
+        }
+    }
+
+    init_game_settings(&q_settings, game);
+    q_settings.sync();
+}
+
+/// This function returns the keys of the settings of the provided game.
+///
+/// Game settings have the game key at the end or in the middle of their key. As some game keys start with the key of another game
+/// (like warhammer and warhammer_2), a setting belongs to the longest game key found in it.
+unsafe fn game_setting_keys(q_settings: &QBox<QSettings>, game: &GameInfo) -> Vec<String> {
+    let game_keys = SUPPORTED_GAMES.games_sorted()
+        .iter()
+        .filter(|game| game.key() != KEY_ARENA)
+        .map(|game| game.key().to_owned())
+        .collect::<Vec<_>>();
+
+    let all_keys = q_settings.all_keys();
+    (0..all_keys.size())
+        .map(|index| all_keys.at(index).to_std_string())
+        .filter(|key| {
+            game_keys.iter()
+                .filter(|game_key| key.ends_with(&format!("_{game_key}")) || key.contains(&format!("_{game_key}_")))
+                .max_by_key(|game_key| game_key.len())
+                .map_or(false, |game_key| game_key == game.key())
+        })
+        .collect()
+}
+
+/// This function migrates the settings from older versions of the program to the current one.
+///
+/// Each migration is executed only once, as the version of the settings is saved after migrating them.
+unsafe fn migrate_settings(q_settings: &QBox<QSettings>) {
+    let version = setting_int_from_q_setting(q_settings, "settings_version");
+    if version >= SETTINGS_VERSION {
+        return;
+    }
+
+    // Fix for bugged update channels.
+    if version < 1 && setting_string_from_q_setting(q_settings, "update_channel") == "stable" {
+        set_setting_string_to_q_setting(q_settings, "update_channel", STABLE);
+    }
+
+    let all_keys = q_settings.all_keys();
+    let all_keys = (0..all_keys.size()).map(|index| all_keys.at(index).to_std_string()).collect::<Vec<_>>();
+    let game_keys = renamed_keys_games();
+
+    for key in &all_keys {
+        if let Some(renamed_key) = renamed_key(SETTINGS_RENAMED_KEYS, version, key, &game_keys) {
+
+            // Do not overwrite values already set under the new key.
+            if !q_settings.contains(&QString::from_std_str(&renamed_key)) {
+                q_settings.set_value(&QString::from_std_str(&renamed_key), &q_settings.value_1a(&QString::from_std_str(key)));
+            }
+
+            q_settings.remove(&QString::from_std_str(key));
+        }
+    }
+
+    set_setting_int_to_q_setting(q_settings, "settings_version", SETTINGS_VERSION);
+    q_settings.sync();
+}
+
+/// This function returns the current name of the provided setting key, for keys stored outside the settings file.
+///
+/// Use it when restoring settings from backups, snapshots and the like, as they may have been saved before a key was renamed.
+pub fn migrated_setting_key(key: &str) -> String {
+    renamed_key(SETTINGS_RENAMED_KEYS, 0, key, &renamed_keys_games()).unwrap_or_else(|| key.to_owned())
+}
+
+/// This function returns the keys of the games replacing `{game}` in the renamed settings keys.
+fn renamed_keys_games() -> Vec<&'static str> {
+    SUPPORTED_GAMES.games_sorted()
+        .iter()
+        .filter(|game| game.key() != KEY_ARENA)
+        .map(|game| game.key())
+        .collect()
+}
+
+/// This function returns the new name of the provided key, if any of the provided renames newer than the provided version matches it.
+///
+/// Renames are applied in order, so a key renamed more than once ends up with its latest name.
+fn renamed_key(renames: &[(i32, &str, &str)], version: i32, key: &str, game_keys: &[&str]) -> Option<String> {
+    let mut current_key = key.to_owned();
+    for (_, old_key, new_key) in renames.iter().filter(|(key_version, _, _)| *key_version > version) {
+        let expanded = if old_key.contains("{game}") {
+            game_keys.iter()
+                .map(|game| (old_key.replace("{game}", game), new_key.replace("{game}", game)))
+                .collect::<Vec<_>>()
+        } else {
+            vec![(old_key.to_string(), new_key.to_string())]
+        };
+
+        for (old_key, new_key) in &expanded {
+            let renamed = match (old_key.strip_suffix('*'), new_key.strip_suffix('*')) {
+                (Some(old_prefix), Some(new_prefix)) => current_key.strip_prefix(old_prefix).map(|rest| format!("{new_prefix}{rest}")),
+                _ => if current_key == *old_key { Some(new_key.to_owned()) } else { None },
+            };
+
+            if let Some(renamed) = renamed {
+                current_key = renamed;
+                break;
+            }
+        }
+    }
+
+    if current_key != key {
+        Some(current_key)
+    } else {
+        None
+    }
+}
+
 //-------------------------------------------------------------------------------//
 //                             Extra Helpers
 //-------------------------------------------------------------------------------//
@@ -667,6 +1224,7 @@ pub fn init_config_path() -> Result<()> {
     DirBuilder::new().recursive(true).create(error_path()?)?;
     DirBuilder::new().recursive(true).create(game_config_path()?)?;
     DirBuilder::new().recursive(true).create(profiles_path()?)?;
+    DirBuilder::new().recursive(true).create(profile_backups_path()?)?;
     DirBuilder::new().recursive(true).create(schemas_path()?)?;
     DirBuilder::new().recursive(true).create(sql_scripts_extracted_path()?)?;
     DirBuilder::new().recursive(true).create(sql_scripts_remote_path()?)?;
@@ -676,6 +1234,8 @@ pub fn init_config_path() -> Result<()> {
     for game in SUPPORTED_GAMES.games_sorted().iter() {
         if game.key() != KEY_ARENA {
             DirBuilder::new().recursive(true).create(config_path()?.join("temp_packs").join(game.key()))?;
+            DirBuilder::new().recursive(true).create(config_path()?.join("script_sandbox").join(game.key()))?;
+            DirBuilder::new().recursive(true).create(config_path()?.join("script_backups").join(game.key()))?;
             DirBuilder::new().recursive(true).create(sql_scripts_local_path()?.join(game.key()))?;
         }
     }
@@ -683,8 +1243,61 @@ pub fn init_config_path() -> Result<()> {
     Ok(())
 }
 
+/// This function returns the root folder for packs generated on launch: the custom one, if set, or the config folder otherwise.
+pub fn generated_packs_path() -> Result<PathBuf> {
+    let path = setting_string(GENERATED_PACKS_PATH);
+    if path.trim().is_empty() {
+        config_path()
+    } else {
+        Ok(PathBuf::from(path.trim()))
+    }
+}
+
+/// This function returns the folder for the temp packs of the provided game, creating it if needed.
 pub fn temp_packs_folder(game: &GameInfo) -> Result<PathBuf> {
-    Ok(config_path()?.join("temp_packs").join(game.key()))
+    let path = generated_packs_path()?.join("temp_packs").join(game.key());
+    DirBuilder::new().recursive(true).create(&path)?;
+    Ok(path)
+}
+
+/// This function returns the folder for the merged packs of the provided game, if a custom folder for generated packs is set.
+///
+/// Without a custom folder, merged packs go to /data. Same for games that cannot load packs from outside /data.
+pub fn merged_packs_folder(game: &GameInfo) -> Result<Option<PathBuf>> {
+    if setting_string(GENERATED_PACKS_PATH).trim().is_empty() || *game.raw_db_version() < 1 {
+        return Ok(None);
+    }
+
+    let path = generated_packs_path()?.join("merged_packs").join(game.key());
+    DirBuilder::new().recursive(true).create(&path)?;
+    Ok(Some(path))
+}
+
+/// This function checks if we can write files in the provided folder, creating it if it doesn't exist.
+pub fn is_folder_writable(path: &Path) -> bool {
+    if DirBuilder::new().recursive(true).create(path).is_err() {
+        return false;
+    }
+
+    let probe_path = path.join(".runcher_write_check");
+    let writable = File::create(&probe_path).is_ok();
+    let _ = std::fs::remove_file(&probe_path);
+    writable
+}
+
+/// This function returns the folder for the mod lists of the launch commands of profiles of the provided game, creating it if needed.
+pub fn profile_mod_lists_folder(game: &GameInfo) -> Result<PathBuf> {
+    let path = config_path()?.join("profile_mod_lists").join(game.key());
+    DirBuilder::new().recursive(true).create(&path)?;
+    Ok(path)
+}
+
+pub fn script_sandbox_folder(game: &GameInfo) -> Result<PathBuf> {
+    Ok(config_path()?.join("script_sandbox").join(game.key()))
+}
+
+pub fn script_backups_folder(game: &GameInfo) -> Result<PathBuf> {
+    Ok(config_path()?.join("script_backups").join(game.key()))
 }
 
 pub fn sql_scripts_extracted_path() -> Result<PathBuf> {
@@ -699,6 +1312,11 @@ pub fn sql_scripts_local_path() -> Result<PathBuf> {
     Ok(config_path()?.join(SQL_SCRIPTS_LOCAL_FOLDER))
 }
 
+/// This function returns the folder for presets installed by the user, which take priority over the extracted ones.
+pub fn sql_scripts_local_presets_path() -> Result<PathBuf> {
+    Ok(sql_scripts_local_path()?.join("presets"))
+}
+
 pub fn sql_scripts_remote_path() -> Result<PathBuf> {
     Ok(config_path()?.join(SQL_SCRIPTS_REMOTE_FOLDER))
 }
@@ -715,6 +1333,14 @@ pub fn profiles_path() -> Result<PathBuf> {
     Ok(config_path()?.join("profiles"))
 }
 
+pub fn profile_backups_path() -> Result<PathBuf> {
+    Ok(config_path()?.join("profile_backups"))
+}
+
+pub fn save_backups_path() -> Result<PathBuf> {
+    Ok(config_path()?.join("save_backups"))
+}
+
 pub fn last_game_update_date(game: &GameInfo, game_path: &Path) -> Result<u64> {
     Ok(if let Some(exe_path) = game.executable_path(game_path) {
         if let Ok(exe) = File::open(exe_path) {
@@ -730,3 +1356,44 @@ pub fn last_game_update_date(game: &GameInfo, game_path: &Path) -> Result<u64> {
         0
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GAMES: [&str; 2] = ["warhammer_3", "troy"];
+
+    #[test]
+    fn renamed_key_renames_per_game_keys() {
+        assert_eq!(renamed_key(SETTINGS_RENAMED_KEYS, 1, "enable_skip_intros_warhammer_3", &GAMES), Some("enable_skip_intro_warhammer_3".to_owned()));
+        assert_eq!(renamed_key(SETTINGS_RENAMED_KEYS, 1, "enable_skip_intros_troy", &GAMES), Some("enable_skip_intro_troy".to_owned()));
+        assert_eq!(renamed_key(SETTINGS_RENAMED_KEYS, 1, "enable_skip_intro_troy", &GAMES), None);
+    }
+
+    #[test]
+    fn renamed_key_skips_migrations_already_applied() {
+        assert_eq!(renamed_key(SETTINGS_RENAMED_KEYS, 2, "enable_skip_intros_warhammer_3", &GAMES), None);
+    }
+
+    #[test]
+    fn renamed_key_only_renames_exact_keys() {
+        let renames = [(2, "merge_all_mods", "merge_mods")];
+        assert_eq!(renamed_key(&renames, 1, "merge_all_mods", &GAMES), Some("merge_mods".to_owned()));
+        assert_eq!(renamed_key(&renames, 1, "merge_all_mods_categories", &GAMES), None);
+    }
+
+    #[test]
+    fn renamed_key_renames_keys_under_wildcards() {
+        let renames = [(2, "script_{game}_old_*", "script_{game}_new_*")];
+        assert_eq!(renamed_key(&renames, 1, "script_troy_old_param", &GAMES), Some("script_troy_new_param".to_owned()));
+        assert_eq!(renamed_key(&renames, 1, "script_troy_older_param", &GAMES), None);
+    }
+
+    #[test]
+    fn renamed_key_chains_renames() {
+        let renames = [(2, "a", "b"), (3, "b", "c")];
+        assert_eq!(renamed_key(&renames, 1, "a", &GAMES), Some("c".to_owned()));
+        assert_eq!(renamed_key(&renames, 2, "a", &GAMES), None);
+        assert_eq!(renamed_key(&renames, 2, "b", &GAMES), Some("c".to_owned()));
+    }
+}
@@ -32,6 +32,7 @@ use rpfm_ui_common::clone;
 use rpfm_ui_common::settings::*;
 use rpfm_ui_common::utils::show_dialog;
 
+use crate::app_ui::AppUI;
 use crate::settings_ui::init_settings;
 use crate::settings_ui::SettingsUI;
 
@@ -52,6 +53,10 @@ pub struct SettingsUISlots {
     select_game_paths: BTreeMap<String, QBox<SlotNoArgs>>,
     select_game_lock: BTreeMap<String, QBox<SlotOfBool>>,
     select_secondary_mods_path: QBox<SlotNoArgs>,
+    add_secondary_mods_extra_path: QBox<SlotNoArgs>,
+    select_generated_packs_path: QBox<SlotNoArgs>,
+    accept: QBox<SlotNoArgs>,
+    apply: QBox<SlotNoArgs>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -60,7 +65,8 @@ pub struct SettingsUISlots {
 
 impl SettingsUISlots {
 
-    pub unsafe fn new(ui: &Rc<SettingsUI>, main_window: QPtr<QMainWindow>) -> Self {
+    pub unsafe fn new(ui: &Rc<SettingsUI>, app_ui: &Rc<AppUI>) -> Self {
+        let main_window: QPtr<QMainWindow> = app_ui.main_window().static_upcast();
         let tools_context_menu = SlotOfQPoint::new(ui.dialog(), clone!(
             ui => move |_| {
             ui.tools_context_menu().exec_1a_mut(&QCursor::pos_0a());
@@ -198,7 +204,36 @@ impl SettingsUISlots {
 
         let select_secondary_mods_path = SlotNoArgs::new(&ui.dialog, clone!(
             ui => move || {
-            ui.update_secondary_mods_path();
+            ui.update_folder_path(ui.secondary_mods_folder_line_edit());
+        }));
+
+        let add_secondary_mods_extra_path = SlotNoArgs::new(&ui.dialog, clone!(
+            ui => move || {
+            ui.add_secondary_mods_extra_path();
+        }));
+
+        let select_generated_packs_path = SlotNoArgs::new(&ui.dialog, clone!(
+            ui => move || {
+            ui.update_folder_path(ui.generated_packs_folder_line_edit());
+        }));
+
+        // Only accept the dialog if the settings are valid, so the user can fix them without losing the rest of the changes.
+        let accept = SlotNoArgs::new(&ui.dialog, clone!(
+            ui => move || {
+            match ui.validate() {
+                Ok(_) => ui.dialog().accept(),
+                Err(error) => show_dialog(ui.dialog(), error, false),
+            }
+        }));
+
+        // Same as accept, but keeping the dialog open after saving and applying the settings.
+        let apply = SlotNoArgs::new(&ui.dialog, clone!(
+            ui,
+            app_ui => move || {
+            match ui.validate().and_then(|_| ui.save()) {
+                Ok(_) => app_ui.apply_settings(&mut ui.applied_settings().borrow_mut()),
+                Err(error) => show_dialog(ui.dialog(), error, false),
+            }
         }));
 
         Self {
@@ -212,6 +247,10 @@ impl SettingsUISlots {
             select_game_paths,
             select_game_lock,
             select_secondary_mods_path,
+            add_secondary_mods_extra_path,
+            select_generated_packs_path,
+            accept,
+            apply,
         }
     }
 }
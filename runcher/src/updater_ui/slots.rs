@@ -97,7 +97,7 @@ impl UpdaterUISlots {
                     Response::Success => {
 
                         // We need to reload the game in question, so stuff that depends on schemas existing actually works.
-                        if let Err(error) = app_ui.change_game_selected(true, true) {
+                        if let Err(error) = app_ui.change_game_selected(true, true, false) {
                             show_dialog(ui.dialog(), error, false);
                             ui.update_schemas_button.set_text(&qtr("updater_update_schemas_error"));
                         } else {
@@ -125,7 +125,7 @@ impl UpdaterUISlots {
                     Response::Success => {
 
                         // We need to reload the game in question, so the script list is updated.
-                        if let Err(error) = app_ui.change_game_selected(true, true) {
+                        if let Err(error) = app_ui.change_game_selected(true, true, false) {
                             show_dialog(ui.dialog(), error, false);
                             ui.update_sql_scripts_button.set_text(&qtr("updater_update_sql_scripts_error"));
                         } else {